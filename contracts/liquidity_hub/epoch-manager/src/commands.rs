@@ -1,67 +1,247 @@
-use cosmwasm_std::{Api, DepsMut, Env, MessageInfo, Response, SubMsg};
+use cosmwasm_std::{
+    Addr, Api, DepsMut, Env, MessageInfo, Order, ReplyOn, Response, StdResult, Storage, SubMsg,
+};
 
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use cw_controllers::HookError;
+use white_whale_std::epoch_manager::epoch_manager::{EpochConfig, EpochV2};
 use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
 
-use crate::state::{ADMIN, CONFIG, EPOCH, HOOKS};
+use crate::queries::query_epoch;
+use crate::state::{ADMIN, CONFIG, EPOCH, FAILED_HOOKS, HOOKS, HOOK_DELIVERIES, HOOK_GAS_LIMITS};
 use crate::ContractError;
 
-/// Adds a new hook to the contract.
+/// The maximum amount of epochs that can be created at once, as each of them is sent to every hook
+/// within the same transaction.
+pub const MAX_EPOCHS_PER_CALL: u32 = 10;
+
+/// Adds a new hook to the contract, optionally with a gas limit.
 pub fn add_hook(
-    deps: DepsMut,
+    mut deps: DepsMut,
     info: MessageInfo,
     api: &dyn Api,
     contract_addr: &str,
+    gas_limit: Option<u64>,
 ) -> Result<Response, ContractError> {
-    Ok(HOOKS.execute_add_hook(&ADMIN, deps, info, api.addr_validate(contract_addr)?)?)
+    let hook = api.addr_validate(contract_addr)?;
+    let response = HOOKS.execute_add_hook(&ADMIN, deps.branch(), info, hook.clone())?;
+
+    if let Some(gas_limit) = gas_limit {
+        HOOK_GAS_LIMITS.save(deps.storage, &hook, &gas_limit)?;
+    }
+
+    Ok(response.add_attribute(
+        "gas_limit",
+        gas_limit.map_or_else(|| "none".to_string(), |gas_limit| gas_limit.to_string()),
+    ))
 }
 
 pub(crate) fn remove_hook(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    api: &dyn Api,
+    contract_addr: &str,
+) -> Result<Response, ContractError> {
+    let hook = api.addr_validate(contract_addr)?;
+    let response = HOOKS.execute_remove_hook(&ADMIN, deps.branch(), info, hook.clone())?;
+
+    // forget about the hook's settings and failures
+    HOOK_GAS_LIMITS.remove(deps.storage, &hook);
+    let failed_epochs = FAILED_HOOKS
+        .prefix(&hook)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for epoch_id in failed_epochs {
+        FAILED_HOOKS.remove(deps.storage, (&hook, epoch_id));
+    }
+
+    Ok(response)
+}
+
+/// Updates the gas limit of the given hook, or removes it if none is provided.
+pub(crate) fn update_hook_gas_limit(
     deps: DepsMut,
     info: MessageInfo,
     api: &dyn Api,
     contract_addr: &str,
+    gas_limit: Option<u64>,
 ) -> Result<Response, ContractError> {
-    Ok(HOOKS.execute_remove_hook(&ADMIN, deps, info, api.addr_validate(contract_addr)?)?)
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+
+    let hook = api.addr_validate(contract_addr)?;
+    if !HOOKS.query_hook(deps.as_ref(), hook.to_string())? {
+        return Err(HookError::HookNotRegistered {}.into());
+    }
+
+    match gas_limit {
+        Some(gas_limit) => HOOK_GAS_LIMITS.save(deps.storage, &hook, &gas_limit)?,
+        None => HOOK_GAS_LIMITS.remove(deps.storage, &hook),
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_hook_gas_limit".to_string()),
+        ("hook", hook.to_string()),
+        (
+            "gas_limit",
+            gas_limit.map_or_else(|| "none".to_string(), |gas_limit| gas_limit.to_string()),
+        ),
+    ]))
 }
 
-/// Creates a new epoch.
-pub fn create_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
-    let mut current_epoch = EPOCH.load(deps.storage)?;
-    let config = CONFIG.load(deps.storage)?;
-
-    if env
-        .block
-        .time
-        .minus_nanos(current_epoch.start_time.nanos())
-        .nanos()
-        < config.epoch_config.duration.u64()
+/// Delivers the given epoch again to a hook that failed to process it. Only the owner and the hook
+/// itself can retry, so third parties can't make the hook reprocess epochs at will.
+pub(crate) fn retry_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    api: &dyn Api,
+    contract_addr: &str,
+    epoch_id: u64,
+) -> Result<Response, ContractError> {
+    let hook = api.addr_validate(contract_addr)?;
+
+    if info.sender != hook && !ADMIN.is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if !FAILED_HOOKS.has(deps.storage, (&hook, epoch_id)) {
+        return Err(ContractError::NoFailedHook {
+            contract_addr: hook.to_string(),
+            epoch_id,
+        });
+    }
+
+    // the failure is recorded again by the reply if the hook fails once more
+    FAILED_HOOKS.remove(deps.storage, (&hook, epoch_id));
+
+    let epoch = query_epoch(deps.as_ref(), epoch_id)?.epoch;
+    let message = prepare_hook_messages(deps.storage, vec![hook], std::slice::from_ref(&epoch))?;
+
+    Ok(Response::default()
+        .add_submessages(message)
+        .add_attributes(vec![
+            ("action", "retry_hook".to_string()),
+            ("hook", contract_addr.to_string()),
+            ("epoch", epoch.to_string()),
+        ]))
+}
+
+/// Prepares the messages notifying the given hooks about the given epochs, in order. Failures are
+/// caught on reply, so a failing hook doesn't prevent the others from being notified.
+fn prepare_hook_messages(
+    storage: &mut dyn Storage,
+    hooks: Vec<Addr>,
+    epochs: &[EpochV2],
+) -> Result<Vec<SubMsg>, ContractError> {
+    let mut messages = vec![];
+    let mut deliveries = vec![];
+
+    for epoch in epochs {
+        for hook in hooks.iter() {
+            let msg = EpochChangedHookMsg {
+                current_epoch: epoch.clone(),
+            }
+            .into_cosmos_msg(hook.clone())?;
+
+            messages.push(SubMsg {
+                id: deliveries.len() as u64,
+                msg,
+                gas_limit: HOOK_GAS_LIMITS.may_load(storage, hook)?,
+                reply_on: ReplyOn::Error,
+            });
+            deliveries.push((hook.clone(), epoch.id));
+        }
+    }
+
+    HOOK_DELIVERIES.save(storage, &deliveries)?;
+
+    Ok(messages)
+}
+
+/// Advances the current epoch by up to `max` epochs, as long as they have expired. Returns the
+/// created epochs.
+fn advance_epochs(
+    storage: &mut dyn Storage,
+    env: &Env,
+    max: u32,
+) -> Result<Vec<EpochV2>, ContractError> {
+    let mut current_epoch = EPOCH.load(storage)?;
+    let config = CONFIG.load(storage)?;
+    let duration = config.epoch_config.duration.u64();
+
+    let mut epochs = vec![];
+    while epochs.len() < max as usize
+        && env
+            .block
+            .time
+            .minus_nanos(current_epoch.start_time.nanos())
+            .nanos()
+            >= duration
     {
+        current_epoch.id = current_epoch
+            .id
+            .checked_add(1u64)
+            .ok_or(ContractError::EpochOverflow)?;
+        current_epoch.start_time = current_epoch.start_time.plus_nanos(duration);
+
+        epochs.push(current_epoch.clone());
+    }
+
+    if epochs.is_empty() {
         return Err(ContractError::CurrentEpochNotExpired);
     }
 
-    current_epoch.id = current_epoch
-        .id
-        .checked_add(1u64)
-        .ok_or(ContractError::EpochOverflow)?;
-    current_epoch.start_time = current_epoch
-        .start_time
-        .plus_nanos(config.epoch_config.duration.u64());
+    EPOCH.save(storage, &current_epoch)?;
 
-    EPOCH.save(deps.storage, &current_epoch)?;
+    Ok(epochs)
+}
 
-    let messages = HOOKS.prepare_hooks(deps.storage, |hook| {
-        EpochChangedHookMsg {
-            current_epoch: current_epoch.clone(),
-        }
-        .into_cosmos_msg(hook)
-        .map(SubMsg::new)
-    })?;
+/// Creates a new epoch.
+pub fn create_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let epochs = advance_epochs(deps.storage, &env, 1u32)?;
+
+    let hooks = HOOKS
+        .query_hooks(deps.as_ref())?
+        .hooks
+        .into_iter()
+        .map(Addr::unchecked)
+        .collect();
+    let messages = prepare_hook_messages(deps.storage, hooks, &epochs)?;
 
     Ok(Response::default()
         .add_submessages(messages)
         .add_attributes(vec![
             ("action", "create_epoch".to_string()),
+            ("current_epoch", epochs[0].to_string()),
+        ]))
+}
+
+/// Creates all the epochs that were missed since the current one, up to `max`, which can't exceed
+/// [MAX_EPOCHS_PER_CALL]. The hooks are notified about each of the created epochs, in order.
+pub fn create_epochs(deps: DepsMut, env: Env, max: u32) -> Result<Response, ContractError> {
+    if max == 0u32 || max > MAX_EPOCHS_PER_CALL {
+        return Err(ContractError::InvalidMaxEpochs);
+    }
+
+    let epochs = advance_epochs(deps.storage, &env, max)?;
+
+    let hooks = HOOKS
+        .query_hooks(deps.as_ref())?
+        .hooks
+        .into_iter()
+        .map(Addr::unchecked)
+        .collect();
+    let messages = prepare_hook_messages(deps.storage, hooks, &epochs)?;
+
+    let first_epoch = epochs
+        .first()
+        .ok_or(ContractError::CurrentEpochNotExpired)?;
+    let current_epoch = epochs.last().ok_or(ContractError::CurrentEpochNotExpired)?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attributes(vec![
+            ("action", "create_epochs".to_string()),
+            ("from_epoch_id", first_epoch.id.to_string()),
+            ("to_epoch_id", current_epoch.id.to_string()),
             ("current_epoch", current_epoch.to_string()),
         ]))
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{entry_point, to_json_binary, StdError};
+use cosmwasm_std::{entry_point, to_json_binary, Reply, StdError, SubMsgResult};
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
@@ -9,7 +9,7 @@ use white_whale_std::epoch_manager::epoch_manager::{
 use white_whale_std::migrate_guards::check_contract_name;
 
 use crate::error::ContractError;
-use crate::state::{ADMIN, CONFIG, EPOCH};
+use crate::state::{ADMIN, CONFIG, EPOCH, FAILED_HOOKS, HOOK_DELIVERIES};
 use crate::{commands, queries};
 
 // version info for migration info
@@ -62,13 +62,23 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     let api = deps.api;
     match msg {
-        ExecuteMsg::AddHook { contract_addr } => {
-            commands::add_hook(deps, info, api, &contract_addr)
-        }
+        ExecuteMsg::AddHook {
+            contract_addr,
+            gas_limit,
+        } => commands::add_hook(deps, info, api, &contract_addr, gas_limit),
         ExecuteMsg::RemoveHook { contract_addr } => {
             commands::remove_hook(deps, info, api, &contract_addr)
         }
+        ExecuteMsg::RetryHook {
+            contract_addr,
+            epoch_id,
+        } => commands::retry_hook(deps, info, api, &contract_addr, epoch_id),
+        ExecuteMsg::UpdateHookGasLimit {
+            contract_addr,
+            gas_limit,
+        } => commands::update_hook_gas_limit(deps, info, api, &contract_addr, gas_limit),
         ExecuteMsg::CreateEpoch {} => commands::create_epoch(deps, env),
+        ExecuteMsg::CreateEpochs { max } => commands::create_epochs(deps, env, max),
         ExecuteMsg::UpdateConfig {
             owner,
             epoch_config,
@@ -82,9 +92,40 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Config {} => Ok(to_json_binary(&queries::query_config(deps)?)?),
         QueryMsg::CurrentEpoch {} => Ok(to_json_binary(&queries::query_current_epoch(deps)?)?),
         QueryMsg::Epoch { id } => Ok(to_json_binary(&queries::query_epoch(deps, id)?)?),
+        QueryMsg::EpochAt { timestamp } => {
+            Ok(to_json_binary(&queries::query_epoch_at(deps, timestamp)?)?)
+        }
+        QueryMsg::HookStatus { contract_addr } => Ok(to_json_binary(&queries::query_hook_status(
+            deps,
+            contract_addr,
+        )?)?),
     }
 }
 
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    // hooks are only replied on error, record the failure so it can be retried
+    let error = match msg.result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => return Err(ContractError::UnknownReplyId(msg.id)),
+    };
+
+    let (hook, epoch_id) = HOOK_DELIVERIES
+        .load(deps.storage)?
+        .get(msg.id as usize)
+        .cloned()
+        .ok_or(ContractError::UnknownReplyId(msg.id))?;
+
+    FAILED_HOOKS.save(deps.storage, (&hook, epoch_id), &error)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "hook_failed".to_string()),
+        ("hook", hook.to_string()),
+        ("epoch_id", epoch_id.to_string()),
+        ("error", error),
+    ]))
+}
+
 #[entry_point]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     check_contract_name(deps.storage, CONTRACT_NAME.to_string())?;
@@ -14,6 +14,9 @@ pub enum ContractError {
     #[error("{0}")]
     HookError(#[from] HookError),
 
+    #[error("Unauthorized")]
+    Unauthorized,
+
     #[error("The epoch id has overflowed.")]
     EpochOverflow,
 
@@ -28,6 +31,21 @@ pub enum ContractError {
 
     #[error("The current epoch epoch has not expired yet.")]
     CurrentEpochNotExpired,
+
+    #[error(
+        "The maximum amount of epochs to create must be between 1 and {}.",
+        crate::commands::MAX_EPOCHS_PER_CALL
+    )]
+    InvalidMaxEpochs,
+
+    #[error("The hook {contract_addr} didn't fail to process epoch {epoch_id}.")]
+    NoFailedHook {
+        contract_addr: String,
+        epoch_id: u64,
+    },
+
+    #[error("Can't handle the given reply id: {0}")]
+    UnknownReplyId(u64),
 }
 
 impl From<semver::Error> for ContractError {
@@ -5,3 +5,6 @@ pub mod contract;
 mod error;
 pub mod queries;
 pub mod state;
+
+#[cfg(test)]
+mod tests;
@@ -1,8 +1,10 @@
-use cosmwasm_std::{Addr, Deps, StdResult};
+use cosmwasm_std::{Addr, Deps, Order, StdError, StdResult, Timestamp};
 
-use white_whale_std::epoch_manager::epoch_manager::{ConfigResponse, EpochResponse, EpochV2};
+use white_whale_std::epoch_manager::epoch_manager::{
+    ConfigResponse, EpochResponse, EpochV2, FailedHookDelivery, HookStatusResponse,
+};
 
-use crate::state::{ADMIN, CONFIG, EPOCH};
+use crate::state::{ADMIN, CONFIG, EPOCH, FAILED_HOOKS, HOOK_GAS_LIMITS};
 
 /// Queries the config. Returns a [ConfigResponse].
 pub(crate) fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
@@ -40,3 +42,61 @@ pub(crate) fn query_epoch(deps: Deps, id: u64) -> StdResult<EpochResponse> {
         Ok(epoch.to_epoch_response())
     }
 }
+
+/// Queries the epoch the given timestamp falls in. Returns an [EpochResponse].
+pub(crate) fn query_epoch_at(deps: Deps, timestamp: Timestamp) -> StdResult<EpochResponse> {
+    let epoch_config = CONFIG.load(deps.storage)?.epoch_config;
+
+    if timestamp.nanos() < epoch_config.genesis_epoch.u64() {
+        return Err(StdError::generic_err(
+            "The timestamp is before the genesis epoch",
+        ));
+    }
+
+    let current_epoch = EPOCH.load(deps.storage)?;
+    let duration = epoch_config.duration.u64();
+
+    let epoch = if timestamp >= current_epoch.start_time {
+        let epochs_ahead = (timestamp.nanos() - current_epoch.start_time.nanos()) / duration;
+
+        EpochV2 {
+            id: current_epoch.id + epochs_ahead,
+            start_time: current_epoch.start_time.plus_nanos(duration * epochs_ahead),
+        }
+    } else {
+        let epochs_behind =
+            (current_epoch.start_time.nanos() - timestamp.nanos() + duration - 1) / duration;
+
+        EpochV2 {
+            id: current_epoch.id.saturating_sub(epochs_behind),
+            start_time: current_epoch
+                .start_time
+                .minus_nanos(duration * epochs_behind),
+        }
+    };
+
+    Ok(epoch.to_epoch_response())
+}
+
+/// Queries the status of the given hook. Returns a [HookStatusResponse].
+pub(crate) fn query_hook_status(
+    deps: Deps,
+    contract_addr: String,
+) -> StdResult<HookStatusResponse> {
+    let contract_addr = deps.api.addr_validate(&contract_addr)?;
+
+    let failed_epochs = FAILED_HOOKS
+        .prefix(&contract_addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (epoch_id, error) = item?;
+            Ok(FailedHookDelivery { epoch_id, error })
+        })
+        .collect::<StdResult<Vec<FailedHookDelivery>>>()?;
+
+    Ok(HookStatusResponse {
+        gas_limit: HOOK_GAS_LIMITS.may_load(deps.storage, &contract_addr)?,
+        contract_addr,
+        failed_epochs,
+    })
+}
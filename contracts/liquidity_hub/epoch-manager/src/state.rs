@@ -1,8 +1,15 @@
+use cosmwasm_std::Addr;
 use cw_controllers::{Admin, Hooks};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 use white_whale_std::epoch_manager::epoch_manager::{Config, EpochV2};
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const ADMIN: Admin = Admin::new("admin");
 pub const HOOKS: Hooks = Hooks::new("hooks");
 pub const EPOCH: Item<EpochV2> = Item::new("epoch");
+/// Gas limit for the hooks that have one.
+pub const HOOK_GAS_LIMITS: Map<&Addr, u64> = Map::new("hook_gas_limits");
+/// Errors returned by the hooks, keyed by hook and the epoch they failed to process.
+pub const FAILED_HOOKS: Map<(&Addr, u64), String> = Map::new("failed_hooks");
+/// The hooks and epochs being delivered in the current transaction, indexed by reply id.
+pub const HOOK_DELIVERIES: Item<Vec<(Addr, u64)>> = Item::new("hook_deliveries");
@@ -0,0 +1,139 @@
+use cosmwasm_std::{Addr, Timestamp};
+
+use crate::commands::MAX_EPOCHS_PER_CALL;
+use crate::tests::suite::{TestingSuite, DAY_IN_NANOSECONDS};
+use crate::ContractError;
+
+#[test]
+fn create_missed_epochs() {
+    let mut suite = TestingSuite::default();
+    let mut hook = Addr::unchecked("");
+
+    suite
+        .instantiate_default()
+        .instantiate_hook(false, &mut hook)
+        .add_hook(&hook, None, |result| {
+            result.unwrap();
+        })
+        .create_epochs(5u32, |result| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CurrentEpochNotExpired => {}
+                _ => {
+                    panic!("Wrong error type, should return ContractError::CurrentEpochNotExpired")
+                }
+            }
+        })
+        // nobody created epochs for 5 days
+        .add_days(5u64)
+        .create_epochs(0u32, |result| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidMaxEpochs => {}
+                _ => panic!("Wrong error type, should return ContractError::InvalidMaxEpochs"),
+            }
+        })
+        .create_epochs(MAX_EPOCHS_PER_CALL + 1, |result| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::InvalidMaxEpochs => {}
+                _ => panic!("Wrong error type, should return ContractError::InvalidMaxEpochs"),
+            }
+        })
+        .create_epochs(3u32, |result| {
+            let result = result.unwrap();
+            assert!(result.events.iter().any(|event| event
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "to_epoch_id" && attr.value == "3")));
+        })
+        .query_current_epoch(|epoch| {
+            assert_eq!(epoch.id, 3u64);
+        })
+        .query_hook_last_epoch(&hook, |last_epoch| {
+            assert_eq!(last_epoch, 3u64);
+        })
+        // only 2 epochs are left to catch up with
+        .create_epochs(10u32, |result| {
+            result.unwrap();
+        })
+        .query_current_epoch(|epoch| {
+            assert_eq!(epoch.id, 5u64);
+        })
+        .query_hook_last_epoch(&hook, |last_epoch| {
+            assert_eq!(last_epoch, 5u64);
+        })
+        .create_epochs(10u32, |result| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::CurrentEpochNotExpired => {}
+                _ => {
+                    panic!("Wrong error type, should return ContractError::CurrentEpochNotExpired")
+                }
+            }
+        });
+}
+
+#[test]
+fn failing_hook_on_missed_epochs() {
+    let mut suite = TestingSuite::default();
+    let mut hook = Addr::unchecked("");
+
+    suite
+        .instantiate_default()
+        .instantiate_hook(true, &mut hook)
+        .add_hook(&hook, None, |result| {
+            result.unwrap();
+        })
+        .add_days(3u64)
+        .create_epochs(3u32, |result| {
+            result.unwrap();
+        })
+        .query_hook_status(&hook, |status| {
+            let failed_epochs = status
+                .failed_epochs
+                .iter()
+                .map(|failed| failed.epoch_id)
+                .collect::<Vec<u64>>();
+            assert_eq!(failed_epochs, vec![1u64, 2u64, 3u64]);
+        });
+}
+
+#[test]
+fn query_epoch_at() {
+    let mut suite = TestingSuite::default();
+    let genesis = suite.get_time();
+
+    suite
+        .instantiate_default()
+        .add_days(3u64)
+        .create_epochs(3u32, |result| {
+            result.unwrap();
+        })
+        .query_epoch_at(genesis.minus_nanos(1u64), |result| {
+            assert!(result.is_err());
+        })
+        .query_epoch_at(genesis, |result| {
+            let epoch = result.unwrap();
+            assert_eq!(epoch.id, 0u64);
+            assert_eq!(epoch.start_time, genesis);
+        })
+        .query_epoch_at(genesis.plus_nanos(DAY_IN_NANOSECONDS + 1), |result| {
+            let epoch = result.unwrap();
+            assert_eq!(epoch.id, 1u64);
+            assert_eq!(epoch.start_time, genesis.plus_nanos(DAY_IN_NANOSECONDS));
+        })
+        .query_epoch_at(genesis.plus_nanos(DAY_IN_NANOSECONDS * 3), |result| {
+            let epoch = result.unwrap();
+            assert_eq!(epoch.id, 3u64);
+        })
+        // epochs in the future are computed from the epoch config
+        .query_epoch_at(
+            Timestamp::from_nanos(genesis.nanos() + DAY_IN_NANOSECONDS * 10 - 1),
+            |result| {
+                let epoch = result.unwrap();
+                assert_eq!(epoch.id, 9u64);
+                assert_eq!(epoch.start_time, genesis.plus_nanos(DAY_IN_NANOSECONDS * 9));
+            },
+        );
+}
@@ -0,0 +1,171 @@
+use cosmwasm_std::Addr;
+
+use crate::tests::suite::TestingSuite;
+use crate::ContractError;
+
+#[test]
+fn failing_hook_does_not_revert_epoch_creation() {
+    let mut suite = TestingSuite::default();
+    let mut healthy_hook = Addr::unchecked("");
+    let mut broken_hook = Addr::unchecked("");
+
+    suite
+        .instantiate_default()
+        .instantiate_hook(false, &mut healthy_hook)
+        .instantiate_hook(true, &mut broken_hook)
+        .add_hook(&healthy_hook, None, |result| {
+            result.unwrap();
+        })
+        .add_hook(&broken_hook, None, |result| {
+            result.unwrap();
+        })
+        .create_epoch(|result| {
+            result.unwrap();
+        })
+        .query_current_epoch(|epoch| {
+            assert_eq!(epoch.id, 1u64);
+        })
+        .query_hook_last_epoch(&healthy_hook, |last_epoch| {
+            assert_eq!(last_epoch, 1u64);
+        })
+        .query_hook_status(&healthy_hook, |status| {
+            assert!(status.failed_epochs.is_empty());
+        })
+        .query_hook_status(&broken_hook, |status| {
+            assert_eq!(status.failed_epochs.len(), 1);
+            assert_eq!(status.failed_epochs[0].epoch_id, 1u64);
+            assert!(!status.failed_epochs[0].error.is_empty());
+        });
+}
+
+#[test]
+fn retry_failed_hook() {
+    let mut suite = TestingSuite::default();
+    let mut hook = Addr::unchecked("");
+    let owner = suite.owner.clone();
+
+    suite
+        .instantiate_default()
+        .instantiate_hook(true, &mut hook)
+        .add_hook(&hook, None, |result| {
+            result.unwrap();
+        })
+        .create_epoch(|result| {
+            result.unwrap();
+        })
+        .create_epoch(|result| {
+            result.unwrap();
+        })
+        .retry_hook(&Addr::unchecked("anyone"), &hook, 1u64, |result| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::Unauthorized => {}
+                _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+            }
+        })
+        .retry_hook(&owner, &hook, 3u64, |result| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::NoFailedHook { epoch_id, .. } => assert_eq!(epoch_id, 3u64),
+                _ => panic!("Wrong error type, should return ContractError::NoFailedHook"),
+            }
+        })
+        // the hook is still broken, so the failure is kept
+        .retry_hook(&owner, &hook, 1u64, |result| {
+            result.unwrap();
+        })
+        .query_hook_status(&hook, |status| {
+            let failed_epochs = status
+                .failed_epochs
+                .iter()
+                .map(|failed| failed.epoch_id)
+                .collect::<Vec<u64>>();
+            assert_eq!(failed_epochs, vec![1u64, 2u64]);
+        })
+        .set_hook_broken(&hook, false)
+        .retry_hook(&owner, &hook, 1u64, |result| {
+            result.unwrap();
+        })
+        .query_hook_last_epoch(&hook, |last_epoch| {
+            assert_eq!(last_epoch, 1u64);
+        })
+        .query_hook_status(&hook, |status| {
+            assert_eq!(status.failed_epochs.len(), 1);
+            assert_eq!(status.failed_epochs[0].epoch_id, 2u64);
+        })
+        // the hook can retry by itself
+        .retry_hook(&hook.clone(), &hook, 2u64, |result| {
+            result.unwrap();
+        })
+        .query_hook_last_epoch(&hook, |last_epoch| {
+            assert_eq!(last_epoch, 2u64);
+        })
+        .query_hook_status(&hook, |status| {
+            assert!(status.failed_epochs.is_empty());
+        });
+}
+
+#[test]
+fn hook_gas_limit() {
+    let mut suite = TestingSuite::default();
+    let mut hook = Addr::unchecked("");
+    let owner = suite.owner.clone();
+
+    suite
+        .instantiate_default()
+        .instantiate_hook(true, &mut hook)
+        .add_hook(&hook, Some(500_000u64), |result| {
+            result.unwrap();
+        })
+        .create_epoch(|result| {
+            result.unwrap();
+        })
+        .query_hook_status(&hook, |status| {
+            assert_eq!(status.gas_limit, Some(500_000u64));
+            assert_eq!(status.failed_epochs.len(), 1);
+        })
+        .update_hook_gas_limit(
+            &Addr::unchecked("anyone"),
+            &hook,
+            Some(1_000_000u64),
+            |result| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::AdminError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::AdminError"),
+                }
+            },
+        )
+        .update_hook_gas_limit(
+            &owner,
+            &Addr::unchecked("not_a_hook"),
+            Some(1_000_000u64),
+            |result| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::HookError(_) => {}
+                    _ => panic!("Wrong error type, should return ContractError::HookError"),
+                }
+            },
+        )
+        .update_hook_gas_limit(&owner, &hook, Some(1_000_000u64), |result| {
+            result.unwrap();
+        })
+        .query_hook_status(&hook, |status| {
+            assert_eq!(status.gas_limit, Some(1_000_000u64));
+        })
+        .update_hook_gas_limit(&owner, &hook, None, |result| {
+            result.unwrap();
+        })
+        .query_hook_status(&hook, |status| {
+            assert_eq!(status.gas_limit, None);
+        })
+        .remove_hook(&hook, |result| {
+            result.unwrap();
+        })
+        .query_hook_status(&hook, |status| {
+            // the hook settings and failures are gone with it
+            assert_eq!(status.gas_limit, None);
+            assert!(status.failed_epochs.is_empty());
+        });
+}
@@ -0,0 +1,3 @@
+mod epochs;
+mod hooks;
+mod suite;
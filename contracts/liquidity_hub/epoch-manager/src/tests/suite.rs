@@ -0,0 +1,344 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError,
+    StdResult, Timestamp, Uint64,
+};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use cw_storage_plus::Item;
+
+use white_whale_std::epoch_manager::epoch_manager::{
+    EpochConfig, EpochResponse, EpochV2, ExecuteMsg, HookStatusResponse, InstantiateMsg, QueryMsg,
+};
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
+
+pub const DAY_IN_NANOSECONDS: u64 = 86_400_000_000_000u64;
+
+/// A hook contract that can be set to fail when processing epochs.
+#[cw_serde]
+pub enum HookExecuteMsg {
+    EpochChangedHook(EpochChangedHookMsg),
+    SetBroken { broken: bool },
+}
+
+#[cw_serde]
+pub struct HookInstantiateMsg {
+    pub broken: bool,
+}
+
+const BROKEN: Item<bool> = Item::new("broken");
+const LAST_EPOCH: Item<u64> = Item::new("last_epoch");
+
+fn hook_instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: HookInstantiateMsg,
+) -> StdResult<Response> {
+    BROKEN.save(deps.storage, &msg.broken)?;
+    LAST_EPOCH.save(deps.storage, &0u64)?;
+    Ok(Response::default())
+}
+
+fn hook_execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: HookExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        HookExecuteMsg::EpochChangedHook(msg) => {
+            if BROKEN.load(deps.storage)? {
+                return Err(StdError::generic_err("hook failure"));
+            }
+            LAST_EPOCH.save(deps.storage, &msg.current_epoch.id)?;
+        }
+        HookExecuteMsg::SetBroken { broken } => BROKEN.save(deps.storage, &broken)?,
+    }
+
+    Ok(Response::default())
+}
+
+fn hook_query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_json_binary(&LAST_EPOCH.load(deps.storage)?)
+}
+
+fn hook_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        hook_execute,
+        hook_instantiate,
+        hook_query,
+    ))
+}
+
+fn epoch_manager_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        )
+        .with_reply(crate::contract::reply),
+    )
+}
+
+pub struct TestingSuite {
+    app: App,
+    pub owner: Addr,
+    pub epoch_manager_addr: Addr,
+}
+
+/// instantiate / execute messages
+impl TestingSuite {
+    pub(crate) fn default() -> Self {
+        Self {
+            app: App::default(),
+            owner: Addr::unchecked("owner"),
+            epoch_manager_addr: Addr::unchecked(""),
+        }
+    }
+
+    pub(crate) fn instantiate_default(&mut self) -> &mut Self {
+        let start_time = self.app.block_info().time;
+        let epoch_manager_id = self.app.store_code(epoch_manager_contract());
+
+        self.epoch_manager_addr = self
+            .app
+            .instantiate_contract(
+                epoch_manager_id,
+                self.owner.clone(),
+                &InstantiateMsg {
+                    start_epoch: EpochV2 { id: 0, start_time },
+                    epoch_config: EpochConfig {
+                        duration: Uint64::new(DAY_IN_NANOSECONDS),
+                        genesis_epoch: Uint64::new(start_time.nanos()),
+                    },
+                },
+                &[],
+                "epoch_manager",
+                None,
+            )
+            .unwrap();
+
+        self
+    }
+
+    pub(crate) fn instantiate_hook(&mut self, broken: bool, hook: &mut Addr) -> &mut Self {
+        let hook_id = self.app.store_code(hook_contract());
+
+        *hook = self
+            .app
+            .instantiate_contract(
+                hook_id,
+                self.owner.clone(),
+                &HookInstantiateMsg { broken },
+                &[],
+                "hook",
+                None,
+            )
+            .unwrap();
+
+        self
+    }
+
+    pub(crate) fn set_hook_broken(&mut self, hook: &Addr, broken: bool) -> &mut Self {
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                hook.clone(),
+                &HookExecuteMsg::SetBroken { broken },
+                &[],
+            )
+            .unwrap();
+
+        self
+    }
+
+    pub(crate) fn add_hook(
+        &mut self,
+        hook: &Addr,
+        gas_limit: Option<u64>,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::AddHook {
+            contract_addr: hook.to_string(),
+            gas_limit,
+        };
+
+        result(self.app.execute_contract(
+            self.owner.clone(),
+            self.epoch_manager_addr.clone(),
+            &msg,
+            &[],
+        ));
+
+        self
+    }
+
+    pub(crate) fn update_hook_gas_limit(
+        &mut self,
+        sender: &Addr,
+        hook: &Addr,
+        gas_limit: Option<u64>,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::UpdateHookGasLimit {
+            contract_addr: hook.to_string(),
+            gas_limit,
+        };
+
+        result(self.app.execute_contract(
+            sender.clone(),
+            self.epoch_manager_addr.clone(),
+            &msg,
+            &[],
+        ));
+
+        self
+    }
+
+    pub(crate) fn remove_hook(
+        &mut self,
+        hook: &Addr,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::RemoveHook {
+            contract_addr: hook.to_string(),
+        };
+
+        result(self.app.execute_contract(
+            self.owner.clone(),
+            self.epoch_manager_addr.clone(),
+            &msg,
+            &[],
+        ));
+
+        self
+    }
+
+    pub(crate) fn create_epoch(
+        &mut self,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        // move to the end of the current epoch
+        let mut block_info = self.app.block_info();
+        block_info.time = block_info.time.plus_nanos(DAY_IN_NANOSECONDS);
+        self.app.set_block(block_info);
+
+        result(self.app.execute_contract(
+            Addr::unchecked("anyone"),
+            self.epoch_manager_addr.clone(),
+            &ExecuteMsg::CreateEpoch {},
+            &[],
+        ));
+
+        self
+    }
+
+    pub(crate) fn get_time(&mut self) -> Timestamp {
+        self.app.block_info().time
+    }
+
+    pub(crate) fn add_days(&mut self, days: u64) -> &mut Self {
+        let mut block_info = self.app.block_info();
+        block_info.time = block_info.time.plus_nanos(DAY_IN_NANOSECONDS * days);
+        self.app.set_block(block_info);
+
+        self
+    }
+
+    pub(crate) fn create_epochs(
+        &mut self,
+        max: u32,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        result(self.app.execute_contract(
+            Addr::unchecked("anyone"),
+            self.epoch_manager_addr.clone(),
+            &ExecuteMsg::CreateEpochs { max },
+            &[],
+        ));
+
+        self
+    }
+
+    pub(crate) fn retry_hook(
+        &mut self,
+        sender: &Addr,
+        hook: &Addr,
+        epoch_id: u64,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::RetryHook {
+            contract_addr: hook.to_string(),
+            epoch_id,
+        };
+
+        result(self.app.execute_contract(
+            sender.clone(),
+            self.epoch_manager_addr.clone(),
+            &msg,
+            &[],
+        ));
+
+        self
+    }
+}
+
+/// queries
+impl TestingSuite {
+    pub(crate) fn query_current_epoch(&mut self, result: impl Fn(EpochV2)) -> &mut Self {
+        let response: EpochResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.epoch_manager_addr, &QueryMsg::CurrentEpoch {})
+            .unwrap();
+
+        result(response.epoch);
+
+        self
+    }
+
+    pub(crate) fn query_epoch_at(
+        &mut self,
+        timestamp: Timestamp,
+        result: impl Fn(StdResult<EpochV2>),
+    ) -> &mut Self {
+        let response: StdResult<EpochResponse> = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.epoch_manager_addr, &QueryMsg::EpochAt { timestamp });
+
+        result(response.map(|response| response.epoch));
+
+        self
+    }
+
+    pub(crate) fn query_hook_status(
+        &mut self,
+        hook: &Addr,
+        result: impl Fn(HookStatusResponse),
+    ) -> &mut Self {
+        let response: HookStatusResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.epoch_manager_addr,
+                &QueryMsg::HookStatus {
+                    contract_addr: hook.to_string(),
+                },
+            )
+            .unwrap();
+
+        result(response);
+
+        self
+    }
+
+    pub(crate) fn query_hook_last_epoch(&mut self, hook: &Addr, result: impl Fn(u64)) -> &mut Self {
+        let last_epoch: u64 = self.app.wrap().query_wasm_smart(hook, &Empty {}).unwrap();
+
+        result(last_epoch);
+
+        self
+    }
+}
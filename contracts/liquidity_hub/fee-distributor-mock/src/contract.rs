@@ -46,7 +46,7 @@ pub fn execute(
     _info: MessageInfo,
     msg: white_whale_std::fee_distributor::ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    if let white_whale_std::fee_distributor::ExecuteMsg::NewEpoch {} = msg {
+    if let white_whale_std::fee_distributor::ExecuteMsg::EpochChangedHook(_) = msg {
         CURRENT_EPOCH.update(deps.storage, |epoch| -> StdResult<_> {
             Ok(white_whale_std::fee_distributor::Epoch {
                 id: epoch.id + Uint64::one(),
@@ -78,6 +78,7 @@ pub fn query(
         white_whale_std::fee_distributor::QueryMsg::Epoch { .. } => {}
         white_whale_std::fee_distributor::QueryMsg::ClaimableEpochs { .. } => {}
         white_whale_std::fee_distributor::QueryMsg::Claimable { .. } => {}
+        white_whale_std::fee_distributor::QueryMsg::ClaimedEpochs { .. } => {}
     }
 
     to_json_binary(&"")
@@ -91,11 +91,19 @@ fn collect_fees_for_factory(
                 )?);
             }
         }
-        FactoryType::Pool { start_after, limit } => {
+        FactoryType::Pool {
+            start_after,
+            start_after_pool_identifier,
+            limit,
+        } => {
             let response: PairsResponse =
                 deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                     contract_addr: factory.to_string(),
-                    msg: to_json_binary(&QueryMsg::Pairs { start_after, limit })?,
+                    msg: to_json_binary(&QueryMsg::Pairs {
+                        start_after,
+                        start_after_pool_identifier,
+                        limit,
+                    })?,
                 }))?;
 
             for pair in response.pairs {
@@ -196,11 +204,19 @@ pub fn aggregate_fees(
                         store_temporal_asset_info(deps.branch(), vault_info.asset_info.clone())?;
                     }
                 }
-                FactoryType::Pool { start_after, limit } => {
+                FactoryType::Pool {
+                    start_after,
+                    start_after_pool_identifier,
+                    limit,
+                } => {
                     let response: PairsResponse =
                         deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                             contract_addr: factory.to_string(),
-                            msg: to_json_binary(&QueryMsg::Pairs { start_after, limit })?,
+                            msg: to_json_binary(&QueryMsg::Pairs {
+                                start_after,
+                                start_after_pool_identifier,
+                                limit,
+                            })?,
                         }))?;
 
                     for pair in response.pairs {
@@ -362,6 +378,7 @@ pub fn forward_fees(
                     factory_addr: config.pool_factory.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: None,
+                        start_after_pool_identifier: None,
                         limit: Some(30u32),
                     },
                 },
@@ -401,6 +418,7 @@ pub fn forward_fees(
                     factory_addr: config.pool_factory.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: None,
+                        start_after_pool_identifier: None,
                         limit: Some(30u32),
                     },
                 },
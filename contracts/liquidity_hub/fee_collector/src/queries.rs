@@ -127,12 +127,17 @@ fn query_fees_for_factory(
                 fees.push(vault_fee);
             }
         }
-        FactoryType::Pool { start_after, limit } => {
+        FactoryType::Pool {
+            start_after,
+            start_after_pool_identifier,
+            limit,
+        } => {
             let response: PairsResponse =
                 deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                     contract_addr: factory.to_string(),
                     msg: to_json_binary(&pool_network::factory::QueryMsg::Pairs {
                         start_after,
+                        start_after_pool_identifier,
                         limit,
                     })?,
                 }))?;
@@ -7,7 +7,7 @@ use cosmwasm_std::{
 use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, MinterResponse};
 use cw_multi_test::Executor;
 
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::CreateEpoch;
 use white_whale_std::fee::{Fee, VaultFee};
 use white_whale_std::fee_collector::ExecuteMsg::{
     AggregateFees, CollectFees, ForwardFees, UpdateConfig,
@@ -15,7 +15,6 @@ use white_whale_std::fee_collector::ExecuteMsg::{
 use white_whale_std::fee_collector::{
     Contract, ContractType, FactoryType, FeesFor, InstantiateMsg, QueryMsg,
 };
-use white_whale_std::fee_distributor::ExecuteMsg::NewEpoch;
 use white_whale_std::fee_distributor::{ClaimableEpochsResponse, Epoch, EpochResponse};
 use white_whale_std::pool_network::asset::AssetInfo::NativeToken;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairType};
@@ -24,7 +23,7 @@ use white_whale_std::pool_network::factory::PairsResponse;
 use white_whale_std::pool_network::pair::{PoolFee, PoolResponse, ProtocolFeesResponse};
 use white_whale_std::pool_network::router::{SwapOperation, SwapRoute};
 use white_whale_std::vault_network::vault_factory::ExecuteMsg;
-use white_whale_std::whale_lair::BondingWeightResponse;
+use white_whale_std::whale_lair::{BondingWeightResponse, WeightGrowth};
 use white_whale_std::{pool_network, vault_network};
 
 use crate::tests::common_integration::{
@@ -34,6 +33,7 @@ use crate::tests::common_integration::{
     store_trio_code, store_vault_code, store_vault_factory_code, store_whale_lair_code,
 };
 use crate::ContractError;
+use white_whale_testing::integration::contracts::instantiate_epoch_manager;
 
 #[test]
 fn collect_all_factories_cw20_fees_successfully() {
@@ -66,6 +66,8 @@ fn collect_all_factories_cw20_fees_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let pool_factory_address = app
@@ -281,6 +283,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                     belief_price: None,
                     max_spread: None,
                     to: None,
+                    trader: None,
                 })
                 .unwrap(),
             },
@@ -298,6 +301,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                     belief_price: None,
                     max_spread: Some(Decimal::percent(20u64)),
                     to: None,
+                    trader: None,
                 })
                 .unwrap(),
             },
@@ -388,6 +392,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                 factory_addr: pool_factory_address.to_string(),
                 factory_type: FactoryType::Pool {
                     start_after: None,
+                    start_after_pool_identifier: None,
                     limit: Some(u32::try_from(TOKEN_AMOUNT).unwrap()),
                 },
             },
@@ -405,6 +410,8 @@ fn collect_all_factories_cw20_fees_successfully() {
 
     // init fee distributor
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -412,11 +419,8 @@ fn collect_all_factories_cw20_fees_successfully() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: "whale_lair".to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(1),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: ask_asset.clone(),
             },
             &[],
@@ -425,6 +429,18 @@ fn collect_all_factories_cw20_fees_successfully() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     app.execute_contract(
         creator.sender.clone(),
         fee_collector_address.clone(),
@@ -490,6 +506,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: cw20_tokens[i as usize].to_string(),
                 },
+                pool_identifier: None,
             };
             swap_operations.push(swap_operation);
         }
@@ -521,6 +538,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                 factory_addr: pool_factory_address.to_string(),
                 factory_type: FactoryType::Pool {
                     start_after: None,
+                    start_after_pool_identifier: None,
                     limit: Some(u32::try_from(TOKEN_AMOUNT).unwrap()),
                 },
             },
@@ -603,6 +621,8 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -611,6 +631,8 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -799,6 +821,7 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                     belief_price: None,
                     max_spread: Some(Decimal::percent(30u64)),
                     to: None,
+                    trader: None,
                 })
                 .unwrap(),
             },
@@ -816,6 +839,7 @@ fn collect_cw20_fees_for_specific_contracts_successfully() {
                     belief_price: None,
                     max_spread: Some(Decimal::percent(30u64)),
                     to: None,
+                    trader: None,
                 })
                 .unwrap(),
             },
@@ -1060,6 +1084,8 @@ fn collect_pools_native_fees_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -1068,6 +1094,8 @@ fn collect_pools_native_fees_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -1254,6 +1282,7 @@ fn collect_pools_native_fees_successfully() {
                     factory_addr: pool_factory_address.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: None,
+                        start_after_pool_identifier: None,
                         limit: None,
                     },
                 },
@@ -1284,6 +1313,7 @@ fn collect_pools_native_fees_successfully() {
                 belief_price: None,
                 max_spread: None,
                 to: None,
+                trader: None,
             },
             &[Coin {
                 denom: "native".to_string(),
@@ -1303,6 +1333,7 @@ fn collect_pools_native_fees_successfully() {
                     belief_price: None,
                     max_spread: None,
                     to: None,
+                    trader: None,
                 })
                 .unwrap(),
             },
@@ -1362,6 +1393,7 @@ fn collect_pools_native_fees_successfully() {
                 factory_addr: pool_factory_address.to_string(),
                 factory_type: FactoryType::Pool {
                     start_after: None,
+                    start_after_pool_identifier: None,
                     limit: None,
                 },
             },
@@ -1380,6 +1412,7 @@ fn collect_pools_native_fees_successfully() {
                     factory_addr: pool_factory_address.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: None,
+                        start_after_pool_identifier: None,
                         limit: None,
                     },
                 },
@@ -1394,6 +1427,8 @@ fn collect_pools_native_fees_successfully() {
         contract_addr: cw20_tokens[0].to_string(),
     };
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -1401,11 +1436,8 @@ fn collect_pools_native_fees_successfully() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: "whale_lair".to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(1),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: ask_asset.clone(),
             },
             &[],
@@ -1414,6 +1446,18 @@ fn collect_pools_native_fees_successfully() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     app.execute_contract(
         creator.sender.clone(),
         fee_collector_address.clone(),
@@ -1496,6 +1540,7 @@ fn collect_pools_native_fees_successfully() {
                     factory_addr: pool_factory_address.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: None,
+                        start_after_pool_identifier: None,
                         limit: None,
                     },
                 },
@@ -1523,12 +1568,14 @@ fn collect_pools_native_fees_successfully() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "native".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::NativeToken {
                     denom: "native".to_string(),
                 },
                 ask_asset_info: ask_asset.clone(),
+                pool_identifier: None,
             },
         ];
 
@@ -1555,6 +1602,7 @@ fn collect_pools_native_fees_successfully() {
             denom: "native".to_string(),
         },
         ask_asset_info: ask_asset.clone(),
+        pool_identifier: None,
     }];
 
     let swap_routes: Vec<SwapRoute> = vec![SwapRoute {
@@ -1582,6 +1630,7 @@ fn collect_pools_native_fees_successfully() {
                 factory_addr: pool_factory_address.to_string(),
                 factory_type: FactoryType::Pool {
                     start_after: None,
+                    start_after_pool_identifier: None,
                     limit: Some(u32::try_from(TOKEN_AMOUNT).unwrap()),
                 },
             },
@@ -1676,6 +1725,8 @@ fn collect_fees_with_pagination_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -1684,6 +1735,8 @@ fn collect_fees_with_pagination_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -1847,6 +1900,7 @@ fn collect_fees_with_pagination_successfully() {
                 belief_price: None,
                 max_spread: Some(Decimal::percent(40u64)),
                 to: None,
+                trader: None,
             },
             &[Coin {
                 denom: "native".to_string(),
@@ -1866,6 +1920,7 @@ fn collect_fees_with_pagination_successfully() {
                     belief_price: None,
                     max_spread: Some(Decimal::percent(40u64)),
                     to: None,
+                    trader: None,
                 })
                 .unwrap(),
             },
@@ -1918,6 +1973,7 @@ fn collect_fees_with_pagination_successfully() {
 
     // Collect the fees with pagination
     let mut start_after: Option<[AssetInfo; 2]> = None;
+    let mut start_after_pool_identifier: Option<String> = None;
     let mut i = 0;
     // there are 10 pools in this test, and we set the pagination limit to half of that. So we will
     // collect the fees twice using pagination
@@ -1928,6 +1984,7 @@ fn collect_fees_with_pagination_successfully() {
                 &pool_factory_address,
                 &pool_network::factory::QueryMsg::Pairs {
                     start_after: start_after.clone(),
+                    start_after_pool_identifier: start_after_pool_identifier.clone(),
                     limit: Some(u32::try_from(TOKEN_AMOUNT / 2).unwrap()),
                 },
             )
@@ -1941,6 +1998,7 @@ fn collect_fees_with_pagination_successfully() {
                     factory_addr: pool_factory_address.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: start_after.clone(),
+                        start_after_pool_identifier: start_after_pool_identifier.clone(),
                         limit: Some(u32::try_from(TOKEN_AMOUNT / 2).unwrap()),
                     },
                 },
@@ -1959,6 +2017,7 @@ fn collect_fees_with_pagination_successfully() {
                 .unwrap()
                 .asset_infos,
         );
+        start_after_pool_identifier = pairs_response.last_pool_identifier;
 
         i += 1;
     }
@@ -2347,6 +2406,8 @@ fn aggregate_fees_for_vault() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -2354,11 +2415,8 @@ fn aggregate_fees_for_vault() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: "whale_lair".to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(1),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uatom".to_string(),
                 },
@@ -2369,6 +2427,18 @@ fn aggregate_fees_for_vault() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     app.execute_contract(
         creator.sender.clone(),
         fee_collector_address.clone(),
@@ -2428,6 +2498,8 @@ fn aggregate_fees_for_vault() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -2436,6 +2508,8 @@ fn aggregate_fees_for_vault() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -2865,6 +2939,7 @@ fn aggregate_fees_for_vault() {
             denom: "ujuno".to_string(),
         },
         ask_asset_info: ask_asset.clone(),
+        pool_identifier: None,
     }];
 
     let swap_routes: Vec<SwapRoute> = vec![SwapRoute {
@@ -3030,6 +3105,8 @@ fn collect_and_distribute_fees_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -3038,6 +3115,8 @@ fn collect_and_distribute_fees_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -3096,6 +3175,11 @@ fn collect_and_distribute_fees_successfully() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -3103,6 +3187,8 @@ fn collect_and_distribute_fees_successfully() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -3110,11 +3196,8 @@ fn collect_and_distribute_fees_successfully() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(2),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -3125,15 +3208,30 @@ fn collect_and_distribute_fees_successfully() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add the fee distributor address to the whale lair contract so we can use it as a clock
     app.execute_contract(
         creator.sender.clone(),
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         },
         &[],
     )
@@ -3313,6 +3411,7 @@ fn collect_and_distribute_fees_successfully() {
                 belief_price: None,
                 max_spread: Some(Decimal::percent(30u64)),
                 to: None,
+                trader: None,
             },
             &[Coin {
                 denom: "uwhale".to_string(),
@@ -3335,6 +3434,7 @@ fn collect_and_distribute_fees_successfully() {
                 belief_price: None,
                 max_spread: Some(Decimal::percent(40u64)),
                 to: None,
+                trader: None,
             },
             &[Coin {
                 denom: native_token.to_string(),
@@ -3363,8 +3463,8 @@ fn collect_and_distribute_fees_successfully() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -3447,6 +3547,8 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -3455,6 +3557,8 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -3513,6 +3617,11 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -3520,6 +3629,8 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -3527,11 +3638,8 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(1),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -3542,15 +3650,30 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add the fee distributor address to the whale lair contract so we can use it as a clock
     app.execute_contract(
         creator.sender.clone(),
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         },
         &[],
     )
@@ -3725,6 +3848,7 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
                 },
                 amount: Uint128::new(1_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -3743,6 +3867,7 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
                 },
                 amount: Uint128::new(1_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -3785,6 +3910,7 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
             belief_price: None,
             max_spread: Some(Decimal::percent(30u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -3804,8 +3930,8 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
     // Verify epoch 1
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -3877,6 +4003,7 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
             belief_price: None,
             max_spread: Some(Decimal::percent(30u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -3920,7 +4047,10 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -3948,7 +4078,10 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
     app.execute_contract(
         Addr::unchecked("other"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -3971,6 +4104,7 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
                 },
                 amount: Uint128::new(500u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -3983,8 +4117,8 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
     // Create EPOCH 2
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -4085,6 +4219,7 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
             belief_price: None,
             max_spread: None,
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -4112,7 +4247,10 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -4139,7 +4277,10 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
     app.execute_contract(
         Addr::unchecked("other"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -4163,6 +4304,7 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
                 },
                 amount: Uint128::new(500u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -4176,8 +4318,8 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
 
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -4243,7 +4385,10 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
     app.execute_contract(
         Addr::unchecked("other"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -4269,7 +4414,10 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -4420,6 +4568,8 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -4428,6 +4578,8 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -4486,6 +4638,11 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -4493,6 +4650,8 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -4500,11 +4659,8 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(1),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -4515,15 +4671,30 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add the fee distributor address to the whale lair contract so we can use it as a clock
     app.execute_contract(
         creator.sender.clone(),
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         },
         &[],
     )
@@ -4697,6 +4868,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
                 },
                 amount: Uint128::new(300_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "bWHALE".to_string(),
@@ -4715,6 +4887,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
                 },
                 amount: Uint128::new(100_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -4739,6 +4912,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
             belief_price: None,
             max_spread: Some(Decimal::percent(30u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -4757,8 +4931,8 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -4793,6 +4967,7 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
             belief_price: None,
             max_spread: Some(Decimal::percent(30u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -4811,8 +4986,8 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -4867,7 +5042,10 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -4885,7 +5063,10 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
         .execute_contract(
             creator.sender.clone(),
             fee_distributor_address.clone(),
-            &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+            &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+                epoch_ids: None,
+                receiver: None,
+            },
             &[],
         )
         .unwrap_err();
@@ -4916,7 +5097,10 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
     app.execute_contract(
         Addr::unchecked("other"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -4994,6 +5178,8 @@ fn create_epoch_unsuccessfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -5002,6 +5188,8 @@ fn create_epoch_unsuccessfully() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -5060,6 +5248,11 @@ fn create_epoch_unsuccessfully() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -5067,6 +5260,8 @@ fn create_epoch_unsuccessfully() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -5074,11 +5269,8 @@ fn create_epoch_unsuccessfully() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(1),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -5089,6 +5281,18 @@ fn create_epoch_unsuccessfully() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add pool router address to the fee collector to be able to aggregate fees
     app.execute_contract(
         creator.sender.clone(),
@@ -5263,6 +5467,7 @@ fn create_epoch_unsuccessfully() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -5280,8 +5485,8 @@ fn create_epoch_unsuccessfully() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -5306,6 +5511,7 @@ fn create_epoch_unsuccessfully() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -5317,16 +5523,16 @@ fn create_epoch_unsuccessfully() {
     let err = app
         .execute_contract(
             creator.sender.clone(),
-            fee_distributor_address.clone(),
-            &NewEpoch {},
+            epoch_manager_address.clone(),
+            &CreateEpoch {},
             &[],
         )
         .unwrap_err();
 
-    assert_eq!(
-        err.downcast::<fee_distributor::ContractError>().unwrap(),
-        fee_distributor::ContractError::CurrentEpochNotExpired {}
-    );
+    assert!(matches!(
+        err.downcast::<epoch_manager::ContractError>().unwrap(),
+        epoch_manager::ContractError::CurrentEpochNotExpired
+    ));
 }
 
 #[test]
@@ -5349,6 +5555,8 @@ fn aggregate_fees_unsuccessfully() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -5356,11 +5564,8 @@ fn aggregate_fees_unsuccessfully() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: "whale_lair".to_string(),
                 fee_collector_addr: fee_collector_address.to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(5),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86400000000000),
-                    genesis_epoch: Default::default(),
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -5371,6 +5576,18 @@ fn aggregate_fees_unsuccessfully() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // update the fee_distributor_address on fee collector
     app.execute_contract(
         creator.sender.clone(),
@@ -5499,6 +5716,8 @@ fn decrease_grace_period_fee_distributor() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -5507,6 +5726,8 @@ fn decrease_grace_period_fee_distributor() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -5565,6 +5786,11 @@ fn decrease_grace_period_fee_distributor() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -5572,6 +5798,8 @@ fn decrease_grace_period_fee_distributor() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -5579,11 +5807,8 @@ fn decrease_grace_period_fee_distributor() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(2),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -5594,6 +5819,18 @@ fn decrease_grace_period_fee_distributor() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add pool router address to the fee collector to be able to aggregate fees
     app.execute_contract(
         creator.sender.clone(),
@@ -5768,6 +6005,7 @@ fn decrease_grace_period_fee_distributor() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -5786,8 +6024,8 @@ fn decrease_grace_period_fee_distributor() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -5807,6 +6045,7 @@ fn decrease_grace_period_fee_distributor() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -5825,8 +6064,8 @@ fn decrease_grace_period_fee_distributor() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -5840,9 +6079,9 @@ fn decrease_grace_period_fee_distributor() {
                 owner: None,
                 bonding_contract_addr: None,
                 fee_collector_addr: None,
+                epoch_manager_addr: None,
                 grace_period: Some(Uint64::one()),
                 distribution_asset: None,
-                epoch_config: None,
             },
             &[],
         )
@@ -5921,6 +6160,8 @@ fn users_cannot_claim_rewards_from_past_epochs() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -5929,6 +6170,8 @@ fn users_cannot_claim_rewards_from_past_epochs() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -5987,6 +6230,11 @@ fn users_cannot_claim_rewards_from_past_epochs() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -5994,6 +6242,8 @@ fn users_cannot_claim_rewards_from_past_epochs() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -6001,11 +6251,8 @@ fn users_cannot_claim_rewards_from_past_epochs() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(3u64),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -6016,15 +6263,30 @@ fn users_cannot_claim_rewards_from_past_epochs() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add the fee distributor address to the whale lair contract so we can use it as a clock
     app.execute_contract(
         creator.sender.clone(),
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         },
         &[],
     )
@@ -6198,6 +6460,7 @@ fn users_cannot_claim_rewards_from_past_epochs() {
                 },
                 amount: Uint128::new(300_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "bWHALE".to_string(),
@@ -6222,6 +6485,7 @@ fn users_cannot_claim_rewards_from_past_epochs() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -6240,8 +6504,8 @@ fn users_cannot_claim_rewards_from_past_epochs() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -6275,6 +6539,7 @@ fn users_cannot_claim_rewards_from_past_epochs() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -6293,8 +6558,8 @@ fn users_cannot_claim_rewards_from_past_epochs() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -6323,6 +6588,7 @@ fn users_cannot_claim_rewards_from_past_epochs() {
                 },
                 amount: Uint128::new(100_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -6357,6 +6623,7 @@ fn users_cannot_claim_rewards_from_past_epochs() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -6375,8 +6642,8 @@ fn users_cannot_claim_rewards_from_past_epochs() {
     // Create new epoch
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -6488,6 +6755,8 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -6496,6 +6765,8 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -6554,6 +6825,11 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -6561,6 +6837,8 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -6568,11 +6846,8 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(3u64),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -6583,15 +6858,30 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add the fee distributor address to the whale lair contract so we can use it as a clock
     app.execute_contract(
         creator.sender.clone(),
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         },
         &[],
     )
@@ -6765,6 +7055,7 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
                 },
                 amount: Uint128::new(300_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "bWHALE".to_string(),
@@ -6783,6 +7074,7 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
                 },
                 amount: Uint128::new(100_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -6807,6 +7099,7 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -6825,8 +7118,8 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -6860,6 +7153,7 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -6878,8 +7172,8 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -6926,7 +7220,10 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -7008,6 +7305,7 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -7025,8 +7323,8 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -7057,7 +7355,10 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
     app.execute_contract(
         Addr::unchecked("other"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -7074,7 +7375,10 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
     app.execute_contract(
         Addr::unchecked("creator"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -7147,6 +7451,8 @@ fn user_weight_accounts_for_unbondings() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -7155,6 +7461,8 @@ fn user_weight_accounts_for_unbondings() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -7213,6 +7521,11 @@ fn user_weight_accounts_for_unbondings() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -7220,6 +7533,8 @@ fn user_weight_accounts_for_unbondings() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -7227,11 +7542,8 @@ fn user_weight_accounts_for_unbondings() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(3u64),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -7242,15 +7554,30 @@ fn user_weight_accounts_for_unbondings() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add the fee distributor address to the whale lair contract so we can use it as a clock
     app.execute_contract(
         creator.sender.clone(),
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         },
         &[],
     )
@@ -7426,6 +7753,7 @@ fn user_weight_accounts_for_unbondings() {
                 },
                 amount: Uint128::new(100_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "bWHALE".to_string(),
@@ -7444,6 +7772,7 @@ fn user_weight_accounts_for_unbondings() {
                 },
                 amount: Uint128::new(200_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -7462,6 +7791,7 @@ fn user_weight_accounts_for_unbondings() {
                 },
                 amount: Uint128::new(100_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -7486,6 +7816,7 @@ fn user_weight_accounts_for_unbondings() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -7504,8 +7835,8 @@ fn user_weight_accounts_for_unbondings() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -7539,6 +7870,7 @@ fn user_weight_accounts_for_unbondings() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -7557,8 +7889,8 @@ fn user_weight_accounts_for_unbondings() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -7605,7 +7937,10 @@ fn user_weight_accounts_for_unbondings() {
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -7687,6 +8022,7 @@ fn user_weight_accounts_for_unbondings() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -7705,8 +8041,8 @@ fn user_weight_accounts_for_unbondings() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -7748,7 +8084,10 @@ fn user_weight_accounts_for_unbondings() {
     app.execute_contract(
         Addr::unchecked("creator"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -7787,7 +8126,10 @@ fn user_weight_accounts_for_unbondings() {
     app.execute_contract(
         Addr::unchecked("other"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -7861,6 +8203,7 @@ fn user_weight_accounts_for_unbondings() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -7879,8 +8222,8 @@ fn user_weight_accounts_for_unbondings() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -7901,7 +8244,10 @@ fn user_weight_accounts_for_unbondings() {
     app.execute_contract(
         Addr::unchecked("creator"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -8004,6 +8350,8 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -8012,6 +8360,8 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -8069,6 +8419,11 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -8076,6 +8431,8 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -8083,11 +8440,8 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(3u64),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -8098,15 +8452,30 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add the fee distributor address to the whale lair contract so we can use it as a clock
     app.execute_contract(
         creator.sender.clone(),
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         },
         &[],
     )
@@ -8287,6 +8656,7 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
                 },
                 amount: Uint128::new(300_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "bWHALE".to_string(),
@@ -8311,6 +8681,7 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -8329,8 +8700,8 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -8370,6 +8741,7 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
             belief_price: None,
             max_spread: Some(Decimal::percent(40u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -8398,6 +8770,7 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
                     },
                     amount: Uint128::new(100_000_000u128),
                 },
+                receiver: None,
             },
             &[Coin {
                 denom: "ampWHALE".to_string(),
@@ -8414,8 +8787,8 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
     // Create new epoch, which triggers fee collection, aggregation and distribution
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -8449,6 +8822,7 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
                 },
                 amount: Uint128::new(100_000_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -8525,6 +8899,8 @@ fn collect_distribute_with_unbonders() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -8533,6 +8909,8 @@ fn collect_distribute_with_unbonders() {
         trio_code_id: trio_id,
         token_code_id: token_id,
         fee_collector_addr: fee_collector_address.to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
     };
 
@@ -8591,6 +8969,11 @@ fn collect_distribute_with_unbonders() {
                         denom: "bWHALE".to_string(),
                     },
                 ],
+                receipt_token: false,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
             },
             &[],
             "whale_lair",
@@ -8598,6 +8981,8 @@ fn collect_distribute_with_unbonders() {
         )
         .unwrap();
 
+    let epoch_manager_address = instantiate_epoch_manager(&mut app, creator.sender.clone());
+
     let fee_distributor_address = app
         .instantiate_contract(
             fee_distributor_id,
@@ -8605,11 +8990,8 @@ fn collect_distribute_with_unbonders() {
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: whale_lair_address.clone().to_string(),
                 fee_collector_addr: fee_collector_address.clone().to_string(),
+                epoch_manager_addr: epoch_manager_address.to_string(),
                 grace_period: Uint64::new(1),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86_400_000_000_000u64), // a day
-                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
@@ -8620,15 +9002,30 @@ fn collect_distribute_with_unbonders() {
         )
         .unwrap();
 
+    // let the epoch manager create the epochs on the fee distributor
+    app.execute_contract(
+        creator.sender.clone(),
+        epoch_manager_address.clone(),
+        &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+            contract_addr: fee_distributor_address.to_string(),
+            gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
     // add the fee distributor address to the whale lair contract so we can use it as a clock
     app.execute_contract(
         creator.sender.clone(),
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         },
         &[],
     )
@@ -8803,6 +9200,7 @@ fn collect_distribute_with_unbonders() {
                 },
                 amount: Uint128::new(1_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -8821,6 +9219,7 @@ fn collect_distribute_with_unbonders() {
                 },
                 amount: Uint128::new(1_000u128),
             },
+            receiver: None,
         },
         &[Coin {
             denom: "ampWHALE".to_string(),
@@ -8846,6 +9245,7 @@ fn collect_distribute_with_unbonders() {
             belief_price: None,
             max_spread: Some(Decimal::percent(30u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -8865,8 +9265,8 @@ fn collect_distribute_with_unbonders() {
     // Verify epoch 1
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -8928,6 +9328,7 @@ fn collect_distribute_with_unbonders() {
             belief_price: None,
             max_spread: Some(Decimal::percent(30u64)),
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -8962,8 +9363,8 @@ fn collect_distribute_with_unbonders() {
     // Create EPOCH 2
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -9048,7 +9449,10 @@ fn collect_distribute_with_unbonders() {
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -9097,6 +9501,7 @@ fn collect_distribute_with_unbonders() {
             belief_price: None,
             max_spread: None,
             to: None,
+            trader: None,
         },
         &[Coin {
             denom: "usdc".to_string(),
@@ -9119,8 +9524,8 @@ fn collect_distribute_with_unbonders() {
 
     app.execute_contract(
         creator.sender.clone(),
-        fee_distributor_address.clone(),
-        &NewEpoch {},
+        epoch_manager_address.clone(),
+        &CreateEpoch {},
         &[],
     )
     .unwrap();
@@ -9185,7 +9590,10 @@ fn collect_distribute_with_unbonders() {
     app.execute_contract(
         Addr::unchecked("other"),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -9210,7 +9618,10 @@ fn collect_distribute_with_unbonders() {
     app.execute_contract(
         creator.sender.clone(),
         fee_distributor_address.clone(),
-        &white_whale_std::fee_distributor::ExecuteMsg::Claim {},
+        &white_whale_std::fee_distributor::ExecuteMsg::Claim {
+            epoch_ids: None,
+            receiver: None,
+        },
         &[],
     )
     .unwrap();
@@ -1,8 +1,9 @@
 use cosmwasm_std::{
-    to_json_binary, CosmosMsg, DepsMut, Env, MessageInfo, QueryRequest, ReplyOn, Response,
-    StdError, SubMsg, Timestamp, Uint64, WasmMsg, WasmQuery,
+    to_json_binary, CosmosMsg, DepsMut, Empty, MessageInfo, Order, QueryRequest, ReplyOn, Response,
+    StdResult, SubMsg, Uint64, WasmMsg, WasmQuery,
 };
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use cw_storage_plus::Bound;
+use white_whale_std::epoch_manager::epoch_manager::EpochV2;
 
 use white_whale_std::fee_distributor::Epoch;
 use white_whale_std::pool_network::asset;
@@ -10,44 +11,43 @@ use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::whale_lair::{BondingWeightResponse, QueryMsg};
 
 use crate::contract::EPOCH_CREATION_REPLY_ID;
-use crate::helpers::{validate_epoch_config, validate_grace_period};
-use crate::state::{get_current_epoch, query_claimable, CONFIG, EPOCHS, LAST_CLAIMED_EPOCH};
+use crate::helpers::validate_grace_period;
+use crate::state::{
+    get_claimable_epochs, get_expiring_epoch, query_claimable, CLAIMED_EPOCHS, CONFIG, EPOCHS,
+};
 use crate::ContractError;
 
 /// Creates a new epoch, forwarding available tokens from epochs that are past the grace period.
-pub fn create_new_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+/// Triggered by the epoch manager whenever a new epoch starts, or when retrying an epoch the
+/// contract failed to process.
+pub fn create_new_epoch(
+    deps: DepsMut,
+    info: MessageInfo,
+    epoch: EpochV2,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let current_epoch = get_current_epoch(deps.as_ref())?.epoch;
-    if env
-        .block
-        .time
-        .minus_nanos(current_epoch.start_time.nanos())
-        .nanos()
-        < config.epoch_config.duration.u64()
-    {
-        return Err(ContractError::CurrentEpochNotExpired {});
-    }
-
-    let start_time =
-        if current_epoch.id == Uint64::zero() && current_epoch.start_time == Timestamp::default() {
-            // if it's the very first epoch, set the start time to the genesis epoch
-            let genesis_epoch_timestamp =
-                Timestamp::from_nanos(config.epoch_config.genesis_epoch.u64());
-
-            if env.block.time.nanos() < genesis_epoch_timestamp.nanos() {
-                return Err(ContractError::GenesisEpochNotStarted {});
-            }
 
-            genesis_epoch_timestamp
-        } else {
-            current_epoch
-                .start_time
-                .plus_nanos(config.epoch_config.duration.u64())
-        };
+    if info.sender != config.epoch_manager_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_epoch_id = Uint64::new(epoch.id);
+
+    if EPOCHS.has(deps.storage, &epoch.id.to_be_bytes()) {
+        return Err(ContractError::EpochAlreadyCreated(new_epoch_id));
+    }
+
+    // epochs missed in the past, e.g. because the hook failed, can be filled as long as they would
+    // still be within the grace period, otherwise their fees could never be claimed nor forwarded
+    if let Some(expiring_epoch) = get_expiring_epoch(deps.as_ref())? {
+        if new_epoch_id <= expiring_epoch.id {
+            return Err(ContractError::EpochOutsideGracePeriod(new_epoch_id));
+        }
+    }
 
     let new_epoch = Epoch {
-        id: current_epoch.id.checked_add(Uint64::new(1u64))?,
-        start_time,
+        id: new_epoch_id,
+        start_time: epoch.start_time,
         total: vec![],
         available: vec![],
         claimed: vec![],
@@ -74,97 +74,126 @@ pub fn create_new_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractErr
         ]))
 }
 
-/// Claims pending rewards for the sender.
-pub fn claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+/// Claims pending rewards for the sender, either for all claimable epochs or for the given ones,
+/// sending them to the receiver if provided.
+pub fn claim(
+    deps: DepsMut,
+    info: MessageInfo,
+    epoch_ids: Option<Vec<Uint64>>,
+    receiver: Option<String>,
+) -> Result<Response, ContractError> {
     // Query the fee share of the sender based on the ratio of his weight and the global weight at the current moment
     let config = CONFIG.load(deps.storage)?;
 
-    let claimable_epochs = query_claimable(deps.as_ref(), &info.sender)?.epochs;
+    let receiver = receiver
+        .map(|receiver| deps.api.addr_validate(&receiver))
+        .transpose()?
+        .unwrap_or_else(|| info.sender.clone());
+
+    let mut claimable_epochs = query_claimable(deps.as_ref(), &info.sender)?.epochs;
+
+    if let Some(epoch_ids) = epoch_ids {
+        // make sure all the requested epochs can be claimed
+        if let Some(epoch_id) = epoch_ids
+            .iter()
+            .find(|epoch_id| !claimable_epochs.iter().any(|epoch| epoch.id == **epoch_id))
+        {
+            return Err(ContractError::EpochNotClaimable(*epoch_id));
+        }
+
+        claimable_epochs.retain(|epoch| epoch_ids.contains(&epoch.id));
+    }
+
     if claimable_epochs.is_empty() {
         return Err(ContractError::NothingToClaim {});
     }
 
     let mut claimable_fees = vec![];
     for mut epoch in claimable_epochs.clone() {
+        // the weight is taken from the checkpoints made when the epoch was created, so it doesn't
+        // change no matter when the rewards are claimed
         let bonding_weight_response: BondingWeightResponse =
             deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                 contract_addr: config.bonding_contract_addr.to_string(),
-                msg: to_json_binary(&QueryMsg::Weight {
+                msg: to_json_binary(&QueryMsg::EpochWeight {
                     address: info.sender.to_string(),
-                    timestamp: Some(epoch.start_time),
-                    global_index: Some(epoch.global_index.clone()),
+                    epoch_id: epoch.id,
                 })?,
             }))?;
 
         for fee in epoch.total.iter() {
-            let reward = fee.amount * bonding_weight_response.share;
+            let available_amount = epoch
+                .available
+                .iter()
+                .find(|available_fee| available_fee.info == fee.info)
+                .map(|available_fee| available_fee.amount)
+                .unwrap_or_default();
+
+            // cap the reward to the available fees, so rounding errors never block the claim
+            let reward = (fee.amount * bonding_weight_response.share).min(available_amount);
 
             if reward.is_zero() {
                 // nothing to claim
                 continue;
             }
-            // make sure the reward is sound
-            let _ = epoch
-                .available
-                .iter()
-                .find(|available_fee| available_fee.info == fee.info)
-                .map(|available_fee| {
-                    if reward > available_fee.amount {
-                        //todo maybe we can just skip this epoch and log something on the attributes instead
-                        // of returning an error and blocking the whole operation
-                        // this would "solve" the case when users unbond and then those who have not claimed
-                        // past epochs won't be able to do it as their rewards exceed the available claimable fees
-                        // cuz their weight increased in relation to the global weight
-                        return Err(ContractError::InvalidReward {});
-                    }
-                    Ok(())
-                })
-                .ok_or_else(|| StdError::generic_err("Invalid fee"))?;
+
+            let reward = Asset {
+                info: fee.info.clone(),
+                amount: reward,
+            };
 
             // add the reward to the claimable fees
-            claimable_fees = asset::aggregate_assets(
-                claimable_fees,
-                vec![Asset {
-                    info: fee.info.clone(),
-                    amount: reward,
-                }],
-            )?;
+            claimable_fees = asset::aggregate_assets(claimable_fees, vec![reward.clone()])?;
 
             // modify the epoch to reflect the new available and claimed amount
-            for available_fee in epoch.available.iter_mut() {
-                if available_fee.info == fee.info {
-                    available_fee.amount = available_fee.amount.checked_sub(reward)?;
-                }
-            }
-
-            if epoch.claimed.is_empty() {
-                epoch.claimed = vec![Asset {
-                    info: fee.info.clone(),
-                    amount: reward,
-                }];
-            } else {
-                for claimed_fee in epoch.claimed.iter_mut() {
-                    if claimed_fee.info == fee.info {
-                        claimed_fee.amount = claimed_fee.amount.checked_add(reward)?;
-                    }
-                }
-            }
+            epoch.available = asset::deduct_assets(epoch.available, vec![reward.clone()])?;
+            epoch.claimed = asset::aggregate_assets(epoch.claimed, vec![reward])?;
 
             EPOCHS.save(deps.storage, &epoch.id.to_be_bytes(), &epoch)?;
         }
     }
 
-    // update the last claimed epoch for the user
-    LAST_CLAIMED_EPOCH.save(deps.storage, &info.sender, &claimable_epochs[0].id)?;
+    // mark the epochs as claimed for the user
+    for epoch in claimable_epochs.iter() {
+        CLAIMED_EPOCHS.save(deps.storage, (&info.sender, epoch.id.u64()), &Empty {})?;
+    }
 
-    // send funds to the user
+    // forget about the claimed epochs that fell out of the grace period, as they can't be claimed
+    // anymore. The epochs that were just claimed are kept, so it's known the user has claimed before.
+    if let Some(oldest_epoch) = get_claimable_epochs(deps.as_ref())?.epochs.last() {
+        let expired_epoch_ids = CLAIMED_EPOCHS
+            .prefix(&info.sender)
+            .keys(
+                deps.storage,
+                None,
+                Some(Bound::exclusive(oldest_epoch.id.u64())),
+                Order::Ascending,
+            )
+            .collect::<StdResult<Vec<u64>>>()?;
+
+        for epoch_id in expired_epoch_ids {
+            CLAIMED_EPOCHS.remove(deps.storage, (&info.sender, epoch_id));
+        }
+    }
+
+    // send funds to the receiver
     let mut messages = vec![];
     for fee in claimable_fees {
-        messages.push(fee.into_msg(info.sender.clone())?);
+        messages.push(fee.into_msg(receiver.clone())?);
     }
 
+    let claimed_epoch_ids = claimable_epochs
+        .iter()
+        .map(|epoch| epoch.id.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+
     Ok(Response::new()
-        .add_attributes(vec![("action", "claim")])
+        .add_attributes(vec![
+            ("action", "claim".to_string()),
+            ("receiver", receiver.to_string()),
+            ("claimed_epochs", claimed_epoch_ids),
+        ])
         .add_messages(messages))
 }
 
@@ -176,9 +205,9 @@ pub fn update_config(
     owner: Option<String>,
     bonding_contract_addr: Option<String>,
     fee_collector_addr: Option<String>,
+    epoch_manager_addr: Option<String>,
     grace_period: Option<Uint64>,
     distribution_asset: Option<AssetInfo>,
-    epoch_config: Option<EpochConfig>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
@@ -198,13 +227,12 @@ pub fn update_config(
         config.fee_collector_addr = deps.api.addr_validate(&fee_collector_addr)?;
     }
 
-    if let Some(distribution_asset) = distribution_asset {
-        config.distribution_asset = distribution_asset;
+    if let Some(epoch_manager_addr) = epoch_manager_addr {
+        config.epoch_manager_addr = deps.api.addr_validate(&epoch_manager_addr)?;
     }
 
-    if let Some(epoch_config) = epoch_config {
-        validate_epoch_config(&epoch_config)?;
-        config.epoch_config = epoch_config;
+    if let Some(distribution_asset) = distribution_asset {
+        config.distribution_asset = distribution_asset;
     }
 
     if let Some(grace_period) = grace_period {
@@ -227,8 +255,8 @@ pub fn update_config(
             config.bonding_contract_addr.to_string(),
         ),
         ("fee_collector_addr", config.fee_collector_addr.to_string()),
+        ("epoch_manager_addr", config.epoch_manager_addr.to_string()),
         ("grace_period", config.grace_period.to_string()),
         ("distribution_asset", config.distribution_asset.to_string()),
-        ("epoch_config", config.epoch_config.to_string()),
     ]))
 }
@@ -8,7 +8,7 @@ use cw2::{get_contract_version, set_contract_version};
 use cw_utils::parse_reply_execute_data;
 
 use crate::error::ContractError;
-use crate::helpers::{validate_epoch_config, validate_grace_period};
+use crate::helpers::validate_grace_period;
 use crate::state::{get_expiring_epoch, CONFIG, EPOCHS};
 use crate::{commands, migrations, queries, state};
 use semver::Version;
@@ -35,14 +35,13 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     validate_grace_period(&msg.grace_period)?;
-    validate_epoch_config(&msg.epoch_config)?;
 
     let config = Config {
         owner: deps.api.addr_validate(info.sender.as_str())?,
         bonding_contract_addr: deps.api.addr_validate(msg.bonding_contract_addr.as_str())?,
         fee_collector_addr: deps.api.addr_validate(msg.fee_collector_addr.as_str())?,
+        epoch_manager_addr: deps.api.addr_validate(msg.epoch_manager_addr.as_str())?,
         grace_period: msg.grace_period,
-        epoch_config: msg.epoch_config,
         distribution_asset: msg.distribution_asset,
     };
 
@@ -56,8 +55,8 @@ pub fn instantiate(
             config.bonding_contract_addr.as_str(),
         )
         .add_attribute("fee_collector_addr", config.fee_collector_addr.as_str())
+        .add_attribute("epoch_manager_addr", config.epoch_manager_addr.as_str())
         .add_attribute("grace_period", config.grace_period.to_string())
-        .add_attribute("epoch_config", config.epoch_config.to_string())
         .add_attribute("distribution_asset", config.distribution_asset.to_string()))
 }
 
@@ -123,29 +122,34 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 #[entry_point]
 pub fn execute(
     deps: DepsMut,
-    env: Env,
+    _env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::NewEpoch {} => commands::create_new_epoch(deps, env),
-        ExecuteMsg::Claim {} => commands::claim(deps, info),
+        ExecuteMsg::EpochChangedHook(msg) => {
+            commands::create_new_epoch(deps, info, msg.current_epoch)
+        }
+        ExecuteMsg::Claim {
+            epoch_ids,
+            receiver,
+        } => commands::claim(deps, info, epoch_ids, receiver),
         ExecuteMsg::UpdateConfig {
             owner,
             bonding_contract_addr,
             fee_collector_addr,
+            epoch_manager_addr,
             grace_period,
             distribution_asset,
-            epoch_config,
         } => commands::update_config(
             deps,
             info,
             owner,
             bonding_contract_addr,
             fee_collector_addr,
+            epoch_manager_addr,
             grace_period,
             distribution_asset,
-            epoch_config,
         ),
     }
 }
@@ -161,12 +165,16 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             deps,
             &deps.api.addr_validate(&address)?,
         )?)?),
+        QueryMsg::ClaimedEpochs { address } => Ok(to_json_binary(&state::query_claimed_epochs(
+            deps,
+            &deps.api.addr_validate(&address)?,
+        )?)?),
     }
 }
 
 #[cfg(not(tarpaulin_include))]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     use white_whale_std::migrate_guards::check_contract_name;
 
     check_contract_name(deps.storage, CONTRACT_NAME.to_string())?;
@@ -186,6 +194,17 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
         migrations::migrate_to_v090(deps.branch())?;
     }
 
+    if storage_version < Version::parse("0.10.0")? {
+        migrations::migrate_to_v0100(deps.branch())?;
+    }
+
+    if storage_version < Version::parse("0.11.0")? {
+        let epoch_manager_addr = msg
+            .epoch_manager_addr
+            .ok_or(ContractError::MissingEpochManager {})?;
+        migrations::migrate_to_v0110(deps.branch(), epoch_manager_addr)?;
+    }
+
     if storage_version == Version::parse("0.9.0")? {
         let fees_refund_messages = migrations::migrate_to_v091(deps.branch())?;
         return Ok(Response::default()
@@ -20,23 +20,23 @@ pub enum ContractError {
     #[error("Invalid grace period: {0}. Must be between 1 and 10.")]
     InvalidGracePeriod(Uint64),
 
-    #[error("Invalid epoch duration: {0}. Must be at least 1 day, in nanoseconds.")]
-    InvalidEpochDuration(Uint64),
-
     #[error("The assets sent don't match the assets expected.")]
     AssetMismatch {},
 
     #[error("There are no claimable rewards.")]
     NothingToClaim {},
 
-    #[error("The rewards cannot exceed the available claimable fees.")]
-    InvalidReward {},
+    #[error("Epoch {0} can't be claimed.")]
+    EpochNotClaimable(Uint64),
+
+    #[error("Epoch {0} has already been created.")]
+    EpochAlreadyCreated(Uint64),
 
-    #[error("The current epoch epoch has not expired yet.")]
-    CurrentEpochNotExpired {},
+    #[error("Epoch {0} is older than the epochs within the grace period.")]
+    EpochOutsideGracePeriod(Uint64),
 
-    #[error("The genesis epoch is set to start in the future, query the config for more details.")]
-    GenesisEpochNotStarted {},
+    #[error("The epoch manager address is required to migrate to this version.")]
+    MissingEpochManager {},
 
     #[error("Couldn't read data for new epoch.")]
     CannotReadEpoch {},
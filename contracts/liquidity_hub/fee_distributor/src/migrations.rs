@@ -2,18 +2,34 @@
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, CosmosMsg, DepsMut, Order, QueryRequest, StdError, StdResult, Timestamp,
-    Uint64, WasmQuery,
+    to_json_binary, Addr, CosmosMsg, DepsMut, Empty, Order, QueryRequest, StdError, StdResult,
+    Timestamp, Uint64, WasmQuery,
 };
-use cw_storage_plus::Map;
+use cw_storage_plus::{Item, Map};
 
-use white_whale_std::fee_distributor::Epoch;
+use white_whale_std::epoch_manager::epoch_manager::{
+    EpochConfig, EpochResponse, QueryMsg as EpochManagerQueryMsg,
+};
+use white_whale_std::fee_distributor::{Config, Epoch};
 use white_whale_std::pool_network::asset;
-use white_whale_std::pool_network::asset::Asset;
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::whale_lair::GlobalIndex;
 use white_whale_std::whale_lair::QueryMsg as LairQueryMsg;
 
-use crate::state::{get_claimable_epochs, CONFIG, EPOCHS};
+use crate::state::{get_claimable_epochs, get_current_epoch, CLAIMED_EPOCHS, CONFIG, EPOCHS};
+
+/// The config prior to v0.11.0, when epochs were created by the contract itself.
+#[cw_serde]
+struct ConfigV0100 {
+    pub owner: Addr,
+    pub bonding_contract_addr: Addr,
+    pub fee_collector_addr: Addr,
+    pub grace_period: Uint64,
+    pub epoch_config: EpochConfig,
+    pub distribution_asset: AssetInfo,
+}
+
+const CONFIGV0100: Item<ConfigV0100> = Item::new("config");
 
 /// Migrates state from the first iteration, v0.8.* to v0.9.0, which includes the global index in
 /// the Epoch. This was done to fix bonding issues.
@@ -43,7 +59,7 @@ pub fn migrate_to_v090(deps: DepsMut) -> Result<(), StdError> {
         })
         .collect::<StdResult<Vec<EpochV08>>>()?;
 
-    let bonding_contract_addr = CONFIG.load(deps.storage)?.bonding_contract_addr;
+    let bonding_contract_addr = CONFIGV0100.load(deps.storage)?.bonding_contract_addr;
     // Query the current global index
     let global_index: GlobalIndex = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
         contract_addr: bonding_contract_addr.to_string(),
@@ -138,3 +154,72 @@ pub fn migrate_to_v091_hotfix(deps: DepsMut) -> Result<Vec<CosmosMsg>, StdError>
 
     Ok(messages)
 }
+
+/// Migrates state to v0.10.0, which replaces the last claimed epoch of each address with the set
+/// of epochs claimed by it. The last claimed epoch and all the epochs within the grace period up to
+/// it are marked as claimed.
+pub fn migrate_to_v0100(deps: DepsMut) -> Result<(), StdError> {
+    const LAST_CLAIMED_EPOCH: Map<&Addr, Uint64> = Map::new("last_claimed_epoch");
+
+    let last_claimed_epochs = LAST_CLAIMED_EPOCH
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Uint64)>>>()?;
+
+    let claimable_epochs = get_claimable_epochs(deps.as_ref())?.epochs;
+
+    for (address, last_claimed_epoch) in last_claimed_epochs {
+        CLAIMED_EPOCHS.save(
+            deps.storage,
+            (&address, last_claimed_epoch.u64()),
+            &Empty {},
+        )?;
+
+        for epoch in claimable_epochs
+            .iter()
+            .filter(|epoch| epoch.id <= last_claimed_epoch)
+        {
+            CLAIMED_EPOCHS.save(deps.storage, (&address, epoch.id.u64()), &Empty {})?;
+        }
+
+        LAST_CLAIMED_EPOCH.remove(deps.storage, &address);
+    }
+
+    Ok(())
+}
+
+/// Migrates state to v0.11.0, which moves the creation of epochs to the epoch manager. The epoch
+/// config is dropped from the config, and the existing epochs are kept as they are.
+///
+/// The epoch manager must be instantiated with the last epoch of the contract as start epoch, so
+/// the epochs it creates carry on from the existing ones.
+pub fn migrate_to_v0110(deps: DepsMut, epoch_manager_addr: String) -> Result<(), StdError> {
+    let config_v0100 = CONFIGV0100.load(deps.storage)?;
+    let epoch_manager_addr = deps.api.addr_validate(&epoch_manager_addr)?;
+
+    let last_epoch_id = get_current_epoch(deps.as_ref())?.epoch.id.u64();
+    let epoch_manager_epoch: EpochResponse =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: epoch_manager_addr.to_string(),
+            msg: to_json_binary(&EpochManagerQueryMsg::CurrentEpoch {})?,
+        }))?;
+
+    if epoch_manager_epoch.epoch.id < last_epoch_id {
+        return Err(StdError::generic_err(format!(
+            "The epoch manager is at epoch {}, behind the last epoch {last_epoch_id}",
+            epoch_manager_epoch.epoch.id
+        )));
+    }
+
+    let config = Config {
+        owner: config_v0100.owner,
+        bonding_contract_addr: config_v0100.bonding_contract_addr,
+        fee_collector_addr: config_v0100.fee_collector_addr,
+        epoch_manager_addr,
+        grace_period: config_v0100.grace_period,
+        distribution_asset: config_v0100.distribution_asset,
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(())
+}
@@ -1,11 +1,16 @@
-use cosmwasm_std::{to_json_binary, Addr, Deps, Order, QueryRequest, StdResult, Uint64, WasmQuery};
+use cosmwasm_std::{
+    to_json_binary, Addr, Deps, Empty, Order, QueryRequest, StdResult, Uint64, WasmQuery,
+};
 use cw_storage_plus::{Item, Map};
 
-use white_whale_std::fee_distributor::{ClaimableEpochsResponse, Config, Epoch, EpochResponse};
+use white_whale_std::fee_distributor::{
+    ClaimableEpochsResponse, ClaimedEpochsResponse, Config, Epoch, EpochResponse,
+};
 use white_whale_std::whale_lair::{BondedResponse, QueryMsg};
 
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const LAST_CLAIMED_EPOCH: Map<&Addr, Uint64> = Map::new("last_claimed_epoch");
+/// The set of epochs claimed by an address. Key is (address, epoch_id).
+pub const CLAIMED_EPOCHS: Map<(&Addr, u64), Empty> = Map::new("claimed_epochs");
 pub const EPOCHS: Map<&[u8], Epoch> = Map::new("epochs");
 
 /// Returns the current epoch, which is the last on the EPOCHS map.
@@ -79,13 +84,18 @@ pub fn get_claimable_epochs(deps: Deps) -> StdResult<ClaimableEpochsResponse> {
 /// Returns the epochs that can be claimed by the given address.
 pub fn query_claimable(deps: Deps, address: &Addr) -> StdResult<ClaimableEpochsResponse> {
     let mut claimable_epochs = get_claimable_epochs(deps)?.epochs;
-    let last_claimed_epoch = LAST_CLAIMED_EPOCH.may_load(deps.storage, address)?;
 
     // filter out epochs that have already been claimed by the user
-    if let Some(last_claimed_epoch) = last_claimed_epoch {
-        claimable_epochs.retain(|epoch| epoch.id > last_claimed_epoch);
-    } else {
-        // if the user doesn't have any last_claimed_epoch two things might be happening:
+    claimable_epochs.retain(|epoch| !CLAIMED_EPOCHS.has(deps.storage, (address, epoch.id.u64())));
+
+    let has_claimed = CLAIMED_EPOCHS
+        .prefix(address)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+
+    if !has_claimed {
+        // if the user doesn't have any claimed epochs two things might be happening:
         // 1- the user has never bonded before
         // 2- the user has bonded, but never claimed any rewards so far
         let bonding_contract = CONFIG.load(deps.storage)?.bonding_contract_addr;
@@ -105,7 +115,7 @@ pub fn query_claimable(deps: Deps, address: &Addr) -> StdResult<ClaimableEpochsR
             // the user has bonded, but never claimed any rewards so far
             claimable_epochs.retain(|epoch| epoch.id > bonded_response.first_bonded_epoch_id);
         }
-    };
+    }
 
     // filter out epochs that have no available fees. This would only happen in case the grace period
     // gets increased after epochs have expired, which would lead to make them available for claiming
@@ -116,3 +126,15 @@ pub fn query_claimable(deps: Deps, address: &Addr) -> StdResult<ClaimableEpochsR
         epochs: claimable_epochs,
     })
 }
+
+/// Returns the ids of the epochs within the grace period that have been claimed by the given address.
+pub fn query_claimed_epochs(deps: Deps, address: &Addr) -> StdResult<ClaimedEpochsResponse> {
+    let epoch_ids = get_claimable_epochs(deps)?
+        .epochs
+        .into_iter()
+        .filter(|epoch| CLAIMED_EPOCHS.has(deps.storage, (address, epoch.id.u64())))
+        .map(|epoch| epoch.id)
+        .collect::<Vec<Uint64>>();
+
+    Ok(ClaimedEpochsResponse { epoch_ids })
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{Addr, Uint64};
 
 use crate::tests::robot::TestingRobot;
 use crate::tests::test_helpers;
@@ -29,3 +29,26 @@ fn test_claimable_epochs() {
             }
         });
 }
+
+#[test]
+fn test_claimed_epochs() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(test_helpers::get_epochs())
+        .add_claimed_epochs_to_state(
+            Addr::unchecked("alice"),
+            vec![Uint64::one(), Uint64::new(3u64)],
+        )
+        .query_claimed_epochs(Addr::unchecked("alice"), |res| {
+            let (_, epoch_ids) = res.unwrap();
+
+            // epoch 1 is outside the grace period, so it's not reported
+            assert_eq!(epoch_ids, vec![Uint64::new(3u64)]);
+        })
+        .query_claimed_epochs(Addr::unchecked("bob"), |res| {
+            let (_, epoch_ids) = res.unwrap();
+            assert!(epoch_ids.is_empty());
+        });
+}
@@ -1,10 +1,9 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 use cosmwasm_std::{Timestamp, Uint64};
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use white_whale_std::epoch_manager::epoch_manager::EpochV2;
 
 use crate::ContractError;
 use white_whale_std::fee_distributor::Epoch;
-use white_whale_std::pool_network::asset::AssetInfo;
 
 use crate::tests::robot::TestingRobot;
 use crate::tests::test_helpers;
@@ -35,38 +34,82 @@ fn test_expiring_epoch() {
 }
 
 #[test]
-fn test_create_genesis_epoch() {
+fn test_create_epoch_from_epoch_manager() {
     let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
-    let grace_period = Uint64::new(2);
-    let distribution_asset = AssetInfo::NativeToken {
-        denom: "uwhale".to_string(),
+    let epoch = EpochV2 {
+        id: 1u64,
+        start_time: Timestamp::from_nanos(1_678_802_400_000_000_000u64), // March 14, 2023 2:00:00 PM
     };
-    let epoch_config = EpochConfig {
-        duration: Uint64::new(86_400_000_000_000u64), // a day
-        genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-    };
-
-    robot.env.block.time = Timestamp::from_nanos(1678802300_000000000u64); // before genesis epoch
 
     robot
-        .instantiate(
-            mock_info("owner", &[]),
-            "bonding_contract_addr".to_string(),
-            "fee_collector_addr".to_string(),
-            grace_period,
-            epoch_config.clone(),
-            distribution_asset.clone(),
-        )
-        .create_new_epoch(mock_info("owner", &[]), |res| {
+        .instantiate_default()
+        .create_new_epoch(mock_info("unauthorized", &[]), epoch.clone(), |res| {
             let err = res.unwrap_err();
-            assert_eq!(err, ContractError::GenesisEpochNotStarted {});
+            assert_eq!(err, ContractError::Unauthorized {});
+        })
+        .create_new_epoch(mock_info("epoch_manager_addr", &[]), epoch, |res| {
+            // the epoch is created once the fees are forwarded by the fee collector
+            let response = res.unwrap();
+            assert_eq!(response.messages.len(), 1);
         });
+}
+
+#[test]
+fn test_fill_missing_epoch() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    // epoch 2 is missing, e.g. because the hook failed when it was created
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(vec![epochs[0].clone(), epochs[2].clone()])
+        .create_new_epoch(
+            mock_info("epoch_manager_addr", &[]),
+            EpochV2 {
+                id: epochs[1].id.u64(),
+                start_time: epochs[1].start_time,
+            },
+            |res| {
+                let response = res.unwrap();
+                assert_eq!(response.messages.len(), 1);
+            },
+        );
 
-    // set the time at genesis epoch
-    robot.env.block.time = Timestamp::from_nanos(1678802400_000000000u64); // before genesis epoch
+    // epoch 1 would fall out of the grace period straight away
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(vec![epochs[1].clone(), epochs[2].clone()])
+        .create_new_epoch(
+            mock_info("epoch_manager_addr", &[]),
+            EpochV2 {
+                id: epochs[0].id.u64(),
+                start_time: epochs[0].start_time,
+            },
+            |res| {
+                let err = res.unwrap_err();
+                assert_eq!(err, ContractError::EpochOutsideGracePeriod(Uint64::one()));
+            },
+        );
+}
 
-    robot.create_new_epoch(mock_info("owner", &[]), |res| {
-        // all good now
-        res.unwrap();
-    });
+#[test]
+fn test_cannot_create_past_epoch() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let epochs = test_helpers::get_epochs();
+
+    robot
+        .instantiate_default()
+        .add_epochs_to_state(epochs.clone())
+        .create_new_epoch(
+            mock_info("epoch_manager_addr", &[]),
+            EpochV2 {
+                id: epochs.last().unwrap().id.u64(),
+                start_time: epochs.last().unwrap().start_time,
+            },
+            |res| {
+                let err = res.unwrap_err();
+                assert_eq!(err, ContractError::EpochAlreadyCreated(Uint64::new(3u64)));
+            },
+        );
 }
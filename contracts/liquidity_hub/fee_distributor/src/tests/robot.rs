@@ -3,15 +3,17 @@ use cosmwasm_std::testing::{mock_info, MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
     from_json, Addr, Empty, Env, MessageInfo, OwnedDeps, Response, StdResult, Uint64,
 };
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use white_whale_std::epoch_manager::epoch_manager::EpochV2;
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
 
 use white_whale_std::fee_distributor::{
-    ClaimableEpochsResponse, Config, Epoch, EpochResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
+    ClaimableEpochsResponse, ClaimedEpochsResponse, Config, Epoch, EpochResponse, ExecuteMsg,
+    InstantiateMsg, QueryMsg,
 };
 use white_whale_std::pool_network::asset::AssetInfo;
 
 use crate::contract::{execute, instantiate, query};
-use crate::state::{get_expiring_epoch, EPOCHS, LAST_CLAIMED_EPOCH};
+use crate::state::{get_expiring_epoch, CLAIMED_EPOCHS, EPOCHS};
 use crate::ContractError;
 
 pub struct TestingRobot {
@@ -31,11 +33,8 @@ impl TestingRobot {
         let msg = InstantiateMsg {
             bonding_contract_addr: "bonding_contract_addr".to_string(),
             fee_collector_addr: "fee_collector_addr".to_string(),
+            epoch_manager_addr: "epoch_manager_addr".to_string(),
             grace_period: Uint64::new(2),
-            epoch_config: EpochConfig {
-                duration: Uint64::new(86_400_000_000_000u64), // a day
-                genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-            },
             distribution_asset: AssetInfo::NativeToken {
                 denom: "uwhale".to_string(),
             },
@@ -57,15 +56,15 @@ impl TestingRobot {
         info: MessageInfo,
         bonding_contract_addr: String,
         fee_collector_addr: String,
+        epoch_manager_addr: String,
         grace_period: Uint64,
-        epoch_config: EpochConfig,
         distribution_asset: AssetInfo,
     ) -> &mut Self {
         let msg = InstantiateMsg {
             bonding_contract_addr,
             fee_collector_addr,
+            epoch_manager_addr,
             grace_period,
-            epoch_config,
             distribution_asset,
         };
 
@@ -79,15 +78,15 @@ impl TestingRobot {
         info: MessageInfo,
         bonding_contract_addr: String,
         fee_collector_addr: String,
+        epoch_manager_addr: String,
         grace_period: Uint64,
-        epoch_config: EpochConfig,
         distribution_asset: AssetInfo,
     ) -> &mut Self {
         let msg = InstantiateMsg {
             bonding_contract_addr,
             fee_collector_addr,
+            epoch_manager_addr,
             grace_period,
-            epoch_config,
             distribution_asset,
         };
 
@@ -110,14 +109,21 @@ impl TestingRobot {
         self
     }
 
-    pub(crate) fn add_last_claimed_epoch_to_state(
+    pub(crate) fn add_claimed_epochs_to_state(
         &mut self,
         address: Addr,
-        epoch_id: Uint64,
+        epoch_ids: Vec<Uint64>,
     ) -> &mut Self {
-        LAST_CLAIMED_EPOCH
-            .save(&mut self.owned_deps.storage, &address, &epoch_id)
-            .unwrap();
+        for epoch_id in epoch_ids {
+            CLAIMED_EPOCHS
+                .save(
+                    &mut self.owned_deps.storage,
+                    (&address, epoch_id.u64()),
+                    &Empty {},
+                )
+                .unwrap();
+        }
+
         self
     }
 
@@ -131,9 +137,9 @@ impl TestingRobot {
             owner: Some(config.owner.to_string()),
             bonding_contract_addr: Some(config.bonding_contract_addr.to_string()),
             fee_collector_addr: Some(config.fee_collector_addr.to_string()),
+            epoch_manager_addr: Some(config.epoch_manager_addr.to_string()),
             grace_period: Some(config.grace_period),
             distribution_asset: Some(config.distribution_asset),
-            epoch_config: Some(config.epoch_config),
         };
 
         response(execute(
@@ -149,9 +155,10 @@ impl TestingRobot {
     pub(crate) fn create_new_epoch(
         &mut self,
         info: MessageInfo,
+        current_epoch: EpochV2,
         response: impl Fn(Result<Response, ContractError>),
     ) -> &mut Self {
-        let msg = ExecuteMsg::NewEpoch {};
+        let msg = ExecuteMsg::EpochChangedHook(EpochChangedHookMsg { current_epoch });
 
         response(execute(
             self.owned_deps.as_mut(),
@@ -228,6 +235,26 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn query_claimed_epochs(
+        &mut self,
+        address: Addr,
+        response: impl Fn(StdResult<(&mut Self, Vec<Uint64>)>),
+    ) -> &mut Self {
+        let query_res = query(
+            self.owned_deps.as_ref(),
+            self.env.clone(),
+            QueryMsg::ClaimedEpochs {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+        let res: ClaimedEpochsResponse = from_json(&query_res).unwrap();
+
+        response(Ok((self, res.epoch_ids)));
+
+        self
+    }
+
     pub(crate) fn query_config(
         &mut self,
         response: impl Fn(StdResult<(&mut Self, Config)>),
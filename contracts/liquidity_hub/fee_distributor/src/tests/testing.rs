@@ -5,7 +5,6 @@ use white_whale_std::fee_distributor::Config;
 
 use crate::tests::robot::TestingRobot;
 use crate::ContractError;
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
 use white_whale_std::pool_network::asset::AssetInfo;
 
 #[test]
@@ -15,18 +14,14 @@ fn instantiate_successfully() {
     let distribution_asset = AssetInfo::NativeToken {
         denom: "uwhale".to_string(),
     };
-    let epoch_config = EpochConfig {
-        duration: Uint64::new(86_400_000_000_000u64), // a day
-        genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-    };
 
     robot
         .instantiate(
             mock_info("owner", &[]),
             "bonding_contract_addr".to_string(),
             "fee_collector_addr".to_string(),
+            "epoch_manager_addr".to_string(),
             grace_period,
-            epoch_config.clone(),
             distribution_asset.clone(),
         )
         .asset_config(Config {
@@ -34,7 +29,7 @@ fn instantiate_successfully() {
             grace_period,
             bonding_contract_addr: Addr::unchecked("bonding_contract_addr"),
             fee_collector_addr: Addr::unchecked("fee_collector_addr"),
-            epoch_config,
+            epoch_manager_addr: Addr::unchecked("epoch_manager_addr"),
             distribution_asset,
         });
 }
@@ -46,17 +41,13 @@ fn instantiate_unsuccessfully() {
     let distribution_asset = AssetInfo::NativeToken {
         denom: "uwhale".to_string(),
     };
-    let epoch_config = EpochConfig {
-        duration: Uint64::new(86_400_000_000_000u64), // a day
-        genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-    };
 
     robot.instantiate_err(
         mock_info("owner", &[]),
         "bonding_contract_addr".to_string(),
         "fee_collector_addr".to_string(),
+        "epoch_manager_addr".to_string(),
         invalid_grace_period,
-        epoch_config.clone(),
         distribution_asset.clone(),
     );
 
@@ -65,21 +56,8 @@ fn instantiate_unsuccessfully() {
         mock_info("owner", &[]),
         "bonding_contract_addr".to_string(),
         "fee_collector_addr".to_string(),
+        "epoch_manager_addr".to_string(),
         invalid_grace_period,
-        epoch_config.clone(),
-        distribution_asset.clone(),
-    );
-
-    let invalid_epoch_duration = Uint64::new(3600u64);
-    robot.instantiate_err(
-        mock_info("owner", &[]),
-        "bonding_contract_addr".to_string(),
-        "fee_collector_addr".to_string(),
-        Uint64::one(),
-        EpochConfig {
-            duration: invalid_epoch_duration,                    // a day
-            genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-        },
         distribution_asset.clone(),
     );
 }
@@ -92,11 +70,8 @@ fn test_update_config() {
         owner: Addr::unchecked("new_owner"),
         bonding_contract_addr: Addr::unchecked("new_bonding_contract_addr"),
         fee_collector_addr: Addr::unchecked("new_fee_collector_addr"),
+        epoch_manager_addr: Addr::unchecked("new_epoch_manager_addr"),
         grace_period: Uint64::new(3),
-        epoch_config: EpochConfig {
-            duration: Uint64::new(86_400_000_000_000u64), // a day
-            genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-        },
         distribution_asset: AssetInfo::NativeToken {
             denom: "uwhale".to_string(),
         },
@@ -15,7 +15,7 @@ use white_whale_std::pool_network::asset::AssetInfo;
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::state::{CONFIG, FLOW_COUNTER, GLOBAL_WEIGHT};
-use crate::{execute, migrations, queries};
+use crate::{execute, helpers, migrations, queries};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "white_whale-incentive";
@@ -77,6 +77,17 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // flows and positions can't be opened nor expanded while the incentive is paused
+    if matches!(
+        msg,
+        ExecuteMsg::OpenFlow { .. }
+            | ExecuteMsg::ExpandFlow { .. }
+            | ExecuteMsg::OpenPosition { .. }
+            | ExecuteMsg::ExpandPosition { .. }
+    ) {
+        helpers::assert_not_paused(deps.as_ref(), &env)?;
+    }
+
     match msg {
         ExecuteMsg::TakeGlobalWeightSnapshot {} => execute::take_global_weight_snapshot(deps),
         ExecuteMsg::OpenFlow {
@@ -153,6 +153,9 @@ pub enum ContractError {
 
     #[error("The flow has already ended, can't be expanded")]
     FlowAlreadyEnded {},
+
+    #[error("The incentive contract is paused: {reason}")]
+    Paused { reason: String },
 }
 
 impl From<semver::Error> for ContractError {
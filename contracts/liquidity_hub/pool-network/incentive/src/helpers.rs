@@ -1,5 +1,6 @@
-use cosmwasm_std::{Addr, Deps, DepsMut, Order, StdError, StdResult, Uint128};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, Order, StdError, StdResult, Uint128};
 
+use white_whale_std::pause::PauseStatusResponse;
 use white_whale_std::pool_network::incentive::Flow;
 
 use crate::error::ContractError;
@@ -17,6 +18,24 @@ pub fn get_current_epoch(deps: Deps) -> Result<u64, ContractError> {
     Ok(epoch_response.epoch.id.u64())
 }
 
+/// Ensures the incentive contract wasn't paused through the incentive factory.
+pub fn assert_not_paused(deps: Deps, env: &Env) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let pause_status: PauseStatusResponse = deps.querier.query_wasm_smart(
+        config.factory_address.into_string(),
+        &white_whale_std::pool_network::incentive_factory::QueryMsg::PauseStatus {
+            contract: Some(env.contract.address.to_string()),
+        },
+    )?;
+
+    match pause_status.contract_paused.or(pause_status.all_paused) {
+        Some(pause_info) => Err(ContractError::Paused {
+            reason: pause_info.reason,
+        }),
+        None => Ok(()),
+    }
+}
+
 /// Gets the flows that are available for the current epoch, i.e. those flows that started either on
 /// the epoch provided or before it.
 pub fn get_available_flows(deps: Deps, epoch: &u64) -> Result<Vec<Flow>, ContractError> {
@@ -6609,3 +6609,108 @@ fn open_expand_flow_over_expand_limit() {
             },
         );
 }
+
+#[test]
+fn cannot_open_positions_while_paused() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(100_000_000_000u128, "uwhale".to_string()),
+        coin(100_000_000_000u128, "usdc".to_string()),
+        coin(100_000_000_000u128, "ampWHALE".to_string()),
+    ]);
+    let alice = suite.creator();
+    let guardian = suite.senders[1].clone();
+
+    suite.instantiate_default_native_fee();
+
+    let incentive_asset = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+
+    let incentive_asset_addr = suite.cw20_tokens.first().unwrap().clone();
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+
+    suite
+        .create_incentive(alice.clone(), incentive_asset.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(incentive_asset.clone(), |result| {
+            *incentive_addr.borrow_mut() = result.unwrap().unwrap();
+        })
+        .update_guardian(alice.clone(), Some(guardian.to_string()), |result| {
+            result.unwrap();
+        })
+        .pause_incentives(
+            guardian.clone(),
+            Some(incentive_addr.clone().into_inner().to_string()),
+            "exploit".to_string(),
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_pause_status(
+            Some(incentive_addr.clone().into_inner().to_string()),
+            |result| {
+                let pause_status = result.unwrap();
+                assert!(pause_status.is_paused());
+                assert_eq!(pause_status.contract_paused.unwrap().reason, "exploit");
+            },
+        );
+
+    let position = incentive::OpenPosition {
+        amount: Uint128::new(1_000u128),
+        unbonding_duration: 86400u64,
+    };
+
+    suite
+        .increase_allowance(
+            alice.clone(),
+            incentive_asset_addr,
+            Uint128::new(1_000u128),
+            incentive_addr.clone().into_inner(),
+        )
+        .open_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            position.amount,
+            position.unbonding_duration,
+            None,
+            vec![],
+            |result| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+
+                match err {
+                    ContractError::Paused { reason } => assert_eq!(reason, "exploit"),
+                    _ => panic!("Wrong error type, should return ContractError::Paused"),
+                }
+            },
+        )
+        // the guardian can't unpause
+        .unpause_incentives(guardian, None, |result| {
+            let err = result
+                .unwrap_err()
+                .downcast::<incentive_factory::error::ContractError>()
+                .unwrap();
+
+            match err {
+                incentive_factory::error::ContractError::Unauthorized => {}
+                _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+            }
+        })
+        .unpause_incentives(alice.clone(), None, |result| {
+            result.unwrap();
+        })
+        .open_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            position.amount,
+            position.unbonding_duration,
+            None,
+            vec![],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_positions(incentive_addr.clone().into_inner(), alice, |result| {
+            assert_eq!(result.unwrap().positions.len(), 1usize);
+        });
+}
@@ -4,7 +4,6 @@ use cosmwasm_std::{
 };
 use cw20::Cw20Coin;
 use cw_multi_test::{App, Executor};
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 
 use crate::contract::instantiate;
@@ -84,11 +83,8 @@ pub fn app_mock_instantiate(app: &mut App, lp_balance: Uint128) -> AppInstantiat
             &white_whale_std::fee_distributor::InstantiateMsg {
                 bonding_contract_addr: "bonding_contract_addr".to_string(),
                 fee_collector_addr: "fee_collector_addr".to_string(),
+                epoch_manager_addr: "epoch_manager_addr".to_string(),
                 grace_period: Uint64::one(),
-                epoch_config: EpochConfig {
-                    duration: Uint64::new(86400_000000000u64),
-                    genesis_epoch: Uint64::new(1685458800_000000000u64),
-                },
                 distribution_asset: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
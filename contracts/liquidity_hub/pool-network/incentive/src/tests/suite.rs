@@ -2,7 +2,10 @@ use cosmwasm_std::{Addr, Coin, StdResult, Timestamp, Uint128};
 use cw20::{BalanceResponse, Cw20Coin, MinterResponse};
 use cw_multi_test::{App, AppBuilder, AppResponse, BankKeeper, Executor};
 
+use white_whale_std::epoch_manager::epoch_manager::EpochV2;
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
 use white_whale_std::fee_distributor::EpochResponse;
+use white_whale_std::pause::PauseStatusResponse;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::pool_network::incentive::{
     Curve, Flow, FlowIdentifier, FlowResponse, GlobalWeightResponse, PositionsResponse,
@@ -357,6 +360,62 @@ impl TestingSuite {
         self
     }
 
+    pub(crate) fn update_guardian(
+        &mut self,
+        sender: Addr,
+        guardian: Option<String>,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive_factory::ExecuteMsg::UpdateGuardian {
+            guardian,
+        };
+
+        result(
+            self.app
+                .execute_contract(sender, self.incentive_factory_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn pause_incentives(
+        &mut self,
+        sender: Addr,
+        incentive_address: Option<String>,
+        reason: String,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive_factory::ExecuteMsg::PauseIncentives {
+            incentive_address,
+            reason,
+        };
+
+        result(
+            self.app
+                .execute_contract(sender, self.incentive_factory_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn unpause_incentives(
+        &mut self,
+        sender: Addr,
+        incentive_address: Option<String>,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive_factory::ExecuteMsg::UnpauseIncentives {
+            incentive_address,
+        };
+
+        result(
+            self.app
+                .execute_contract(sender, self.incentive_factory_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
     pub(crate) fn open_incentive_flow(
         &mut self,
         sender: Addr,
@@ -506,7 +565,10 @@ impl TestingSuite {
         epoch_amount: u64,
         incentive_addresses_to_snapshot_global_weight_for: Vec<Addr>,
     ) -> &mut Self {
-        let msg = white_whale_std::fee_distributor::ExecuteMsg::NewEpoch {};
+        let msg =
+            white_whale_std::fee_distributor::ExecuteMsg::EpochChangedHook(EpochChangedHookMsg {
+                current_epoch: EpochV2::default(),
+            });
 
         for _ in 0..epoch_amount {
             self.app
@@ -534,7 +596,10 @@ impl TestingSuite {
         &mut self,
         epoch_amount: u64,
     ) -> &mut Self {
-        let msg = white_whale_std::fee_distributor::ExecuteMsg::NewEpoch {};
+        let msg =
+            white_whale_std::fee_distributor::ExecuteMsg::EpochChangedHook(EpochChangedHookMsg {
+                current_epoch: EpochV2::default(),
+            });
 
         for _ in 0..epoch_amount {
             self.app
@@ -771,6 +836,23 @@ impl TestingSuite {
         self
     }
 
+    pub(crate) fn query_pause_status(
+        &mut self,
+        contract: Option<String>,
+        result: impl Fn(StdResult<PauseStatusResponse>),
+    ) -> &mut Self {
+        let pause_status_response: StdResult<PauseStatusResponse> =
+            self.app.wrap().query_wasm_smart(
+                self.incentive_factory_addr.clone(),
+                &white_whale_std::pool_network::incentive_factory::QueryMsg::PauseStatus {
+                    contract,
+                },
+            );
+
+        result(pause_status_response);
+        self
+    }
+
     pub(crate) fn query_incentive_config(
         &mut self,
         incentive: Addr,
@@ -13,7 +13,7 @@ use white_whale_std::pool_network::incentive_factory::{
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::reply::create_incentive_reply::CREATE_INCENTIVE_REPLY_ID;
-use crate::state::CONFIG;
+use crate::state::{CONFIG, GUARDIAN};
 use crate::{execute, queries, reply};
 
 // version info for migration info
@@ -91,9 +91,11 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Only the owner can execute messages on the factory
+    // Only the owner can execute messages on the factory, besides the guardian pausing incentives
     let config: Config = CONFIG.load(deps.storage)?;
-    if deps.api.addr_validate(info.sender.as_str())? != config.owner {
+    let is_guardian = matches!(msg, ExecuteMsg::PauseIncentives { .. })
+        && GUARDIAN.may_load(deps.storage)?.as_ref() == Some(&info.sender);
+    if deps.api.addr_validate(info.sender.as_str())? != config.owner && !is_guardian {
         return Err(ContractError::Unauthorized {});
     }
 
@@ -125,6 +127,14 @@ pub fn execute(
             incentive_address,
             code_id,
         } => execute::migrate_incentives(deps, incentive_address, code_id),
+        ExecuteMsg::PauseIncentives {
+            incentive_address,
+            reason,
+        } => execute::pause_incentives(deps, env, info, incentive_address, reason),
+        ExecuteMsg::UnpauseIncentives { incentive_address } => {
+            execute::unpause_incentives(deps, incentive_address)
+        }
+        ExecuteMsg::UpdateGuardian { guardian } => execute::update_guardian(deps, guardian),
     }
 }
 
@@ -150,6 +160,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Incentives { start_after, limit } => {
             to_json_binary(&queries::get_incentives(deps, start_after, limit)?)
         }
+        QueryMsg::PauseStatus { contract } => {
+            to_json_binary(&queries::get_pause_status(deps, contract)?)
+        }
     }
 }
 
@@ -1,7 +1,11 @@
 mod create_incentive;
 mod migrate_incentive;
+mod pause_incentives;
 mod update_config;
+mod update_guardian;
 
 pub use create_incentive::create_incentive;
 pub use migrate_incentive::migrate_incentives;
+pub use pause_incentives::{pause_incentives, unpause_incentives};
 pub use update_config::update_config;
+pub use update_guardian::update_guardian;
@@ -0,0 +1,60 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use white_whale_std::pause::PauseInfo;
+
+use crate::error::ContractError;
+use crate::state::{ALL_PAUSED, PAUSED_INCENTIVES};
+
+/// Pauses the given incentive contract, or all of them if none is given.
+pub fn pause_incentives(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    incentive_address: Option<String>,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let pause_info = PauseInfo::new(info.sender.clone(), reason.clone(), &env);
+
+    let incentive = match incentive_address {
+        Some(incentive_address) => {
+            let incentive_address = deps.api.addr_validate(&incentive_address)?;
+            PAUSED_INCENTIVES.save(deps.storage, &incentive_address, &pause_info)?;
+            incentive_address.to_string()
+        }
+        None => {
+            ALL_PAUSED.save(deps.storage, &pause_info)?;
+            "all".to_string()
+        }
+    };
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "pause_incentives".to_string()),
+        ("incentive", incentive),
+        ("reason", reason),
+        ("paused_by", info.sender.to_string()),
+    ]))
+}
+
+/// Unpauses the given incentive contract, or all of them if none is given.
+pub fn unpause_incentives(
+    deps: DepsMut,
+    incentive_address: Option<String>,
+) -> Result<Response, ContractError> {
+    let incentive = match incentive_address {
+        Some(incentive_address) => {
+            let incentive_address = deps.api.addr_validate(&incentive_address)?;
+            PAUSED_INCENTIVES.remove(deps.storage, &incentive_address);
+            incentive_address.to_string()
+        }
+        None => {
+            ALL_PAUSED.remove(deps.storage);
+            PAUSED_INCENTIVES.clear(deps.storage);
+            "all".to_string()
+        }
+    };
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "unpause_incentives".to_string()),
+        ("incentive", incentive),
+    ]))
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{DepsMut, Response};
+
+use crate::error::ContractError;
+use crate::state::GUARDIAN;
+
+/// Sets the guardian of the factory, or removes it if none is given.
+pub fn update_guardian(deps: DepsMut, guardian: Option<String>) -> Result<Response, ContractError> {
+    let guardian = guardian
+        .map(|guardian| deps.api.addr_validate(&guardian))
+        .transpose()?;
+
+    match &guardian {
+        Some(guardian) => GUARDIAN.save(deps.storage, guardian)?,
+        None => GUARDIAN.remove(deps.storage),
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_guardian".to_string()),
+        (
+            "guardian",
+            guardian.map_or("None".to_string(), |guardian| guardian.to_string()),
+        ),
+    ]))
+}
@@ -0,0 +1,23 @@
+use cosmwasm_std::{Deps, StdError};
+use white_whale_std::pause::PauseStatusResponse;
+
+use crate::state::{ALL_PAUSED, GUARDIAN, PAUSED_INCENTIVES};
+
+/// Retrieves the guardian and whether incentive contracts are paused.
+pub fn get_pause_status(
+    deps: Deps,
+    contract: Option<String>,
+) -> Result<PauseStatusResponse, StdError> {
+    let contract_paused = match contract {
+        Some(contract) => {
+            PAUSED_INCENTIVES.may_load(deps.storage, &deps.api.addr_validate(&contract)?)?
+        }
+        None => None,
+    };
+
+    Ok(PauseStatusResponse {
+        guardian: GUARDIAN.may_load(deps.storage)?,
+        all_paused: ALL_PAUSED.may_load(deps.storage)?,
+        contract_paused,
+    })
+}
@@ -1,7 +1,9 @@
 mod get_config;
 mod get_incentive;
 mod get_incentives;
+mod get_pause_status;
 
 pub use get_config::get_config;
 pub use get_incentive::get_incentive;
 pub use get_incentives::get_incentives;
+pub use get_pause_status::get_pause_status;
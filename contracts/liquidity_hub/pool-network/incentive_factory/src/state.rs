@@ -1,8 +1,16 @@
 use cosmwasm_std::Addr;
 use cw_storage_plus::{Item, Map};
+use white_whale_std::pause::PauseInfo;
 use white_whale_std::pool_network::incentive_factory::Config;
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
 /// Maps the address of the LP token to the incentive contract address
 pub const INCENTIVE_MAPPINGS: Map<&[u8], Addr> = Map::new("incentive_mappings");
+
+/// The guardian of the factory, which can pause incentive contracts but never unpause them.
+pub const GUARDIAN: Item<Addr> = Item::new("guardian");
+/// Set while all the incentive contracts are paused.
+pub const ALL_PAUSED: Item<PauseInfo> = Item::new("all_paused");
+/// The incentive contracts paused on their own.
+pub const PAUSED_INCENTIVES: Map<&Addr, PauseInfo> = Map::new("paused_incentives");
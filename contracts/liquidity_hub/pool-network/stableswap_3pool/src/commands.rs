@@ -4,9 +4,9 @@ use cosmwasm_std::{
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
-use crate::contract::{MAX_AMP, MAX_AMP_CHANGE, MIN_AMP, MIN_RAMP_BLOCKS};
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use cosmwasm_std::coins;
+use white_whale_std::pool_network::amp::validate_amp_ramp;
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use white_whale_std::pool_network::asset::is_factory_token;
 use white_whale_std::pool_network::asset::{
@@ -16,6 +16,7 @@ use white_whale_std::pool_network::asset::{
 use white_whale_std::pool_network::denom_injective::{Coin, MsgBurn, MsgMint};
 #[cfg(feature = "osmosis_token_factory")]
 use white_whale_std::pool_network::denom_osmosis::{Coin, MsgBurn, MsgMint};
+use white_whale_std::pool_network::pool_stats::{current_epoch, record_swap_stats};
 use white_whale_std::pool_network::swap;
 use white_whale_std::pool_network::trio::{Config, Cw20HookMsg, FeatureToggle, PoolFee, RampAmp};
 
@@ -371,7 +372,7 @@ pub fn swap(
 
     // To calculate pool amounts properly we should subtract user deposit and the protocol fees from the pool
     let pools = trio_info
-        .query_pools(&deps.querier, deps.api, env.contract.address)?
+        .query_pools(&deps.querier, deps.api, env.contract.address.clone())?
         .into_iter()
         .map(|mut pool| {
             // subtract the protocol fee from the pool
@@ -446,7 +447,7 @@ pub fn swap(
         ask_pool.amount,
         unswapped_pool.amount,
         offer_amount,
-        config.pool_fees,
+        config.pool_fees.clone(),
         invariant,
     )?;
 
@@ -508,6 +509,17 @@ pub fn swap(
         ALL_TIME_COLLECTED_PROTOCOL_FEES,
     )?;
 
+    let epoch_id = current_epoch(deps.storage, &deps.querier, &env, &config.owner)?;
+    record_swap_stats(
+        deps.storage,
+        epoch_id,
+        &offer_asset,
+        &Asset {
+            info: ask_pool.info.clone(),
+            amount: swap_computation.swap_fee_amount,
+        },
+    )?;
+
     // 1. send collateral token from the contract to a user
     // 2. stores the protocol fees
     Ok(Response::new().add_messages(messages).add_attributes(vec![
@@ -577,28 +589,7 @@ pub fn update_config(
         );
         let current_amp = invariant.compute_amp_factor().unwrap();
         //check new amp value and ramp time are valid
-        if ramp.future_a < MIN_AMP {
-            return Err(ContractError::Std(StdError::generic_err(format!(
-                "New amp must be over {MIN_AMP}"
-            ))));
-        }
-        if ramp.future_a > MAX_AMP {
-            return Err(ContractError::Std(StdError::generic_err(format!(
-                "Initial amp must be under {MAX_AMP}"
-            ))));
-        }
-        if (ramp.future_a > current_amp) && (ramp.future_a > current_amp * MAX_AMP_CHANGE)
-            || (ramp.future_a < current_amp) && (ramp.future_a * MAX_AMP_CHANGE > current_amp)
-        {
-            return Err(ContractError::Std(StdError::generic_err(
-                "Amp change over max",
-            )));
-        }
-        if ramp.future_block < env.block.height + MIN_RAMP_BLOCKS {
-            return Err(ContractError::Std(StdError::generic_err(
-                "Amp change ramp time under minimum",
-            )));
-        }
+        validate_amp_ramp(current_amp, &ramp, env.block.height)?;
         config.initial_amp_block = env.block.height;
         config.future_amp_block = ramp.future_block;
         config.initial_amp = current_amp;
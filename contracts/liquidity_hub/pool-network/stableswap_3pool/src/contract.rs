@@ -27,14 +27,7 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const INSTANTIATE_REPLY_ID: u64 = 1;
 
-/// Minimum amplification coefficient.
-pub const MIN_AMP: u64 = 1;
-/// Maximum amplification coefficient.
-pub const MAX_AMP: u64 = 1_000_000;
-/// Minimum number of blocks an amplification coefficient change must take place over.
-pub const MIN_RAMP_BLOCKS: u64 = 10000;
-/// Maximum factor the amplification coefficient can be changed by in a single command.
-pub const MAX_AMP_CHANGE: u64 = 10;
+pub use white_whale_std::pool_network::amp::{MAX_AMP, MAX_AMP_CHANGE, MIN_AMP, MIN_RAMP_BLOCKS};
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -269,6 +262,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
             ALL_TIME_BURNED_FEES,
             None,
         )?)?),
+        QueryMsg::PoolStats { epoch_id } => {
+            Ok(to_json_binary(&queries::query_pool_stats(deps, epoch_id)?)?)
+        }
     }
 }
 
@@ -2,6 +2,7 @@ use cosmwasm_std::{Deps, StdResult};
 use cw_storage_plus::Item;
 
 use white_whale_std::pool_network::asset::{Asset, AssetInfoRaw, TrioInfo, TrioInfoRaw};
+use white_whale_std::pool_network::pool_stats::{PoolStats, EPOCH_POOL_STATS, POOL_STATS};
 use white_whale_std::pool_network::trio::{
     ConfigResponse, PoolResponse, ProtocolFeesResponse, ReverseSimulationResponse,
     SimulationResponse,
@@ -317,3 +318,13 @@ pub fn query_fees(
     let fees = fees_storage_item.load(deps.storage)?;
     Ok(ProtocolFeesResponse { fees })
 }
+
+/// Queries the swap statistics of the pool since its inception, or for the given epoch.
+pub fn query_pool_stats(deps: Deps, epoch_id: Option<u64>) -> StdResult<PoolStats> {
+    let pool_stats = match epoch_id {
+        Some(epoch_id) => EPOCH_POOL_STATS.may_load(deps.storage, epoch_id)?,
+        None => POOL_STATS.may_load(deps.storage)?,
+    };
+
+    Ok(pool_stats.unwrap_or_default())
+}
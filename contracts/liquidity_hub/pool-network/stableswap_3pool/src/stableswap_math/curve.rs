@@ -1,7 +1,7 @@
 //! Swap calculations and curve invariant implementation
 use cosmwasm_std::{Uint128, Uint256};
 
-use num_traits::ToPrimitive;
+use white_whale_std::pool_network::amp::compute_amp_factor;
 
 /// Number of coins in a swap.
 pub const N_COINS: u8 = 3;
@@ -103,36 +103,13 @@ impl StableSwap {
     ///
     /// [^stableswap]: [Egorov, "StableSwap," 2019.](https://curve.fi/files/stableswap-paper.pdf)
     pub fn compute_amp_factor(&self) -> Option<u64> {
-        if self.current_ts < self.stop_ramp_ts {
-            let time_range = self.stop_ramp_ts.checked_sub(self.start_ramp_ts)?;
-            let time_delta = self.current_ts.checked_sub(self.start_ramp_ts)?;
-
-            // Compute amp factor based on ramp time
-            if self.target_amp_factor >= self.initial_amp_factor {
-                // Ramp up
-                let amp_range = self
-                    .target_amp_factor
-                    .checked_sub(self.initial_amp_factor)?;
-                let amp_delta = (amp_range as u128)
-                    .checked_mul(time_delta.to_u128()?)?
-                    .checked_div(time_range.to_u128()?)?
-                    .to_u64()?;
-                self.initial_amp_factor.checked_add(amp_delta)
-            } else {
-                // Ramp down
-                let amp_range = self
-                    .initial_amp_factor
-                    .checked_sub(self.target_amp_factor)?;
-                let amp_delta = (amp_range as u128)
-                    .checked_mul(time_delta.to_u128()?)?
-                    .checked_div(time_range.to_u128()?)?
-                    .to_u64()?;
-                self.initial_amp_factor.checked_sub(amp_delta)
-            }
-        } else {
-            // when stop_ramp_ts == 0 or current_ts >= stop_ramp_ts
-            Some(self.target_amp_factor)
-        }
+        compute_amp_factor(
+            self.initial_amp_factor,
+            self.target_amp_factor,
+            self.current_ts,
+            self.start_ramp_ts,
+            self.stop_ramp_ts,
+        )
     }
 
     /// Computes the Stable Swap invariant (D).
@@ -16,7 +16,6 @@ pub const ALL_TIME_COLLECTED_PROTOCOL_FEES: Item<Vec<Asset>> =
     Item::new("all_time_collected_protocol_fees");
 // Fees that have been burned by the pool since the pool's inception
 pub const ALL_TIME_BURNED_FEES: Item<Vec<Asset>> = Item::new("all_time_burned_fees");
-
 /// Stores the fee for an asset in the given fees_storage_item
 pub fn store_fee(
     storage: &mut dyn Storage,
@@ -1,7 +1,7 @@
 use crate::contract::{execute, instantiate, query, reply};
 use crate::error::ContractError;
 use crate::helpers::compute_swap;
-use crate::queries::query_fees;
+use crate::queries::{query_fees, query_pool_stats};
 use crate::stableswap_math::curve::StableSwap;
 use crate::state::{
     ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES,
@@ -15,6 +15,7 @@ use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 use white_whale_std::fee::Fee;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::pool_network::mock_querier::mock_dependencies;
+use white_whale_std::pool_network::pool_stats::PoolStats;
 use white_whale_std::pool_network::trio::{
     Cw20HookMsg, ExecuteMsg, InstantiateMsg, PoolFee, QueryMsg, ReverseSimulationResponse,
     SimulationResponse,
@@ -156,6 +157,9 @@ fn try_native_to_token() {
             amount: offer_amount,
         }],
     );
+    // the swap is recorded in the stats of the current epoch
+    deps.querier
+        .with_current_epoch(Some((1u64, env.block.time.plus_days(1))));
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
     assert_eq!(res.messages.len(), 2);
     let msg_transfer = res.messages.get(0).expect("no message");
@@ -240,6 +244,30 @@ fn try_native_to_token() {
         Uint128::zero()
     );
 
+    let expected_pool_stats = PoolStats {
+        volume: vec![Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            amount: offer_amount,
+        }],
+        lp_fees: vec![Asset {
+            info: AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+            amount: expected_swap_fee_amount,
+        }],
+        swap_count: 1,
+    };
+    assert_eq!(
+        query_pool_stats(deps.as_ref(), None).unwrap(),
+        expected_pool_stats
+    );
+    assert_eq!(
+        query_pool_stats(deps.as_ref(), Some(1u64)).unwrap(),
+        expected_pool_stats
+    );
+
     // check simulation res, reset values pre-swap to check simulation
     deps.querier.with_balance(&[(
         &MOCK_CONTRACT_ADDR.to_string(),
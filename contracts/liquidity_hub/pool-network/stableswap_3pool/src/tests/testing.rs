@@ -17,7 +17,9 @@ use white_whale_std::pool_network::mock_querier::mock_dependencies;
 use white_whale_std::pool_network::swap::assert_max_spread;
 use white_whale_std::pool_network::token::InstantiateMsg as TokenInstantiateMsg;
 use white_whale_std::pool_network::trio::ExecuteMsg::UpdateConfig;
-use white_whale_std::pool_network::trio::{Config, InstantiateMsg, MigrateMsg, PoolFee, QueryMsg};
+use white_whale_std::pool_network::trio::{
+    Config, InstantiateMsg, MigrateMsg, PoolFee, QueryMsg, RampAmp,
+};
 
 use crate::contract::{execute, instantiate, migrate, query, reply};
 use crate::error::ContractError;
@@ -667,6 +669,107 @@ fn test_update_config_successful() {
     assert_eq!(config.pool_fees.swap_fee.share, Decimal::percent(3u64));
 }
 
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn test_update_config_amp_ramp() {
+    let mut deps = mock_dependencies(&[]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+        ),
+        (&"asset0000".to_string(), &[]),
+        (&"asset0001".to_string(), &[]),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0001".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8, 10u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        amp_factor: 1000,
+        token_factory_lp: false,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let ramp_message = |future_a: u64, future_block: u64| UpdateConfig {
+        owner: None,
+        fee_collector_addr: None,
+        pool_fees: None,
+        feature_toggle: None,
+        amp_factor: Some(RampAmp {
+            future_a,
+            future_block,
+        }),
+    };
+
+    // the new amp can't exceed the max amp
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ramp_message(1_000_001, env.block.height + 20_000),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("New amp must be under 1000000"))
+    );
+
+    // the amp can't decrease by more than the max factor
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ramp_message(99, env.block.height + 20_000),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("Amp change over max"))
+    );
+
+    // but it can decrease by exactly the max factor
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ramp_message(100, env.block.height + 20_000),
+    )
+    .unwrap();
+
+    let config: Config =
+        from_json(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.initial_amp, 1000);
+    assert_eq!(config.future_amp, 100);
+    assert_eq!(config.future_amp_block, env.block.height + 20_000);
+}
+
 #[test]
 fn test_assert_slippage_tolerance_invalid_ratio() {
     let res = assert_slippage_tolerance(
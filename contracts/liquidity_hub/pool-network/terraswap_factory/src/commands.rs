@@ -1,12 +1,22 @@
 use cosmwasm_std::{
-    to_json_binary, wasm_execute, CosmosMsg, DepsMut, Env, MessageInfo, ReplyOn, Response, SubMsg,
-    WasmMsg,
+    to_json_binary, wasm_execute, Addr, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Order, ReplyOn, Response, StdResult, SubMsg, WasmMsg,
 };
+use serde::Deserialize;
 
+use white_whale_std::pause::PauseInfo;
 use white_whale_std::pool_network;
-use white_whale_std::pool_network::asset::{AssetInfo, PairType};
+use white_whale_std::pool_network::amp::AmpFactorUpdate;
+use white_whale_std::pool_network::asset::{AssetInfo, AssetInfoRaw, PairType};
+use white_whale_std::pool_network::factory::{
+    AssetMetadata, AssetMetadataInfo, FeeDiscounts, PairsFilter, PoolCreationFee, PoolCursor,
+};
 use white_whale_std::pool_network::pair::{
-    FeatureToggle, InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
+    DynamicFeeUpdate, FeatureToggle, InstantiateMsg as PairInstantiateMsg,
+    MigrateMsg as PairMigrateMsg, PoolFee,
+};
+use white_whale_std::pool_network::querier::{
+    query_pair_info_from_pair, query_trio_info_from_trio,
 };
 use white_whale_std::pool_network::trio::{
     FeatureToggle as TrioFeatureToggle, InstantiateMsg as TrioInstantiateMsg,
@@ -17,11 +27,15 @@ use white_whale_std::pool_network::{pair, trio};
 use crate::contract::{CREATE_PAIR_RESPONSE, CREATE_TRIO_RESPONSE};
 use crate::error::ContractError;
 use crate::state::{
-    add_allow_native_token, pair_key, trio_key, Config, TmpPairInfo, TmpTrioInfo, CONFIG, PAIRS,
-    TMP_PAIR_INFO, TMP_TRIO_INFO, TRIOS,
+    add_allow_native_token, find_pair_pool_by_addr, has_pair_pool, load_pair_pool,
+    next_pool_identifier, pair_key, read_pairs, read_paused_by_all, read_pools, trio_key, Config,
+    PausedPool, Pool, TmpPairInfo, TmpTrioInfo, ALL_PAUSED, ASSET_METADATA, CONFIG,
+    DEFAULT_PAIR_POOLS, EPOCH_MANAGER, FEE_DISCOUNTS, FEE_DISCOUNT_WHITELIST, GUARDIAN, PAIRS,
+    PAIR_POOL_FEES, PAUSED_BY_ALL, PAUSED_CONTRACTS, TMP_PAIR_INFO, TMP_TRIO_INFO, TRIOS,
 };
 
 /// Updates the contract's [Config]
+#[allow(clippy::too_many_arguments)]
 pub fn update_config(
     deps: DepsMut,
     owner: Option<String>,
@@ -29,6 +43,8 @@ pub fn update_config(
     token_code_id: Option<u64>,
     pair_code_id: Option<u64>,
     trio_code_id: Option<u64>,
+    pool_creation_fee: Option<PoolCreationFee>,
+    allowed_fee_tiers: Option<Vec<PoolFee>>,
 ) -> Result<Response, ContractError> {
     let mut config: Config = CONFIG.load(deps.storage)?;
 
@@ -55,12 +71,24 @@ pub fn update_config(
         config.fee_collector_addr = deps.api.addr_validate(fee_collector_addr.as_str())?;
     }
 
+    if let Some(pool_creation_fee) = pool_creation_fee {
+        config.pool_creation_fee = Some(pool_creation_fee);
+    }
+
+    if let Some(allowed_fee_tiers) = allowed_fee_tiers {
+        for fee_tier in &allowed_fee_tiers {
+            fee_tier.is_valid()?;
+        }
+        config.allowed_fee_tiers = allowed_fee_tiers;
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::new().add_attribute("action", "update_config"))
 }
 
 #[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
 /// Updates a pair config
 pub fn update_pair_config(
     deps: DepsMut,
@@ -69,14 +97,48 @@ pub fn update_pair_config(
     fee_collector_addr: Option<String>,
     pool_fees: Option<PoolFee>,
     feature_toggle: Option<FeatureToggle>,
+    amp_factor: Option<AmpFactorUpdate>,
+    dynamic_fee: Option<DynamicFeeUpdate>,
     cosmwasm_pool_interface: Option<String>,
 ) -> Result<Response, ContractError> {
+    let pair_addr = deps.api.addr_validate(&pair_addr)?;
+
+    // updating the feature toggle unpauses the pair
+    if feature_toggle.is_some() {
+        PAUSED_CONTRACTS.remove(deps.storage, &pair_addr);
+        PAUSED_BY_ALL.remove(deps.storage, &pair_addr);
+    }
+
+    // the pool fees are tracked apart from the pool identifier, which never changes
+    let mut attributes = vec![];
+    if let Some(pool_fees) = &pool_fees {
+        let pair_info = query_pair_info_from_pair(&deps.querier, pair_addr.clone())?;
+        let pair_key = pair_key(&[
+            pair_info.asset_infos[0].to_raw(deps.api)?,
+            pair_info.asset_infos[1].to_raw(deps.api)?,
+        ]);
+
+        let Some((pool_identifier, _)) = find_pair_pool_by_addr(
+            deps.storage,
+            &pair_key,
+            &deps.api.addr_canonicalize(pair_addr.as_str())?,
+        )?
+        else {
+            return Err(ContractError::UnExistingPair {});
+        };
+
+        PAIR_POOL_FEES.save(deps.storage, (&pair_key, &pool_identifier), pool_fees)?;
+        attributes.push(("pool_identifier", pool_identifier));
+    }
+
     #[cfg(not(feature = "osmosis"))]
     let msg = pool_network::pair::ExecuteMsg::UpdateConfig {
         owner,
         fee_collector_addr,
         pool_fees,
         feature_toggle,
+        amp_factor,
+        dynamic_fee,
     };
 
     #[cfg(feature = "osmosis")]
@@ -85,16 +147,15 @@ pub fn update_pair_config(
         fee_collector_addr,
         pool_fees,
         feature_toggle,
+        amp_factor,
+        dynamic_fee,
         cosmwasm_pool_interface,
     };
 
     Ok(Response::default()
-        .add_message(wasm_execute(
-            deps.api.addr_validate(&pair_addr)?.to_string(),
-            &msg,
-            vec![],
-        )?)
-        .add_attribute("action", "update_pair_config"))
+        .add_message(wasm_execute(pair_addr.to_string(), &msg, vec![])?)
+        .add_attribute("action", "update_pair_config")
+        .add_attributes(attributes))
 }
 
 /// Creates a Pair
@@ -113,6 +174,8 @@ pub fn create_pair(
         return Err(ContractError::SameAsset {});
     }
 
+    let (fee_messages, funds) = charge_pool_creation_fee(&deps, &info, &config, &pool_fees)?;
+
     let asset_1_decimal =
         match asset_infos[0].query_decimals(env.contract.address.clone(), &deps.querier) {
             Ok(decimal) => decimal,
@@ -140,39 +203,45 @@ pub fn create_pair(
 
     let asset_decimals = [asset_1_decimal, asset_2_decimal];
 
+    // multiple pools can exist for the same assets, as long as they differ in type or fees
     let pair_key = pair_key(&raw_infos);
-    if let Ok(Some(_)) = PAIRS.may_load(deps.storage, &pair_key) {
+    if has_pair_pool(deps.storage, &pair_key, &pair_type, &pool_fees)? {
         return Err(ContractError::ExistingPair {});
     }
+    let pool_identifier = next_pool_identifier(deps.storage)?;
 
     TMP_PAIR_INFO.save(
         deps.storage,
         &TmpPairInfo {
             pair_key,
+            pool_identifier: pool_identifier.clone(),
             asset_infos: raw_infos,
             asset_decimals,
             pair_type: pair_type.clone(),
+            pool_fees: pool_fees.clone(),
         },
     )?;
 
     // prepare labels for creating the pair token with a meaningful name
-    let asset0_label = asset_infos[0].clone().get_label(&deps.as_ref())?;
-    let asset1_label = asset_infos[1].clone().get_label(&deps.as_ref())?;
+    let asset0_label = asset_label(deps.as_ref(), &asset_infos[0])?;
+    let asset1_label = asset_label(deps.as_ref(), &asset_infos[1])?;
     let pair_label = format!("{asset0_label}-{asset1_label} pair");
 
     Ok(Response::new()
+        .add_messages(fee_messages)
         .add_attributes(vec![
             ("action", "create_pair"),
             ("pair", &format!("{asset0_label}-{asset1_label}")),
             ("pair_label", pair_label.as_str()),
             ("pair_type", pair_type.get_label()),
+            ("pool_identifier", pool_identifier.as_str()),
         ])
         .add_submessage(SubMsg {
             id: CREATE_PAIR_RESPONSE,
             gas_limit: None,
             msg: CosmosMsg::Wasm(WasmMsg::Instantiate {
                 code_id: config.pair_code_id,
-                funds: info.funds,
+                funds,
                 admin: Some(env.contract.address.to_string()),
                 label: pair_label,
                 msg: to_json_binary(&PairInstantiateMsg {
@@ -200,9 +269,17 @@ pub fn update_trio_config(
     feature_toggle: Option<TrioFeatureToggle>,
     amp_factor: Option<RampAmp>,
 ) -> Result<Response, ContractError> {
+    let trio_addr = deps.api.addr_validate(&trio_addr)?;
+
+    // updating the feature toggle unpauses the trio
+    if feature_toggle.is_some() {
+        PAUSED_CONTRACTS.remove(deps.storage, &trio_addr);
+        PAUSED_BY_ALL.remove(deps.storage, &trio_addr);
+    }
+
     Ok(Response::new()
         .add_message(wasm_execute(
-            deps.api.addr_validate(&trio_addr)?.to_string(),
+            trio_addr.to_string(),
             &pool_network::trio::ExecuteMsg::UpdateConfig {
                 owner,
                 fee_collector_addr,
@@ -234,6 +311,9 @@ pub fn create_trio(
         return Err(ContractError::SameAsset {});
     }
 
+    let (fee_messages, funds) =
+        charge_pool_creation_fee(&deps, &info, &config, &trio_fee_tier(&pool_fees))?;
+
     let asset_1_decimal =
         match asset_infos[0].query_decimals(env.contract.address.clone(), &deps.querier) {
             Ok(decimal) => decimal,
@@ -286,12 +366,13 @@ pub fn create_trio(
     )?;
 
     // prepare labels for creating the pair token with a meaningful name
-    let asset0_label = asset_infos[0].clone().get_label(&deps.as_ref())?;
-    let asset1_label = asset_infos[1].clone().get_label(&deps.as_ref())?;
-    let asset2_label = asset_infos[2].clone().get_label(&deps.as_ref())?;
+    let asset0_label = asset_label(deps.as_ref(), &asset_infos[0])?;
+    let asset1_label = asset_label(deps.as_ref(), &asset_infos[1])?;
+    let asset2_label = asset_label(deps.as_ref(), &asset_infos[2])?;
     let trio_label = format!("{asset0_label}-{asset1_label}-{asset2_label} trio");
 
     Ok(Response::new()
+        .add_messages(fee_messages)
         .add_attributes(vec![
             ("action", "create_trio"),
             (
@@ -305,7 +386,7 @@ pub fn create_trio(
             gas_limit: None,
             msg: CosmosMsg::Wasm(WasmMsg::Instantiate {
                 code_id: config.trio_code_id,
-                funds: info.funds,
+                funds,
                 admin: Some(env.contract.address.to_string()),
                 label: trio_label,
                 msg: to_json_binary(&TrioInstantiateMsg {
@@ -322,10 +403,112 @@ pub fn create_trio(
         }))
 }
 
+/// Checks that a pool with the given fees can be created by the sender, collecting the pool creation
+/// fee if needed. The owner is exempt from both the fee and the fee tier restriction, and is the only
+/// one allowed to create pools until the pool creation fee and the fee tiers are set.
+///
+/// Returns the messages forwarding the fee to the fee collector (or burning it), and the funds left
+/// to be sent along with the pool instantiation.
+fn charge_pool_creation_fee(
+    deps: &DepsMut,
+    info: &MessageInfo,
+    config: &Config,
+    pool_fees: &PoolFee,
+) -> Result<(Vec<CosmosMsg>, Vec<Coin>), ContractError> {
+    let mut funds = info.funds.clone();
+
+    if deps.api.addr_canonicalize(info.sender.as_str())? == config.owner {
+        return Ok((vec![], funds));
+    }
+
+    // permissionless pool creation stays disabled until the owner sets both the fee and the tiers
+    let Some(PoolCreationFee { fee, burn }) = config.pool_creation_fee.clone() else {
+        return Err(ContractError::PermissionlessPoolCreationDisabled {});
+    };
+
+    if config.allowed_fee_tiers.is_empty() {
+        return Err(ContractError::PermissionlessPoolCreationDisabled {});
+    }
+
+    if !config.allowed_fee_tiers.contains(pool_fees) {
+        return Err(ContractError::FeeTierNotAllowed {});
+    }
+
+    if fee.amount.is_zero() {
+        return Ok((vec![], funds));
+    }
+
+    let message = match &fee.info {
+        AssetInfo::NativeToken { denom } => {
+            // the fee is taken out of the funds sent along, the rest goes to the pool instantiation
+            let paid_amount = funds
+                .iter()
+                .find(|coin| &coin.denom == denom)
+                .map(|coin| coin.amount)
+                .unwrap_or_default();
+
+            if paid_amount < fee.amount {
+                return Err(ContractError::PoolCreationFeeNotPaid {
+                    paid_amount,
+                    required_amount: fee.amount,
+                });
+            }
+
+            funds = funds
+                .into_iter()
+                .map(|mut coin| {
+                    if &coin.denom == denom {
+                        coin.amount -= fee.amount;
+                    }
+                    coin
+                })
+                .filter(|coin| !coin.amount.is_zero())
+                .collect();
+
+            if burn {
+                fee.into_burn_msg()?
+            } else {
+                fee.into_msg(config.fee_collector_addr.clone())?
+            }
+        }
+        AssetInfo::Token { contract_addr } => {
+            // the factory must have been given an allowance to spend the fee
+            let msg = if burn {
+                cw20::Cw20ExecuteMsg::BurnFrom {
+                    owner: info.sender.to_string(),
+                    amount: fee.amount,
+                }
+            } else {
+                cw20::Cw20ExecuteMsg::TransferFrom {
+                    owner: info.sender.to_string(),
+                    recipient: config.fee_collector_addr.to_string(),
+                    amount: fee.amount,
+                }
+            };
+
+            wasm_execute(contract_addr, &msg, vec![])?.into()
+        }
+    };
+
+    Ok((vec![message], funds))
+}
+
+/// Maps the fees of a trio to a [PoolFee], so they can be matched against the allowed fee tiers.
+fn trio_fee_tier(pool_fees: &TrioPoolFee) -> PoolFee {
+    PoolFee {
+        protocol_fee: pool_fees.protocol_fee.clone(),
+        swap_fee: pool_fees.swap_fee.clone(),
+        burn_fee: pool_fees.burn_fee.clone(),
+        #[cfg(feature = "osmosis")]
+        osmosis_fee: pool_fees.osmosis_fee.clone(),
+    }
+}
+
 pub fn remove_pair(
     deps: DepsMut,
     _env: Env,
     asset_infos: [AssetInfo; 2],
+    pool_identifier: Option<String>,
 ) -> Result<Response, ContractError> {
     let raw_infos = [
         asset_infos[0].to_raw(deps.api)?,
@@ -333,16 +516,21 @@ pub fn remove_pair(
     ];
 
     let pair_key = pair_key(&raw_infos);
-    let pair = PAIRS.may_load(deps.storage, &pair_key)?;
+    let pair = load_pair_pool(deps.storage, &pair_key, pool_identifier)?;
 
-    let Some(pair) = pair else {
+    let Some((pool_identifier, pair)) = pair else {
         return Err(ContractError::UnExistingPair {});
     };
 
-    PAIRS.remove(deps.storage, &pair_key);
+    PAIRS.remove(deps.storage, (&pair_key, &pool_identifier));
+    PAIR_POOL_FEES.remove(deps.storage, (&pair_key, &pool_identifier));
+    if DEFAULT_PAIR_POOLS.may_load(deps.storage, &pair_key)? == Some(pool_identifier.clone()) {
+        DEFAULT_PAIR_POOLS.remove(deps.storage, &pair_key);
+    }
 
     Ok(Response::new().add_attributes(vec![
         ("action", "remove_pair"),
+        ("pool_identifier", pool_identifier.as_str()),
         (
             "pair_contract_addr",
             deps.api.addr_humanize(&pair.contract_addr)?.as_ref(),
@@ -394,6 +582,69 @@ pub fn add_native_token_decimals(
     ]))
 }
 
+/// Registers the metadata of the given asset in the asset registry. The decimals of native tokens
+/// are added to the factory's whitelist as well, so it can create pairs with them.
+pub fn register_asset_metadata(
+    deps: DepsMut,
+    asset_info: AssetInfo,
+    metadata: AssetMetadata,
+) -> Result<Response, ContractError> {
+    if metadata.symbol.trim().is_empty() {
+        return Err(ContractError::EmptyAssetSymbol {});
+    }
+
+    if let AssetInfo::NativeToken { denom } = &asset_info {
+        add_allow_native_token(deps.storage, denom.clone(), metadata.decimals)?;
+    }
+
+    let raw_asset_info = asset_info.to_raw(deps.api)?;
+    ASSET_METADATA.save(
+        deps.storage,
+        raw_asset_info.as_bytes(),
+        &AssetMetadataInfo {
+            asset_info: asset_info.clone(),
+            metadata: metadata.clone(),
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "register_asset_metadata".to_string()),
+        ("asset_info", asset_info.to_string()),
+        ("symbol", metadata.symbol),
+        ("decimals", metadata.decimals.to_string()),
+    ]))
+}
+
+/// Removes the metadata of the given asset from the asset registry.
+pub fn remove_asset_metadata(
+    deps: DepsMut,
+    asset_info: AssetInfo,
+) -> Result<Response, ContractError> {
+    let raw_asset_info = asset_info.to_raw(deps.api)?;
+    if !ASSET_METADATA.has(deps.storage, raw_asset_info.as_bytes()) {
+        return Err(ContractError::UnknownAsset {
+            asset_info: asset_info.to_string(),
+        });
+    }
+
+    ASSET_METADATA.remove(deps.storage, raw_asset_info.as_bytes());
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "remove_asset_metadata".to_string()),
+        ("asset_info", asset_info.to_string()),
+    ]))
+}
+
+/// Gets the label of the given asset, used to name the pools created by the factory. The symbol in
+/// the asset registry is used if registered, otherwise it is derived from the asset itself.
+fn asset_label(deps: Deps, asset_info: &AssetInfo) -> StdResult<String> {
+    let raw_asset_info = asset_info.to_raw(deps.api)?;
+    match ASSET_METADATA.may_load(deps.storage, raw_asset_info.as_bytes())? {
+        Some(asset_metadata) => Ok(asset_metadata.metadata.symbol),
+        None => asset_info.clone().get_label(&deps),
+    }
+}
+
 /// Migrates a pair.
 pub fn execute_migrate_pair(
     deps: DepsMut,
@@ -445,3 +696,474 @@ pub fn execute_migrate_trio(
         })),
     )
 }
+
+/// Sets the swap fee discounts granted by the pairs, or removes them if none is given.
+pub fn update_fee_discounts(
+    deps: DepsMut,
+    fee_discounts: Option<FeeDiscounts>,
+) -> Result<Response, ContractError> {
+    let Some(mut fee_discounts) = fee_discounts else {
+        FEE_DISCOUNTS.remove(deps.storage);
+        return Ok(Response::new().add_attribute("action", "remove_fee_discounts"));
+    };
+
+    fee_discounts.whale_lair_addr = deps
+        .api
+        .addr_validate(&fee_discounts.whale_lair_addr)?
+        .to_string();
+    fee_discounts.trusted_routers = fee_discounts
+        .trusted_routers
+        .iter()
+        .map(|router| Ok(deps.api.addr_validate(router)?.to_string()))
+        .collect::<StdResult<_>>()?;
+
+    for discount in fee_discounts
+        .weight_tiers
+        .iter()
+        .map(|tier| tier.discount)
+        .chain(std::iter::once(fee_discounts.whitelist_discount))
+    {
+        if discount > Decimal::one() {
+            return Err(ContractError::InvalidFeeDiscount { discount });
+        }
+    }
+
+    FEE_DISCOUNTS.save(deps.storage, &fee_discounts)?;
+
+    Ok(Response::new().add_attribute("action", "update_fee_discounts"))
+}
+
+/// Adds and removes addresses from the fee discount whitelist.
+pub fn update_fee_discount_whitelist(
+    deps: DepsMut,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    for address in &add {
+        FEE_DISCOUNT_WHITELIST.save(deps.storage, &deps.api.addr_validate(address)?, &())?;
+    }
+
+    for address in &remove {
+        FEE_DISCOUNT_WHITELIST.remove(deps.storage, &deps.api.addr_validate(address)?);
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_fee_discount_whitelist".to_string()),
+        ("added", add.join(",")),
+        ("removed", remove.join(",")),
+    ]))
+}
+
+/// Migrates a page of the pairs created by the factory.
+pub fn migrate_pairs(
+    deps: DepsMut,
+    code_id: Option<u64>,
+    start_after: Option<[AssetInfo; 2]>,
+    start_after_pool_identifier: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    let pair_code_id = code_id.unwrap_or(config.pair_code_id);
+
+    let start_after = start_after
+        .map(|asset_infos| -> StdResult<[AssetInfoRaw; 2]> {
+            Ok([
+                asset_infos[0].to_raw(deps.api)?,
+                asset_infos[1].to_raw(deps.api)?,
+            ])
+        })
+        .transpose()?;
+    let pairs = read_pairs(
+        deps.storage,
+        deps.api,
+        start_after,
+        start_after_pool_identifier,
+        limit,
+    )?;
+
+    let messages = pairs
+        .iter()
+        .map(|(_, pair)| -> StdResult<CosmosMsg> {
+            Ok(CosmosMsg::Wasm(WasmMsg::Migrate {
+                contract_addr: pair.contract_addr.clone(),
+                new_code_id: pair_code_id,
+                msg: to_json_binary(&PairMigrateMsg {})?,
+            }))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        ("action", "migrate_pairs".to_string()),
+        ("code_id", pair_code_id.to_string()),
+        ("migrated_pairs", pairs.len().to_string()),
+    ]))
+}
+
+/// Updates the feature toggle of the pairs matching the given filter.
+pub fn update_pairs_feature_toggle(
+    deps: DepsMut,
+    feature_toggle: FeatureToggle,
+    filter: PairsFilter,
+) -> Result<Response, ContractError> {
+    let start_after = filter
+        .start_after
+        .as_ref()
+        .map(|asset_infos| -> StdResult<[AssetInfoRaw; 2]> {
+            Ok([
+                asset_infos[0].to_raw(deps.api)?,
+                asset_infos[1].to_raw(deps.api)?,
+            ])
+        })
+        .transpose()?;
+
+    let pairs: Vec<_> = read_pairs(
+        deps.storage,
+        deps.api,
+        start_after,
+        filter.start_after_pool_identifier.clone(),
+        filter.limit,
+    )?
+    .into_iter()
+    .map(|(_, pair)| pair)
+    .filter(|pair| filter.matches(pair))
+    .collect();
+
+    let mut messages = vec![];
+    for pair in pairs {
+        // updating the feature toggle unpauses the pair
+        let pair_addr = deps.api.addr_validate(&pair.contract_addr)?;
+        PAUSED_CONTRACTS.remove(deps.storage, &pair_addr);
+        PAUSED_BY_ALL.remove(deps.storage, &pair_addr);
+        messages.push(pair_feature_toggle_msg(
+            pair.contract_addr,
+            feature_toggle.clone(),
+        )?);
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            ("action", "update_pairs_feature_toggle".to_string()),
+            ("updated_pairs", messages.len().to_string()),
+        ])
+        .add_messages(messages))
+}
+
+/// Disables swaps, deposits and withdrawals on a page of the pairs and trios created by the
+/// factory. The feature toggle of each pool is kept so [unpause_all] can restore it, while pools
+/// paused on their own or with everything disabled already are skipped.
+pub fn pause_all(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    reason: String,
+    start_after: Option<PoolCursor>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    // pausing everything takes several pages, the first one sets the pause info
+    if !ALL_PAUSED.exists(deps.storage) {
+        ALL_PAUSED.save(
+            deps.storage,
+            &PauseInfo::new(info.sender.clone(), reason.clone(), &env),
+        )?;
+    }
+
+    let pools = read_pools(deps.storage, deps.api, start_after, limit)?;
+    let last_pool = pools.last().map(Pool::cursor);
+
+    let mut pair_messages = vec![];
+    let mut trio_messages = vec![];
+    for pool in pools {
+        let pool_addr = deps.api.addr_validate(pool.contract_addr())?;
+        if PAUSED_CONTRACTS.has(deps.storage, &pool_addr)
+            || PAUSED_BY_ALL.has(deps.storage, &pool_addr)
+        {
+            continue;
+        }
+
+        let feature_toggle = query_pool_feature_toggle(deps.as_ref(), &pool_addr)?;
+        if feature_toggle == all_features(false) {
+            continue;
+        }
+
+        match pool {
+            Pool::Pair(..) => {
+                PAUSED_BY_ALL.save(deps.storage, &pool_addr, &PausedPool::Pair(feature_toggle))?;
+                pair_messages.push(pair_feature_toggle_msg(
+                    pool_addr.to_string(),
+                    all_features(false),
+                )?);
+            }
+            Pool::Trio(..) => {
+                PAUSED_BY_ALL.save(deps.storage, &pool_addr, &PausedPool::Trio(feature_toggle))?;
+                trio_messages.push(trio_feature_toggle_msg(
+                    pool_addr.to_string(),
+                    all_features(false),
+                )?);
+            }
+        }
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            ("action", "pause_all".to_string()),
+            ("reason", reason),
+            ("paused_by", info.sender.to_string()),
+            ("pairs", pair_messages.len().to_string()),
+            ("trios", trio_messages.len().to_string()),
+        ])
+        .add_messages(pair_messages)
+        .add_messages(trio_messages)
+        .set_data(to_json_binary(&last_pool)?))
+}
+
+/// Restores the feature toggle of a page of the pairs and trios disabled by [pause_all]. Pools
+/// paused on their own in the meantime stay paused. Once every pool is restored, the factory is no
+/// longer paused.
+pub fn unpause_all(
+    deps: DepsMut,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let start_after = start_after
+        .map(|start_after| deps.api.addr_validate(&start_after))
+        .transpose()?;
+
+    let mut pair_messages = vec![];
+    let mut trio_messages = vec![];
+    for (pool_addr, paused_pool) in read_paused_by_all(deps.storage, start_after, limit)? {
+        PAUSED_BY_ALL.remove(deps.storage, &pool_addr);
+        if PAUSED_CONTRACTS.has(deps.storage, &pool_addr) {
+            continue;
+        }
+
+        match paused_pool {
+            PausedPool::Pair(feature_toggle) => pair_messages.push(pair_feature_toggle_msg(
+                pool_addr.to_string(),
+                feature_toggle,
+            )?),
+            PausedPool::Trio(feature_toggle) => trio_messages.push(trio_feature_toggle_msg(
+                pool_addr.to_string(),
+                feature_toggle,
+            )?),
+        }
+    }
+
+    if PAUSED_BY_ALL
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_none()
+    {
+        ALL_PAUSED.remove(deps.storage);
+    }
+
+    Ok(Response::new()
+        .add_attributes(vec![
+            ("action", "unpause_all".to_string()),
+            ("pairs", pair_messages.len().to_string()),
+            ("trios", trio_messages.len().to_string()),
+        ])
+        .add_messages(pair_messages)
+        .add_messages(trio_messages))
+}
+
+/// Disables swaps, deposits and withdrawals on the given pair.
+pub fn pause_pair(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pair_addr: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let pair_addr = deps.api.addr_validate(&pair_addr)?;
+
+    // only the pairs created by the factory can be paused
+    let pair_info = query_pair_info_from_pair(&deps.querier, pair_addr.clone())
+        .map_err(|_| ContractError::UnExistingPair {})?;
+    let pair_key = pair_key(&[
+        pair_info.asset_infos[0].to_raw(deps.api)?,
+        pair_info.asset_infos[1].to_raw(deps.api)?,
+    ]);
+    if find_pair_pool_by_addr(
+        deps.storage,
+        &pair_key,
+        &deps.api.addr_canonicalize(pair_addr.as_str())?,
+    )?
+    .is_none()
+    {
+        return Err(ContractError::UnExistingPair {});
+    }
+
+    PAUSED_CONTRACTS.save(
+        deps.storage,
+        &pair_addr,
+        &PauseInfo::new(info.sender.clone(), reason.clone(), &env),
+    )?;
+
+    Ok(Response::new()
+        .add_message(pair_feature_toggle_msg(
+            pair_addr.to_string(),
+            all_features(false),
+        )?)
+        .add_attributes(vec![
+            ("action", "pause_pair".to_string()),
+            ("pair_addr", pair_addr.to_string()),
+            ("reason", reason),
+            ("paused_by", info.sender.to_string()),
+        ]))
+}
+
+/// Disables swaps, deposits and withdrawals on the given trio.
+pub fn pause_trio(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    trio_addr: String,
+    reason: String,
+) -> Result<Response, ContractError> {
+    let trio_addr = deps.api.addr_validate(&trio_addr)?;
+
+    // only the trios created by the factory can be paused
+    let trio_info = query_trio_info_from_trio(&deps.querier, trio_addr.clone())
+        .map_err(|_| ContractError::NonExistantTrio {})?;
+    let trio_key = trio_key(&[
+        trio_info.asset_infos[0].to_raw(deps.api)?,
+        trio_info.asset_infos[1].to_raw(deps.api)?,
+        trio_info.asset_infos[2].to_raw(deps.api)?,
+    ]);
+    match TRIOS.may_load(deps.storage, &trio_key)? {
+        Some(trio) if trio.contract_addr == deps.api.addr_canonicalize(trio_addr.as_str())? => {}
+        _ => return Err(ContractError::NonExistantTrio {}),
+    }
+
+    PAUSED_CONTRACTS.save(
+        deps.storage,
+        &trio_addr,
+        &PauseInfo::new(info.sender.clone(), reason.clone(), &env),
+    )?;
+
+    Ok(Response::new()
+        .add_message(trio_feature_toggle_msg(
+            trio_addr.to_string(),
+            all_features(false),
+        )?)
+        .add_attributes(vec![
+            ("action", "pause_trio".to_string()),
+            ("trio_addr", trio_addr.to_string()),
+            ("reason", reason),
+            ("paused_by", info.sender.to_string()),
+        ]))
+}
+
+/// Sets the guardian of the factory, or removes it if none is given.
+pub fn update_guardian(deps: DepsMut, guardian: Option<String>) -> Result<Response, ContractError> {
+    let guardian = guardian
+        .map(|guardian| deps.api.addr_validate(&guardian))
+        .transpose()?;
+
+    match &guardian {
+        Some(guardian) => GUARDIAN.save(deps.storage, guardian)?,
+        None => GUARDIAN.remove(deps.storage),
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_guardian".to_string()),
+        (
+            "guardian",
+            guardian.map_or("None".to_string(), |guardian| guardian.to_string()),
+        ),
+    ]))
+}
+
+/// Sets the epoch manager the pairs and trios bucket their swap statistics by, or removes it if
+/// none is given.
+pub fn update_epoch_manager(
+    deps: DepsMut,
+    epoch_manager_addr: Option<String>,
+) -> Result<Response, ContractError> {
+    let epoch_manager_addr = epoch_manager_addr
+        .map(|epoch_manager_addr| deps.api.addr_validate(&epoch_manager_addr))
+        .transpose()?;
+
+    match &epoch_manager_addr {
+        Some(epoch_manager_addr) => EPOCH_MANAGER.save(deps.storage, epoch_manager_addr)?,
+        None => EPOCH_MANAGER.remove(deps.storage),
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_epoch_manager".to_string()),
+        (
+            "epoch_manager_addr",
+            epoch_manager_addr.map_or("None".to_string(), |addr| addr.to_string()),
+        ),
+    ]))
+}
+
+/// The part of the pair and trio configs needed to pause them. The rest is ignored, so the config
+/// of both can be read with it.
+#[derive(Deserialize)]
+struct PoolConfig {
+    feature_toggle: FeatureToggle,
+}
+
+/// Queries the feature toggle of a pair or trio. Both answer the same config query.
+fn query_pool_feature_toggle(deps: Deps, pool_addr: &Addr) -> StdResult<FeatureToggle> {
+    let config: PoolConfig = deps
+        .querier
+        .query_wasm_smart(pool_addr, &pair::QueryMsg::Config {})?;
+
+    Ok(config.feature_toggle)
+}
+
+/// A pair feature toggle enabling or disabling everything.
+fn all_features(enabled: bool) -> FeatureToggle {
+    FeatureToggle {
+        withdrawals_enabled: enabled,
+        deposits_enabled: enabled,
+        swaps_enabled: enabled,
+    }
+}
+
+/// Builds the message updating the feature toggle of a trio, leaving the rest of its config as is.
+fn trio_feature_toggle_msg(
+    trio_addr: String,
+    feature_toggle: FeatureToggle,
+) -> StdResult<CosmosMsg> {
+    Ok(wasm_execute(
+        trio_addr,
+        &trio::ExecuteMsg::UpdateConfig {
+            owner: None,
+            fee_collector_addr: None,
+            pool_fees: None,
+            feature_toggle: Some(TrioFeatureToggle {
+                withdrawals_enabled: feature_toggle.withdrawals_enabled,
+                deposits_enabled: feature_toggle.deposits_enabled,
+                swaps_enabled: feature_toggle.swaps_enabled,
+            }),
+            amp_factor: None,
+        },
+        vec![],
+    )?
+    .into())
+}
+
+/// Builds the message updating the feature toggle of a pair, leaving the rest of its config as is.
+fn pair_feature_toggle_msg(
+    pair_addr: String,
+    feature_toggle: FeatureToggle,
+) -> StdResult<CosmosMsg> {
+    Ok(wasm_execute(
+        pair_addr,
+        &pair::ExecuteMsg::UpdateConfig {
+            owner: None,
+            fee_collector_addr: None,
+            pool_fees: None,
+            feature_toggle: Some(feature_toggle),
+            amp_factor: None,
+            dynamic_fee: None,
+            #[cfg(feature = "osmosis")]
+            cosmwasm_pool_interface: None,
+        },
+        vec![],
+    )?
+    .into())
+}
@@ -16,7 +16,10 @@ use white_whale_std::pool_network::querier::{
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::response::MsgInstantiateContractResponse;
-use crate::state::{Config, CONFIG, PAIRS, TMP_PAIR_INFO, TMP_TRIO_INFO, TRIOS};
+use crate::state::{
+    Config, CONFIG, DEFAULT_PAIR_POOLS, GUARDIAN, PAIRS, PAIR_POOL_FEES, TMP_PAIR_INFO,
+    TMP_TRIO_INFO, TRIOS,
+};
 use crate::{commands, queries};
 
 // version info for migration info
@@ -41,6 +44,8 @@ pub fn instantiate(
         pair_code_id: msg.pair_code_id,
         trio_code_id: msg.trio_code_id,
         fee_collector_addr: deps.api.addr_validate(msg.fee_collector_addr.as_str())?,
+        pool_creation_fee: msg.pool_creation_fee,
+        allowed_fee_tiers: msg.allowed_fee_tiers.unwrap_or_default(),
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -55,10 +60,24 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Only the owner can execute messages on the factory
-    let config: Config = CONFIG.load(deps.storage)?;
-    if deps.api.addr_canonicalize(info.sender.as_str())? != config.owner {
-        return Err(ContractError::Unauthorized {});
+    // Anyone can create pools and the guardian can pause them, all the other messages can only be
+    // executed by the owner
+    if !matches!(
+        msg,
+        ExecuteMsg::CreatePair { .. } | ExecuteMsg::CreateTrio { .. }
+    ) {
+        let config: Config = CONFIG.load(deps.storage)?;
+        let is_owner = deps.api.addr_canonicalize(info.sender.as_str())? == config.owner;
+        let is_guardian = matches!(
+            msg,
+            ExecuteMsg::PauseAll { .. }
+                | ExecuteMsg::PausePair { .. }
+                | ExecuteMsg::PauseTrio { .. }
+        ) && GUARDIAN.may_load(deps.storage)?.as_ref() == Some(&info.sender);
+
+        if !is_owner && !is_guardian {
+            return Err(ContractError::Unauthorized {});
+        }
     }
 
     match msg {
@@ -68,6 +87,8 @@ pub fn execute(
             token_code_id,
             pair_code_id,
             trio_code_id,
+            pool_creation_fee,
+            allowed_fee_tiers,
         } => commands::update_config(
             deps,
             owner,
@@ -75,6 +96,8 @@ pub fn execute(
             token_code_id,
             pair_code_id,
             trio_code_id,
+            pool_creation_fee,
+            allowed_fee_tiers,
         ),
         ExecuteMsg::CreatePair {
             asset_infos,
@@ -104,7 +127,10 @@ pub fn execute(
             amp_factor,
             token_factory_lp,
         ),
-        ExecuteMsg::RemovePair { asset_infos } => commands::remove_pair(deps, env, asset_infos),
+        ExecuteMsg::RemovePair {
+            asset_infos,
+            pool_identifier,
+        } => commands::remove_pair(deps, env, asset_infos, pool_identifier),
         ExecuteMsg::RemoveTrio { asset_infos } => commands::remove_trio(deps, env, asset_infos),
         ExecuteMsg::AddNativeTokenDecimals { denom, decimals } => {
             commands::add_native_token_decimals(deps, denom, decimals)
@@ -115,6 +141,53 @@ pub fn execute(
         ExecuteMsg::MigrateTrio { contract, code_id } => {
             commands::execute_migrate_trio(deps, contract, code_id)
         }
+        ExecuteMsg::UpdateFeeDiscounts { fee_discounts } => {
+            commands::update_fee_discounts(deps, fee_discounts)
+        }
+        ExecuteMsg::UpdateFeeDiscountWhitelist { add, remove } => {
+            commands::update_fee_discount_whitelist(deps, add, remove)
+        }
+        ExecuteMsg::MigratePairs {
+            code_id,
+            start_after,
+            start_after_pool_identifier,
+            limit,
+        } => commands::migrate_pairs(
+            deps,
+            code_id,
+            start_after,
+            start_after_pool_identifier,
+            limit,
+        ),
+        ExecuteMsg::UpdatePairsFeatureToggle {
+            feature_toggle,
+            filter,
+        } => commands::update_pairs_feature_toggle(deps, feature_toggle, filter),
+        ExecuteMsg::PauseAll {
+            reason,
+            start_after,
+            limit,
+        } => commands::pause_all(deps, env, info, reason, start_after, limit),
+        ExecuteMsg::UnpauseAll { start_after, limit } => {
+            commands::unpause_all(deps, start_after, limit)
+        }
+        ExecuteMsg::PausePair { pair_addr, reason } => {
+            commands::pause_pair(deps, env, info, pair_addr, reason)
+        }
+        ExecuteMsg::PauseTrio { trio_addr, reason } => {
+            commands::pause_trio(deps, env, info, trio_addr, reason)
+        }
+        ExecuteMsg::UpdateGuardian { guardian } => commands::update_guardian(deps, guardian),
+        ExecuteMsg::RegisterAssetMetadata {
+            asset_info,
+            metadata,
+        } => commands::register_asset_metadata(deps, asset_info, metadata),
+        ExecuteMsg::RemoveAssetMetadata { asset_info } => {
+            commands::remove_asset_metadata(deps, asset_info)
+        }
+        ExecuteMsg::UpdateEpochManager { epoch_manager_addr } => {
+            commands::update_epoch_manager(deps, epoch_manager_addr)
+        }
         #[cfg(feature = "osmosis")]
         ExecuteMsg::UpdatePairConfig {
             pair_addr,
@@ -122,6 +195,8 @@ pub fn execute(
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            amp_factor,
+            dynamic_fee,
             cosmwasm_pool_interface,
         } => commands::update_pair_config(
             deps,
@@ -130,6 +205,8 @@ pub fn execute(
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            amp_factor,
+            dynamic_fee,
             cosmwasm_pool_interface,
         ),
         #[cfg(not(feature = "osmosis"))]
@@ -139,6 +216,8 @@ pub fn execute(
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            amp_factor,
+            dynamic_fee,
         } => commands::update_pair_config(
             deps,
             pair_addr,
@@ -146,6 +225,8 @@ pub fn execute(
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            amp_factor,
+            dynamic_fee,
             None,
         ),
         ExecuteMsg::UpdateTrioConfig {
@@ -192,7 +273,7 @@ fn create_pair_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractErro
 
     PAIRS.save(
         deps.storage,
-        &tmp_pair_info.pair_key,
+        (&tmp_pair_info.pair_key, &tmp_pair_info.pool_identifier),
         &PairInfoRaw {
             liquidity_token: pair_info.liquidity_token.to_raw(deps.api)?,
             contract_addr: deps.api.addr_canonicalize(pair_contract.as_str())?,
@@ -201,6 +282,20 @@ fn create_pair_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractErro
             pair_type: tmp_pair_info.pair_type,
         },
     )?;
+    PAIR_POOL_FEES.save(
+        deps.storage,
+        (&tmp_pair_info.pair_key, &tmp_pair_info.pool_identifier),
+        &tmp_pair_info.pool_fees,
+    )?;
+
+    // the first pool created for an asset couple is its default pool
+    if !DEFAULT_PAIR_POOLS.has(deps.storage, &tmp_pair_info.pair_key) {
+        DEFAULT_PAIR_POOLS.save(
+            deps.storage,
+            &tmp_pair_info.pair_key,
+            &tmp_pair_info.pool_identifier,
+        )?;
+    }
 
     Ok(Response::new().add_attributes(vec![
         ("pair_contract_addr", pair_contract.as_str()),
@@ -243,12 +338,25 @@ fn create_trio_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractErro
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&queries::query_config(deps)?),
-        QueryMsg::Pair { asset_infos } => to_json_binary(&queries::query_pair(deps, asset_infos)?),
-        QueryMsg::Pairs { start_after, limit } => {
-            to_json_binary(&queries::query_pairs(deps, start_after, limit)?)
+        QueryMsg::Pair {
+            asset_infos,
+            pool_identifier,
+        } => to_json_binary(&queries::query_pair(deps, asset_infos, pool_identifier)?),
+        QueryMsg::Pairs {
+            start_after,
+            start_after_pool_identifier,
+            limit,
+        } => to_json_binary(&queries::query_pairs(
+            deps,
+            start_after,
+            start_after_pool_identifier,
+            limit,
+        )?),
+        QueryMsg::PairsByAssets { asset_infos } => {
+            to_json_binary(&queries::query_pairs_by_assets(deps, asset_infos)?)
         }
         QueryMsg::Trio { asset_infos } => to_json_binary(&queries::query_trio(deps, asset_infos)?),
         QueryMsg::Trios { start_after, limit } => {
@@ -257,6 +365,45 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::NativeTokenDecimals { denom } => {
             to_json_binary(&queries::query_native_token_decimal(deps, denom)?)
         }
+        QueryMsg::FeeDiscounts {} => to_json_binary(&queries::query_fee_discounts(deps)?),
+        QueryMsg::FeeDiscountWhitelist { start_after, limit } => to_json_binary(
+            &queries::query_fee_discount_whitelist(deps, start_after, limit)?,
+        ),
+        QueryMsg::FeeDiscount { address, trader } => {
+            to_json_binary(&queries::query_fee_discount(deps, env, address, trader)?)
+        }
+        QueryMsg::PauseStatus { contract } => {
+            to_json_binary(&queries::query_pause_status(deps, contract)?)
+        }
+        QueryMsg::AssetMetadata { asset_info } => {
+            to_json_binary(&queries::query_asset_metadata(deps, asset_info)?)
+        }
+        QueryMsg::AssetsMetadata { start_after, limit } => {
+            to_json_binary(&queries::query_assets_metadata(deps, start_after, limit)?)
+        }
+        QueryMsg::CurrentEpoch {} => to_json_binary(&queries::query_current_epoch(deps, env)?),
+        QueryMsg::PairsStats {
+            start_after,
+            start_after_pool_identifier,
+            limit,
+            epoch_id,
+        } => to_json_binary(&queries::query_pairs_stats(
+            deps,
+            start_after,
+            start_after_pool_identifier,
+            limit,
+            epoch_id,
+        )?),
+        QueryMsg::TriosStats {
+            start_after,
+            limit,
+            epoch_id,
+        } => to_json_binary(&queries::query_trios_stats(
+            deps,
+            start_after,
+            limit,
+            epoch_id,
+        )?),
     }
 }
 
@@ -287,6 +434,12 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
     if storage_version < Version::parse("1.2.0")? {
         migrations::migrate_to_v120(deps.branch())?;
     }
+    if storage_version < Version::parse("1.3.0")? {
+        migrations::migrate_to_v130(deps.branch())?;
+    }
+    if storage_version < Version::parse("1.4.0")? {
+        migrations::migrate_to_v140(deps.branch())?;
+    }
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
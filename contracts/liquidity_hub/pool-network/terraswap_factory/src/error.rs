@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Decimal, StdError, Uint128};
 use semver::Version;
 use thiserror::Error;
 
@@ -40,8 +40,29 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("The pool creation fee was not paid. Paid {paid_amount}, required {required_amount}")]
+    PoolCreationFeeNotPaid {
+        paid_amount: Uint128,
+        required_amount: Uint128,
+    },
+
+    #[error("The pool fees don't match any of the allowed fee tiers")]
+    FeeTierNotAllowed {},
+
+    #[error("Only the owner can create pools until a pool creation fee and fee tiers are set")]
+    PermissionlessPoolCreationDisabled {},
+
+    #[error("Invalid fee discount {discount}, fee discounts can't be higher than 1")]
+    InvalidFeeDiscount { discount: Decimal },
+
     #[error("Attempt to migrate a pool to a different type. Make sure you are using the write Migrate message, e.g. MigratePair or MigrateTrio for pairs and trios respectively.")]
     MigratingWrongPool {},
+
+    #[error("The symbol of an asset can't be empty")]
+    EmptyAssetSymbol {},
+
+    #[error("The asset {asset_info} is not in the asset registry")]
+    UnknownAsset { asset_info: String },
 }
 
 impl From<semver::Error> for ContractError {
@@ -1,10 +1,10 @@
-use crate::state::Config;
+use crate::state::{Config, CONFIG, DEFAULT_PAIR_POOLS, PAIRS, TMP_PAIR_INFO};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, CanonicalAddr, DepsMut, Order, StdError, Uint128};
 use cw_storage_plus::{Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use white_whale_std::pool_network::asset::{AssetInfo, AssetInfoRaw, PairType};
+use white_whale_std::pool_network::asset::{AssetInfo, AssetInfoRaw, PairInfoRaw, PairType};
 
 #[cfg(not(feature = "osmosis"))]
 /// Migrate state of the factory from PascalCase to snake_case for the following items:
@@ -133,14 +133,23 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
         pub token_code_id: u64,
     }
 
+    #[cw_serde]
+    pub struct NewConfig {
+        pub owner: CanonicalAddr,
+        pub fee_collector_addr: Addr,
+        pub pair_code_id: u64,
+        pub trio_code_id: u64,
+        pub token_code_id: u64,
+    }
+
     const OLD_CONFIG: Item<OldConfig> = Item::new("config");
-    const NEW_CONFIG: Item<Config> = Item::new("config");
+    const NEW_CONFIG: Item<NewConfig> = Item::new("config");
 
     let old_config = OLD_CONFIG.may_load(deps.storage)?;
     if let Some(old_config) = old_config {
         NEW_CONFIG.save(
             deps.storage,
-            &Config {
+            &NewConfig {
                 owner: old_config.owner,
                 fee_collector_addr: old_config.fee_collector_addr,
                 pair_code_id: old_config.pair_code_id,
@@ -197,3 +206,62 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
 
     Ok(())
 }
+
+/// Migrates the config of the factory to include the pool creation fee and the allowed fee tiers,
+/// introduced with permissionless pool creation. Pool creation remains owner-only until the owner
+/// sets both of them.
+pub fn migrate_to_v130(deps: DepsMut) -> Result<(), StdError> {
+    #[cw_serde]
+    pub struct OldConfig {
+        pub owner: CanonicalAddr,
+        pub fee_collector_addr: Addr,
+        pub pair_code_id: u64,
+        pub trio_code_id: u64,
+        pub token_code_id: u64,
+    }
+
+    const OLD_CONFIG: Item<OldConfig> = Item::new("config");
+
+    let old_config = OLD_CONFIG.load(deps.storage)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: old_config.owner,
+            fee_collector_addr: old_config.fee_collector_addr,
+            pair_code_id: old_config.pair_code_id,
+            trio_code_id: old_config.trio_code_id,
+            token_code_id: old_config.token_code_id,
+            pool_creation_fee: None,
+            allowed_fee_tiers: vec![],
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Migrates the pairs of the factory so multiple pools can exist for the same asset couple. Pairs
+/// were keyed by their asset couple only, now they are also keyed by a pool identifier. Existing
+/// pairs get their pair type as identifier, which can't clash with the numeric identifiers of the
+/// pools created from now on, and become the default pool of their asset couple. Their fees are
+/// only tracked once they are updated through the factory, as reading them would require querying
+/// every pair.
+pub fn migrate_to_v140(deps: DepsMut) -> Result<(), StdError> {
+    const OLD_PAIRS: Map<&[u8], PairInfoRaw> = Map::new("pair_info");
+
+    let old_pairs = OLD_PAIRS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (pair_key, pair) in old_pairs {
+        let pool_identifier = pair.pair_type.get_label().to_string();
+
+        OLD_PAIRS.remove(deps.storage, &pair_key);
+        PAIRS.save(deps.storage, (&pair_key, &pool_identifier), &pair)?;
+        DEFAULT_PAIR_POOLS.save(deps.storage, &pair_key, &pool_identifier)?;
+    }
+
+    // the temporary pair info only lives within a pair creation, and its shape changed
+    TMP_PAIR_INFO.remove(deps.storage);
+
+    Ok(())
+}
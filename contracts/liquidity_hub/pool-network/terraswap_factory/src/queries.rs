@@ -1,13 +1,29 @@
 use crate::state::{
-    pair_key, read_pairs, read_trios, trio_key, Config, ALLOW_NATIVE_TOKENS, CONFIG, PAIRS, TRIOS,
+    load_pair_pool, pair_key, read_assets_metadata, read_fee_discount_whitelist, read_pairs,
+    read_trios, trio_key, Config, ALLOW_NATIVE_TOKENS, ALL_PAUSED, ASSET_METADATA, CONFIG,
+    DEFAULT_PAIR_POOLS, EPOCH_MANAGER, FEE_DISCOUNTS, FEE_DISCOUNT_WHITELIST, GUARDIAN, PAIRS,
+    PAIR_POOL_FEES, PAUSED_CONTRACTS, TRIOS,
 };
-use cosmwasm_std::{Deps, StdResult};
-use white_whale_std::pool_network::asset::{
-    AssetInfo, PairInfo, PairInfoRaw, TrioInfo, TrioInfoRaw,
+use cosmwasm_std::{Decimal, Deps, Env, Order, StdError, StdResult, Uint128};
+use white_whale_std::epoch_manager::epoch_manager::{
+    ConfigResponse as EpochManagerConfigResponse, EpochResponse, QueryMsg as EpochManagerQueryMsg,
 };
+use white_whale_std::pause::PauseStatusResponse;
+use white_whale_std::pool_network::asset::{AssetInfo, PairInfo, TrioInfo, TrioInfoRaw};
 use white_whale_std::pool_network::factory::{
-    ConfigResponse, NativeTokenDecimalsResponse, PairsResponse, TriosResponse,
+    AssetMetadata, AssetsMetadataResponse, ConfigResponse, CurrentEpochResponse,
+    FeeDiscountResponse, FeeDiscountWhitelistResponse, FeeDiscounts, NativeTokenDecimalsResponse,
+    PairStatsInfo, PairsByAssetsResponse, PairsResponse, PairsStatsResponse, PoolInfo,
+    TrioStatsInfo, TriosResponse, TriosStatsResponse,
 };
+use white_whale_std::pool_network::pair::{
+    PoolResponse as PairPoolResponse, QueryMsg as PairQueryMsg,
+};
+use white_whale_std::pool_network::pool_stats::PoolStats;
+use white_whale_std::pool_network::trio::{
+    PoolResponse as TrioPoolResponse, QueryMsg as TrioQueryMsg,
+};
+use white_whale_std::whale_lair::{BondingWeightResponse, QueryMsg as WhaleLairQueryMsg};
 
 /// Queries [Config]
 pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
@@ -19,23 +35,60 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         pair_code_id: config.pair_code_id,
         trio_code_id: config.trio_code_id,
         fee_collector_addr: config.fee_collector_addr.to_string(),
+        pool_creation_fee: config.pool_creation_fee,
+        allowed_fee_tiers: config.allowed_fee_tiers,
     })
 }
 
 /// Queries info about a given Pair
-pub fn query_pair(deps: Deps, asset_infos: [AssetInfo; 2]) -> StdResult<PairInfo> {
+pub fn query_pair(
+    deps: Deps,
+    asset_infos: [AssetInfo; 2],
+    pool_identifier: Option<String>,
+) -> StdResult<PairInfo> {
     let pair_key = pair_key(&[
         asset_infos[0].to_raw(deps.api)?,
         asset_infos[1].to_raw(deps.api)?,
     ]);
-    let pair_info: PairInfoRaw = PAIRS.load(deps.storage, &pair_key)?;
+    let (_, pair_info) = load_pair_pool(deps.storage, &pair_key, pool_identifier)?
+        .ok_or_else(|| StdError::not_found("PairInfoRaw"))?;
     pair_info.to_normal(deps.api)
 }
 
+/// Queries all the pools for a given asset couple
+pub fn query_pairs_by_assets(
+    deps: Deps,
+    asset_infos: [AssetInfo; 2],
+) -> StdResult<PairsByAssetsResponse> {
+    let pair_key = pair_key(&[
+        asset_infos[0].to_raw(deps.api)?,
+        asset_infos[1].to_raw(deps.api)?,
+    ]);
+
+    let pairs = PAIRS
+        .prefix(&pair_key)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (pool_identifier, pair_info) = item?;
+            Ok(PoolInfo {
+                pool_fees: PAIR_POOL_FEES.may_load(deps.storage, (&pair_key, &pool_identifier))?,
+                pool_identifier,
+                pair_info: pair_info.to_normal(deps.api)?,
+            })
+        })
+        .collect::<StdResult<Vec<PoolInfo>>>()?;
+
+    Ok(PairsByAssetsResponse {
+        pairs,
+        default_pool_identifier: DEFAULT_PAIR_POOLS.may_load(deps.storage, &pair_key)?,
+    })
+}
+
 /// Queries all the pairs created by the factory
 pub fn query_pairs(
     deps: Deps,
     start_after: Option<[AssetInfo; 2]>,
+    start_after_pool_identifier: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<PairsResponse> {
     let start_after = if let Some(start_after) = start_after {
@@ -47,8 +100,21 @@ pub fn query_pairs(
         None
     };
 
-    let pairs: Vec<PairInfo> = read_pairs(deps.storage, deps.api, start_after, limit)?;
-    let resp = PairsResponse { pairs };
+    let pools = read_pairs(
+        deps.storage,
+        deps.api,
+        start_after,
+        start_after_pool_identifier,
+        limit,
+    )?;
+    let last_pool_identifier = pools
+        .last()
+        .map(|(pool_identifier, _)| pool_identifier.clone());
+    let pairs: Vec<PairInfo> = pools.into_iter().map(|(_, pair)| pair).collect();
+    let resp = PairsResponse {
+        pairs,
+        last_pool_identifier,
+    };
 
     Ok(resp)
 }
@@ -95,3 +161,236 @@ pub fn query_native_token_decimal(
 
     Ok(NativeTokenDecimalsResponse { decimals })
 }
+
+/// Queries the swap fee discounts granted by the pairs
+pub fn query_fee_discounts(deps: Deps) -> StdResult<Option<FeeDiscounts>> {
+    FEE_DISCOUNTS.may_load(deps.storage)
+}
+
+/// Queries the addresses in the fee discount whitelist
+pub fn query_fee_discount_whitelist(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<FeeDiscountWhitelistResponse> {
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    let addresses = read_fee_discount_whitelist(deps.storage, start_after, limit)?
+        .into_iter()
+        .map(|address| address.to_string())
+        .collect();
+
+    Ok(FeeDiscountWhitelistResponse { addresses })
+}
+
+/// Queries the swap fee discount granted to the given address. Whitelisted addresses get the
+/// whitelist discount, otherwise the discount is given by the address' bonding weight in the whale
+/// lair. If the address is a trusted router, the discount of the given trader is granted instead.
+pub fn query_fee_discount(
+    deps: Deps,
+    env: Env,
+    address: String,
+    trader: Option<String>,
+) -> StdResult<FeeDiscountResponse> {
+    let no_discount = FeeDiscountResponse {
+        discount: Decimal::zero(),
+    };
+
+    let Some(fee_discounts) = FEE_DISCOUNTS.may_load(deps.storage)? else {
+        return Ok(no_discount);
+    };
+
+    let address = match trader {
+        Some(trader) if fee_discounts.trusted_routers.contains(&address) => {
+            deps.api.addr_validate(&trader)?
+        }
+        _ => deps.api.addr_validate(&address)?,
+    };
+    if FEE_DISCOUNT_WHITELIST.has(deps.storage, &address) {
+        return Ok(FeeDiscountResponse {
+            discount: fee_discounts.whitelist_discount,
+        });
+    }
+
+    if fee_discounts.weight_tiers.is_empty() {
+        return Ok(no_discount);
+    }
+
+    let weight: BondingWeightResponse = deps.querier.query_wasm_smart(
+        fee_discounts.whale_lair_addr,
+        &WhaleLairQueryMsg::Weight {
+            address: address.to_string(),
+            timestamp: Some(env.block.time),
+            global_index: None,
+        },
+    )?;
+
+    let discount = fee_discounts
+        .weight_tiers
+        .into_iter()
+        .filter(|tier| weight.weight >= tier.min_weight)
+        .max_by_key(|tier| tier.min_weight)
+        .map_or(Decimal::zero(), |tier| tier.discount);
+
+    Ok(FeeDiscountResponse { discount })
+}
+
+/// Queries the guardian and whether pairs and trios were paused through the factory.
+pub fn query_pause_status(deps: Deps, contract: Option<String>) -> StdResult<PauseStatusResponse> {
+    let contract_paused = contract
+        .map(|contract| -> StdResult<_> {
+            PAUSED_CONTRACTS.may_load(deps.storage, &deps.api.addr_validate(&contract)?)
+        })
+        .transpose()?
+        .flatten();
+
+    Ok(PauseStatusResponse {
+        guardian: GUARDIAN.may_load(deps.storage)?,
+        all_paused: ALL_PAUSED.may_load(deps.storage)?,
+        contract_paused,
+    })
+}
+
+/// Queries the metadata registered for the given asset, if any.
+pub fn query_asset_metadata(deps: Deps, asset_info: AssetInfo) -> StdResult<Option<AssetMetadata>> {
+    let raw_asset_info = asset_info.to_raw(deps.api)?;
+
+    Ok(ASSET_METADATA
+        .may_load(deps.storage, raw_asset_info.as_bytes())?
+        .map(|asset_metadata| asset_metadata.metadata))
+}
+
+/// Queries the metadata of the assets in the asset registry, paginated.
+pub fn query_assets_metadata(
+    deps: Deps,
+    start_after: Option<AssetInfo>,
+    limit: Option<u32>,
+) -> StdResult<AssetsMetadataResponse> {
+    let start_after = start_after
+        .map(|asset_info| asset_info.to_raw(deps.api))
+        .transpose()?;
+
+    Ok(AssetsMetadataResponse {
+        assets: read_assets_metadata(deps.storage, start_after, limit)?,
+    })
+}
+
+/// Queries the epoch the current block falls in from the epoch manager, if any is set.
+pub fn query_current_epoch(deps: Deps, env: Env) -> StdResult<CurrentEpochResponse> {
+    let Some(epoch_manager_addr) = EPOCH_MANAGER.may_load(deps.storage)? else {
+        return Ok(CurrentEpochResponse {
+            epoch_id: None,
+            end_time: None,
+        });
+    };
+
+    let epoch_response: EpochResponse = deps.querier.query_wasm_smart(
+        &epoch_manager_addr,
+        &EpochManagerQueryMsg::EpochAt {
+            timestamp: env.block.time,
+        },
+    )?;
+    let epoch_manager_config: EpochManagerConfigResponse = deps
+        .querier
+        .query_wasm_smart(&epoch_manager_addr, &EpochManagerQueryMsg::Config {})?;
+
+    Ok(CurrentEpochResponse {
+        epoch_id: Some(epoch_response.epoch.id),
+        end_time: Some(
+            epoch_response
+                .epoch
+                .start_time
+                .plus_nanos(epoch_manager_config.epoch_config.duration.u64()),
+        ),
+    })
+}
+
+/// Queries the reserves, total share and swap statistics of a page of the pairs.
+pub fn query_pairs_stats(
+    deps: Deps,
+    start_after: Option<[AssetInfo; 2]>,
+    start_after_pool_identifier: Option<String>,
+    limit: Option<u32>,
+    epoch_id: Option<u64>,
+) -> StdResult<PairsStatsResponse> {
+    let PairsResponse {
+        pairs,
+        last_pool_identifier,
+    } = query_pairs(deps, start_after, start_after_pool_identifier, limit)?;
+
+    let pairs = pairs
+        .into_iter()
+        .map(|pair_info| {
+            let pool: StdResult<PairPoolResponse> = deps
+                .querier
+                .query_wasm_smart(&pair_info.contract_addr, &PairQueryMsg::Pool {});
+            let stats: StdResult<PoolStats> = deps.querier.query_wasm_smart(
+                &pair_info.contract_addr,
+                &PairQueryMsg::PoolStats { epoch_id },
+            );
+
+            // a pair failing to answer doesn't fail the whole page, it's returned empty instead
+            match (pool, stats) {
+                (Ok(pool), Ok(stats)) => PairStatsInfo {
+                    pair_info,
+                    assets: pool.assets,
+                    total_share: pool.total_share,
+                    stats,
+                },
+                _ => PairStatsInfo {
+                    pair_info,
+                    assets: vec![],
+                    total_share: Uint128::zero(),
+                    stats: PoolStats::default(),
+                },
+            }
+        })
+        .collect();
+
+    Ok(PairsStatsResponse {
+        pairs,
+        last_pool_identifier,
+    })
+}
+
+/// Queries the reserves, total share and swap statistics of a page of the trios.
+pub fn query_trios_stats(
+    deps: Deps,
+    start_after: Option<[AssetInfo; 3]>,
+    limit: Option<u32>,
+    epoch_id: Option<u64>,
+) -> StdResult<TriosStatsResponse> {
+    let trios = query_trios(deps, start_after, limit)?
+        .trios
+        .into_iter()
+        .map(|trio_info| {
+            let pool: StdResult<TrioPoolResponse> = deps
+                .querier
+                .query_wasm_smart(&trio_info.contract_addr, &TrioQueryMsg::Pool {});
+            let stats: StdResult<PoolStats> = deps.querier.query_wasm_smart(
+                &trio_info.contract_addr,
+                &TrioQueryMsg::PoolStats { epoch_id },
+            );
+
+            // a trio failing to answer doesn't fail the whole page, it's returned empty instead
+            match (pool, stats) {
+                (Ok(pool), Ok(stats)) => TrioStatsInfo {
+                    trio_info,
+                    assets: pool.assets,
+                    total_share: pool.total_share,
+                    stats,
+                },
+                _ => TrioStatsInfo {
+                    trio_info,
+                    assets: vec![],
+                    total_share: Uint128::zero(),
+                    stats: PoolStats::default(),
+                },
+            }
+        })
+        .collect();
+
+    Ok(TriosStatsResponse { trios })
+}
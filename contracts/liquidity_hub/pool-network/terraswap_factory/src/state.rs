@@ -1,9 +1,14 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Api, CanonicalAddr, Order, StdResult, Storage};
-use cw_storage_plus::{Bound, Item, Map};
+use cw_storage_plus::{Bound, Item, Map, PrefixBound};
+use white_whale_std::pause::PauseInfo;
 use white_whale_std::pool_network::asset::{
     AssetInfoRaw, PairInfo, PairInfoRaw, PairType, TrioInfo, TrioInfoRaw,
 };
+use white_whale_std::pool_network::factory::{
+    AssetMetadataInfo, FeeDiscounts, PoolCreationFee, PoolCursor,
+};
+use white_whale_std::pool_network::pair::{FeatureToggle, PoolFee};
 
 #[cw_serde]
 pub struct Config {
@@ -12,6 +17,8 @@ pub struct Config {
     pub pair_code_id: u64,
     pub trio_code_id: u64,
     pub token_code_id: u64,
+    pub pool_creation_fee: Option<PoolCreationFee>,
+    pub allowed_fee_tiers: Vec<PoolFee>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -19,13 +26,61 @@ pub const CONFIG: Item<Config> = Item::new("config");
 #[cw_serde]
 pub struct TmpPairInfo {
     pub pair_key: Vec<u8>,
+    pub pool_identifier: String,
     pub asset_infos: [AssetInfoRaw; 2],
     pub asset_decimals: [u8; 2],
     pub pair_type: PairType,
+    pub pool_fees: PoolFee,
 }
 
 pub const TMP_PAIR_INFO: Item<TmpPairInfo> = Item::new("tmp_pair_info");
-pub const PAIRS: Map<&[u8], PairInfoRaw> = Map::new("pair_info");
+/// The pair pools created by the factory, keyed by their asset couple (see [pair_key]) and their
+/// pool identifier within it, so multiple pools can exist for the same assets.
+pub const PAIRS: Map<(&[u8], &str), PairInfoRaw> = Map::new("pair_pools");
+/// The identifier of the default pool of each asset couple, i.e. the pool picked when no pool
+/// identifier is given. It is the first pool created for the asset couple, and is cleared when
+/// that pool is removed.
+pub const DEFAULT_PAIR_POOLS: Map<&[u8], String> = Map::new("default_pair_pools");
+/// The fees of the pair pools, keyed as [PAIRS]. Kept apart from the pool identifier, so updating
+/// the fees of a pool doesn't change how it is looked up.
+pub const PAIR_POOL_FEES: Map<(&[u8], &str), PoolFee> = Map::new("pair_pool_fees");
+/// The amount of pair pools created by the factory, used to assign the pool identifiers.
+pub const POOL_COUNTER: Item<u64> = Item::new("pool_counter");
+
+/// Assigns the identifier of a new pair pool. Identifiers are never reused, so they stay valid for
+/// as long as the pool exists.
+pub fn next_pool_identifier(storage: &mut dyn Storage) -> StdResult<String> {
+    let counter = POOL_COUNTER.may_load(storage)?.unwrap_or_default() + 1;
+    POOL_COUNTER.save(storage, &counter)?;
+
+    Ok(counter.to_string())
+}
+
+/// Returns whether the asset couple with the given key already has a pool with the given type and
+/// fees.
+pub fn has_pair_pool(
+    storage: &dyn Storage,
+    pair_key: &[u8],
+    pair_type: &PairType,
+    pool_fees: &PoolFee,
+) -> StdResult<bool> {
+    for item in PAIRS
+        .prefix(pair_key)
+        .range(storage, None, None, Order::Ascending)
+    {
+        let (pool_identifier, pair) = item?;
+        if &pair.pair_type == pair_type
+            && PAIR_POOL_FEES
+                .may_load(storage, (pair_key, &pool_identifier))?
+                .as_ref()
+                == Some(pool_fees)
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
 
 pub fn pair_key(asset_infos: &[AssetInfoRaw; 2]) -> Vec<u8> {
     let mut asset_infos = asset_infos.to_vec();
@@ -56,41 +111,87 @@ pub fn trio_key(asset_infos: &[AssetInfoRaw; 3]) -> Vec<u8> {
     .concat()
 }
 
+/// Loads the pool with the given identifier for the asset couple with the given key. If no
+/// identifier is given, the default pool of the asset couple is loaded, see [DEFAULT_PAIR_POOLS].
+pub fn load_pair_pool(
+    storage: &dyn Storage,
+    pair_key: &[u8],
+    pool_identifier: Option<String>,
+) -> StdResult<Option<(String, PairInfoRaw)>> {
+    let pool_identifier = match pool_identifier {
+        Some(pool_identifier) => pool_identifier,
+        None => match DEFAULT_PAIR_POOLS.may_load(storage, pair_key)? {
+            Some(pool_identifier) => pool_identifier,
+            None => return Ok(None),
+        },
+    };
+
+    Ok(PAIRS
+        .may_load(storage, (pair_key, &pool_identifier))?
+        .map(|pair| (pool_identifier, pair)))
+}
+
+/// Finds the pool with the given contract address among the pools of the asset couple with the
+/// given key.
+pub fn find_pair_pool_by_addr(
+    storage: &dyn Storage,
+    pair_key: &[u8],
+    contract_addr: &CanonicalAddr,
+) -> StdResult<Option<(String, PairInfoRaw)>> {
+    for item in PAIRS
+        .prefix(pair_key)
+        .range(storage, None, None, Order::Ascending)
+    {
+        let (pool_identifier, pair) = item?;
+        if &pair.contract_addr == contract_addr {
+            return Ok(Some((pool_identifier, pair)));
+        }
+    }
+
+    Ok(None)
+}
+
 // settings for pagination
 const MAX_LIMIT: u32 = 30;
 const DEFAULT_LIMIT: u32 = 10;
+/// Reads the pairs created by the factory, paginated. Pools are sorted by asset couple and then by
+/// pool identifier, so a page can be resumed from the asset couple and pool identifier of its last
+/// item. If only the asset couple is given, the page starts after all of its pools.
 pub fn read_pairs(
     storage: &dyn Storage,
     api: &dyn Api,
     start_after: Option<[AssetInfoRaw; 2]>,
+    start_after_pool_identifier: Option<String>,
     limit: Option<u32>,
-) -> StdResult<Vec<PairInfo>> {
+) -> StdResult<Vec<(String, PairInfo)>> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
-    let start = calc_range_start(start_after).map(Bound::ExclusiveRaw);
+    let start = start_after.map(|asset_infos| pair_key(&asset_infos));
 
-    PAIRS
-        .range(storage, start, None, Order::Ascending)
+    let pairs = match (&start, &start_after_pool_identifier) {
+        (Some(pair_key), Some(pool_identifier)) => PAIRS.range(
+            storage,
+            Some(Bound::exclusive((
+                pair_key.as_slice(),
+                pool_identifier.as_str(),
+            ))),
+            None,
+            Order::Ascending,
+        ),
+        _ => PAIRS.prefix_range(
+            storage,
+            start.as_deref().map(PrefixBound::exclusive),
+            None,
+            Order::Ascending,
+        ),
+    };
+
+    pairs
         .take(limit)
         .map(|item| {
-            let (_, v) = item?;
-            v.to_normal(api)
+            let ((_, pool_identifier), pair) = item?;
+            Ok((pool_identifier, pair.to_normal(api)?))
         })
-        .collect::<StdResult<Vec<PairInfo>>>()
-}
-
-// this will set the first key after the provided key, by appending a 1 byte
-fn calc_range_start(start_after: Option<[AssetInfoRaw; 2]>) -> Option<Vec<u8>> {
-    start_after.map(|asset_infos| {
-        let mut asset_infos = asset_infos.to_vec();
-        asset_infos.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
-
-        let mut v = [asset_infos[0].as_bytes(), asset_infos[1].as_bytes()]
-            .concat()
-            .as_slice()
-            .to_vec();
-        v.push(1);
-        v
-    })
+        .collect()
 }
 
 pub fn read_trios(
@@ -131,6 +232,171 @@ fn trio_calc_range_start(start_after: Option<[AssetInfoRaw; 3]>) -> Option<Vec<u
     })
 }
 
+/// The guardian of the factory, which can pause pairs and trios but never unpause them.
+pub const GUARDIAN: Item<Addr> = Item::new("guardian");
+/// Set while all the pairs and trios are paused, see [PauseInfo].
+pub const ALL_PAUSED: Item<PauseInfo> = Item::new("all_paused");
+/// The pairs and trios paused on their own.
+pub const PAUSED_CONTRACTS: Map<&Addr, PauseInfo> = Map::new("paused_contracts");
+
+/// The feature toggle a pool had before being disabled by [ALL_PAUSED], restored when unpausing.
+#[cw_serde]
+pub enum PausedPool {
+    Pair(FeatureToggle),
+    Trio(FeatureToggle),
+}
+
+/// The pairs and trios disabled by [ALL_PAUSED]. Pools that were paused on their own or had
+/// everything disabled already aren't tracked, so unpausing leaves them as they are.
+pub const PAUSED_BY_ALL: Map<&Addr, PausedPool> = Map::new("paused_by_all");
+
+/// Reads the pools disabled by [ALL_PAUSED], paginated.
+pub fn read_paused_by_all(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, PausedPool)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    PAUSED_BY_ALL
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+/// A pair, along with its pool identifier, or a trio created by the factory.
+pub enum Pool {
+    Pair(String, PairInfo),
+    Trio(TrioInfo),
+}
+
+impl Pool {
+    pub fn contract_addr(&self) -> &str {
+        match self {
+            Pool::Pair(_, pair) => &pair.contract_addr,
+            Pool::Trio(trio) => &trio.contract_addr,
+        }
+    }
+
+    /// The cursor to resume a page of pools after this one.
+    pub fn cursor(&self) -> PoolCursor {
+        match self {
+            Pool::Pair(pool_identifier, pair) => PoolCursor::Pair {
+                asset_infos: pair.asset_infos.clone(),
+                pool_identifier: pool_identifier.clone(),
+            },
+            Pool::Trio(trio) => PoolCursor::Trio {
+                asset_infos: trio.asset_infos.clone(),
+            },
+        }
+    }
+}
+
+/// Reads the pairs and then the trios created by the factory, paginated.
+pub fn read_pools(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    start_after: Option<PoolCursor>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Pool>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+
+    let (mut pools, trios_start_after) = match start_after {
+        Some(PoolCursor::Trio { asset_infos }) => (vec![], Some(asset_infos)),
+        start_after => {
+            let (start_after, start_after_pool_identifier) = match start_after {
+                Some(PoolCursor::Pair {
+                    asset_infos,
+                    pool_identifier,
+                }) => (
+                    Some([asset_infos[0].to_raw(api)?, asset_infos[1].to_raw(api)?]),
+                    Some(pool_identifier),
+                ),
+                _ => (None, None),
+            };
+
+            let pairs = read_pairs(
+                storage,
+                api,
+                start_after,
+                start_after_pool_identifier,
+                Some(limit),
+            )?
+            .into_iter()
+            .map(|(pool_identifier, pair)| Pool::Pair(pool_identifier, pair))
+            .collect::<Vec<_>>();
+
+            (pairs, None)
+        }
+    };
+
+    let remaining = limit - pools.len() as u32;
+    if remaining > 0 {
+        let start_after = trios_start_after
+            .map(|asset_infos| -> StdResult<_> {
+                Ok([
+                    asset_infos[0].to_raw(api)?,
+                    asset_infos[1].to_raw(api)?,
+                    asset_infos[2].to_raw(api)?,
+                ])
+            })
+            .transpose()?;
+
+        pools.extend(
+            read_trios(storage, api, start_after, Some(remaining))?
+                .into_iter()
+                .map(Pool::Trio),
+        );
+    }
+
+    Ok(pools)
+}
+
+/// The epoch manager the pairs and trios bucket their swap statistics by, if any.
+pub const EPOCH_MANAGER: Item<Addr> = Item::new("epoch_manager");
+
+/// The swap fee discounts granted by the pairs, if any.
+pub const FEE_DISCOUNTS: Item<FeeDiscounts> = Item::new("fee_discounts");
+/// The addresses granted the whitelist fee discount, e.g. the fee collector or aggregator partners.
+pub const FEE_DISCOUNT_WHITELIST: Map<&Addr, ()> = Map::new("fee_discount_whitelist");
+
+/// Reads the addresses in the fee discount whitelist, paginated.
+pub fn read_fee_discount_whitelist(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    FEE_DISCOUNT_WHITELIST
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+/// The asset registry, keyed by the raw asset info of the assets.
+pub const ASSET_METADATA: Map<&[u8], AssetMetadataInfo> = Map::new("asset_metadata");
+
+/// Reads the metadata of the assets in the asset registry, paginated.
+pub fn read_assets_metadata(
+    storage: &dyn Storage,
+    start_after: Option<AssetInfoRaw>,
+    limit: Option<u32>,
+) -> StdResult<Vec<AssetMetadataInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|asset_info| Bound::exclusive(asset_info.as_bytes()));
+
+    ASSET_METADATA
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect()
+}
+
 // key : asset info / value: decimals
 pub const ALLOW_NATIVE_TOKENS: Map<&[u8], u8> = Map::new("allow_native_token");
 pub fn add_allow_native_token(
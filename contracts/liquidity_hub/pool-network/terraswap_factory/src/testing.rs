@@ -2,32 +2,40 @@ use cosmwasm_std::testing::{
     mock_dependencies_with_balance, mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR,
 };
 use cosmwasm_std::{
-    attr, coin, from_json, to_json_binary, Api, CanonicalAddr, Coin, CosmosMsg, Decimal, OwnedDeps,
-    Reply, ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+    attr, coin, from_json, to_json_binary, Addr, Api, BankMsg, CanonicalAddr, Coin, CosmosMsg,
+    Decimal, OwnedDeps, Reply, ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult, Uint128,
+    WasmMsg,
 };
 
 use white_whale_std::fee::Fee;
+use white_whale_std::pause::{PauseInfo, PauseStatusResponse};
 use white_whale_std::pool_network;
 use white_whale_std::pool_network::asset::{
-    AssetInfo, AssetInfoRaw, PairInfo, PairInfoRaw, PairType,
+    Asset, AssetInfo, AssetInfoRaw, PairInfo, PairInfoRaw, PairType, TrioInfo, TrioInfoRaw,
 };
 use white_whale_std::pool_network::factory::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, NativeTokenDecimalsResponse, QueryMsg,
+    AssetMetadata, AssetMetadataInfo, AssetsMetadataResponse, ConfigResponse, CurrentEpochResponse,
+    ExecuteMsg, FeeDiscountResponse, FeeDiscountTier, FeeDiscountWhitelistResponse, FeeDiscounts,
+    InstantiateMsg, MigrateMsg, NativeTokenDecimalsResponse, PairsByAssetsResponse, PairsFilter,
+    PairsResponse, PairsStatsResponse, PoolCreationFee, PoolCursor, PoolInfo, QueryMsg,
 };
 use white_whale_std::pool_network::mock_querier::{
     mock_dependencies, mock_dependencies_trio, WasmMockQuerier, WasmMockTrioQuerier,
 };
 use white_whale_std::pool_network::pair::{
-    InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
+    FeatureToggle, InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
 };
+use white_whale_std::pool_network::pool_stats::PoolStats;
 use white_whale_std::pool_network::trio::{
-    InstantiateMsg as TrioInstantiateMsg, MigrateMsg as TrioMigrateMsg, PoolFee as TrioPoolFee,
+    FeatureToggle as TrioFeatureToggle, InstantiateMsg as TrioInstantiateMsg,
+    MigrateMsg as TrioMigrateMsg, PoolFee as TrioPoolFee,
 };
 
 use crate::contract::{execute, instantiate, migrate, query, reply};
 use crate::error::ContractError;
 use crate::state::{
-    pair_key, trio_key, TmpPairInfo, TmpTrioInfo, PAIRS, TMP_PAIR_INFO, TMP_TRIO_INFO,
+    pair_key, trio_key, TmpPairInfo, TmpTrioInfo, DEFAULT_PAIR_POOLS, EPOCH_MANAGER, PAIRS,
+    PAIR_POOL_FEES, POOL_COUNTER, TMP_PAIR_INFO, TMP_TRIO_INFO, TRIOS,
 };
 
 #[test]
@@ -39,6 +47,8 @@ fn proper_initialization() {
         trio_code_id: 456u64,
         token_code_id: 123u64,
         fee_collector_addr: "collector".to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -62,6 +72,8 @@ fn can_migrate_contract() {
         trio_code_id: 456u64,
         token_code_id: 123u64,
         fee_collector_addr: "collector".to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -86,6 +98,8 @@ fn update_config() {
         trio_code_id: 456u64,
         token_code_id: 123u64,
         fee_collector_addr: "collector".to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let info = mock_info("addr0000", &[]);
@@ -100,6 +114,8 @@ fn update_config() {
         owner: Some("addr0001".to_string()),
         pair_code_id: None,
         trio_code_id: None,
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         token_code_id: None,
         fee_collector_addr: None,
     };
@@ -123,6 +139,8 @@ fn update_config() {
         owner: None,
         pair_code_id: Some(100u64),
         trio_code_id: Some(300u64),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         token_code_id: Some(200u64),
         fee_collector_addr: Some("new_collector".to_string()),
     };
@@ -147,6 +165,8 @@ fn update_config() {
         fee_collector_addr: None,
         pair_code_id: None,
         trio_code_id: None,
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
         token_code_id: None,
     };
 
@@ -165,6 +185,8 @@ fn init(
         trio_code_id: 456u64,
         token_code_id: 123u64,
         fee_collector_addr: "collector".to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let env = mock_env();
@@ -188,6 +210,8 @@ fn init_trio(
         trio_code_id: 456u64,
         token_code_id: 123u64,
         fee_collector_addr: "collector".to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let env = mock_env();
@@ -270,6 +294,7 @@ fn create_pair() {
             attr("pair", "uusd-mAAPL"),
             attr("pair_label", "uusd-mAAPL pair"),
             attr("pair_type", "ConstantProduct"),
+            attr("pool_identifier", "1"),
         ]
     );
 
@@ -314,8 +339,10 @@ fn create_pair() {
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
             pair_key: pair_key(&raw_infos),
+            pool_identifier: "1".to_string(),
             asset_decimals: [6u8, 8u8],
             pair_type: PairType::ConstantProduct,
+            pool_fees,
         }
     );
 }
@@ -381,6 +408,7 @@ fn create_stableswap_pair() {
             attr("pair", "uusd-mAAPL"),
             attr("pair_label", "uusd-mAAPL pair"),
             attr("pair_type", "StableSwap"),
+            attr("pool_identifier", "1"),
         ]
     );
 
@@ -421,8 +449,10 @@ fn create_stableswap_pair() {
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
             pair_key: pair_key(&raw_infos),
+            pool_identifier: "1".to_string(),
             asset_decimals: [6u8, 8u8],
             pair_type: PairType::StableSwap { amp: 100 },
+            pool_fees,
         }
     );
 }
@@ -504,6 +534,7 @@ fn create_pair_native_token_and_ibc_token() {
             attr("pair", "uusd-ibc/2739...5EB2"),
             attr("pair_label", "uusd-ibc/2739...5EB2 pair"),
             attr("pair_type", "ConstantProduct"),
+            attr("pool_identifier", "1"),
         ]
     );
 
@@ -544,8 +575,10 @@ fn create_pair_native_token_and_ibc_token() {
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
             pair_key: pair_key(&raw_infos),
+            pool_identifier: "1".to_string(),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct,
+            pool_fees,
         }
     );
 }
@@ -634,6 +667,7 @@ fn create_ibc_tokens_pair() {
             attr("pair", "ibc/4CD5...3D04-ibc/2739...5EB2"),
             attr("pair_label", "ibc/4CD5...3D04-ibc/2739...5EB2 pair"),
             attr("pair_type", "ConstantProduct"),
+            attr("pool_identifier", "1"),
         ]
     );
 
@@ -674,8 +708,10 @@ fn create_ibc_tokens_pair() {
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
             pair_key: pair_key(&raw_infos),
+            pool_identifier: "1".to_string(),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct,
+            pool_fees,
         }
     );
 }
@@ -745,6 +781,7 @@ fn create_pair_ethereum_asset_and_ibc_token() {
             attr("pair", "peggy0x87a...1B5-ibc/2739...5EB2"),
             attr("pair_label", "peggy0x87a...1B5-ibc/2739...5EB2 pair"),
             attr("pair_type", "ConstantProduct"),
+            attr("pool_identifier", "1"),
         ]
     );
     assert_eq!(
@@ -793,8 +830,10 @@ fn create_pair_ethereum_asset_and_ibc_token() {
         TmpPairInfo {
             asset_infos: raw_infos.clone(),
             pair_key: pair_key(&raw_infos),
+            pool_identifier: "1".to_string(),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct,
+            pool_fees,
         }
     );
 }
@@ -858,19 +897,20 @@ fn fail_to_create_existing_pair() {
         },
     ];
 
+    let pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
     let msg = ExecuteMsg::CreatePair {
         asset_infos: asset_infos.clone(),
-        pool_fees: PoolFee {
-            protocol_fee: Fee {
-                share: Decimal::percent(1u64),
-            },
-            swap_fee: Fee {
-                share: Decimal::percent(1u64),
-            },
-            burn_fee: Fee {
-                share: Decimal::zero(),
-            },
-        },
+        pool_fees: pool_fees.clone(),
         pair_type: PairType::ConstantProduct,
         token_factory_lp: false,
     };
@@ -888,7 +928,7 @@ fn fail_to_create_existing_pair() {
     PAIRS
         .save(
             &mut deps.storage,
-            &pair_key,
+            (&pair_key, "1"),
             &PairInfoRaw {
                 liquidity_token: AssetInfoRaw::Token {
                     contract_addr: CanonicalAddr::from(vec![]),
@@ -900,6 +940,9 @@ fn fail_to_create_existing_pair() {
             },
         )
         .unwrap();
+    PAIR_POOL_FEES
+        .save(&mut deps.storage, (&pair_key, "1"), &pool_fees)
+        .unwrap();
 
     // try to recreate the same pair
     let res = execute(deps.as_mut(), env, info, msg);
@@ -910,6 +953,226 @@ fn fail_to_create_existing_pair() {
     }
 }
 
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn multiple_pools_for_the_same_assets() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        AssetInfo::Token {
+            contract_addr: "asset0001".to_string(),
+        },
+    ];
+    let raw_infos = [
+        asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
+        asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
+    ];
+    let pair_key = pair_key(&raw_infos);
+
+    let pair_info_raw = |contract_addr: &str, pair_type: PairType| PairInfoRaw {
+        liquidity_token: AssetInfoRaw::NativeToken {
+            denom: format!("factory/{contract_addr}/uLP"),
+        },
+        contract_addr: deps.api.addr_canonicalize(contract_addr).unwrap(),
+        asset_infos: raw_infos.clone(),
+        asset_decimals: [6u8, 8u8],
+        pair_type,
+    };
+    let constant_product_pair = pair_info_raw("pair0000", PairType::ConstantProduct);
+    let stableswap_pair = pair_info_raw("pair0001", PairType::StableSwap { amp: 100 });
+
+    let pool_fees = |protocol_fee: u64, swap_fee: u64| PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::permille(protocol_fee),
+        },
+        swap_fee: Fee {
+            share: Decimal::permille(swap_fee),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
+
+    PAIRS
+        .save(&mut deps.storage, (&pair_key, "1"), &constant_product_pair)
+        .unwrap();
+    PAIR_POOL_FEES
+        .save(&mut deps.storage, (&pair_key, "1"), &pool_fees(10, 10))
+        .unwrap();
+    PAIRS
+        .save(&mut deps.storage, (&pair_key, "2"), &stableswap_pair)
+        .unwrap();
+    PAIR_POOL_FEES
+        .save(&mut deps.storage, (&pair_key, "2"), &pool_fees(1, 4))
+        .unwrap();
+    DEFAULT_PAIR_POOLS
+        .save(&mut deps.storage, &pair_key, &"2".to_string())
+        .unwrap();
+    POOL_COUNTER.save(&mut deps.storage, &2).unwrap();
+
+    // a pool with the same type and fees can't be created twice
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::CreatePair {
+            asset_infos: asset_infos.clone(),
+            pool_fees: pool_fees(10, 10),
+            pair_type: PairType::ConstantProduct,
+            token_factory_lp: false,
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::ExistingPair {} => (),
+        _ => panic!("should return ContractError::ExistingPair"),
+    }
+
+    // but it can with other fees, even if they add up to the same total fee
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::CreatePair {
+            asset_infos: asset_infos.clone(),
+            pool_fees: pool_fees(5, 15),
+            pair_type: PairType::ConstantProduct,
+            token_factory_lp: false,
+        },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("pool_identifier", "3")));
+
+    // or with another amp, and the identifiers keep increasing
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::CreatePair {
+            asset_infos: asset_infos.clone(),
+            pool_fees: pool_fees(1, 4),
+            pair_type: PairType::StableSwap { amp: 200 },
+            token_factory_lp: false,
+        },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("pool_identifier", "4")));
+
+    // query all the pools for the assets
+    let res: PairsByAssetsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairsByAssets {
+                asset_infos: asset_infos.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.pairs,
+        vec![
+            PoolInfo {
+                pool_identifier: "1".to_string(),
+                pair_info: constant_product_pair.to_normal(&deps.api).unwrap(),
+                pool_fees: Some(pool_fees(10, 10)),
+            },
+            PoolInfo {
+                pool_identifier: "2".to_string(),
+                pair_info: stableswap_pair.to_normal(&deps.api).unwrap(),
+                pool_fees: Some(pool_fees(1, 4)),
+            },
+        ]
+    );
+    assert_eq!(res.default_pool_identifier, Some("2".to_string()));
+
+    // query a specific pool, or the default one if not specified
+    let res: PairInfo = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pool_identifier: Some("1".to_string()),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.contract_addr, "pair0000");
+
+    let res: PairInfo = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pool_identifier: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.contract_addr, "pair0001");
+
+    // pages are bounded by the limit, and resumed from the pool identifier of their last pair
+    let res: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: None,
+                start_after_pool_identifier: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.pairs.len(), 1);
+    assert_eq!(res.pairs[0].contract_addr, "pair0000");
+    assert_eq!(res.last_pool_identifier, Some("1".to_string()));
+
+    let res: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: Some(asset_infos.clone()),
+                start_after_pool_identifier: res.last_pool_identifier,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.pairs.len(), 1);
+    assert_eq!(res.pairs[0].contract_addr, "pair0001");
+
+    // without a pool identifier, the page starts after all the pools of the asset couple
+    let res: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: Some(asset_infos),
+                start_after_pool_identifier: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(res.pairs.is_empty());
+}
+
 #[cfg(not(feature = "osmosis"))]
 #[test]
 fn fail_to_create_pair_with_inactive_denoms() {
@@ -1044,6 +1307,8 @@ fn fail_to_create_pair_with_unknown_token() {
         trio_code_id: 456u64,
         token_code_id: 123u64,
         fee_collector_addr: "collector".to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let env = mock_env();
@@ -1099,6 +1364,8 @@ fn fail_to_create_pair_with_unknown_ibc_token() {
         trio_code_id: 456u64,
         token_code_id: 123u64,
         fee_collector_addr: "collector".to_string(),
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let env = mock_env();
@@ -1144,6 +1411,7 @@ fn fail_to_create_pair_with_unknown_ibc_token() {
     }
 }
 
+#[cfg(not(feature = "osmosis"))]
 #[test]
 fn reply_test() {
     let mut deps = mock_dependencies(&[]);
@@ -1170,15 +1438,29 @@ fn reply_test() {
         asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
     ];
 
+    let pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
+
     let pair_key = pair_key(&raw_infos);
     TMP_PAIR_INFO
         .save(
             &mut deps.storage,
             &TmpPairInfo {
                 asset_infos: raw_infos,
-                pair_key,
+                pair_key: pair_key.clone(),
+                pool_identifier: "1".to_string(),
                 asset_decimals: [8u8, 8u8],
                 pair_type: PairType::ConstantProduct,
+                pool_fees: pool_fees.clone(),
             },
         )
         .unwrap();
@@ -1222,6 +1504,7 @@ fn reply_test() {
         mock_env(),
         QueryMsg::Pair {
             asset_infos: asset_infos.clone(),
+            pool_identifier: None,
         },
     )
     .unwrap();
@@ -1239,6 +1522,12 @@ fn reply_test() {
             pair_type: PairType::ConstantProduct,
         }
     );
+    assert_eq!(
+        PAIR_POOL_FEES
+            .load(&deps.storage, (&pair_key, "1"))
+            .unwrap(),
+        pool_fees
+    );
 }
 
 #[test]
@@ -1344,45 +1633,10 @@ fn append_add_allow_native_token_with_already_exist_token() {
 fn execute_transactions_unauthorized() {
     let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
     deps = init(deps);
-    deps.querier
-        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
-    let asset_infos = [
-        AssetInfo::NativeToken {
-            denom: "uusd".to_string(),
-        },
-        AssetInfo::Token {
-            contract_addr: "asset0001".to_string(),
-        },
-    ];
     let env = mock_env();
     // unauthorized user
     let info = mock_info("unauthorized", &[]);
 
-    // Try executing ExecuteMsg::CreatePair
-    let msg = ExecuteMsg::CreatePair {
-        asset_infos,
-        pool_fees: PoolFee {
-            protocol_fee: Fee {
-                share: Decimal::percent(1u64),
-            },
-            swap_fee: Fee {
-                share: Decimal::percent(1u64),
-            },
-            burn_fee: Fee {
-                share: Decimal::zero(),
-            },
-        },
-        pair_type: PairType::ConstantProduct,
-        token_factory_lp: false,
-    };
-    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg);
-
-    match res {
-        Ok(_) => panic!("Must return ContractError::Unauthorized"),
-        Err(ContractError::Unauthorized {}) => (),
-        _ => panic!("Must return ContractError::Unauthorized"),
-    }
-
     // Try executing ExecuteMsg::AddNativeTokenDecimals
     let msg = ExecuteMsg::AddNativeTokenDecimals {
         denom: "any".to_string(),
@@ -1404,6 +1658,8 @@ fn execute_transactions_unauthorized() {
         token_code_id: None,
         pair_code_id: None,
         trio_code_id: None,
+        pool_creation_fee: None,
+        allowed_fee_tiers: None,
     };
 
     let res = execute(deps.as_mut(), env, info, msg);
@@ -1415,76 +1671,383 @@ fn execute_transactions_unauthorized() {
     }
 }
 
-#[test]
-fn normal_migrate_pair() {
-    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
-    deps = init(deps);
-
-    let msg = ExecuteMsg::MigratePair {
-        code_id: Some(123u64),
-        contract: "contract0000".to_string(),
+#[cfg(not(feature = "osmosis"))]
+fn set_pool_creation_fee(
+    deps: &mut OwnedDeps<MockStorage, MockApi, WasmMockQuerier>,
+    pool_creation_fee: PoolCreationFee,
+    allowed_fee_tiers: Vec<PoolFee>,
+) {
+    let msg = ExecuteMsg::UpdateConfig {
+        owner: None,
+        fee_collector_addr: None,
+        token_code_id: None,
+        pair_code_id: None,
+        trio_code_id: None,
+        pool_creation_fee: Some(pool_creation_fee),
+        allowed_fee_tiers: Some(allowed_fee_tiers),
     };
 
-    let info = mock_info("addr0000", &[]);
-
-    assert_eq!(
-        execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
-        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
-            contract_addr: "contract0000".to_string(),
-            new_code_id: 123u64,
-            msg: to_json_binary(&PairMigrateMsg {}).unwrap(),
-        })),
-    );
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
 }
 
-#[test]
-fn normal_migrate_trio() {
-    let mut deps = mock_dependencies_trio(&[coin(1u128, "uluna".to_string())]);
-    deps = init_trio(deps);
-
-    let msg = ExecuteMsg::MigrateTrio {
-        code_id: Some(123u64),
-        contract: "contract0000".to_string(),
-    };
+#[cfg(not(feature = "osmosis"))]
+fn pool_fee(protocol_fee: Decimal, swap_fee: Decimal) -> PoolFee {
+    PoolFee {
+        protocol_fee: Fee {
+            share: protocol_fee,
+        },
+        swap_fee: Fee { share: swap_fee },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    }
+}
 
-    let info = mock_info("addr0000", &[]);
+#[cfg(not(feature = "osmosis"))]
+fn create_pair_msg(swap_fee: Decimal) -> ExecuteMsg {
+    create_pair_msg_with_fees(pool_fee(Decimal::permille(1u64), swap_fee))
+}
 
-    assert_eq!(
-        execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
-        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
-            contract_addr: "contract0000".to_string(),
-            new_code_id: 123u64,
-            msg: to_json_binary(&TrioMigrateMsg {}).unwrap(),
-        })),
-    );
+#[cfg(not(feature = "osmosis"))]
+fn create_pair_msg_with_fees(pool_fees: PoolFee) -> ExecuteMsg {
+    ExecuteMsg::CreatePair {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0001".to_string(),
+            },
+        ],
+        pool_fees,
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    }
 }
 
+#[cfg(not(feature = "osmosis"))]
 #[test]
-fn normal_migrate_pair_with_none_code_id_will_config_code_id() {
-    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+fn create_pair_permissionless_disabled_until_configured() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
     deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
 
-    let msg = ExecuteMsg::MigratePair {
-        code_id: None,
-        contract: "contract0000".to_string(),
-    };
-
-    let info = mock_info("addr0000", &[]);
+    // neither the creation fee nor the fee tiers are set
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        create_pair_msg(Decimal::permille(2u64)),
+    )
+    .unwrap_err();
+    match err {
+        ContractError::PermissionlessPoolCreationDisabled {} => (),
+        _ => panic!("should return ContractError::PermissionlessPoolCreationDisabled"),
+    }
 
-    assert_eq!(
-        execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
-        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
-            contract_addr: "contract0000".to_string(),
-            new_code_id: 321u64,
-            msg: to_json_binary(&PairMigrateMsg {}).unwrap(),
-        })),
+    // the creation fee is set but the fee tiers are not
+    set_pool_creation_fee(
+        &mut deps,
+        PoolCreationFee {
+            fee: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            burn: false,
+        },
+        vec![],
     );
-}
 
-#[test]
-fn normal_migrate_trio_with_none_code_id_will_config_code_id() {
-    let mut deps = mock_dependencies_trio(&[coin(1u128, "uluna".to_string())]);
-    deps = init_trio(deps);
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[coin(1_000u128, "uwhale")]),
+        create_pair_msg(Decimal::permille(2u64)),
+    )
+    .unwrap_err();
+    match err {
+        ContractError::PermissionlessPoolCreationDisabled {} => (),
+        _ => panic!("should return ContractError::PermissionlessPoolCreationDisabled"),
+    }
+
+    // the owner can still create pools
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        create_pair_msg(Decimal::permille(2u64)),
+    )
+    .unwrap();
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn create_pair_permissionless_with_creation_fee() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+
+    set_pool_creation_fee(
+        &mut deps,
+        PoolCreationFee {
+            fee: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            burn: false,
+        },
+        vec![pool_fee(Decimal::permille(1u64), Decimal::permille(2u64))],
+    );
+
+    // not paying the fee fails
+    let info = mock_info("creator", &[coin(999u128, "uwhale")]);
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        create_pair_msg(Decimal::permille(2u64)),
+    )
+    .unwrap_err();
+    match err {
+        ContractError::PoolCreationFeeNotPaid {
+            paid_amount,
+            required_amount,
+        } => {
+            assert_eq!(paid_amount, Uint128::new(999u128));
+            assert_eq!(required_amount, Uint128::new(1_000u128));
+        }
+        _ => panic!("should return ContractError::PoolCreationFeeNotPaid"),
+    }
+
+    // paying the fee sends it to the fee collector, the rest of the funds go to the pair
+    let info = mock_info("creator", &[coin(1_000u128, "uwhale"), coin(1u128, "uusd")]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        create_pair_msg(Decimal::permille(2u64)),
+    )
+    .unwrap();
+
+    assert_eq!(res.messages.len(), 2);
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "collector".to_string(),
+            amount: vec![coin(1_000u128, "uwhale")],
+        })
+    );
+    match &res.messages[1].msg {
+        CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => {
+            assert_eq!(funds, &vec![coin(1u128, "uusd")]);
+        }
+        _ => panic!("expected the pair instantiation"),
+    }
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn create_pair_burns_creation_fee() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+
+    set_pool_creation_fee(
+        &mut deps,
+        PoolCreationFee {
+            fee: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            burn: true,
+        },
+        vec![pool_fee(Decimal::permille(1u64), Decimal::permille(2u64))],
+    );
+
+    let info = mock_info("creator", &[coin(1_000u128, "uwhale")]);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        create_pair_msg(Decimal::permille(2u64)),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Burn {
+            amount: vec![coin(1_000u128, "uwhale")],
+        })
+    );
+    match &res.messages[1].msg {
+        CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => assert!(funds.is_empty()),
+        _ => panic!("expected the pair instantiation"),
+    }
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn create_pair_respects_allowed_fee_tiers() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+
+    set_pool_creation_fee(
+        &mut deps,
+        PoolCreationFee {
+            fee: Asset {
+                info: AssetInfo::Token {
+                    contract_addr: "whale_token".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            burn: false,
+        },
+        vec![
+            pool_fee(Decimal::permille(1u64), Decimal::permille(2u64)),
+            pool_fee(Decimal::permille(1u64), Decimal::permille(9u64)),
+        ],
+    );
+
+    // 0.1% protocol fee + 0.1% swap fee is not an allowed tier
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        create_pair_msg(Decimal::permille(1u64)),
+    )
+    .unwrap_err();
+    match err {
+        ContractError::FeeTierNotAllowed {} => (),
+        _ => panic!("should return ContractError::FeeTierNotAllowed"),
+    }
+
+    // the full fee split is matched, not only the aggregated fee
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        create_pair_msg_with_fees(pool_fee(Decimal::permille(2u64), Decimal::permille(1u64))),
+    )
+    .unwrap_err();
+    match err {
+        ContractError::FeeTierNotAllowed {} => (),
+        _ => panic!("should return ContractError::FeeTierNotAllowed"),
+    }
+
+    // 0.1% protocol fee + 0.2% swap fee is, the cw20 fee is transferred from the creator
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        create_pair_msg(Decimal::permille(2u64)),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "whale_token".to_string(),
+            msg: to_json_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
+                owner: "creator".to_string(),
+                recipient: "collector".to_string(),
+                amount: Uint128::new(1_000u128),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+
+    // the owner is exempt from both the fee tiers and the creation fee
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        create_pair_msg(Decimal::permille(1u64)),
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+}
+
+#[test]
+fn normal_migrate_pair() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+
+    let msg = ExecuteMsg::MigratePair {
+        code_id: Some(123u64),
+        contract: "contract0000".to_string(),
+    };
+
+    let info = mock_info("addr0000", &[]);
+
+    assert_eq!(
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
+        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: "contract0000".to_string(),
+            new_code_id: 123u64,
+            msg: to_json_binary(&PairMigrateMsg {}).unwrap(),
+        })),
+    );
+}
+
+#[test]
+fn normal_migrate_trio() {
+    let mut deps = mock_dependencies_trio(&[coin(1u128, "uluna".to_string())]);
+    deps = init_trio(deps);
+
+    let msg = ExecuteMsg::MigrateTrio {
+        code_id: Some(123u64),
+        contract: "contract0000".to_string(),
+    };
+
+    let info = mock_info("addr0000", &[]);
+
+    assert_eq!(
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
+        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: "contract0000".to_string(),
+            new_code_id: 123u64,
+            msg: to_json_binary(&TrioMigrateMsg {}).unwrap(),
+        })),
+    );
+}
+
+#[test]
+fn normal_migrate_pair_with_none_code_id_will_config_code_id() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+
+    let msg = ExecuteMsg::MigratePair {
+        code_id: None,
+        contract: "contract0000".to_string(),
+    };
+
+    let info = mock_info("addr0000", &[]);
+
+    assert_eq!(
+        execute(deps.as_mut(), mock_env(), info, msg).unwrap(),
+        Response::new().add_message(CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: "contract0000".to_string(),
+            new_code_id: 321u64,
+            msg: to_json_binary(&PairMigrateMsg {}).unwrap(),
+        })),
+    );
+}
+
+#[test]
+fn normal_migrate_trio_with_none_code_id_will_config_code_id() {
+    let mut deps = mock_dependencies_trio(&[coin(1u128, "uluna".to_string())]);
+    deps = init_trio(deps);
 
     let msg = ExecuteMsg::MigrateTrio {
         code_id: None,
@@ -1568,7 +2131,7 @@ fn delete_pair() {
     PAIRS
         .save(
             &mut deps.storage,
-            &pair_key_vec,
+            (&pair_key_vec, "1"),
             &PairInfoRaw {
                 liquidity_token: AssetInfoRaw::Token {
                     contract_addr: CanonicalAddr(cosmwasm_std::Binary(vec![])),
@@ -1580,12 +2143,18 @@ fn delete_pair() {
             },
         )
         .unwrap();
+    DEFAULT_PAIR_POOLS
+        .save(&mut deps.storage, &pair_key_vec, &"1".to_string())
+        .unwrap();
 
-    let pair = PAIRS.load(&deps.storage, &pair_key_vec);
+    let pair = PAIRS.load(&deps.storage, (&pair_key_vec, "1"));
 
     assert!(pair.is_ok(), "pair key should exist");
 
-    let msg = ExecuteMsg::RemovePair { asset_infos };
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos,
+        pool_identifier: None,
+    };
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -1594,13 +2163,15 @@ fn delete_pair() {
         res.attributes,
         vec![
             attr("action", "remove_pair"),
+            attr("pool_identifier", "1"),
             attr("pair_contract_addr", "pair0000"),
         ]
     );
 
-    let pair = PAIRS.load(&deps.storage, &pair_key_vec);
+    let pair = PAIRS.load(&deps.storage, (&pair_key_vec, "1"));
 
     assert!(pair.is_err(), "pair key should not exist");
+    assert!(!DEFAULT_PAIR_POOLS.has(&deps.storage, &pair_key_vec));
 }
 
 #[test]
@@ -1618,7 +2189,10 @@ fn delete_pair_failed_if_not_found() {
         },
     ];
 
-    let msg = ExecuteMsg::RemovePair { asset_infos };
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos,
+        pool_identifier: None,
+    };
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
     let res = execute(deps.as_mut(), env, info, msg);
@@ -1664,6 +2238,41 @@ fn update_pair_config() {
         },
     };
 
+    // the mocked pair is a legacy uluna-uluna pool, identified by its pair type only
+    let raw_infos = [
+        AssetInfoRaw::NativeToken {
+            denom: "uluna".to_string(),
+        },
+        AssetInfoRaw::NativeToken {
+            denom: "uluna".to_string(),
+        },
+    ];
+    let pair_key_vec = pair_key(&raw_infos);
+    let pair_info_raw = |contract_addr: &str| PairInfoRaw {
+        liquidity_token: AssetInfoRaw::Token {
+            contract_addr: CanonicalAddr(cosmwasm_std::Binary(vec![])),
+        },
+        contract_addr: deps.api.addr_canonicalize(contract_addr).unwrap(),
+        asset_infos: raw_infos.clone(),
+        asset_decimals: [6, 6],
+        pair_type: PairType::ConstantProduct,
+    };
+    let pair = pair_info_raw("pair_addr");
+    let other_pair = pair_info_raw("pair0002");
+    PAIRS
+        .save(&mut deps.storage, (&pair_key_vec, "ConstantProduct"), &pair)
+        .unwrap();
+    PAIRS
+        .save(&mut deps.storage, (&pair_key_vec, "1"), &other_pair)
+        .unwrap();
+    DEFAULT_PAIR_POOLS
+        .save(
+            &mut deps.storage,
+            &pair_key_vec,
+            &"ConstantProduct".to_string(),
+        )
+        .unwrap();
+
     #[cfg(not(feature = "osmosis"))]
     let msg = ExecuteMsg::UpdatePairConfig {
         pair_addr: "pair_addr".to_string(),
@@ -1671,6 +2280,8 @@ fn update_pair_config() {
         fee_collector_addr: None,
         pool_fees: Some(pool_fee.clone()),
         feature_toggle: None,
+        amp_factor: None,
+        dynamic_fee: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -1680,6 +2291,8 @@ fn update_pair_config() {
         fee_collector_addr: None,
         pool_fees: Some(pool_fee.clone()),
         feature_toggle: None,
+        amp_factor: None,
+        dynamic_fee: None,
         cosmwasm_pool_interface: Some("new_interface".to_string()),
     };
 
@@ -1691,29 +2304,57 @@ fn update_pair_config() {
     let expected_msg = pool_network::pair::ExecuteMsg::UpdateConfig {
         owner: Some("new_owner".to_string()),
         fee_collector_addr: None,
-        pool_fees: Some(pool_fee),
+        pool_fees: Some(pool_fee.clone()),
         feature_toggle: None,
+        amp_factor: None,
+        dynamic_fee: None,
     };
 
     #[cfg(feature = "osmosis")]
     let expected_msg = pool_network::pair::ExecuteMsg::UpdateConfig {
         owner: Some("new_owner".to_string()),
         fee_collector_addr: None,
-        pool_fees: Some(pool_fee),
+        pool_fees: Some(pool_fee.clone()),
         feature_toggle: None,
+        amp_factor: None,
+        dynamic_fee: None,
         cosmwasm_pool_interface: Some("new_interface".to_string()),
     };
 
     assert_eq!(
         res,
         Response::new()
-            .add_attributes(vec![attr("action", "update_pair_config")])
+            .add_attributes(vec![
+                attr("action", "update_pair_config"),
+                attr("pool_identifier", "ConstantProduct"),
+            ])
             .add_message(WasmMsg::Execute {
                 contract_addr: "pair_addr".to_string(),
                 funds: vec![],
                 msg: to_json_binary(&expected_msg).unwrap(),
             })
     );
+
+    // the pair keeps its pool identifier, and its new fees are tracked apart
+    assert_eq!(
+        PAIRS
+            .load(&deps.storage, (&pair_key_vec, "ConstantProduct"))
+            .unwrap(),
+        pair
+    );
+    assert_eq!(
+        DEFAULT_PAIR_POOLS
+            .load(&deps.storage, &pair_key_vec)
+            .unwrap(),
+        "ConstantProduct"
+    );
+    assert_eq!(
+        PAIR_POOL_FEES
+            .load(&deps.storage, (&pair_key_vec, "ConstantProduct"))
+            .unwrap(),
+        pool_fee
+    );
+    assert!(!PAIR_POOL_FEES.has(&deps.storage, (&pair_key_vec, "1")));
 }
 
 #[cfg(not(feature = "osmosis"))]
@@ -1954,3 +2595,1383 @@ fn create_trio_with_native_tokens_token_factory_lp() {
         }
     );
 }
+
+#[test]
+fn update_fee_discounts() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let fee_discounts = FeeDiscounts {
+        whale_lair_addr: "whale_lair".to_string(),
+        weight_tiers: vec![FeeDiscountTier {
+            min_weight: Uint128::new(1_000),
+            discount: Decimal::percent(10),
+        }],
+        whitelist_discount: Decimal::percent(100),
+        trusted_routers: vec!["router".to_string()],
+    };
+
+    // only the owner can update the fee discounts
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::UpdateFeeDiscounts {
+            fee_discounts: Some(fee_discounts.clone()),
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::Unauthorized {} => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    // discounts can't be higher than 100%
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateFeeDiscounts {
+            fee_discounts: Some(FeeDiscounts {
+                whitelist_discount: Decimal::percent(101),
+                ..fee_discounts.clone()
+            }),
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::InvalidFeeDiscount { discount } => {
+            assert_eq!(discount, Decimal::percent(101))
+        }
+        _ => panic!("should return ContractError::InvalidFeeDiscount"),
+    }
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateFeeDiscounts {
+            fee_discounts: Some(fee_discounts.clone()),
+        },
+    )
+    .unwrap();
+
+    let res: Option<FeeDiscounts> =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::FeeDiscounts {}).unwrap()).unwrap();
+    assert_eq!(res, Some(fee_discounts));
+
+    // removing the fee discounts disables them
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateFeeDiscounts {
+            fee_discounts: None,
+        },
+    )
+    .unwrap();
+
+    let res: Option<FeeDiscounts> =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::FeeDiscounts {}).unwrap()).unwrap();
+    assert_eq!(res, None);
+}
+
+#[test]
+fn update_fee_discount_whitelist() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateFeeDiscounts {
+            fee_discounts: Some(FeeDiscounts {
+                whale_lair_addr: "whale_lair".to_string(),
+                weight_tiers: vec![],
+                whitelist_discount: Decimal::percent(100),
+                trusted_routers: vec!["router".to_string()],
+            }),
+        },
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateFeeDiscountWhitelist {
+            add: vec![
+                "aggregator".to_string(),
+                "collector".to_string(),
+                "router".to_string(),
+            ],
+            remove: vec![],
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "update_fee_discount_whitelist"),
+            attr("added", "aggregator,collector,router"),
+            attr("removed", ""),
+        ]
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateFeeDiscountWhitelist {
+            add: vec![],
+            remove: vec!["router".to_string()],
+        },
+    )
+    .unwrap();
+
+    let res: FeeDiscountWhitelistResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FeeDiscountWhitelist {
+                start_after: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.addresses, vec!["aggregator".to_string()]);
+
+    let res: FeeDiscountWhitelistResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FeeDiscountWhitelist {
+                start_after: Some("aggregator".to_string()),
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.addresses, vec!["collector".to_string()]);
+
+    // whitelisted addresses get the whitelist discount, the rest get no discount without tiers
+    let fee_discount = |address: &str, trader: Option<&str>| -> Decimal {
+        from_json::<FeeDiscountResponse>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::FeeDiscount {
+                    address: address.to_string(),
+                    trader: trader.map(|trader| trader.to_string()),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+        .discount
+    };
+    assert_eq!(fee_discount("collector", None), Decimal::percent(100));
+    assert_eq!(fee_discount("router", None), Decimal::zero());
+
+    // trusted routers get the discount of the trader they swap for, other addresses can't pass
+    // a trader along
+    assert_eq!(
+        fee_discount("router", Some("collector")),
+        Decimal::percent(100)
+    );
+    assert_eq!(fee_discount("router", Some("addr0001")), Decimal::zero());
+    assert_eq!(fee_discount("addr0001", Some("collector")), Decimal::zero());
+    assert_eq!(
+        fee_discount("collector", Some("addr0001")),
+        Decimal::percent(100)
+    );
+}
+
+fn save_pair(
+    deps: &mut OwnedDeps<MockStorage, MockApi, WasmMockQuerier>,
+    asset_infos: [AssetInfo; 2],
+    contract_addr: &str,
+    pair_type: PairType,
+) {
+    let raw_infos = [
+        asset_infos[0].to_raw(&deps.api).unwrap(),
+        asset_infos[1].to_raw(&deps.api).unwrap(),
+    ];
+
+    PAIRS
+        .save(
+            &mut deps.storage,
+            (&pair_key(&raw_infos), "1"),
+            &PairInfoRaw {
+                liquidity_token: AssetInfoRaw::Token {
+                    contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+                },
+                contract_addr: deps.api.addr_canonicalize(contract_addr).unwrap(),
+                asset_infos: raw_infos,
+                asset_decimals: [6, 6],
+                pair_type,
+            },
+        )
+        .unwrap();
+}
+
+fn save_pairs(deps: &mut OwnedDeps<MockStorage, MockApi, WasmMockQuerier>) {
+    let mut pairs = vec![];
+    for (denom, contract_addr, pair_type) in [
+        ("uatom", "pair0000", PairType::ConstantProduct),
+        ("uluna", "pair0001", PairType::StableSwap { amp: 100 }),
+        ("uwhale", "pair0002", PairType::ConstantProduct),
+    ] {
+        let asset_infos = [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::NativeToken {
+                denom: denom.to_string(),
+            },
+        ];
+        save_pair(deps, asset_infos.clone(), contract_addr, pair_type.clone());
+        pairs.push((
+            contract_addr.to_string(),
+            PairInfo {
+                asset_infos,
+                contract_addr: contract_addr.to_string(),
+                liquidity_token: AssetInfo::Token {
+                    contract_addr: "liquidity0000".to_string(),
+                },
+                asset_decimals: [6, 6],
+                pair_type,
+            },
+        ));
+    }
+
+    // the pairs answer the pair info query with what the factory stored
+    deps.querier.with_pool_factory(
+        &pairs
+            .iter()
+            .map(|(contract_addr, pair_info)| (contract_addr, pair_info))
+            .collect::<Vec<_>>(),
+        &[],
+    );
+}
+
+#[cfg(not(feature = "osmosis"))]
+fn pair_feature_toggle_msg(contract_addr: &str, enabled: bool) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: contract_addr.to_string(),
+        msg: to_json_binary(&pool_network::pair::ExecuteMsg::UpdateConfig {
+            owner: None,
+            fee_collector_addr: None,
+            pool_fees: None,
+            feature_toggle: Some(FeatureToggle {
+                withdrawals_enabled: enabled,
+                deposits_enabled: enabled,
+                swaps_enabled: enabled,
+            }),
+            amp_factor: None,
+            dynamic_fee: None,
+        })
+        .unwrap(),
+        funds: vec![],
+    })
+}
+
+#[test]
+fn migrate_pairs() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+    save_pairs(&mut deps);
+
+    // only the owner can migrate pairs
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: None,
+            start_after: None,
+            start_after_pool_identifier: None,
+            limit: None,
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::Unauthorized {} => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: None,
+            start_after: None,
+            start_after_pool_identifier: None,
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "migrate_pairs"),
+            attr("code_id", "321"),
+            attr("migrated_pairs", "2"),
+        ]
+    );
+    assert_eq!(
+        res.messages
+            .into_iter()
+            .map(|sub_msg| sub_msg.msg)
+            .collect::<Vec<_>>(),
+        vec![
+            CosmosMsg::Wasm(WasmMsg::Migrate {
+                contract_addr: "pair0000".to_string(),
+                new_code_id: 321u64,
+                msg: to_json_binary(&PairMigrateMsg {}).unwrap(),
+            }),
+            CosmosMsg::Wasm(WasmMsg::Migrate {
+                contract_addr: "pair0001".to_string(),
+                new_code_id: 321u64,
+                msg: to_json_binary(&PairMigrateMsg {}).unwrap(),
+            }),
+        ]
+    );
+
+    // next page, to a given code id
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::MigratePairs {
+            code_id: Some(654u64),
+            start_after: Some([
+                AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+            ]),
+            start_after_pool_identifier: None,
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: "pair0002".to_string(),
+            new_code_id: 654u64,
+            msg: to_json_binary(&PairMigrateMsg {}).unwrap(),
+        }))]
+    );
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn update_pairs_feature_toggle() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+    save_pairs(&mut deps);
+
+    let feature_toggle = FeatureToggle {
+        withdrawals_enabled: true,
+        deposits_enabled: false,
+        swaps_enabled: false,
+    };
+
+    // filter by pair type
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdatePairsFeatureToggle {
+            feature_toggle: feature_toggle.clone(),
+            filter: PairsFilter {
+                pair_type: Some(PairType::ConstantProduct),
+                ..PairsFilter::default()
+            },
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "update_pairs_feature_toggle"),
+            attr("updated_pairs", "2"),
+        ]
+    );
+
+    // filter by asset, the amp of stableswap pairs doesn't matter
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdatePairsFeatureToggle {
+            feature_toggle: feature_toggle.clone(),
+            filter: PairsFilter {
+                pair_type: Some(PairType::StableSwap { amp: 1 }),
+                asset_info: Some(AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                }),
+                ..PairsFilter::default()
+            },
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "pair0001".to_string(),
+            msg: to_json_binary(&pool_network::pair::ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                pool_fees: None,
+                feature_toggle: Some(feature_toggle),
+                amp_factor: None,
+                dynamic_fee: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn pause_and_unpause_all() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+    save_pairs(&mut deps);
+
+    let raw_infos = [
+        AssetInfoRaw::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        AssetInfoRaw::NativeToken {
+            denom: "uusdc".to_string(),
+        },
+        AssetInfoRaw::NativeToken {
+            denom: "uusdt".to_string(),
+        },
+    ];
+    TRIOS
+        .save(
+            &mut deps.storage,
+            &trio_key(&raw_infos),
+            &TrioInfoRaw {
+                liquidity_token: AssetInfoRaw::Token {
+                    contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+                },
+                contract_addr: deps.api.addr_canonicalize("trio0000").unwrap(),
+                asset_infos: raw_infos,
+                asset_decimals: [6, 6, 6],
+            },
+        )
+        .unwrap();
+
+    // pair0001 is paused on its own, the owner disabled swaps on pair0002 and everything on the trio
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::PausePair {
+            pair_addr: "pair0001".to_string(),
+            reason: "oracle manipulation".to_string(),
+        },
+    )
+    .unwrap();
+    let swaps_disabled = FeatureToggle {
+        withdrawals_enabled: true,
+        deposits_enabled: true,
+        swaps_enabled: false,
+    };
+    deps.querier.with_feature_toggles(&[
+        (&"pair0002".to_string(), swaps_disabled.clone()),
+        (
+            &"trio0000".to_string(),
+            FeatureToggle {
+                withdrawals_enabled: false,
+                deposits_enabled: false,
+                swaps_enabled: false,
+            },
+        ),
+    ]);
+
+    // only the owner can pause everything
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::PauseAll {
+            reason: "exploit".to_string(),
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::Unauthorized {} => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    // pools are paused a page at a time, skipping the ones already paused or disabled
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::PauseAll {
+            reason: "exploit".to_string(),
+            start_after: None,
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("action", "pause_all")));
+    assert!(res.attributes.contains(&attr("pairs", "1")));
+    assert!(res.attributes.contains(&attr("trios", "0")));
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(pair_feature_toggle_msg("pair0000", false))]
+    );
+
+    let last_pool: Option<PoolCursor> = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(
+        last_pool,
+        Some(PoolCursor::Pair {
+            asset_infos: [
+                AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+            ],
+            pool_identifier: "1".to_string(),
+        })
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::PauseAll {
+            reason: "exploit".to_string(),
+            start_after: last_pool,
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("pairs", "1")));
+    assert!(res.attributes.contains(&attr("trios", "0")));
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(pair_feature_toggle_msg("pair0002", false))]
+    );
+    let last_pool: Option<PoolCursor> = from_json(res.data.unwrap()).unwrap();
+    assert_eq!(
+        last_pool,
+        Some(PoolCursor::Trio {
+            asset_infos: [
+                AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "uusdc".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "uusdt".to_string(),
+                },
+            ],
+        })
+    );
+
+    // the last page is empty
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::PauseAll {
+            reason: "exploit".to_string(),
+            start_after: last_pool,
+            limit: Some(2),
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(
+        from_json::<Option<PoolCursor>>(res.data.unwrap()).unwrap(),
+        None
+    );
+
+    // unpausing restores the feature toggles of the pools paused by PauseAll only
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UnpauseAll {
+            start_after: None,
+            limit: Some(1),
+        },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr("action", "unpause_all")));
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(pair_feature_toggle_msg("pair0000", true))]
+    );
+
+    let pause_status = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>| {
+        from_json::<PauseStatusResponse>(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PauseStatus { contract: None },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    assert!(pause_status(&deps).all_paused.is_some());
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UnpauseAll {
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "pair0002".to_string(),
+            msg: to_json_binary(&pool_network::pair::ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                pool_fees: None,
+                feature_toggle: Some(swaps_disabled),
+                amp_factor: None,
+                dynamic_fee: None,
+            })
+            .unwrap(),
+            funds: vec![],
+        }))]
+    );
+    assert!(pause_status(&deps).all_paused.is_none());
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn pause_all_trios() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let raw_infos = [
+        AssetInfoRaw::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        AssetInfoRaw::NativeToken {
+            denom: "uusdc".to_string(),
+        },
+        AssetInfoRaw::NativeToken {
+            denom: "uusdt".to_string(),
+        },
+    ];
+    TRIOS
+        .save(
+            &mut deps.storage,
+            &trio_key(&raw_infos),
+            &TrioInfoRaw {
+                liquidity_token: AssetInfoRaw::Token {
+                    contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+                },
+                contract_addr: deps.api.addr_canonicalize("trio0000").unwrap(),
+                asset_infos: raw_infos,
+                asset_decimals: [6, 6, 6],
+            },
+        )
+        .unwrap();
+
+    for (msg, enabled, action) in [
+        (
+            ExecuteMsg::PauseAll {
+                reason: "exploit".to_string(),
+                start_after: None,
+                limit: None,
+            },
+            false,
+            "pause_all",
+        ),
+        (
+            ExecuteMsg::UnpauseAll {
+                start_after: None,
+                limit: None,
+            },
+            true,
+            "unpause_all",
+        ),
+    ] {
+        let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+        assert!(res.attributes.contains(&attr("action", action)));
+        assert!(res.attributes.contains(&attr("trios", "1")));
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "trio0000".to_string(),
+                msg: to_json_binary(&pool_network::trio::ExecuteMsg::UpdateConfig {
+                    owner: None,
+                    fee_collector_addr: None,
+                    pool_fees: None,
+                    feature_toggle: Some(TrioFeatureToggle {
+                        withdrawals_enabled: enabled,
+                        deposits_enabled: enabled,
+                        swaps_enabled: enabled,
+                    }),
+                    amp_factor: None,
+                })
+                .unwrap(),
+                funds: vec![],
+            }))]
+        );
+    }
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn guardian_can_only_pause() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+    save_pairs(&mut deps);
+
+    // only the owner can set the guardian
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::UpdateGuardian {
+            guardian: Some("guardian".to_string()),
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::Unauthorized {} => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateGuardian {
+            guardian: Some("guardian".to_string()),
+        },
+    )
+    .unwrap();
+
+    // the guardian pauses a pair
+    let env = mock_env();
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::PausePair {
+            pair_addr: "pair0001".to_string(),
+            reason: "oracle manipulation".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "pause_pair"),
+            attr("pair_addr", "pair0001"),
+            attr("reason", "oracle manipulation"),
+            attr("paused_by", "guardian"),
+        ]
+    );
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(pair_feature_toggle_msg("pair0001", false))]
+    );
+
+    let pause_status = |deps: &OwnedDeps<MockStorage, MockApi, WasmMockQuerier>,
+                        contract: Option<&str>|
+     -> PauseStatusResponse {
+        from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::PauseStatus {
+                    contract: contract.map(|contract| contract.to_string()),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    let expected_pause_info = PauseInfo {
+        paused_by: Addr::unchecked("guardian"),
+        reason: "oracle manipulation".to_string(),
+        paused_at: env.block.time,
+    };
+    assert_eq!(
+        pause_status(&deps, Some("pair0001")),
+        PauseStatusResponse {
+            guardian: Some(Addr::unchecked("guardian")),
+            all_paused: None,
+            contract_paused: Some(expected_pause_info),
+        }
+    );
+    assert!(!pause_status(&deps, Some("pair0000")).is_paused());
+
+    // the guardian can pause everything, but it can't unpause nor update pairs
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::PauseAll {
+            reason: "exploit".to_string(),
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert!(pause_status(&deps, Some("pair0000")).is_paused());
+
+    for msg in [
+        ExecuteMsg::UnpauseAll {
+            start_after: None,
+            limit: None,
+        },
+        ExecuteMsg::UpdatePairConfig {
+            pair_addr: "pair0001".to_string(),
+            owner: None,
+            fee_collector_addr: None,
+            pool_fees: None,
+            feature_toggle: Some(FeatureToggle {
+                withdrawals_enabled: true,
+                deposits_enabled: true,
+                swaps_enabled: true,
+            }),
+            amp_factor: None,
+            dynamic_fee: None,
+        },
+    ] {
+        let err = execute(deps.as_mut(), mock_env(), mock_info("guardian", &[]), msg).unwrap_err();
+        match err {
+            ContractError::Unauthorized {} => (),
+            _ => panic!("should return ContractError::Unauthorized"),
+        }
+    }
+
+    // the owner unpauses everything, except for the pair paused on its own
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UnpauseAll {
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    assert!(!pause_status(&deps, Some("pair0000")).is_paused());
+    assert!(pause_status(&deps, Some("pair0001")).is_paused());
+
+    // once removed, the guardian can't pause anymore
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateGuardian { guardian: None },
+    )
+    .unwrap();
+    assert_eq!(pause_status(&deps, None).guardian, None);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::PauseTrio {
+            trio_addr: "trio0000".to_string(),
+            reason: "exploit".to_string(),
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::Unauthorized {} => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+}
+
+#[test]
+fn only_existing_pools_can_be_paused() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+    save_pairs(&mut deps);
+
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        AssetInfo::NativeToken {
+            denom: "uusdc".to_string(),
+        },
+        AssetInfo::NativeToken {
+            denom: "uusdt".to_string(),
+        },
+    ];
+    let raw_infos = [
+        asset_infos[0].to_raw(&deps.api).unwrap(),
+        asset_infos[1].to_raw(&deps.api).unwrap(),
+        asset_infos[2].to_raw(&deps.api).unwrap(),
+    ];
+    TRIOS
+        .save(
+            &mut deps.storage,
+            &trio_key(&raw_infos),
+            &TrioInfoRaw {
+                liquidity_token: AssetInfoRaw::Token {
+                    contract_addr: deps.api.addr_canonicalize("liquidity0000").unwrap(),
+                },
+                contract_addr: deps.api.addr_canonicalize("trio0000").unwrap(),
+                asset_infos: raw_infos,
+                asset_decimals: [6, 6, 6],
+            },
+        )
+        .unwrap();
+
+    // trio0001 claims to be a trio of the same assets, but it was not created by the factory
+    let trio_info = |contract_addr: &str| TrioInfo {
+        asset_infos: asset_infos.clone(),
+        contract_addr: contract_addr.to_string(),
+        liquidity_token: AssetInfo::Token {
+            contract_addr: "liquidity0000".to_string(),
+        },
+        asset_decimals: [6, 6, 6],
+    };
+    deps.querier
+        .with_trios(&[&trio_info("trio0000"), &trio_info("trio0001")]);
+
+    // unknown pair
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::PausePair {
+            pair_addr: "pair0009".to_string(),
+            reason: "exploit".to_string(),
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::UnExistingPair {} => (),
+        _ => panic!("should return ContractError::UnExistingPair"),
+    }
+
+    // unknown trios
+    for trio_addr in ["trio0001", "trio0009"] {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("addr0000", &[]),
+            ExecuteMsg::PauseTrio {
+                trio_addr: trio_addr.to_string(),
+                reason: "exploit".to_string(),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::NonExistantTrio {} => (),
+            _ => panic!("should return ContractError::NonExistantTrio"),
+        }
+    }
+
+    // the pools created by the factory can be paused
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::PausePair {
+            pair_addr: "pair0002".to_string(),
+            reason: "exploit".to_string(),
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::PauseTrio {
+            trio_addr: "trio0000".to_string(),
+            reason: "exploit".to_string(),
+        },
+    )
+    .unwrap();
+}
+
+fn whale_metadata() -> AssetMetadata {
+    AssetMetadata {
+        symbol: "WHALE".to_string(),
+        display_name: "White Whale".to_string(),
+        decimals: 6,
+        logo_uri: Some("https://whitewhale.money/logo.svg".to_string()),
+        ibc_origin_path: None,
+    }
+}
+
+#[test]
+fn register_and_remove_asset_metadata() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let whale = AssetInfo::NativeToken {
+        denom: "uwhale".to_string(),
+    };
+    let atom = AssetInfo::NativeToken {
+        denom: "ibc/atom".to_string(),
+    };
+    let atom_metadata = AssetMetadata {
+        symbol: "ATOM".to_string(),
+        display_name: "Cosmos Hub".to_string(),
+        decimals: 6,
+        logo_uri: None,
+        ibc_origin_path: Some("transfer/channel-0".to_string()),
+    };
+
+    // only the owner can register asset metadata
+    let msg = ExecuteMsg::RegisterAssetMetadata {
+        asset_info: whale.clone(),
+        metadata: whale_metadata(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("addr0001", &[]), msg).unwrap_err();
+    match err {
+        ContractError::Unauthorized {} => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    let msg = ExecuteMsg::RegisterAssetMetadata {
+        asset_info: whale.clone(),
+        metadata: AssetMetadata {
+            symbol: " ".to_string(),
+            ..whale_metadata()
+        },
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap_err();
+    match err {
+        ContractError::EmptyAssetSymbol {} => (),
+        _ => panic!("should return ContractError::EmptyAssetSymbol"),
+    }
+
+    let msg = ExecuteMsg::RegisterAssetMetadata {
+        asset_info: whale.clone(),
+        metadata: whale_metadata(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "register_asset_metadata"),
+            attr("asset_info", "uwhale"),
+            attr("symbol", "WHALE"),
+            attr("decimals", "6"),
+        ]
+    );
+
+    let msg = ExecuteMsg::RegisterAssetMetadata {
+        asset_info: atom.clone(),
+        metadata: atom_metadata.clone(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    // the decimals of native tokens are registered too
+    let res: NativeTokenDecimalsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::NativeTokenDecimals {
+                denom: "uwhale".to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.decimals, 6);
+
+    let res: Option<AssetMetadata> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AssetMetadata {
+                asset_info: whale.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res, Some(whale_metadata()));
+
+    let res: AssetsMetadataResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AssetsMetadata {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.assets,
+        vec![
+            AssetMetadataInfo {
+                asset_info: atom.clone(),
+                metadata: atom_metadata,
+            },
+            AssetMetadataInfo {
+                asset_info: whale.clone(),
+                metadata: whale_metadata(),
+            },
+        ]
+    );
+
+    let res: AssetsMetadataResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AssetsMetadata {
+                start_after: Some(atom),
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.assets,
+        vec![AssetMetadataInfo {
+            asset_info: whale.clone(),
+            metadata: whale_metadata(),
+        }]
+    );
+
+    let msg = ExecuteMsg::RemoveAssetMetadata {
+        asset_info: whale.clone(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "remove_asset_metadata"),
+            attr("asset_info", "uwhale"),
+        ]
+    );
+
+    let res: Option<AssetMetadata> = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::AssetMetadata {
+                asset_info: whale.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res, None);
+
+    let msg = ExecuteMsg::RemoveAssetMetadata { asset_info: whale };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap_err();
+    match err {
+        ContractError::UnknownAsset { .. } => (),
+        _ => panic!("should return ContractError::UnknownAsset"),
+    }
+}
+
+#[test]
+fn create_pair_with_registered_asset_labels() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uwhale".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uwhale".to_string(), 6u8)]);
+
+    let msg = ExecuteMsg::RegisterAssetMetadata {
+        asset_info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        metadata: whale_metadata(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    #[cfg(not(feature = "osmosis"))]
+    let pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
+
+    #[cfg(feature = "osmosis")]
+    let pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+        osmosis_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+    };
+
+    // the registered symbol is used for uwhale, the cw20 symbol for the unregistered token
+    let msg = ExecuteMsg::CreatePair {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0001".to_string(),
+            },
+        ],
+        pool_fees,
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+    let info = mock_info("addr0000", &[coin(1u128, "uwhale")]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "create_pair"),
+            attr("pair", "WHALE-mAAPL"),
+            attr("pair_label", "WHALE-mAAPL pair"),
+            attr("pair_type", "ConstantProduct"),
+            attr("pool_identifier", "1"),
+        ]
+    );
+}
+
+#[test]
+fn update_epoch_manager() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let msg = ExecuteMsg::UpdateEpochManager {
+        epoch_manager_addr: Some("epoch_manager".to_string()),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0001", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    match err {
+        ContractError::Unauthorized {} => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "update_epoch_manager"),
+            attr("epoch_manager_addr", "epoch_manager"),
+        ]
+    );
+    assert_eq!(
+        EPOCH_MANAGER.load(&deps.storage).unwrap(),
+        Addr::unchecked("epoch_manager")
+    );
+
+    // without an epoch manager, the pools only keep their all time stats
+    let msg = ExecuteMsg::UpdateEpochManager {
+        epoch_manager_addr: None,
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let res: CurrentEpochResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::CurrentEpoch {}).unwrap()).unwrap();
+    assert_eq!(res.epoch_id, None);
+}
+
+#[test]
+fn query_pairs_stats() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+    save_pairs(&mut deps);
+
+    let res: PairsStatsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairsStats {
+                start_after: None,
+                start_after_pool_identifier: None,
+                limit: Some(2),
+                epoch_id: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        res.pairs
+            .iter()
+            .map(|pair| pair.pair_info.contract_addr.as_str())
+            .collect::<Vec<_>>(),
+        vec!["pair0000", "pair0001"]
+    );
+
+    // the reserves and total share come from the pool query of the pairs, as mocked
+    let pair_stats = res.pairs.first().unwrap();
+    assert_eq!(pair_stats.assets.len(), 2);
+    assert_eq!(pair_stats.total_share, Uint128::new(2_000_000_000u128));
+    assert_eq!(pair_stats.stats, PoolStats::default());
+
+    // a pair failing to answer is returned empty, without failing the rest of the page
+    deps.querier.with_unresponsive_pools(&["pair0001"]);
+    let res: PairsStatsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairsStats {
+                start_after: None,
+                start_after_pool_identifier: None,
+                limit: Some(2),
+                epoch_id: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(res.pairs[0].assets.len(), 2);
+    let pair_stats = &res.pairs[1];
+    assert_eq!(pair_stats.pair_info.contract_addr, "pair0001");
+    assert!(pair_stats.assets.is_empty());
+    assert_eq!(pair_stats.total_share, Uint128::zero());
+    assert_eq!(pair_stats.stats, PoolStats::default());
+}
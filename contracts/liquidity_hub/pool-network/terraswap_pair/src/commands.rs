@@ -8,24 +8,29 @@ use cosmwasm_std::{
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
+use white_whale_std::pool_network::amp::{validate_amp_ramp, AmpFactorUpdate, AmpRamp};
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use white_whale_std::pool_network::asset::is_factory_token;
 use white_whale_std::pool_network::asset::{
-    get_total_share, Asset, AssetInfo, AssetInfoRaw, PairInfoRaw, MINIMUM_LIQUIDITY_AMOUNT,
+    get_total_share, Asset, AssetInfo, AssetInfoRaw, PairInfoRaw, PairType,
+    MINIMUM_LIQUIDITY_AMOUNT,
 };
 #[cfg(feature = "injective")]
 use white_whale_std::pool_network::denom_injective::{Coin, MsgBurn, MsgMint};
 #[cfg(feature = "osmosis_token_factory")]
 use white_whale_std::pool_network::denom_osmosis::{Coin, MsgBurn, MsgMint};
-use white_whale_std::pool_network::pair::{Config, Cw20HookMsg, FeatureToggle, PoolFee};
+use white_whale_std::pool_network::pair::{
+    Config, Cw20HookMsg, DynamicFeeMode, DynamicFeeUpdate, FeatureToggle, PoolFee,
+};
+use white_whale_std::pool_network::pool_stats::{current_epoch, record_swap_stats};
 use white_whale_std::pool_network::{swap, U256};
 
 use crate::error::ContractError;
 use crate::helpers;
 use crate::helpers::get_protocol_fee_for_asset;
 use crate::state::{
-    store_fee, ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES,
-    CONFIG, PAIR_INFO,
+    load_pair_info, store_fee, ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, AMP_RAMP,
+    COLLECTED_PROTOCOL_FEES, CONFIG, PAIR_INFO,
 };
 
 const MINIMUM_COLLECTABLE_BALANCE: Uint128 = Uint128::new(1_000u128);
@@ -47,6 +52,7 @@ pub fn receive_cw20(
             belief_price,
             max_spread,
             to,
+            trader,
         }) => {
             // check if the swap feature is enabled
             if !feature_toggle.swaps_enabled {
@@ -90,6 +96,7 @@ pub fn receive_cw20(
                 belief_price,
                 max_spread,
                 to_addr,
+                trader,
             )
         }
         Ok(Cw20HookMsg::WithdrawLiquidity {}) => {
@@ -138,7 +145,7 @@ pub fn provide_liquidity(
         asset.assert_sent_native_token_balance(&info)?;
     }
 
-    let pair_info: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
+    let pair_info: PairInfoRaw = load_pair_info(deps.storage, env.block.height)?;
     let mut pools: [Asset; 2] =
         pair_info.query_pools(&deps.querier, deps.api, env.contract.address.clone())?;
     let deposits: [Uint128; 2] = [
@@ -341,10 +348,11 @@ pub fn swap(
     belief_price: Option<Decimal>,
     max_spread: Option<Decimal>,
     to: Option<Addr>,
+    trader: Option<String>,
 ) -> Result<Response, ContractError> {
     offer_asset.assert_sent_native_token_balance(&info)?;
 
-    let pair_info = PAIR_INFO.load(deps.storage)?;
+    let pair_info = load_pair_info(deps.storage, env.block.height)?;
 
     // determine what's the offer and ask pool based on the offer_asset
     let offer_pool: Asset;
@@ -355,9 +363,6 @@ pub fn swap(
     let ask_decimal: u8;
 
     // To calculate pool amounts properly we should subtract user deposit and the protocol fees from the pool
-    #[cfg(not(feature = "osmosis"))]
-    let contract_addr = env.contract.address;
-    #[cfg(feature = "osmosis")]
     let contract_addr = env.contract.address.clone();
 
     let pools = pair_info
@@ -396,11 +401,27 @@ pub fn swap(
     let offer_amount = offer_asset.amount;
     let config = CONFIG.load(deps.storage)?;
 
+    let mut dynamic_swap_fee =
+        helpers::get_dynamic_swap_fee(deps.storage, &config, &pools, env.block.time.seconds())?;
+    helpers::record_price_observation(deps.storage, &config, &pools, env.block.time.seconds())?;
+
+    // a failing fee discount query shouldn't block the swap, so no discount is granted and the
+    // failure is surfaced in the response attributes instead
+    let (fee_discount, fee_discount_error) =
+        match helpers::query_fee_discount(&deps.querier, &config, sender.as_str(), trader) {
+            Ok(fee_discount) => (fee_discount, None),
+            Err(err) => (Decimal::zero(), Some(err.to_string())),
+        };
+
+    let mut pool_fees = config.pool_fees.clone();
+    helpers::apply_fee_discount(&mut pool_fees, &mut dynamic_swap_fee, fee_discount);
+
     let swap_computation = helpers::compute_swap(
         offer_pool.amount,
         ask_pool.amount,
         offer_amount,
-        config.pool_fees,
+        pool_fees,
+        dynamic_swap_fee,
         &pair_info.pair_type,
         offer_decimal,
         ask_decimal,
@@ -505,9 +526,20 @@ pub fn swap(
         ALL_TIME_COLLECTED_PROTOCOL_FEES,
     )?;
 
+    let epoch_id = current_epoch(deps.storage, &deps.querier, &env, &config.owner)?;
+    record_swap_stats(
+        deps.storage,
+        epoch_id,
+        &offer_asset,
+        &Asset {
+            info: ask_pool.info.clone(),
+            amount: swap_computation.swap_fee_amount,
+        },
+    )?;
+
     // 1. send collateral token from the contract to a user
     // 2. stores the protocol fees
-    Ok(Response::new().add_messages(messages).add_attributes(vec![
+    let mut response = Response::new().add_messages(messages).add_attributes(vec![
         ("action", "swap"),
         ("sender", sender.as_str()),
         ("receiver", receiver.as_str()),
@@ -516,6 +548,7 @@ pub fn swap(
         ("offer_amount", &offer_amount.to_string()),
         ("return_amount", &swap_computation.return_amount.to_string()),
         ("spread_amount", &swap_computation.spread_amount.to_string()),
+        ("swap_fee", &swap_computation.swap_fee.to_string()),
         (
             "swap_fee_amount",
             &swap_computation.swap_fee_amount.to_string(),
@@ -534,18 +567,28 @@ pub fn swap(
             &swap_computation.osmosis_fee_amount.to_string(),
         ),
         ("swap_type", pair_info.pair_type.get_label()),
-    ]))
+    ]);
+
+    if let Some(fee_discount_error) = fee_discount_error {
+        response = response.add_attribute("fee_discount_error", fee_discount_error);
+    }
+
+    Ok(response)
 }
 
 #[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
 /// Updates the [Config] of the contract. Only the owner of the contract can do this.
 pub fn update_config(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     owner: Option<String>,
     fee_collector_addr: Option<String>,
     pool_fees: Option<PoolFee>,
     feature_toggle: Option<FeatureToggle>,
+    amp_factor: Option<AmpFactorUpdate>,
+    dynamic_fee: Option<DynamicFeeUpdate>,
     cosmwasm_pool_interface: Option<String>,
 ) -> Result<Response, ContractError> {
     let mut config: Config = CONFIG.load(deps.storage)?;
@@ -568,6 +611,59 @@ pub fn update_config(
         config.feature_toggle = feature_toggle;
     }
 
+    if let Some(amp_factor) = amp_factor {
+        let PairType::StableSwap { amp: current_amp } =
+            load_pair_info(deps.storage, env.block.height)?.pair_type
+        else {
+            return Err(ContractError::AmpUpdateOnNonStableSwap {});
+        };
+
+        let amp_ramp = match amp_factor {
+            AmpFactorUpdate::Ramp(ramp) => {
+                validate_amp_ramp(current_amp, &ramp, env.block.height)?;
+
+                AmpRamp {
+                    initial_amp: current_amp,
+                    future_amp: ramp.future_a,
+                    initial_amp_block: env.block.height,
+                    future_amp_block: ramp.future_block,
+                }
+            }
+            // freeze the amp factor where it currently is
+            AmpFactorUpdate::StopRamp {} => AmpRamp {
+                initial_amp: current_amp,
+                future_amp: current_amp,
+                initial_amp_block: env.block.height,
+                future_amp_block: env.block.height,
+            },
+        };
+
+        AMP_RAMP.save(deps.storage, &amp_ramp)?;
+    }
+
+    if let Some(dynamic_fee) = dynamic_fee {
+        config.dynamic_fee = match dynamic_fee {
+            DynamicFeeUpdate::Enable(dynamic_fee) => {
+                if matches!(dynamic_fee.mode, DynamicFeeMode::Imbalance { .. })
+                    && !matches!(
+                        PAIR_INFO.load(deps.storage)?.pair_type,
+                        PairType::StableSwap { .. }
+                    )
+                {
+                    return Err(ContractError::ImbalanceFeeOnNonStableSwap {});
+                }
+
+                Some(dynamic_fee)
+            }
+            DynamicFeeUpdate::Disable {} => None,
+        };
+    }
+
+    // the dynamic fee bounds are validated against the pool fees, which might have been updated
+    if let Some(dynamic_fee) = &config.dynamic_fee {
+        dynamic_fee.is_valid(&config.pool_fees)?;
+    }
+
     if let Some(fee_collector_addr) = fee_collector_addr {
         config.fee_collector_addr = deps.api.addr_validate(fee_collector_addr.as_str())?;
     }
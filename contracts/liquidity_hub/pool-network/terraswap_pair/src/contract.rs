@@ -72,6 +72,7 @@ pub fn instantiate(
             deposits_enabled: true,
             swaps_enabled: true,
         },
+        dynamic_fee: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -84,6 +85,7 @@ pub fn instantiate(
             deposits_enabled: true,
             swaps_enabled: true,
         },
+        dynamic_fee: None,
         cosmwasm_pool_interface: Addr::unchecked(""),
     };
 
@@ -145,6 +147,7 @@ pub fn execute(
             belief_price,
             max_spread,
             to,
+            trader,
         } => {
             // check if the swap feature is enabled
             let feature_toggle: FeatureToggle = CONFIG.load(deps.storage)?.feature_toggle;
@@ -171,6 +174,7 @@ pub fn execute(
                 belief_price,
                 max_spread,
                 to_addr,
+                trader,
             )
         }
         #[cfg(feature = "osmosis")]
@@ -179,14 +183,19 @@ pub fn execute(
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            amp_factor,
+            dynamic_fee,
             cosmwasm_pool_interface,
         } => commands::update_config(
             deps,
+            env,
             info,
             owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            amp_factor,
+            dynamic_fee,
             cosmwasm_pool_interface,
         ),
         #[cfg(not(feature = "osmosis"))]
@@ -195,13 +204,18 @@ pub fn execute(
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            amp_factor,
+            dynamic_fee,
         } => commands::update_config(
             deps,
+            env,
             info,
             owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
+            amp_factor,
+            dynamic_fee,
             None,
         ),
         ExecuteMsg::CollectProtocolFees {} => commands::collect_protocol_fees(deps),
@@ -230,16 +244,30 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
-        QueryMsg::Pair {} => Ok(to_json_binary(&queries::query_pair_info(deps)?)?),
+        QueryMsg::Pair {} => Ok(to_json_binary(&queries::query_pair_info(deps, &env)?)?),
         QueryMsg::Pool {} => Ok(to_json_binary(&queries::query_pool(deps)?)?),
         QueryMsg::Simulation { offer_asset } => Ok(to_json_binary(&queries::query_simulation(
             deps,
+            &env,
             offer_asset,
+            None,
         )?)?),
         QueryMsg::ReverseSimulation { ask_asset } => Ok(to_json_binary(
-            &queries::query_reverse_simulation(deps, ask_asset)?,
+            &queries::query_reverse_simulation(deps, &env, ask_asset, None)?,
+        )?),
+        QueryMsg::DiscountedSimulation {
+            offer_asset,
+            trader,
+        } => Ok(to_json_binary(&queries::query_simulation(
+            deps,
+            &env,
+            offer_asset,
+            Some(trader),
+        )?)?),
+        QueryMsg::DiscountedReverseSimulation { ask_asset, trader } => Ok(to_json_binary(
+            &queries::query_reverse_simulation(deps, &env, ask_asset, Some(trader))?,
         )?),
         QueryMsg::Config {} => Ok(to_json_binary(&queries::query_config(deps)?)?),
         QueryMsg::ProtocolFees { asset_id, all_time } => Ok(to_json_binary(&queries::query_fees(
@@ -256,6 +284,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
             ALL_TIME_BURNED_FEES,
             None,
         )?)?),
+        QueryMsg::PoolStats { epoch_id } => {
+            Ok(to_json_binary(&queries::query_pool_stats(deps, epoch_id)?)?)
+        }
     }
 }
 
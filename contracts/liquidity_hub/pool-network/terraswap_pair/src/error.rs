@@ -69,6 +69,12 @@ pub enum ContractError {
 
     #[error("The token factory feature is not enabled")]
     TokenFactoryNotEnabled {},
+
+    #[error("The amp factor can only be updated on StableSwap pairs")]
+    AmpUpdateOnNonStableSwap {},
+
+    #[error("The imbalance dynamic fee can only be set on StableSwap pairs")]
+    ImbalanceFeeOnNonStableSwap {},
 }
 
 impl From<semver::Error> for ContractError {
@@ -4,24 +4,31 @@ use cosmwasm_schema::cw_serde;
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use cosmwasm_std::CosmosMsg;
 use cosmwasm_std::{
-    to_json_binary, Decimal, Decimal256, DepsMut, Env, ReplyOn, Response, StdError, StdResult,
-    Storage, SubMsg, Uint128, Uint256, WasmMsg,
+    to_json_binary, Decimal, Decimal256, DepsMut, Env, QuerierWrapper, ReplyOn, Response, StdError,
+    StdResult, Storage, SubMsg, Uint128, Uint256, WasmMsg,
 };
 use cw20::MinterResponse;
 use cw_storage_plus::Item;
 
+use white_whale_std::fee::Fee;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, AssetInfoRaw, PairType};
 #[cfg(feature = "injective")]
 use white_whale_std::pool_network::denom_injective::MsgCreateDenom;
 #[cfg(feature = "osmosis_token_factory")]
 use white_whale_std::pool_network::denom_osmosis::MsgCreateDenom;
-use white_whale_std::pool_network::pair::{InstantiateMsg, PoolFee};
+use white_whale_std::pool_network::factory::{FeeDiscountResponse, QueryMsg as FactoryQueryMsg};
+use white_whale_std::pool_network::pair::{
+    Config, DynamicFee, DynamicFeeMode, InstantiateMsg, PoolFee, MAX_PRICE_OBSERVATIONS,
+};
 use white_whale_std::pool_network::token::InstantiateMsg as TokenInstantiateMsg;
 
 use crate::contract::INSTANTIATE_REPLY_ID;
 use crate::error::ContractError;
 use crate::math::Decimal256Helper;
-use crate::state::{LP_SYMBOL, PAIR_INFO};
+use crate::state::{
+    load_price_observations, PriceObservation, LATEST_PRICE_OBSERVATION_SLOT, LP_SYMBOL, PAIR_INFO,
+    PRICE_OBSERVATIONS,
+};
 
 /// The amount of iterations to perform when calculating the Newton-Raphson approximation.
 const NEWTON_ITERATIONS: u64 = 32;
@@ -151,11 +158,45 @@ pub fn calculate_stableswap_y(
     Err(ContractError::ConvergeError {})
 }
 
+/// A [DynamicFee] together with the recent price volatility of the pool, used by [compute_swap]
+/// to scale the swap fee.
+pub struct DynamicSwapFee {
+    pub dynamic_fee: DynamicFee,
+    pub volatility: Decimal,
+}
+
+/// Gets the swap fee to charge. `imbalance` is the imbalance of the pool after the swap, which is
+/// only used by StableSwap pairs.
+pub fn get_swap_fee(
+    pool_fees: &PoolFee,
+    dynamic_swap_fee: &Option<DynamicSwapFee>,
+    imbalance: Decimal,
+) -> Fee {
+    match dynamic_swap_fee {
+        None => pool_fees.swap_fee.clone(),
+        Some(DynamicSwapFee {
+            dynamic_fee,
+            volatility,
+        }) => match dynamic_fee.mode {
+            DynamicFeeMode::Volatility { max_volatility, .. } => {
+                dynamic_fee.scale(*volatility, max_volatility)
+            }
+            DynamicFeeMode::Imbalance { max_imbalance } => {
+                dynamic_fee.scale(imbalance, max_imbalance)
+            }
+        },
+    }
+}
+
+/// Computes the swap. If a [DynamicSwapFee] is given, the swap fee is scaled according to it
+/// instead of using the swap fee of the [PoolFee].
+#[allow(clippy::too_many_arguments)]
 pub fn compute_swap(
     offer_pool: Uint128,
     ask_pool: Uint128,
     offer_amount: Uint128,
     pool_fees: PoolFee,
+    dynamic_swap_fee: Option<DynamicSwapFee>,
     swap_type: &PairType,
     offer_precision: u8,
     ask_precision: u8,
@@ -174,7 +215,8 @@ pub fn compute_swap(
             // calculate spread, swap and protocol fees
             let exchange_rate = Decimal256::from_ratio(ask_pool, offer_pool);
             let spread_amount: Uint256 = (offer_amount * exchange_rate) - return_amount;
-            let swap_fee_amount: Uint256 = pool_fees.swap_fee.compute(return_amount);
+            let swap_fee = get_swap_fee(&pool_fees, &dynamic_swap_fee, Decimal::zero());
+            let swap_fee_amount: Uint256 = swap_fee.compute(return_amount);
             let protocol_fee_amount: Uint256 = pool_fees.protocol_fee.compute(return_amount);
             let burn_fee_amount: Uint256 = pool_fees.burn_fee.compute(return_amount);
 
@@ -191,6 +233,7 @@ pub fn compute_swap(
                     spread_amount: spread_amount
                         .try_into()
                         .map_err(|_| ContractError::SwapOverflowError {})?,
+                    swap_fee: swap_fee.share,
                     swap_fee_amount: swap_fee_amount
                         .try_into()
                         .map_err(|_| ContractError::SwapOverflowError {})?,
@@ -220,6 +263,7 @@ pub fn compute_swap(
                     spread_amount: spread_amount
                         .try_into()
                         .map_err(|_| ContractError::SwapOverflowError {})?,
+                    swap_fee: swap_fee.share,
                     swap_fee_amount: swap_fee_amount
                         .try_into()
                         .map_err(|_| ContractError::SwapOverflowError {})?,
@@ -259,8 +303,24 @@ pub fn compute_swap(
                 .to_uint256_with_precision(u32::from(ask_precision))?
                 .saturating_sub(return_amount);
 
+            // the imbalance of the pool after the swap, used by the imbalance dynamic fee
+            let new_offer_pool = offer_pool.checked_add(offer_amount)?;
+            let new_ask_pool = Decimal256::decimal_with_precision(new_pool, ask_precision)?;
+            let new_pools_sum = new_offer_pool.checked_add(new_ask_pool)?;
+            let imbalance = if new_pools_sum.is_zero() {
+                Decimal::zero()
+            } else {
+                Decimal::try_from(
+                    new_offer_pool
+                        .abs_diff(new_ask_pool)
+                        .checked_div(new_pools_sum)?,
+                )
+                .map_err(|e| StdError::generic_err(e.to_string()))?
+            };
+
             // subtract fees from return_amount
-            let swap_fee_amount: Uint256 = pool_fees.swap_fee.compute(return_amount);
+            let swap_fee = get_swap_fee(&pool_fees, &dynamic_swap_fee, imbalance);
+            let swap_fee_amount: Uint256 = swap_fee.compute(return_amount);
             let protocol_fee_amount: Uint256 = pool_fees.protocol_fee.compute(return_amount);
             let burn_fee_amount: Uint256 = pool_fees.burn_fee.compute(return_amount);
 
@@ -278,6 +338,7 @@ pub fn compute_swap(
                     spread_amount: spread_amount
                         .try_into()
                         .map_err(|_| ContractError::SwapOverflowError {})?,
+                    swap_fee: swap_fee.share,
                     swap_fee_amount: swap_fee_amount
                         .try_into()
                         .map_err(|_| ContractError::SwapOverflowError {})?,
@@ -307,6 +368,7 @@ pub fn compute_swap(
                     spread_amount: spread_amount
                         .try_into()
                         .map_err(|_| ContractError::SwapOverflowError {})?,
+                    swap_fee: swap_fee.share,
                     swap_fee_amount: swap_fee_amount
                         .try_into()
                         .map_err(|_| ContractError::SwapOverflowError {})?,
@@ -325,11 +387,161 @@ pub fn compute_swap(
     }
 }
 
+/// Gets the [DynamicSwapFee] of the pool if it has a dynamic fee set, computing the price
+/// volatility from the recorded observations and the current `pools`.
+pub fn get_dynamic_swap_fee(
+    storage: &dyn Storage,
+    config: &Config,
+    pools: &[Asset],
+    now: u64,
+) -> StdResult<Option<DynamicSwapFee>> {
+    let Some(dynamic_fee) = config.dynamic_fee.clone() else {
+        return Ok(None);
+    };
+
+    let volatility = match dynamic_fee.mode {
+        DynamicFeeMode::Volatility { window, .. } => {
+            let observations = load_price_observations(storage)?;
+            compute_volatility(&observations, get_pool_price(pools), now, window)
+        }
+        DynamicFeeMode::Imbalance { .. } => Decimal::zero(),
+    };
+
+    Ok(Some(DynamicSwapFee {
+        dynamic_fee,
+        volatility,
+    }))
+}
+
+/// Records the current price of the pool if it has a volatility dynamic fee set. Observations are
+/// kept in a ring buffer of [MAX_PRICE_OBSERVATIONS] slots, overwriting the oldest one. The ones
+/// that fell out of the volatility window are ignored when computing the volatility.
+pub fn record_price_observation(
+    storage: &mut dyn Storage,
+    config: &Config,
+    pools: &[Asset],
+    now: u64,
+) -> StdResult<()> {
+    let Some(DynamicFee {
+        mode: DynamicFeeMode::Volatility { .. },
+        ..
+    }) = config.dynamic_fee
+    else {
+        return Ok(());
+    };
+
+    let Some(price) = get_pool_price(pools) else {
+        return Ok(());
+    };
+
+    let slot = match LATEST_PRICE_OBSERVATION_SLOT.may_load(storage)? {
+        // keep a single observation per timestamp
+        Some(latest_slot) if PRICE_OBSERVATIONS.load(storage, latest_slot)?.timestamp == now => {
+            latest_slot
+        }
+        Some(latest_slot) => (latest_slot + 1) % MAX_PRICE_OBSERVATIONS,
+        None => 0,
+    };
+
+    PRICE_OBSERVATIONS.save(
+        storage,
+        slot,
+        &PriceObservation {
+            timestamp: now,
+            price,
+        },
+    )?;
+    LATEST_PRICE_OBSERVATION_SLOT.save(storage, &slot)
+}
+
+/// Gets the price of the pool, i.e. the amount of the second asset per unit of the first. Returns
+/// `None` if any of the pools is empty.
+fn get_pool_price(pools: &[Asset]) -> Option<Decimal256> {
+    if pools[0].amount.is_zero() || pools[1].amount.is_zero() {
+        return None;
+    }
+
+    Some(Decimal256::from_ratio(pools[1].amount, pools[0].amount))
+}
+
+/// Computes the price volatility over the last `window` seconds, as the relative difference
+/// between the highest and lowest price observed, including the `current_price`.
+pub fn compute_volatility(
+    observations: &[PriceObservation],
+    current_price: Option<Decimal256>,
+    now: u64,
+    window: u64,
+) -> Decimal {
+    let (min_price, max_price) = match observations
+        .iter()
+        .filter(|observation| observation.timestamp.saturating_add(window) >= now)
+        .map(|observation| observation.price)
+        .chain(current_price)
+        .fold(None, |bounds: Option<(Decimal256, Decimal256)>, price| {
+            Some(match bounds {
+                None => (price, price),
+                Some((min, max)) => (min.min(price), max.max(price)),
+            })
+        }) {
+        Some(bounds) => bounds,
+        None => return Decimal::zero(),
+    };
+
+    if min_price.is_zero() {
+        return Decimal::zero();
+    }
+
+    // a volatility too big to fit in a Decimal is way past any sensible max volatility anyway
+    Decimal::try_from((max_price - min_price) / min_price).unwrap_or(Decimal::MAX)
+}
+
+/// Queries the swap fee discount of the given sender on the factory, i.e. the owner of the pair.
+/// If the sender is a router trusted by the factory, the discount of the given trader is granted
+/// instead.
+pub fn query_fee_discount(
+    querier: &QuerierWrapper,
+    config: &Config,
+    sender: &str,
+    trader: Option<String>,
+) -> StdResult<Decimal> {
+    let response: FeeDiscountResponse = querier.query_wasm_smart(
+        &config.owner,
+        &FactoryQueryMsg::FeeDiscount {
+            address: sender.to_string(),
+            trader,
+        },
+    )?;
+
+    Ok(response.discount.min(Decimal::one()))
+}
+
+/// Applies a trader's fee discount to the swap and protocol fees, including the bounds of the
+/// dynamic swap fee, if any.
+pub fn apply_fee_discount(
+    pool_fees: &mut PoolFee,
+    dynamic_swap_fee: &mut Option<DynamicSwapFee>,
+    discount: Decimal,
+) {
+    if discount.is_zero() {
+        return;
+    }
+
+    let multiplier = Decimal::one() - discount;
+    pool_fees.swap_fee.share *= multiplier;
+    pool_fees.protocol_fee.share *= multiplier;
+
+    if let Some(DynamicSwapFee { dynamic_fee, .. }) = dynamic_swap_fee {
+        dynamic_fee.min_fee.share *= multiplier;
+        dynamic_fee.max_fee.share *= multiplier;
+    }
+}
+
 /// Represents the swap computation values
 #[cw_serde]
 pub struct SwapComputation {
     pub return_amount: Uint128,
     pub spread_amount: Uint128,
+    pub swap_fee: Decimal,
     pub swap_fee_amount: Uint128,
     pub protocol_fee_amount: Uint128,
     pub burn_fee_amount: Uint128,
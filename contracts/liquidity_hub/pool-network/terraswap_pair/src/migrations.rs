@@ -106,6 +106,7 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
             },
         },
         feature_toggle: config_v110.feature_toggle,
+        dynamic_fee: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -202,6 +203,7 @@ pub fn migrate_to_v13x(deps: DepsMut) -> Result<(), StdError> {
             },
         },
         feature_toggle: config_v110.feature_toggle,
+        dynamic_fee: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -297,6 +299,7 @@ pub fn migrate_to_v135(deps: DepsMut) -> Result<(), StdError> {
         fee_collector_addr: config_v133.fee_collector_addr,
         pool_fees: config_v133.pool_fees,
         feature_toggle: config_v133.feature_toggle,
+        dynamic_fee: None,
         // set the cosmwasm pool interface to empty for now
         cosmwasm_pool_interface: Addr::unchecked(""),
     };
@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use cosmwasm_std::{Decimal256, Deps, Fraction, StdResult, Uint128};
+use cosmwasm_std::{Decimal, Decimal256, Deps, Env, Fraction, StdResult, Uint128};
 use cw_storage_plus::Item;
 
 use white_whale_std::pool_network::asset::{
@@ -10,17 +10,20 @@ use white_whale_std::pool_network::pair::{
     ConfigResponse, PoolResponse, ProtocolFeesResponse, ReverseSimulationResponse,
     SimulationResponse,
 };
+use white_whale_std::pool_network::pool_stats::{PoolStats, EPOCH_POOL_STATS, POOL_STATS};
 
 use crate::error::ContractError;
 use crate::helpers::{
     self, calculate_stableswap_y, get_protocol_fee_for_asset, StableSwapDirection,
 };
 use crate::math::Decimal256Helper;
-use crate::state::{get_fees_for_asset, COLLECTED_PROTOCOL_FEES, CONFIG, PAIR_INFO};
+use crate::state::{
+    get_fees_for_asset, load_pair_info, COLLECTED_PROTOCOL_FEES, CONFIG, PAIR_INFO,
+};
 
 /// Queries the [PairInfo] of the pool
-pub fn query_pair_info(deps: Deps) -> Result<PairInfo, ContractError> {
-    let pair_info: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
+pub fn query_pair_info(deps: Deps, env: &Env) -> Result<PairInfo, ContractError> {
+    let pair_info: PairInfoRaw = load_pair_info(deps.storage, env.block.height)?;
     let pair_info = pair_info.to_normal(deps.api)?;
 
     Ok(pair_info)
@@ -64,12 +67,15 @@ pub fn query_pool(deps: Deps) -> Result<PoolResponse, ContractError> {
     Ok(resp)
 }
 
-/// Queries a swap simulation. Used to know how much the target asset will be returned for the source token
+/// Queries a swap simulation. Used to know how much the target asset will be returned for the source token.
+/// If a trader is given, its fee discount is applied.
 pub fn query_simulation(
     deps: Deps,
+    env: &Env,
     offer_asset: Asset,
+    trader: Option<String>,
 ) -> Result<SimulationResponse, ContractError> {
-    let pair_info = PAIR_INFO.load(deps.storage)?;
+    let pair_info = load_pair_info(deps.storage, env.block.height)?;
 
     let contract_addr = deps.api.addr_humanize(&pair_info.contract_addr)?;
 
@@ -111,13 +117,25 @@ pub fn query_simulation(
         return Err(ContractError::AssetMismatch {});
     }
 
-    let pool_fees = CONFIG.load(deps.storage)?.pool_fees;
+    let config = CONFIG.load(deps.storage)?;
+    let mut dynamic_swap_fee =
+        helpers::get_dynamic_swap_fee(deps.storage, &config, &pools, env.block.time.seconds())?;
+
+    let mut pool_fees = config.pool_fees.clone();
+    if let Some(trader) = trader {
+        helpers::apply_fee_discount(
+            &mut pool_fees,
+            &mut dynamic_swap_fee,
+            helpers::query_fee_discount(&deps.querier, &config, &trader, None)?,
+        );
+    }
 
     let swap_computation = helpers::compute_swap(
         offer_pool.amount,
         ask_pool.amount,
         offer_asset.amount,
         pool_fees,
+        dynamic_swap_fee,
         &pair_info.pair_type,
         offer_decimal,
         ask_decimal,
@@ -128,6 +146,7 @@ pub fn query_simulation(
         Ok(SimulationResponse {
             return_amount: swap_computation.return_amount,
             spread_amount: swap_computation.spread_amount,
+            swap_fee: swap_computation.swap_fee,
             swap_fee_amount: swap_computation.swap_fee_amount,
             protocol_fee_amount: swap_computation.protocol_fee_amount,
             burn_fee_amount: swap_computation.burn_fee_amount,
@@ -139,6 +158,7 @@ pub fn query_simulation(
         Ok(SimulationResponse {
             return_amount: swap_computation.return_amount,
             spread_amount: swap_computation.spread_amount,
+            swap_fee: swap_computation.swap_fee,
             swap_fee_amount: swap_computation.swap_fee_amount,
             protocol_fee_amount: swap_computation.protocol_fee_amount,
             burn_fee_amount: swap_computation.burn_fee_amount,
@@ -148,12 +168,14 @@ pub fn query_simulation(
 }
 
 /// Queries a swap reverse simulation. Used to derive the number of source tokens returned for
-/// the number of target tokens.
+/// the number of target tokens. If a trader is given, its fee discount is applied.
 pub fn query_reverse_simulation(
     deps: Deps,
+    env: &Env,
     ask_asset: Asset,
+    trader: Option<String>,
 ) -> Result<ReverseSimulationResponse, ContractError> {
-    let pair_info: PairInfoRaw = PAIR_INFO.load(deps.storage)?;
+    let pair_info: PairInfoRaw = load_pair_info(deps.storage, env.block.height)?;
 
     let contract_addr = deps.api.addr_humanize(&pair_info.contract_addr)?;
 
@@ -194,7 +216,22 @@ pub fn query_reverse_simulation(
         return Err(ContractError::AssetMismatch {});
     }
 
-    let pool_fees = CONFIG.load(deps.storage)?.pool_fees;
+    let config = CONFIG.load(deps.storage)?;
+    let mut dynamic_swap_fee =
+        helpers::get_dynamic_swap_fee(deps.storage, &config, &pools, env.block.time.seconds())?;
+
+    let mut pool_fees = config.pool_fees.clone();
+    if let Some(trader) = trader {
+        helpers::apply_fee_discount(
+            &mut pool_fees,
+            &mut dynamic_swap_fee,
+            helpers::query_fee_discount(&deps.querier, &config, &trader, None)?,
+        );
+    }
+
+    // the imbalance after the swap is not known before computing the offer amount, so the max fee
+    // is quoted when the pair has an imbalance dynamic fee
+    pool_fees.swap_fee = helpers::get_swap_fee(&pool_fees, &dynamic_swap_fee, Decimal::one());
 
     match pair_info.pair_type {
         PairType::ConstantProduct => {
@@ -337,3 +374,13 @@ pub fn query_fees(
     let fees = fees_storage_item.load(deps.storage)?;
     Ok(ProtocolFeesResponse { fees })
 }
+
+/// Queries the swap statistics of the pool since its inception, or for the given epoch.
+pub fn query_pool_stats(deps: Deps, epoch_id: Option<u64>) -> StdResult<PoolStats> {
+    let pool_stats = match epoch_id {
+        Some(epoch_id) => EPOCH_POOL_STATS.may_load(deps.storage, epoch_id)?,
+        None => POOL_STATS.may_load(deps.storage)?,
+    };
+
+    Ok(pool_stats.unwrap_or_default())
+}
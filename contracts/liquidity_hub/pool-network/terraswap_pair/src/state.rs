@@ -1,13 +1,17 @@
-use cosmwasm_std::{StdError, StdResult, Storage, Uint128};
-use cw_storage_plus::Item;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal256, Order, StdError, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
 
-use white_whale_std::pool_network::asset::{Asset, PairInfoRaw};
+use white_whale_std::pool_network::amp::AmpRamp;
+use white_whale_std::pool_network::asset::{Asset, PairInfoRaw, PairType};
 use white_whale_std::pool_network::pair::Config;
 
 pub const LP_SYMBOL: &str = "uLP";
 
 pub const PAIR_INFO: Item<PairInfoRaw> = Item::new("pair_info");
 pub const CONFIG: Item<Config> = Item::new("config");
+// The ramp of the amplification coefficient of StableSwap pairs, if any was ever started
+pub const AMP_RAMP: Item<AmpRamp> = Item::new("amp_ramp");
 
 // Fees that have been accrued by the pool, still unclaimed by the fee collector
 pub const COLLECTED_PROTOCOL_FEES: Item<Vec<Asset>> = Item::new("collected_protocol_fees");
@@ -16,6 +20,43 @@ pub const ALL_TIME_COLLECTED_PROTOCOL_FEES: Item<Vec<Asset>> =
     Item::new("all_time_collected_protocol_fees");
 // Fees that have been burned by the pool since the pool's inception
 pub const ALL_TIME_BURNED_FEES: Item<Vec<Asset>> = Item::new("all_time_burned_fees");
+/// The price of the pool, i.e. the amount of the second asset per unit of the first, at a given
+/// time in seconds.
+#[cw_serde]
+pub struct PriceObservation {
+    pub timestamp: u64,
+    pub price: Decimal256,
+}
+
+// Recent price observations of the pool, recorded on swaps when the volatility dynamic fee is set.
+// It's a ring buffer keyed by slot, so recording an observation only writes a single entry.
+pub const PRICE_OBSERVATIONS: Map<u64, PriceObservation> = Map::new("price_observations");
+// The slot of the ring buffer the latest price observation was written to
+pub const LATEST_PRICE_OBSERVATION_SLOT: Item<u64> = Item::new("latest_price_observation_slot");
+
+/// Loads the price observations kept in the ring buffer, in no particular order.
+pub fn load_price_observations(storage: &dyn Storage) -> StdResult<Vec<PriceObservation>> {
+    PRICE_OBSERVATIONS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| Ok(item?.1))
+        .collect()
+}
+
+/// Loads the [PairInfoRaw] of the pool. For StableSwap pairs, the amplification coefficient is the
+/// one at the given block height, taking into account any amp ramp.
+pub fn load_pair_info(storage: &dyn Storage, block_height: u64) -> StdResult<PairInfoRaw> {
+    let mut pair_info = PAIR_INFO.load(storage)?;
+
+    if let PairType::StableSwap { amp } = &mut pair_info.pair_type {
+        if let Some(amp_ramp) = AMP_RAMP.may_load(storage)? {
+            *amp = amp_ramp
+                .amp_at(block_height)
+                .ok_or_else(|| StdError::generic_err("Failed to compute the amp factor"))?;
+        }
+    }
+
+    Ok(pair_info)
+}
 
 /// Stores the fee for an asset in the given fees_storage_item
 pub fn store_fee(
@@ -0,0 +1,422 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    attr, coins, from_json, Coin, Decimal, Decimal256, Reply, StdError, SubMsgResponse,
+    SubMsgResult, Uint128,
+};
+
+use white_whale_std::fee::Fee;
+use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairType};
+use white_whale_std::pool_network::mock_querier::mock_dependencies;
+use white_whale_std::pool_network::pair::{
+    DynamicFee, DynamicFeeMode, DynamicFeeUpdate, ExecuteMsg, InstantiateMsg, PoolFee, QueryMsg,
+    SimulationResponse, MAX_PRICE_OBSERVATIONS,
+};
+
+use crate::contract::{execute, instantiate, query, reply};
+use crate::error::ContractError;
+use crate::helpers::{compute_swap, record_price_observation, DynamicSwapFee};
+use crate::state::{
+    load_price_observations, PriceObservation, CONFIG, LATEST_PRICE_OBSERVATION_SLOT,
+};
+
+#[cfg(not(feature = "osmosis"))]
+fn pool_fees() -> PoolFee {
+    PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::zero(),
+        },
+        swap_fee: Fee {
+            share: Decimal::permille(3),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    }
+}
+
+#[cfg(not(feature = "osmosis"))]
+fn dynamic_fee(mode: DynamicFeeMode) -> DynamicFee {
+    DynamicFee {
+        min_fee: Fee {
+            share: Decimal::permille(1),
+        },
+        max_fee: Fee {
+            share: Decimal::percent(1),
+        },
+        mode,
+    }
+}
+
+#[cfg(not(feature = "osmosis"))]
+fn update_dynamic_fee_msg(dynamic_fee: DynamicFeeUpdate) -> ExecuteMsg {
+    ExecuteMsg::UpdateConfig {
+        owner: None,
+        fee_collector_addr: None,
+        pool_fees: None,
+        feature_toggle: None,
+        amp_factor: None,
+        dynamic_fee: Some(dynamic_fee),
+    }
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn compute_swap_with_imbalance_dynamic_fee() {
+    let pool = Uint128::from(1_000_000_000u128);
+    let dynamic_swap_fee = || {
+        Some(DynamicSwapFee {
+            dynamic_fee: dynamic_fee(DynamicFeeMode::Imbalance {
+                max_imbalance: Decimal::percent(10),
+            }),
+            volatility: Decimal::zero(),
+        })
+    };
+
+    // a small swap barely moves the pool away from balance, so the fee stays close to the min
+    let swap_computation = compute_swap(
+        pool,
+        pool,
+        Uint128::from(1_000_000u128),
+        pool_fees(),
+        dynamic_swap_fee(),
+        &PairType::StableSwap { amp: 100 },
+        6,
+        6,
+    )
+    .unwrap();
+    assert!(swap_computation.swap_fee > Decimal::permille(1));
+    assert!(swap_computation.swap_fee < Decimal::permille(2));
+
+    // a big swap imbalances the pool beyond the max imbalance, so the max fee is charged
+    let swap_computation = compute_swap(
+        pool,
+        pool,
+        Uint128::from(500_000_000u128),
+        pool_fees(),
+        dynamic_swap_fee(),
+        &PairType::StableSwap { amp: 100 },
+        6,
+        6,
+    )
+    .unwrap();
+    assert_eq!(swap_computation.swap_fee, Decimal::percent(1));
+
+    // without a dynamic fee, the swap fee of the pool is used
+    let swap_computation = compute_swap(
+        pool,
+        pool,
+        Uint128::from(500_000_000u128),
+        pool_fees(),
+        None,
+        &PairType::StableSwap { amp: 100 },
+        6,
+        6,
+    )
+    .unwrap();
+    assert_eq!(swap_computation.swap_fee, Decimal::permille(3));
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn volatility_dynamic_fee_scales_with_price_moves() {
+    let pool = Uint128::from(1_000_000_000u128);
+    let offer_amount = Uint128::from(1_000u128);
+
+    let mut deps = mock_dependencies(&[Coin {
+        denom: "uusd".to_string(),
+        amount: pool + offer_amount,
+        /* user deposit must be pre-applied */
+    }]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &pool)],
+        ),
+        (
+            &"asset0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &pool)],
+        ),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 6u8],
+        pool_fees: pool_fees(),
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+
+    let mut env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        update_dynamic_fee_msg(DynamicFeeUpdate::Enable(dynamic_fee(
+            DynamicFeeMode::Volatility {
+                window: 3_600,
+                max_volatility: Decimal::percent(10),
+            },
+        ))),
+    )
+    .unwrap();
+
+    // the price hasn't moved yet, so the min fee is charged
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &coins(offer_amount.u128(), "uusd")),
+        ExecuteMsg::Swap {
+            offer_asset: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                amount: offer_amount,
+            },
+            belief_price: None,
+            max_spread: None,
+            to: None,
+            trader: None,
+        },
+    )
+    .unwrap();
+    assert!(res
+        .attributes
+        .contains(&attr("swap_fee", Decimal::permille(1).to_string())));
+
+    // the price moves by 5%, half of the max volatility
+    deps.querier
+        .with_balance(&[(&MOCK_CONTRACT_ADDR.to_string(), coins(pool.u128(), "uusd"))]);
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &pool)],
+        ),
+        (
+            &"asset0000".to_string(),
+            &[(
+                &MOCK_CONTRACT_ADDR.to_string(),
+                &Uint128::from(1_050_000_000u128),
+            )],
+        ),
+    ]);
+
+    let simulation = |env| -> SimulationResponse {
+        from_json(
+            query(
+                deps.as_ref(),
+                env,
+                QueryMsg::Simulation {
+                    offer_asset: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uusd".to_string(),
+                        },
+                        amount: offer_amount,
+                    },
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+
+    env.block.time = env.block.time.plus_seconds(60);
+    assert_eq!(
+        simulation(env.clone()).swap_fee,
+        Decimal::from_ratio(55u128, 10_000u128)
+    );
+
+    // once the observation falls out of the window, the fee goes back to the min
+    env.block.time = env.block.time.plus_seconds(3_600);
+    assert_eq!(simulation(env).swap_fee, Decimal::permille(1));
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn cannot_set_invalid_dynamic_fee() {
+    let mut deps = mock_dependencies(&[]);
+    deps.querier.with_token_balances(&[(
+        &"asset0000".to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+    )]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 6u8],
+        pool_fees: pool_fees(),
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    // the imbalance dynamic fee is only for stableswap pairs
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        update_dynamic_fee_msg(DynamicFeeUpdate::Enable(dynamic_fee(
+            DynamicFeeMode::Imbalance {
+                max_imbalance: Decimal::percent(10),
+            },
+        ))),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ImbalanceFeeOnNonStableSwap {});
+
+    // the min fee can't be higher than the max fee
+    let err = execute(
+        deps.as_mut(),
+        env,
+        info,
+        update_dynamic_fee_msg(DynamicFeeUpdate::Enable(DynamicFee {
+            min_fee: Fee {
+                share: Decimal::percent(2),
+            },
+            ..dynamic_fee(DynamicFeeMode::Volatility {
+                window: 3_600,
+                max_volatility: Decimal::percent(10),
+            })
+        })),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err(
+            "Dynamic fee min_fee can't be higher than max_fee"
+        ))
+    );
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn price_observations_are_kept_in_a_ring_buffer() {
+    let mut deps = mock_dependencies(&[]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 6u8],
+        pool_fees: pool_fees(),
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    execute(
+        deps.as_mut(),
+        env,
+        info,
+        update_dynamic_fee_msg(DynamicFeeUpdate::Enable(dynamic_fee(
+            DynamicFeeMode::Volatility {
+                window: 3_600,
+                max_volatility: Decimal::percent(10),
+            },
+        ))),
+    )
+    .unwrap();
+
+    let config = CONFIG.load(&deps.storage).unwrap();
+    let pools = |amount: u128| {
+        vec![
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uusd".to_string(),
+                },
+                amount: Uint128::from(1_000u128),
+            },
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::from(amount),
+            },
+        ]
+    };
+
+    // a single observation is kept per timestamp
+    record_price_observation(&mut deps.storage, &config, &pools(1_000), 1).unwrap();
+    record_price_observation(&mut deps.storage, &config, &pools(2_000), 1).unwrap();
+    assert_eq!(
+        load_price_observations(&deps.storage).unwrap(),
+        vec![PriceObservation {
+            timestamp: 1,
+            price: Decimal256::from_ratio(2u128, 1u128),
+        }]
+    );
+
+    // once the buffer is full, the oldest observations are overwritten
+    for timestamp in 2..=MAX_PRICE_OBSERVATIONS + 10 {
+        record_price_observation(&mut deps.storage, &config, &pools(1_000), timestamp).unwrap();
+    }
+
+    let observations = load_price_observations(&deps.storage).unwrap();
+    assert_eq!(observations.len() as u64, MAX_PRICE_OBSERVATIONS);
+    assert_eq!(
+        observations
+            .iter()
+            .map(|observation| observation.timestamp)
+            .min(),
+        Some(11)
+    );
+    assert_eq!(
+        LATEST_PRICE_OBSERVATION_SLOT.load(&deps.storage).unwrap(),
+        9
+    );
+}
+
+#[test]
+fn simulation_response_without_swap_fee_deserializes() {
+    let response: SimulationResponse = from_json(
+        r#"{"return_amount":"100","spread_amount":"0","swap_fee_amount":"1","protocol_fee_amount":"0","burn_fee_amount":"0"}"#,
+    )
+    .unwrap();
+    assert_eq!(response.swap_fee, Decimal::zero());
+}
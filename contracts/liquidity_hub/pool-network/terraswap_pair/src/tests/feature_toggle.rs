@@ -69,6 +69,8 @@ fn test_feature_toggle_swap_disabled() {
             deposits_enabled: true,
             swaps_enabled: false,
         }),
+        amp_factor: None,
+        dynamic_fee: None,
     };
     execute(deps.as_mut(), env.clone(), info, update_config_message).unwrap();
 
@@ -84,6 +86,7 @@ fn test_feature_toggle_swap_disabled() {
         belief_price: None,
         max_spread: None,
         to: None,
+        trader: None,
     };
     let info = mock_info(
         "addr0000",
@@ -108,6 +111,7 @@ fn test_feature_toggle_swap_disabled() {
             belief_price: None,
             max_spread: None,
             to: None,
+            trader: None,
         })
         .unwrap(),
     });
@@ -179,6 +183,8 @@ fn test_feature_toggle_withdrawals_disabled() {
             deposits_enabled: true,
             swaps_enabled: true,
         }),
+        amp_factor: None,
+        dynamic_fee: None,
     };
     execute(deps.as_mut(), env, info, update_config_message).unwrap();
 
@@ -256,6 +262,8 @@ fn test_feature_toggle_deposits_disabled() {
             deposits_enabled: false,
             swaps_enabled: true,
         }),
+        amp_factor: None,
+        dynamic_fee: None,
     };
     execute(deps.as_mut(), env, info, update_config_message).unwrap();
 
@@ -0,0 +1,172 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{
+    attr, coins, from_json, Coin, Decimal, Reply, SubMsgResponse, SubMsgResult, Uint128,
+};
+
+use white_whale_std::fee::Fee;
+use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairType};
+use white_whale_std::pool_network::mock_querier::mock_dependencies;
+use white_whale_std::pool_network::pair::{
+    ExecuteMsg, InstantiateMsg, PoolFee, QueryMsg, ReverseSimulationResponse, SimulationResponse,
+};
+
+use crate::contract::{execute, instantiate, query, reply};
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn fee_discount_is_applied_to_swaps_and_simulations() {
+    let pool = Uint128::from(1_000_000_000u128);
+    let offer_amount = Uint128::from(1_000_000u128);
+
+    let mut deps = mock_dependencies(&[Coin {
+        denom: "uusd".to_string(),
+        amount: pool + offer_amount,
+        /* user deposit must be pre-applied */
+    }]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &pool)],
+        ),
+        (
+            &"asset0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &pool)],
+        ),
+    ]);
+    // the pair owner, i.e. the factory, grants a 50% discount to addr0001
+    deps.querier
+        .with_fee_discounts(&[(&"addr0001".to_string(), Decimal::percent(50))]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 6u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::permille(1),
+            },
+            swap_fee: Fee {
+                share: Decimal::permille(3),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+
+    let env = mock_env();
+    instantiate(deps.as_mut(), env.clone(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
+
+    let offer_asset = Asset {
+        info: AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        amount: offer_amount,
+    };
+    let simulation = |msg: QueryMsg| -> SimulationResponse {
+        from_json(query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap()
+    };
+    let reverse_simulation = |msg: QueryMsg| -> ReverseSimulationResponse {
+        from_json(query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap()
+    };
+    let ask_asset = Asset {
+        info: AssetInfo::Token {
+            contract_addr: "asset0000".to_string(),
+        },
+        amount: offer_amount,
+    };
+
+    // the plain simulations, or the discounted ones for a trader without discount, quote the full
+    // fees
+    let full_fee_simulation = simulation(QueryMsg::Simulation {
+        offer_asset: offer_asset.clone(),
+    });
+    assert_eq!(full_fee_simulation.swap_fee, Decimal::permille(3));
+    assert_eq!(
+        simulation(QueryMsg::DiscountedSimulation {
+            offer_asset: offer_asset.clone(),
+            trader: "addr0000".to_string(),
+        }),
+        full_fee_simulation
+    );
+    let full_fee_reverse_simulation = reverse_simulation(QueryMsg::ReverseSimulation {
+        ask_asset: ask_asset.clone(),
+    });
+    assert_eq!(
+        reverse_simulation(QueryMsg::DiscountedReverseSimulation {
+            ask_asset: ask_asset.clone(),
+            trader: "addr0000".to_string(),
+        }),
+        full_fee_reverse_simulation
+    );
+
+    // the discounted trader gets the swap and protocol fees halved
+    let discounted_simulation = simulation(QueryMsg::DiscountedSimulation {
+        offer_asset: offer_asset.clone(),
+        trader: "addr0001".to_string(),
+    });
+    assert_eq!(
+        discounted_simulation.swap_fee,
+        Decimal::from_ratio(15u128, 10_000u128)
+    );
+    assert!(discounted_simulation.swap_fee_amount < full_fee_simulation.swap_fee_amount);
+    assert!(discounted_simulation.protocol_fee_amount < full_fee_simulation.protocol_fee_amount);
+    assert!(discounted_simulation.return_amount > full_fee_simulation.return_amount);
+
+    let discounted_reverse_simulation = reverse_simulation(QueryMsg::DiscountedReverseSimulation {
+        ask_asset,
+        trader: "addr0001".to_string(),
+    });
+    assert!(
+        discounted_reverse_simulation.swap_fee_amount < full_fee_reverse_simulation.swap_fee_amount
+    );
+    assert!(
+        discounted_reverse_simulation.protocol_fee_amount
+            < full_fee_reverse_simulation.protocol_fee_amount
+    );
+    assert!(discounted_reverse_simulation.offer_amount < full_fee_reverse_simulation.offer_amount);
+
+    // the actual swap charges the discounted fees as well
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("addr0001", &coins(offer_amount.u128(), "uusd")),
+        ExecuteMsg::Swap {
+            offer_asset,
+            belief_price: None,
+            max_spread: None,
+            to: None,
+            trader: None,
+        },
+    )
+    .unwrap();
+    assert!(res.attributes.contains(&attr(
+        "swap_fee",
+        Decimal::from_ratio(15u128, 10_000u128).to_string()
+    )));
+}
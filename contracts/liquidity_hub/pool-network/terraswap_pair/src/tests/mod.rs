@@ -1,4 +1,7 @@
+mod dynamic_fee;
 mod feature_toggle;
+mod fee_discount;
+mod pool_stats;
 mod protocol_fees;
 mod provide_liquidity;
 mod queries;
@@ -0,0 +1,176 @@
+use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+use cosmwasm_std::{coins, from_json, Coin, Decimal, Reply, SubMsgResponse, SubMsgResult, Uint128};
+
+use white_whale_std::fee::Fee;
+use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairType};
+use white_whale_std::pool_network::mock_querier::mock_dependencies;
+use white_whale_std::pool_network::pair::{ExecuteMsg, InstantiateMsg, PoolFee, QueryMsg};
+use white_whale_std::pool_network::pool_stats::PoolStats;
+
+use crate::contract::{execute, instantiate, query, reply};
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn swaps_are_recorded_in_pool_stats() {
+    let pool = Uint128::from(1_000_000_000u128);
+    let offer_amount = Uint128::from(1_000_000u128);
+
+    let mut deps = mock_dependencies(&[Coin {
+        denom: "uusd".to_string(),
+        amount: pool + offer_amount,
+        /* user deposit must be pre-applied */
+    }]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &pool)],
+        ),
+        (
+            &"asset0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &pool)],
+        ),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 6u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::permille(1),
+            },
+            swap_fee: Fee {
+                share: Decimal::permille(3),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+
+    let env = mock_env();
+    instantiate(deps.as_mut(), env.clone(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
+
+    let offer_asset = Asset {
+        info: AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        amount: offer_amount,
+    };
+
+    // the first swap happens before the factory has an epoch manager, the next ones in epoch 3.
+    // The epoch is cached until it ends, so the third swap is still recorded in epoch 3 even
+    // though the factory moved on, while the last one is recorded in epoch 4
+    let epoch_3_end = env.block.time.plus_days(1);
+    let mut swap_fee_amount = Uint128::zero();
+    for (current_epoch, block_time) in [
+        (None, env.block.time),
+        (Some((3u64, epoch_3_end)), env.block.time.plus_hours(1)),
+        (
+            Some((4u64, epoch_3_end.plus_days(1))),
+            env.block.time.plus_hours(2),
+        ),
+        (Some((4u64, epoch_3_end.plus_days(1))), epoch_3_end),
+    ] {
+        deps.querier.with_current_epoch(current_epoch);
+        let mut env = env.clone();
+        env.block.time = block_time;
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("addr0001", &coins(offer_amount.u128(), "uusd")),
+            ExecuteMsg::Swap {
+                offer_asset: offer_asset.clone(),
+                belief_price: None,
+                max_spread: None,
+                to: None,
+                trader: None,
+            },
+        )
+        .unwrap();
+
+        let amount = res
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == "swap_fee_amount")
+            .unwrap()
+            .value
+            .parse::<Uint128>()
+            .unwrap();
+        assert!(!amount.is_zero());
+        swap_fee_amount += amount;
+    }
+
+    let pool_stats = |epoch_id: Option<u64>| -> PoolStats {
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::PoolStats { epoch_id }).unwrap())
+            .unwrap()
+    };
+
+    let all_time_stats = pool_stats(None);
+    assert_eq!(all_time_stats.swap_count, 4);
+    assert_eq!(
+        all_time_stats.volume,
+        vec![Asset {
+            info: offer_asset.info.clone(),
+            amount: offer_amount * Uint128::new(4),
+        }]
+    );
+    assert_eq!(
+        all_time_stats.lp_fees,
+        vec![Asset {
+            info: AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+            amount: swap_fee_amount,
+        }]
+    );
+
+    let epoch_stats = pool_stats(Some(3));
+    assert_eq!(epoch_stats.swap_count, 2);
+    assert_eq!(
+        epoch_stats.volume,
+        vec![Asset {
+            info: offer_asset.info.clone(),
+            amount: offer_amount * Uint128::new(2),
+        }]
+    );
+
+    let epoch_stats = pool_stats(Some(4));
+    assert_eq!(epoch_stats.swap_count, 1);
+    assert_eq!(
+        epoch_stats.volume,
+        vec![Asset {
+            info: offer_asset.info,
+            amount: offer_amount,
+        }]
+    );
+
+    // epochs without swaps have empty stats
+    assert_eq!(pool_stats(Some(5)), PoolStats::default());
+}
@@ -96,6 +96,7 @@ fn test_protocol_fees() {
         belief_price: None,
         max_spread: None,
         to: None,
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info(
@@ -151,6 +152,7 @@ fn test_protocol_fees() {
         belief_price: None,
         max_spread: None,
         to: None,
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info(
@@ -281,6 +283,7 @@ fn test_collect_protocol_fees_successful() {
         belief_price: None,
         max_spread: None,
         to: None,
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info(
@@ -305,6 +308,7 @@ fn test_collect_protocol_fees_successful() {
             belief_price: None,
             max_spread: None,
             to: None,
+            trader: None,
         })
         .unwrap(),
     });
@@ -510,6 +514,7 @@ fn test_collect_protocol_fees_successful_1_fee_only() {
         belief_price: None,
         max_spread: None,
         to: None,
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info(
@@ -66,6 +66,7 @@ mod tests {
                     share: Decimal::zero(),
                 },
             },
+            None,
             &PairType::StableSwap { amp: 100 },
             6,
             6,
@@ -79,7 +80,8 @@ mod tests {
                 swap_fee_amount: Uint128::new(24),
                 return_amount: Uint128::new(9_949),
                 spread_amount: Uint128::new(3),
-                burn_fee_amount: Uint128::zero()
+                burn_fee_amount: Uint128::zero(),
+                swap_fee: Decimal::from_ratio(1u128, 400u128),
             }
         );
     }
@@ -108,6 +110,7 @@ mod tests {
                     share: Decimal::zero(),
                 },
             },
+            None,
             &PairType::StableSwap { amp: 100 },
             6,
             6,
@@ -121,7 +124,8 @@ mod tests {
                 swap_fee_amount: Uint128::new(2500),
                 return_amount: Uint128::new(995_011),
                 spread_amount: Uint128::new(0),
-                burn_fee_amount: Uint128::zero()
+                burn_fee_amount: Uint128::zero(),
+                swap_fee: Decimal::from_ratio(1u128, 400u128),
             }
         );
     }
@@ -149,6 +153,7 @@ mod tests {
                     share: Decimal::zero(),
                 },
             },
+            None,
             &PairType::StableSwap { amp: 100 },
             6,
             18,
@@ -161,7 +166,8 @@ mod tests {
                 swap_fee_amount: Uint128::new(2_499975247745560004),
                 return_amount: Uint128::new(994_990148602732881842),
                 spread_amount: Uint128::new(9900901775998150),
-                burn_fee_amount: Uint128::zero()
+                burn_fee_amount: Uint128::zero(),
+                swap_fee: Decimal::from_ratio(1u128, 400u128),
             }
         );
 
@@ -190,6 +196,7 @@ mod tests {
                     share: Decimal::zero(),
                 },
             },
+            None,
             &PairType::StableSwap { amp: 100 },
             18,
             6,
@@ -202,7 +209,8 @@ mod tests {
                 swap_fee_amount: Uint128::new(12_499628),
                 return_amount: Uint128::new(4974_852233),
                 spread_amount: Uint128::new(148511),
-                burn_fee_amount: Uint128::zero()
+                burn_fee_amount: Uint128::zero(),
+                swap_fee: Decimal::from_ratio(1u128, 400u128),
             }
         );
     }
@@ -230,6 +238,7 @@ mod tests {
                     share: Decimal::zero(),
                 },
             },
+            None,
             &PairType::StableSwap { amp: 50 },
             18,
             18,
@@ -242,7 +251,8 @@ mod tests {
                 swap_fee_amount: Uint128::new(2_499950981306193408),
                 return_amount: Uint128::new(994_980490559864976477),
                 spread_amount: Uint128::new(19607477522636707),
-                burn_fee_amount: Uint128::zero()
+                burn_fee_amount: Uint128::zero(),
+                swap_fee: Decimal::from_ratio(1u128, 400u128),
             }
         );
 
@@ -271,6 +281,7 @@ mod tests {
                     share: Decimal::zero(),
                 },
             },
+            None,
             &PairType::StableSwap { amp: 50 },
             18,
             18,
@@ -283,7 +294,8 @@ mod tests {
                 swap_fee_amount: Uint128::new(12_499264751528814624),
                 return_amount: Uint128::new(4974_707371108468220566),
                 spread_amount: Uint128::new(294099388474150186),
-                burn_fee_amount: Uint128::zero()
+                burn_fee_amount: Uint128::zero(),
+                swap_fee: Decimal::from_ratio(1u128, 400u128),
             }
         );
     }
@@ -47,6 +47,7 @@ fn test_compute_swap_with_huge_pool_variance() {
             ask_pool,
             Uint128::from(1u128),
             pool_fees,
+            None,
             &PairType::ConstantProduct,
             6,
             6,
@@ -144,6 +145,7 @@ fn try_native_to_token() {
         belief_price: None,
         max_spread: Some(Decimal::percent(5u64)),
         to: None,
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info(
@@ -382,6 +384,7 @@ fn try_native_to_token() {
             attr("offer_amount", offer_amount.to_string()),
             attr("return_amount", expected_return_amount.to_string()),
             attr("spread_amount", expected_spread_amount.to_string()),
+            attr("swap_fee", pool_fees.swap_fee.share.to_string()),
             attr("swap_fee_amount", expected_swap_fee_amount.to_string()),
             attr(
                 "protocol_fee_amount",
@@ -490,6 +493,7 @@ fn try_swap_invalid_token() {
         belief_price: None,
         max_spread: None,
         to: None,
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info(
@@ -612,6 +616,7 @@ fn try_token_to_native() {
         belief_price: None,
         max_spread: None,
         to: None,
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
@@ -629,6 +634,7 @@ fn try_token_to_native() {
             belief_price: None,
             max_spread: Some(Decimal::percent(5u64)),
             to: Some("third_party".to_string()),
+            trader: None,
         })
         .unwrap(),
     });
@@ -894,6 +900,7 @@ fn try_token_to_native() {
             attr("offer_amount", offer_amount.to_string()),
             attr("return_amount", expected_return_amount.to_string()),
             attr("spread_amount", expected_spread_amount.to_string()),
+            attr("swap_fee", pool_fees.swap_fee.share.to_string()),
             attr("swap_fee_amount", expected_swap_fee_amount.to_string()),
             attr(
                 "protocol_fee_amount",
@@ -928,6 +935,7 @@ fn try_token_to_native() {
             belief_price: None,
             max_spread: None,
             to: None,
+            trader: None,
         })
         .unwrap(),
     });
@@ -1023,6 +1031,7 @@ fn test_swap_to_third_party() {
         belief_price: None,
         max_spread: Some(Decimal::percent(5u64)),
         to: Some("third_party".to_string()),
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info(
@@ -1256,6 +1265,7 @@ fn stableswap_with_different_precisions() {
         belief_price: None,
         max_spread: None,
         to: None,
+        trader: None,
     };
     let env = mock_env();
     let info = mock_info(
@@ -1414,6 +1424,7 @@ fn stableswap_with_different_precisions() {
             attr("offer_amount", uusd_offer_amount.to_string()),
             attr("return_amount", expected_return_amount.to_string()),
             attr("spread_amount", expected_spread_amount.to_string()),
+            attr("swap_fee", Decimal::from_ratio(1u128, 400u128).to_string()),
             attr("swap_fee_amount", expected_swap_fee_amount.to_string()),
             attr(
                 "protocol_fee_amount",
@@ -1472,6 +1483,7 @@ fn test_stableswap_with_no_swap_amount() {
             ask_pool,
             Uint128::zero(),
             pool_fees,
+            None,
             &PairType::ConstantProduct,
             6,
             6,
@@ -8,6 +8,7 @@ use cosmwasm_std::{
 use cw20::MinterResponse;
 
 use white_whale_std::fee::Fee;
+use white_whale_std::pool_network::amp::{AmpFactorUpdate, RampAmp};
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
 #[cfg(feature = "osmosis_token_factory")]
 use white_whale_std::pool_network::denom_osmosis::MsgCreateDenom;
@@ -109,7 +110,7 @@ fn proper_initialization_cw20_lp() {
     let _res = reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
 
     // it worked, let's query the state
-    let pair_info: PairInfo = query_pair_info(deps.as_ref()).unwrap();
+    let pair_info: PairInfo = query_pair_info(deps.as_ref(), &mock_env()).unwrap();
     assert_eq!(
         "liquidity0000".to_string(),
         pair_info.liquidity_token.to_string()
@@ -177,7 +178,7 @@ fn proper_initialization_token_factory_lp() {
     assert_eq!(res.messages[0].msg, expected);
 
     // let's query the state
-    let pair_info: PairInfo = query_pair_info(deps.as_ref()).unwrap();
+    let pair_info: PairInfo = query_pair_info(deps.as_ref(), &mock_env()).unwrap();
     assert_eq!(
         pair_info.liquidity_token,
         AssetInfo::NativeToken {
@@ -431,6 +432,8 @@ fn test_update_config_unsuccessful() {
             },
         }),
         feature_toggle: None,
+        amp_factor: None,
+        dynamic_fee: None,
     };
 
     let res = execute(deps.as_mut(), env.clone(), info, update_config_message);
@@ -447,6 +450,8 @@ fn test_update_config_unsuccessful() {
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: None,
+        amp_factor: None,
+        dynamic_fee: None,
     };
 
     let res = execute(deps.as_mut(), env, info, update_config_message);
@@ -573,6 +578,8 @@ fn test_update_config_successful() {
         fee_collector_addr: Some("new_collector".to_string()),
         pool_fees: Some(pool_fees),
         feature_toggle: None,
+        amp_factor: None,
+        dynamic_fee: None,
     };
 
     #[cfg(feature = "osmosis")]
@@ -582,6 +589,8 @@ fn test_update_config_successful() {
         pool_fees: Some(pool_fees),
         feature_toggle: None,
         cosmwasm_pool_interface: Some("new_interface".to_string()),
+        amp_factor: None,
+        dynamic_fee: None,
     };
 
     execute(deps.as_mut(), env, info, update_config_message).unwrap();
@@ -603,6 +612,202 @@ fn test_update_config_successful() {
     }
 }
 
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn test_update_config_amp_ramp() {
+    let mut deps = mock_dependencies(&[]);
+
+    deps.querier.with_token_balances(&[(
+        &"asset0000".to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+    )]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 6u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            swap_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::StableSwap { amp: 100 },
+        token_factory_lp: false,
+    };
+
+    let mut env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let reply_msg = Reply {
+        id: 1,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(
+                vec![
+                    10, 13, 108, 105, 113, 117, 105, 100, 105, 116, 121, 48, 48, 48, 48,
+                ]
+                .into(),
+            ),
+        }),
+    };
+    reply(deps.as_mut(), env.clone(), reply_msg).unwrap();
+
+    let ramp_message = |amp_factor: AmpFactorUpdate| UpdateConfig {
+        owner: None,
+        fee_collector_addr: None,
+        pool_fees: None,
+        feature_toggle: None,
+        amp_factor: Some(amp_factor),
+        dynamic_fee: None,
+    };
+
+    // the ramp can't be shorter than the minimum amount of blocks
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ramp_message(AmpFactorUpdate::Ramp(RampAmp {
+            future_a: 200,
+            future_block: env.block.height + 100,
+        })),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("Amp change ramp time under minimum"))
+    );
+
+    // the amp can't change by more than the max factor
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ramp_message(AmpFactorUpdate::Ramp(RampAmp {
+            future_a: 1_001,
+            future_block: env.block.height + 20_000,
+        })),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("Amp change over max"))
+    );
+
+    // only the owner can ramp the amp
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("unauthorized", &[]),
+        ramp_message(AmpFactorUpdate::Ramp(RampAmp {
+            future_a: 200,
+            future_block: env.block.height + 20_000,
+        })),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Std(StdError::generic_err("unauthorized"))
+    );
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        ramp_message(AmpFactorUpdate::Ramp(RampAmp {
+            future_a: 200,
+            future_block: env.block.height + 20_000,
+        })),
+    )
+    .unwrap();
+
+    // halfway through the ramp
+    env.block.height += 10_000;
+    let pair_info: PairInfo = query_pair_info(deps.as_ref(), &env).unwrap();
+    assert_eq!(pair_info.pair_type, PairType::StableSwap { amp: 150 });
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ramp_message(AmpFactorUpdate::StopRamp {}),
+    )
+    .unwrap();
+
+    // the amp stays where the ramp was stopped
+    env.block.height += 50_000;
+    let pair_info: PairInfo = query_pair_info(deps.as_ref(), &env).unwrap();
+    assert_eq!(pair_info.pair_type, PairType::StableSwap { amp: 150 });
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn test_update_config_amp_ramp_non_stableswap() {
+    let mut deps = mock_dependencies(&[]);
+
+    deps.querier.with_token_balances(&[(
+        &"asset0000".to_string(),
+        &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+    )]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            swap_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    let update_config_message = UpdateConfig {
+        owner: None,
+        fee_collector_addr: None,
+        pool_fees: None,
+        feature_toggle: None,
+        amp_factor: Some(AmpFactorUpdate::StopRamp {}),
+        dynamic_fee: None,
+    };
+
+    let err = execute(deps.as_mut(), env, info, update_config_message).unwrap_err();
+    assert_eq!(err, ContractError::AmpUpdateOnNonStableSwap {});
+}
+
 #[test]
 fn test_assert_slippage_tolerance_invalid_ratio() {
     let res = assert_slippage_tolerance(
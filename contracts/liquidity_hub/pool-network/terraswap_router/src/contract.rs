@@ -12,7 +12,9 @@ use semver::Version;
 
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairInfo};
 use white_whale_std::pool_network::pair::SimulationResponse;
-use white_whale_std::pool_network::querier::{query_pair_info, reverse_simulate, simulate};
+use white_whale_std::pool_network::querier::{
+    query_pair_info_by_identifier, reverse_simulate, simulate,
+};
 use white_whale_std::pool_network::router::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
     SimulateSwapOperationsResponse, SwapOperation, SwapRoute, SwapRouteResponse,
@@ -77,6 +79,7 @@ pub fn execute(
             operation,
             to,
             max_spread,
+            trader,
         } => {
             let api = deps.api;
             execute_swap_operation(
@@ -86,6 +89,7 @@ pub fn execute(
                 operation,
                 optional_addr_validate(api, to)?.map(|v| v.to_string()),
                 max_spread,
+                trader,
             )
         }
         ExecuteMsg::AssertMinimumReceive {
@@ -167,6 +171,8 @@ pub fn execute_swap_operations(
     // Assert the operations are properly set
     assert_operations(&operations)?;
 
+    // the pairs see the router as the sender, so the trader is passed along for its fee discount
+    let trader = sender.to_string();
     let to = if let Some(to) = to { to } else { sender };
     let target_asset_info = operations
         .last()
@@ -189,6 +195,7 @@ pub fn execute_swap_operations(
                         None
                     },
                     max_spread,
+                    trader: Some(trader.clone()),
                 })?,
             }))
         })
@@ -353,11 +360,13 @@ fn simulate_swap_operations(
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+                pool_identifier,
             } => {
-                let pair_info: PairInfo = query_pair_info(
+                let pair_info: PairInfo = query_pair_info_by_identifier(
                     &deps.querier,
                     terraswap_factory.clone(),
                     &[offer_asset_info.clone(), ask_asset_info.clone()],
+                    pool_identifier,
                 )?;
 
                 let res: SimulationResponse = simulate(
@@ -397,6 +406,7 @@ fn reverse_simulate_swap_operations(
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+                pool_identifier,
             } => {
                 let terraswap_factory = deps.api.addr_humanize(&config.terraswap_factory)?;
 
@@ -406,6 +416,7 @@ fn reverse_simulate_swap_operations(
                     ask_amount,
                     offer_asset_info,
                     ask_asset_info,
+                    pool_identifier,
                 )?
             }
         }
@@ -420,11 +431,13 @@ fn reverse_simulate_return_amount(
     ask_amount: Uint128,
     offer_asset_info: AssetInfo,
     ask_asset_info: AssetInfo,
+    pool_identifier: Option<String>,
 ) -> StdResult<Uint128> {
-    let pair_info: PairInfo = query_pair_info(
+    let pair_info: PairInfo = query_pair_info_by_identifier(
         &deps.querier,
         factory,
         &[offer_asset_info, ask_asset_info.clone()],
+        pool_identifier,
     )?;
 
     let res = reverse_simulate(
@@ -487,6 +500,7 @@ fn assert_operations(operations: &[SwapOperation]) -> Result<(), ContractError>
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+                ..
             } => (offer_asset_info.clone(), ask_asset_info.clone()),
         };
 
@@ -515,6 +529,7 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0001".to_string(),
             },
+            pool_identifier: None,
         },
         SwapOperation::TerraSwap {
             offer_asset_info: AssetInfo::Token {
@@ -523,6 +538,7 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
+            pool_identifier: None,
         }
     ])
     .is_ok());
@@ -536,6 +552,7 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0001".to_string(),
             },
+            pool_identifier: None,
         },
         SwapOperation::TerraSwap {
             offer_asset_info: AssetInfo::Token {
@@ -544,6 +561,7 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::NativeToken {
                 denom: "uluna".to_string(),
             },
+            pool_identifier: None,
         },
         SwapOperation::TerraSwap {
             offer_asset_info: AssetInfo::NativeToken {
@@ -552,6 +570,7 @@ fn test_invalid_operations() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0002".to_string(),
             },
+            pool_identifier: None,
         },
     ])
     .is_ok());
@@ -10,7 +10,9 @@ use cw20::Cw20ExecuteMsg;
 use white_whale_std::pool_network;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairInfo};
 use white_whale_std::pool_network::pair::ExecuteMsg as PairExecuteMsg;
-use white_whale_std::pool_network::querier::{query_balance, query_pair_info, query_token_balance};
+use white_whale_std::pool_network::querier::{
+    query_balance, query_pair_info_by_identifier, query_token_balance,
+};
 use white_whale_std::pool_network::router::SwapOperation;
 
 /// Execute swap operation
@@ -22,6 +24,7 @@ pub fn execute_swap_operation(
     operation: SwapOperation,
     to: Option<String>,
     max_spread: Option<Decimal>,
+    trader: Option<String>,
 ) -> Result<Response, ContractError> {
     if env.contract.address != info.sender {
         return Err(ContractError::Unauthorized {});
@@ -31,13 +34,15 @@ pub fn execute_swap_operation(
         SwapOperation::TerraSwap {
             offer_asset_info,
             ask_asset_info,
+            pool_identifier,
         } => {
             let config: Config = CONFIG.load(deps.as_ref().storage)?;
             let terraswap_factory = deps.api.addr_humanize(&config.terraswap_factory)?;
-            let pair_info: PairInfo = query_pair_info(
+            let pair_info: PairInfo = query_pair_info_by_identifier(
                 &deps.querier,
                 terraswap_factory,
                 &[offer_asset_info.clone(), ask_asset_info],
+                pool_identifier,
             )?;
 
             let amount = match offer_asset_info.clone() {
@@ -61,6 +66,7 @@ pub fn execute_swap_operation(
                 offer_asset,
                 max_spread,
                 to,
+                trader,
             )?]
         }
     };
@@ -74,6 +80,7 @@ pub fn asset_into_swap_msg(
     offer_asset: Asset,
     max_spread: Option<Decimal>,
     to: Option<String>,
+    trader: Option<String>,
 ) -> Result<CosmosMsg, ContractError> {
     match offer_asset.info.clone() {
         AssetInfo::NativeToken { denom } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
@@ -87,6 +94,7 @@ pub fn asset_into_swap_msg(
                 belief_price: None,
                 max_spread,
                 to,
+                trader,
             })?,
         })),
         AssetInfo::Token { contract_addr } => Ok(CosmosMsg::Wasm(WasmMsg::Execute {
@@ -99,6 +107,7 @@ pub fn asset_into_swap_msg(
                     belief_price: None,
                     max_spread,
                     to,
+                    trader,
                 })?,
             })?,
         })),
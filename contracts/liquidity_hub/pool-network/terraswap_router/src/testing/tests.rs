@@ -81,6 +81,7 @@ fn execute_swap_operations() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0001".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -89,6 +90,7 @@ fn execute_swap_operations() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::NativeToken {
@@ -97,6 +99,7 @@ fn execute_swap_operations() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0002".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
         minimum_receive: Some(Uint128::from(1000000u128)),
@@ -120,9 +123,11 @@ fn execute_swap_operations() {
                         ask_asset_info: AssetInfo::Token {
                             contract_addr: "asset0001".to_string(),
                         },
+                        pool_identifier: None,
                     },
                     to: None,
                     max_spread: None,
+                    trader: Some("addr0000".to_string()),
                 })
                 .unwrap(),
             })),
@@ -137,9 +142,11 @@ fn execute_swap_operations() {
                         ask_asset_info: AssetInfo::NativeToken {
                             denom: "uluna".to_string(),
                         },
+                        pool_identifier: None,
                     },
                     to: None,
                     max_spread: None,
+                    trader: Some("addr0000".to_string()),
                 })
                 .unwrap(),
             })),
@@ -154,9 +161,11 @@ fn execute_swap_operations() {
                         ask_asset_info: AssetInfo::Token {
                             contract_addr: "asset0002".to_string(),
                         },
+                        pool_identifier: None,
                     },
                     to: Some("addr0000".to_string()),
                     max_spread: None,
+                    trader: Some("addr0000".to_string()),
                 })
                 .unwrap(),
             })),
@@ -188,6 +197,7 @@ fn execute_swap_operations() {
                     ask_asset_info: AssetInfo::Token {
                         contract_addr: "asset0001".to_string(),
                     },
+                    pool_identifier: None,
                 },
                 SwapOperation::TerraSwap {
                     offer_asset_info: AssetInfo::Token {
@@ -196,6 +206,7 @@ fn execute_swap_operations() {
                     ask_asset_info: AssetInfo::NativeToken {
                         denom: "uluna".to_string(),
                     },
+                    pool_identifier: None,
                 },
                 SwapOperation::TerraSwap {
                     offer_asset_info: AssetInfo::NativeToken {
@@ -204,6 +215,7 @@ fn execute_swap_operations() {
                     ask_asset_info: AssetInfo::Token {
                         contract_addr: "asset0002".to_string(),
                     },
+                    pool_identifier: None,
                 },
             ],
             minimum_receive: None,
@@ -229,9 +241,11 @@ fn execute_swap_operations() {
                         ask_asset_info: AssetInfo::Token {
                             contract_addr: "asset0001".to_string(),
                         },
+                        pool_identifier: None,
                     },
                     to: None,
                     max_spread: None,
+                    trader: Some("addr0000".to_string()),
                 })
                 .unwrap(),
             })),
@@ -246,9 +260,11 @@ fn execute_swap_operations() {
                         ask_asset_info: AssetInfo::NativeToken {
                             denom: "uluna".to_string(),
                         },
+                        pool_identifier: None,
                     },
                     to: None,
                     max_spread: None,
+                    trader: Some("addr0000".to_string()),
                 })
                 .unwrap(),
             })),
@@ -263,9 +279,11 @@ fn execute_swap_operations() {
                         ask_asset_info: AssetInfo::Token {
                             contract_addr: "asset0002".to_string(),
                         },
+                        pool_identifier: None,
                     },
                     to: Some("addr0002".to_string()),
                     max_spread: None,
+                    trader: Some("addr0000".to_string()),
                 })
                 .unwrap(),
             })),
@@ -324,9 +342,11 @@ fn execute_swap_operation() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0000".to_string(),
             },
+            pool_identifier: None,
         },
         to: None,
         max_spread: None,
+        trader: Some("addr0000".to_string()),
     };
     let info = mock_info("addr0000", &[]);
     let res = execute(deps.as_mut(), mock_env(), info, msg.clone());
@@ -351,6 +371,7 @@ fn execute_swap_operation() {
                 },
                 None,
                 None,
+                Some("addr0000".to_string()),
             )
             .unwrap()
         )],
@@ -366,9 +387,11 @@ fn execute_swap_operation() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0000".to_string(),
             },
+            pool_identifier: None,
         },
         to: Some("addr0000".to_string()),
         max_spread: None,
+        trader: Some("addr0000".to_string()),
     };
     let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
     let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
@@ -386,6 +409,7 @@ fn execute_swap_operation() {
                 },
                 None,
                 Some("addr0000".to_string()),
+                Some("addr0000".to_string()),
             )
             .unwrap()
         )],
@@ -425,9 +449,11 @@ fn execute_swap_operation() {
             ask_asset_info: AssetInfo::NativeToken {
                 denom: "uusd".to_string(),
             },
+            pool_identifier: None,
         },
         to: Some("addr0000".to_string()),
         max_spread: None,
+        trader: Some("addr0000".to_string()),
     };
 
     let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
@@ -444,6 +470,7 @@ fn execute_swap_operation() {
                     belief_price: None,
                     max_spread: None,
                     to: Some("addr0000".to_string()),
+                    trader: Some("addr0000".to_string()),
                 })
                 .unwrap(),
             })
@@ -488,6 +515,7 @@ fn query_buy_with_routes() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -496,6 +524,7 @@ fn query_buy_with_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -605,6 +634,7 @@ fn query_reverse_routes_with_from_native() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0000".to_string(),
             },
+            pool_identifier: None,
         }],
     };
 
@@ -672,9 +702,11 @@ fn query_reverse_routes_with_from_native() {
             ask_asset_info: AssetInfo::Token {
                 contract_addr: "asset0000".to_string(),
             },
+            pool_identifier: None,
         },
         to: None,
         max_spread: None,
+        trader: Some("addr0000".to_string()),
     };
     let info = mock_info("addr0", &[coin(offer_amount.u128(), "ukrw")]);
     let res = execute(deps.as_mut(), mock_env(), info, msg.clone());
@@ -701,6 +733,7 @@ fn query_reverse_routes_with_from_native() {
                 belief_price: None,
                 max_spread: None,
                 to: None,
+                trader: Some("addr0000".to_string()),
             })
             .unwrap(),
         })),],
@@ -742,6 +775,7 @@ fn query_reverse_routes_with_to_native() {
             ask_asset_info: AssetInfo::NativeToken {
                 denom: "ukrw".to_string(),
             },
+            pool_identifier: None,
         }],
     };
 
@@ -812,6 +846,7 @@ fn query_reverse_routes_with_to_native() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "ukrw".to_string(),
                 },
+                pool_identifier: None,
             }],
             minimum_receive: None,
             to: None,
@@ -835,9 +870,11 @@ fn query_reverse_routes_with_to_native() {
                     ask_asset_info: AssetInfo::NativeToken {
                         denom: "ukrw".to_string(),
                     },
+                    pool_identifier: None,
                 },
                 to: Some("addr0".to_string()),
                 max_spread: None,
+                trader: Some("addr0".to_string()),
             })
             .unwrap(),
         })),],
@@ -851,9 +888,11 @@ fn query_reverse_routes_with_to_native() {
             ask_asset_info: AssetInfo::NativeToken {
                 denom: "ukrw".to_string(),
             },
+            pool_identifier: None,
         },
         to: None,
         max_spread: None,
+        trader: Some("addr0000".to_string()),
     };
 
     let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
@@ -871,6 +910,7 @@ fn query_reverse_routes_with_to_native() {
                     belief_price: None,
                     max_spread: None,
                     to: None,
+                    trader: Some("addr0000".to_string()),
                 })
                 .unwrap(),
             })
@@ -1081,6 +1121,7 @@ fn add_swap_routes() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1089,6 +1130,7 @@ fn add_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1108,6 +1150,7 @@ fn add_swap_routes() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1116,6 +1159,7 @@ fn add_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::NativeToken {
@@ -1124,6 +1168,7 @@ fn add_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1241,6 +1286,7 @@ fn add_swap_routes_invalid_route() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1249,6 +1295,7 @@ fn add_swap_routes_invalid_route() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1317,6 +1364,7 @@ fn add_swap_routes_unauthorized() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1325,6 +1373,7 @@ fn add_swap_routes_unauthorized() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1443,6 +1492,7 @@ fn all_swap_routes() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1451,6 +1501,7 @@ fn all_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1470,6 +1521,7 @@ fn all_swap_routes() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1478,6 +1530,7 @@ fn all_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::NativeToken {
@@ -1486,6 +1539,7 @@ fn all_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1614,6 +1668,7 @@ fn remove_swap_route() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1622,6 +1677,7 @@ fn remove_swap_route() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1640,6 +1696,7 @@ fn remove_swap_route() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1648,6 +1705,7 @@ fn remove_swap_route() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::NativeToken {
@@ -1656,6 +1714,7 @@ fn remove_swap_route() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1818,6 +1877,7 @@ fn remove_swap_routes() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1826,6 +1886,7 @@ fn remove_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -1844,6 +1905,7 @@ fn remove_swap_routes() {
                 ask_asset_info: AssetInfo::Token {
                     contract_addr: "asset0000".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::Token {
@@ -1852,6 +1914,7 @@ fn remove_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uluna".to_string(),
                 },
+                pool_identifier: None,
             },
             SwapOperation::TerraSwap {
                 offer_asset_info: AssetInfo::NativeToken {
@@ -1860,6 +1923,7 @@ fn remove_swap_routes() {
                 ask_asset_info: AssetInfo::NativeToken {
                     denom: "uwhale".to_string(),
                 },
+                pool_identifier: None,
             },
         ],
     };
@@ -16,9 +16,12 @@ use white_whale_std::vault_network::vault::{
 use crate::state::{initialize_fee, ALL_TIME_BURNED_FEES};
 use crate::{
     error::VaultError,
-    execute::{callback, collect_protocol_fees, deposit, flash_loan, receive, update_config},
+    execute::{
+        callback, collect_protocol_fees, deposit, flash_loan, receive, update_config,
+        FlashLoanCallback,
+    },
     migrations,
-    queries::{get_config, get_fees, get_payback_amount, get_share},
+    queries::{get_available_liquidity, get_config, get_fees, get_payback_amount, get_share},
     state::{ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
 };
 
@@ -187,7 +190,12 @@ pub fn execute(
 
             withdraw(deps, env, info.sender.into_string(), info.funds[0].amount)
         }
-        ExecuteMsg::FlashLoan { amount, msg } => flash_loan(deps, env, info, amount, msg),
+        ExecuteMsg::FlashLoan { amount, msg } => {
+            flash_loan(deps, env, info, amount, FlashLoanCallback::Message(msg))
+        }
+        ExecuteMsg::FlashLoanToReceiver { amount, data } => {
+            flash_loan(deps, env, info, amount, FlashLoanCallback::Receiver(data))
+        }
         ExecuteMsg::CollectProtocolFees {} => collect_protocol_fees(deps),
         ExecuteMsg::Receive(msg) => receive(deps, env, info, msg),
         ExecuteMsg::Callback(msg) => callback(deps, env, info, msg),
@@ -253,5 +261,6 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, VaultError>
         ),
         QueryMsg::GetPaybackAmount { amount } => get_payback_amount(deps, amount),
         QueryMsg::BurnedFees {} => get_fees(deps, true, ALL_TIME_BURNED_FEES, None),
+        QueryMsg::AvailableLiquidity {} => get_available_liquidity(deps, env),
     }
 }
@@ -4,19 +4,29 @@ use cosmwasm_std::{
 };
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 use white_whale_std::pool_network::asset::AssetInfo;
+use white_whale_std::vault_network::flash_loan_receiver::FlashLoanReceiverMsg;
 use white_whale_std::vault_network::vault::{CallbackMsg, ExecuteMsg};
 
 use crate::{
     error::VaultError,
+    queries::compute_payback_amount,
     state::{CONFIG, LOAN_COUNTER},
 };
 
+/// How the borrower is called once the loaned funds are sent.
+pub enum FlashLoanCallback {
+    /// The borrower is executed with the given message, as is.
+    Message(Binary),
+    /// The borrower is called with [FlashLoanReceiverMsg::OnFlashLoan], carrying the given data.
+    Receiver(Binary),
+}
+
 pub fn flash_loan(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     amount: Uint128,
-    msg: Binary,
+    callback: FlashLoanCallback,
 ) -> Result<Response, VaultError> {
     // check that flash loans are enabled
     let config = CONFIG.load(deps.storage)?;
@@ -65,20 +75,31 @@ pub fn flash_loan(
     };
 
     // get funds to send to callback (if native token then send in the callback msg)
-    let callback_funds = match config.asset_info {
+    let callback_funds = match config.asset_info.clone() {
         AssetInfo::Token { .. } => vec![],
         AssetInfo::NativeToken { denom } => coins(amount.u128(), denom),
     };
 
-    // add callback msg to messages
-    messages.push(
-        WasmMsg::Execute {
+    // add callback msg to messages. Receivers are told the fee they have to pay on top of the loan
+    let callback_msg = match callback {
+        FlashLoanCallback::Message(msg) => WasmMsg::Execute {
             contract_addr: info.sender.into_string(),
             msg,
             funds: callback_funds,
         }
         .into(),
-    );
+        FlashLoanCallback::Receiver(data) => {
+            let payback_amount = compute_payback_amount(&config, amount)?;
+            FlashLoanReceiverMsg::OnFlashLoan {
+                asset: config.asset_info,
+                amount,
+                fee: payback_amount.payback_amount.checked_sub(amount)?,
+                data,
+            }
+            .into_cosmos_msg(info.sender, callback_funds)?
+        }
+    };
+    messages.push(callback_msg);
 
     // call after trade msg
     messages.push(
@@ -107,6 +128,7 @@ mod test {
         to_json_binary, Addr, BankMsg, Response, Uint128, WasmMsg,
     };
     use white_whale_std::pool_network::asset::AssetInfo;
+    use white_whale_std::vault_network::flash_loan_receiver::FlashLoanReceiverMsg;
     use white_whale_std::vault_network::vault::Config;
 
     use crate::{
@@ -258,6 +280,76 @@ mod test {
         );
     }
 
+    #[test]
+    fn can_loan_to_receiver() {
+        let mut deps = mock_dependencies_with_balance(&coins(10_000, "uluna"));
+        let env = mock_env();
+
+        let callback_msg = to_json_binary(&BankMsg::Burn { amount: vec![] }).unwrap();
+
+        instantiate(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            white_whale_std::vault_network::vault::InstantiateMsg {
+                owner: mock_creator().sender.into_string(),
+                token_id: 2,
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fee_collector_addr: "fee_collector".to_string(),
+                vault_fees: get_fees(),
+                token_factory_lp: false,
+            },
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            white_whale_std::vault_network::vault::ExecuteMsg::FlashLoanToReceiver {
+                amount: Uint128::new(5_000),
+                data: callback_msg.clone(),
+            },
+        );
+
+        // check old balance
+        assert_eq!(
+            res.unwrap(),
+            Response::new()
+                .add_attributes(vec![("method", "flash_loan"), ("amount", "5000")])
+                .add_messages(vec![
+                    WasmMsg::Execute {
+                        contract_addr: mock_creator().sender.into_string(),
+                        msg: to_json_binary(&FlashLoanReceiverMsg::OnFlashLoan {
+                            asset: AssetInfo::NativeToken {
+                                denom: "uluna".to_string(),
+                            },
+                            amount: Uint128::new(5_000),
+                            fee: Uint128::new(50),
+                            data: callback_msg,
+                        })
+                        .unwrap(),
+                        funds: coins(5_000, "uluna")
+                    },
+                    WasmMsg::Execute {
+                        contract_addr: env.contract.address.into_string(),
+                        funds: vec![],
+                        msg: to_json_binary(
+                            &white_whale_std::vault_network::vault::ExecuteMsg::Callback(
+                                white_whale_std::vault_network::vault::CallbackMsg::AfterTrade {
+                                    old_balance: Uint128::new(10_000),
+                                    loan_amount: Uint128::new(5_000)
+                                }
+                            )
+                        )
+                        .unwrap()
+                    }
+                ])
+        );
+    }
+
     #[test]
     fn can_loan_token() {
         let env = mock_env();
@@ -8,6 +8,6 @@ mod update_config;
 pub use callback::callback;
 pub use collect_protocol_fee::collect_protocol_fees;
 pub use deposit::deposit;
-pub use flash_loan::flash_loan;
+pub use flash_loan::{flash_loan, FlashLoanCallback};
 pub use receive::receive;
 pub use update_config::update_config;
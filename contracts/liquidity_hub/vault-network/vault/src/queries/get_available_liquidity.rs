@@ -0,0 +1,135 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+use cw20::{BalanceResponse, Cw20QueryMsg};
+
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::vault_network::vault::AvailableLiquidityResponse;
+
+use crate::error::VaultError;
+use crate::state::CONFIG;
+
+/// Retrieves the amount of the vault asset that can be flash-loaned.
+pub fn get_available_liquidity(deps: Deps, env: Env) -> Result<Binary, VaultError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let balance = match config.asset_info.clone() {
+        AssetInfo::NativeToken { denom } => {
+            deps.querier
+                .query_balance(env.contract.address, denom)?
+                .amount
+        }
+        AssetInfo::Token { contract_addr } => {
+            let balance: BalanceResponse = deps.querier.query_wasm_smart(
+                contract_addr,
+                &Cw20QueryMsg::Balance {
+                    address: env.contract.address.into_string(),
+                },
+            )?;
+            balance.balance
+        }
+    };
+
+    Ok(to_json_binary(&AvailableLiquidityResponse {
+        available_liquidity: Asset {
+            info: config.asset_info,
+            amount: balance,
+        },
+        flash_loan_enabled: config.flash_loan_enabled,
+    })?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{coins, from_json, testing::mock_env, Addr, Uint128};
+
+    use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+    use white_whale_std::vault_network::vault::{AvailableLiquidityResponse, Config, QueryMsg};
+
+    use crate::{
+        contract::query,
+        state::CONFIG,
+        tests::{get_fees, mock_creator, mock_dependencies_lp},
+    };
+
+    fn mock_config(asset_info: AssetInfo, flash_loan_enabled: bool) -> Config {
+        Config {
+            owner: mock_creator().sender,
+            lp_asset: AssetInfo::Token {
+                contract_addr: "lp_token".to_string(),
+            },
+            asset_info,
+            deposit_enabled: true,
+            flash_loan_enabled,
+            withdraw_enabled: true,
+            fee_collector_addr: Addr::unchecked("fee_collector"),
+            fees: get_fees(),
+        }
+    }
+
+    #[test]
+    fn does_get_available_liquidity_native() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(
+            &[(
+                &env.clone().contract.address.into_string(),
+                &coins(100_000, "uluna"),
+            )],
+            &[],
+            vec![],
+        );
+
+        let asset_info = AssetInfo::NativeToken {
+            denom: "uluna".to_string(),
+        };
+        CONFIG
+            .save(&mut deps.storage, &mock_config(asset_info.clone(), true))
+            .unwrap();
+
+        let res: AvailableLiquidityResponse =
+            from_json(query(deps.as_ref(), env, QueryMsg::AvailableLiquidity {}).unwrap()).unwrap();
+
+        assert_eq!(
+            res,
+            AvailableLiquidityResponse {
+                available_liquidity: Asset {
+                    info: asset_info,
+                    amount: Uint128::new(100_000),
+                },
+                flash_loan_enabled: true,
+            }
+        );
+    }
+
+    #[test]
+    fn does_get_available_liquidity_token() {
+        let env = mock_env();
+        let mut deps = mock_dependencies_lp(
+            &[],
+            &[(
+                env.clone().contract.address.into_string(),
+                &[("vault_token".to_string(), Uint128::new(50_000))],
+            )],
+            vec![],
+        );
+
+        let asset_info = AssetInfo::Token {
+            contract_addr: "vault_token".to_string(),
+        };
+        CONFIG
+            .save(&mut deps.storage, &mock_config(asset_info.clone(), false))
+            .unwrap();
+
+        let res: AvailableLiquidityResponse =
+            from_json(query(deps.as_ref(), env, QueryMsg::AvailableLiquidity {}).unwrap()).unwrap();
+
+        assert_eq!(
+            res,
+            AvailableLiquidityResponse {
+                available_liquidity: Asset {
+                    info: asset_info,
+                    amount: Uint128::new(50_000),
+                },
+                flash_loan_enabled: false,
+            }
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::{to_json_binary, Binary, Deps, Uint128, Uint256};
-use white_whale_std::vault_network::vault::PaybackAmountResponse;
+use white_whale_std::vault_network::vault::{Config, PaybackAmountResponse};
 
 use crate::error::VaultError;
 use crate::state::CONFIG;
@@ -7,6 +7,14 @@ use crate::state::CONFIG;
 pub fn get_payback_amount(deps: Deps, amount: Uint128) -> Result<Binary, VaultError> {
     let config = CONFIG.load(deps.storage)?;
 
+    Ok(to_json_binary(&compute_payback_amount(&config, amount)?)?)
+}
+
+/// Computes the amount that must be paid back for a loan of the given amount, along with its fees.
+pub fn compute_payback_amount(
+    config: &Config,
+    amount: Uint128,
+) -> Result<PaybackAmountResponse, VaultError> {
     // check that balance is greater than expected
     let protocol_fee = Uint128::try_from(config.fees.protocol_fee.compute(Uint256::from(amount)))?;
     let flash_loan_fee =
@@ -18,12 +26,12 @@ pub fn get_payback_amount(deps: Deps, amount: Uint128) -> Result<Binary, VaultEr
         .checked_add(flash_loan_fee)?
         .checked_add(burn_fee)?;
 
-    Ok(to_json_binary(&PaybackAmountResponse {
+    Ok(PaybackAmountResponse {
         payback_amount: required_amount,
         protocol_fee,
         flash_loan_fee,
         burn_fee,
-    })?)
+    })
 }
 
 #[cfg(test)]
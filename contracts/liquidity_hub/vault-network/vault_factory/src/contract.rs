@@ -8,11 +8,12 @@ use white_whale_std::vault_network::vault_factory::{
 
 use crate::err::{StdResult, VaultFactoryError};
 use crate::execute::{
-    create_vault, migrate_vaults, remove_vault, update_config, update_vault_config,
+    create_vault, migrate_vaults, pause_vaults, remove_vault, unpause_vaults, update_config,
+    update_guardian, update_vault_config,
 };
 use crate::migrations;
-use crate::queries::{get_config, get_vault, get_vaults};
-use crate::state::CONFIG;
+use crate::queries::{get_config, get_pause_status, get_vault, get_vaults};
+use crate::state::{CONFIG, GUARDIAN};
 
 const CONTRACT_NAME: &str = "white_whale-vault_factory";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -31,6 +32,8 @@ pub fn instantiate(
         vault_id: msg.vault_id,
         token_id: msg.token_id,
         fee_collector_addr: deps.api.addr_validate(&msg.fee_collector_addr)?,
+        vault_creation_fee: None,
+        fee_bounds: None,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -39,10 +42,14 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
-    // permission check
-    let config = CONFIG.load(deps.storage)?;
-    if config.owner != info.sender {
-        return Err(VaultFactoryError::Unauthorized {});
+    // permission check, anyone can create vaults and the guardian can pause them as well
+    if !matches!(msg, ExecuteMsg::CreateVault { .. }) {
+        let config = CONFIG.load(deps.storage)?;
+        let is_guardian = matches!(msg, ExecuteMsg::PauseVaults { .. })
+            && GUARDIAN.may_load(deps.storage)?.as_ref() == Some(&info.sender);
+        if config.owner != info.sender && !is_guardian {
+            return Err(VaultFactoryError::Unauthorized {});
+        }
     }
 
     match msg {
@@ -64,13 +71,35 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
             fee_collector_addr,
             vault_id,
             token_id,
-        } => update_config(deps, owner, fee_collector_addr, vault_id, token_id),
+            vault_creation_fee,
+            fee_bounds,
+        } => update_config(
+            deps,
+            owner,
+            fee_collector_addr,
+            vault_id,
+            token_id,
+            vault_creation_fee,
+            fee_bounds,
+        ),
+        ExecuteMsg::PauseVaults {
+            vault_addr,
+            reason,
+            start_after,
+            limit,
+        } => pause_vaults(deps, env, info, vault_addr, reason, start_after, limit),
+        ExecuteMsg::UnpauseVaults {
+            vault_addr,
+            start_after,
+            limit,
+        } => unpause_vaults(deps, vault_addr, start_after, limit),
+        ExecuteMsg::UpdateGuardian { guardian } => update_guardian(deps, guardian),
     }
 }
 
 #[cfg(not(tarpaulin_include))]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response> {
+pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
     use white_whale_std::migrate_guards::check_contract_name;
 
     check_contract_name(deps.storage, CONTRACT_NAME.to_string())?;
@@ -89,25 +118,41 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Resp
         migrations::migrate_to_v110(deps.branch())?;
     }
 
+    migrations::set_vault_creation_config(deps.branch(), msg.vault_creation_fee, msg.fee_bounds)?;
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
 
 #[cfg(test)]
 mod test {
-    use white_whale_std::vault_network::vault_factory::MigrateMsg;
+    use cosmwasm_std::Uint128;
+    use cw2::set_contract_version;
+    use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+    use white_whale_std::vault_network::vault_factory::{
+        MigrateMsg, VaultCreationFee, VaultFeeBounds,
+    };
 
     use crate::err::VaultFactoryError;
+    use crate::state::CONFIG;
+    use crate::tests::get_fees;
     use crate::tests::mock_instantiate::mock_instantiate;
 
-    use super::migrate;
+    use super::{migrate, CONTRACT_NAME};
 
     #[test]
     fn can_migrate() {
         // instantiate contract
         let (mut deps, env) = mock_instantiate(5, 6);
 
-        let res = migrate(deps.as_mut(), env, MigrateMsg {});
+        let res = migrate(
+            deps.as_mut(),
+            env,
+            MigrateMsg {
+                vault_creation_fee: None,
+                fee_bounds: None,
+            },
+        );
 
         // should not be able to migrate as the version is lower
         match res {
@@ -115,6 +160,40 @@ mod test {
             _ => panic!("should return VaultFactoryError::MigrateInvalidVersion"),
         }
     }
+
+    #[test]
+    fn migration_sets_the_vault_creation_config() {
+        let (mut deps, env) = mock_instantiate(5, 6);
+        set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "1.2.0").unwrap();
+
+        let vault_creation_fee = VaultCreationFee {
+            fee: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            burn: true,
+        };
+        let fee_bounds = VaultFeeBounds {
+            min: get_fees(),
+            max: get_fees(),
+        };
+
+        migrate(
+            deps.as_mut(),
+            env,
+            MigrateMsg {
+                vault_creation_fee: Some(vault_creation_fee.clone()),
+                fee_bounds: Some(fee_bounds.clone()),
+            },
+        )
+        .unwrap();
+
+        let config = CONFIG.load(deps.as_ref().storage).unwrap();
+        assert_eq!(config.vault_creation_fee, Some(vault_creation_fee));
+        assert_eq!(config.fee_bounds, Some(fee_bounds));
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -123,5 +202,6 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Config {} => get_config(deps),
         QueryMsg::Vault { asset_info } => get_vault(deps, asset_info),
         QueryMsg::Vaults { start_after, limit } => get_vaults(deps, start_after, limit),
+        QueryMsg::PauseStatus { contract } => get_pause_status(deps, contract),
     }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use semver::Version;
 use thiserror::Error;
 
@@ -26,6 +26,26 @@ pub enum VaultFactoryError {
 
     #[error("Vault doesn't exist given the vault address provided")]
     NonExistentVault {},
+
+    #[error("The vault creation fee was not paid. Paid {paid_amount}, required {required_amount}")]
+    VaultCreationFeeNotPaid {
+        paid_amount: Uint128,
+        required_amount: Uint128,
+    },
+
+    #[error("The {fee} of {share} is out of bounds, it must be between {min} and {max}")]
+    FeeOutOfBounds {
+        fee: String,
+        share: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+
+    #[error("Invalid fee bounds, the max fees must be valid and no min fee can exceed its max")]
+    InvalidFeeBounds {},
+
+    #[error("Only the owner can create vaults until a vault creation fee and fee bounds are set")]
+    PermissionlessVaultCreationDisabled {},
 }
 
 impl From<semver::Error> for VaultFactoryError {
@@ -7,6 +7,7 @@ use white_whale_std::vault_network::vault_factory::INSTANTIATE_VAULT_REPLY_ID;
 use crate::{
     asset::AssetReference,
     err::{StdResult, VaultFactoryError},
+    fee::{assert_fees_within_bounds, charge_vault_creation_fee},
     state::{CONFIG, TMP_VAULT_ASSET, VAULTS},
 };
 
@@ -18,7 +19,6 @@ pub fn create_vault(
     fees: VaultFee,
     token_factory_lp: bool,
 ) -> StdResult<Response> {
-    // check that owner is creating vault
     let config = CONFIG.load(deps.storage)?;
 
     // check that existing vault does not exist
@@ -27,9 +27,11 @@ pub fn create_vault(
         return Err(VaultFactoryError::ExistingVault { addr });
     }
 
-    // check the fees are valid
-    fees.flash_loan_fee.is_valid()?;
-    fees.protocol_fee.is_valid()?;
+    // check the fees are valid and within the bounds set in the factory
+    assert_fees_within_bounds(&fees, &config.fee_bounds)?;
+
+    // non-owners pay the vault creation fee, taken out of the funds sent along
+    let (fee_messages, funds) = charge_vault_creation_fee(&info, &config)?;
 
     // create a new vault
     let vault_instantiate_msg: SubMsg = SubMsg {
@@ -45,7 +47,7 @@ pub fn create_vault(
                 vault_fees: fees,
                 token_factory_lp,
             })?,
-            funds: info.funds,
+            funds,
             label: format!(
                 "White Whale {} Vault",
                 asset_info.clone().get_label(&deps.as_ref())?
@@ -63,6 +65,7 @@ pub fn create_vault(
     )?;
 
     Ok(Response::new()
+        .add_messages(fee_messages)
         .add_submessage(vault_instantiate_msg)
         .add_attributes(vec![("method", "create_vault")]))
 }
@@ -70,13 +73,15 @@ pub fn create_vault(
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{
-        testing::mock_info, to_json_binary, Addr, Decimal, ReplyOn, Response, StdError, SubMsg,
-        WasmMsg,
+        coin, coins, testing::mock_info, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal,
+        ReplyOn, Response, StdError, SubMsg, Uint128, WasmMsg,
     };
     use cw_multi_test::Executor;
     use white_whale_std::fee::{Fee, VaultFee};
-    use white_whale_std::pool_network::asset::AssetInfo;
-    use white_whale_std::vault_network::vault_factory::INSTANTIATE_VAULT_REPLY_ID;
+    use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+    use white_whale_std::vault_network::vault_factory::{
+        VaultCreationFee, VaultFeeBounds, INSTANTIATE_VAULT_REPLY_ID,
+    };
 
     use crate::{
         contract::execute,
@@ -182,28 +187,242 @@ mod tests {
     }
 
     #[test]
-    fn cannot_create_vault_unauthorized() {
+    fn anyone_can_create_vault_paying_the_fee() {
         let asset_info = AssetInfo::NativeToken {
             denom: "uluna".to_string(),
         };
 
         let (mut deps, env) = mock_instantiate(5, 6);
 
-        // create a vault unauthorized
-        let bad_actor = mock_info("not_owner", &[]);
+        let create_vault_msg =
+            white_whale_std::vault_network::vault_factory::ExecuteMsg::CreateVault {
+                asset_info,
+                fees: get_fees(),
+                token_factory_lp: false,
+            };
+
+        // permissionless vault creation is disabled until both the fee and the bounds are set
+        for (vault_creation_fee, fee_bounds) in [
+            (None, None),
+            (
+                Some(VaultCreationFee {
+                    fee: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        amount: Uint128::new(1_000u128),
+                    },
+                    burn: false,
+                }),
+                None,
+            ),
+        ] {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_creator(),
+                white_whale_std::vault_network::vault_factory::ExecuteMsg::UpdateConfig {
+                    owner: None,
+                    fee_collector_addr: None,
+                    vault_id: None,
+                    token_id: None,
+                    vault_creation_fee,
+                    fee_bounds,
+                },
+            )
+            .unwrap();
+
+            let res = execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("not_owner", &coins(1_000u128, "uwhale")),
+                create_vault_msg.clone(),
+            );
+            assert_eq!(
+                res.unwrap_err(),
+                VaultFactoryError::PermissionlessVaultCreationDisabled {}
+            );
+        }
 
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            white_whale_std::vault_network::vault_factory::ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                vault_id: None,
+                token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: Some(VaultFeeBounds {
+                    min: VaultFee {
+                        flash_loan_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                        protocol_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                        burn_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                    },
+                    max: VaultFee {
+                        flash_loan_fee: Fee {
+                            share: Decimal::percent(5),
+                        },
+                        protocol_fee: Fee {
+                            share: Decimal::percent(5),
+                        },
+                        burn_fee: Fee {
+                            share: Decimal::percent(1),
+                        },
+                    },
+                }),
+            },
+        )
+        .unwrap();
+
+        // the fee is not paid
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("not_owner", &coins(500u128, "uwhale")),
+            create_vault_msg.clone(),
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            VaultFactoryError::VaultCreationFeeNotPaid {
+                paid_amount: Uint128::new(500u128),
+                required_amount: Uint128::new(1_000u128),
+            }
+        );
+
+        // the fee goes to the fee collector, the rest of the funds to the vault instantiation
         let res = execute(
             deps.as_mut(),
             env,
-            bad_actor,
+            mock_info(
+                "not_owner",
+                &[coin(1_000u128, "uwhale"), coin(1_000u128, "uluna")],
+            ),
+            create_vault_msg,
+        )
+        .unwrap();
+
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "fee_collector".to_string(),
+                amount: coins(1_000u128, "uwhale"),
+            })
+        );
+        match &res.messages[1].msg {
+            CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => {
+                assert_eq!(funds, &coins(1_000u128, "uluna"))
+            }
+            _ => panic!("should instantiate the vault"),
+        }
+    }
+
+    #[test]
+    fn does_error_if_fees_out_of_bounds() {
+        let (mut deps, env) = mock_instantiate(1, 2);
+
+        let fee_bounds = VaultFeeBounds {
+            min: VaultFee {
+                flash_loan_fee: Fee {
+                    share: Decimal::permille(1),
+                },
+                protocol_fee: Fee {
+                    share: Decimal::zero(),
+                },
+                burn_fee: Fee {
+                    share: Decimal::zero(),
+                },
+            },
+            max: VaultFee {
+                flash_loan_fee: Fee {
+                    share: Decimal::percent(5),
+                },
+                protocol_fee: Fee {
+                    share: Decimal::percent(5),
+                },
+                burn_fee: Fee {
+                    share: Decimal::percent(1),
+                },
+            },
+        };
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            white_whale_std::vault_network::vault_factory::ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                vault_id: None,
+                token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: Some(fee_bounds),
+            },
+        )
+        .unwrap();
+
+        // the bounds apply to the owner as well
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
             white_whale_std::vault_network::vault_factory::ExecuteMsg::CreateVault {
-                asset_info,
-                fees: get_fees(),
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: VaultFee {
+                    burn_fee: Fee {
+                        share: Decimal::percent(2),
+                    },
+                    ..get_fees()
+                },
                 token_factory_lp: false,
             },
         );
+        assert_eq!(
+            res.unwrap_err(),
+            VaultFactoryError::FeeOutOfBounds {
+                fee: "burn_fee".to_string(),
+                share: Decimal::percent(2),
+                min: Decimal::zero(),
+                max: Decimal::percent(1),
+            }
+        );
 
-        assert_eq!(res.unwrap_err(), VaultFactoryError::Unauthorized {})
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            white_whale_std::vault_network::vault_factory::ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: VaultFee {
+                    flash_loan_fee: Fee {
+                        share: Decimal::zero(),
+                    },
+                    ..get_fees()
+                },
+                token_factory_lp: false,
+            },
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            VaultFactoryError::FeeOutOfBounds {
+                fee: "flash_loan_fee".to_string(),
+                share: Decimal::zero(),
+                min: Decimal::permille(1),
+                max: Decimal::percent(5),
+            }
+        );
     }
 
     #[test]
@@ -330,6 +549,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn does_error_if_aggregated_fee_is_invalid() {
+        let (mut deps, env) = mock_instantiate(1, 2);
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            white_whale_std::vault_network::vault_factory::ExecuteMsg::CreateVault {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                fees: VaultFee {
+                    flash_loan_fee: Fee {
+                        share: Decimal::percent(50),
+                    },
+                    protocol_fee: Fee {
+                        share: Decimal::percent(30),
+                    },
+                    burn_fee: Fee {
+                        share: Decimal::percent(20),
+                    },
+                },
+                token_factory_lp: false,
+            },
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            VaultFactoryError::Std(StdError::GenericErr {
+                msg: "Invalid fees".to_string()
+            })
+        );
+    }
+
     #[test]
     fn can_create_ibc_token_vault() {
         let asset_info = AssetInfo::NativeToken {
@@ -1,11 +1,15 @@
 mod create_vault;
 mod migrate_vaults;
+mod pause_vaults;
 mod remove_vault;
 mod update_config;
+mod update_guardian;
 mod update_vault_config;
 
 pub use create_vault::create_vault;
 pub use migrate_vaults::migrate_vaults;
+pub use pause_vaults::{pause_vaults, unpause_vaults};
 pub use remove_vault::remove_vault;
 pub use update_config::update_config;
+pub use update_guardian::update_guardian;
 pub use update_vault_config::update_vault_config;
@@ -0,0 +1,544 @@
+use cosmwasm_std::{
+    to_json_binary, wasm_execute, Addr, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response,
+};
+use serde::Deserialize;
+
+use white_whale_std::pause::PauseInfo;
+use white_whale_std::pool_network::asset::AssetInfo;
+use white_whale_std::vault_network::vault::{ExecuteMsg, QueryMsg, UpdateConfigParams};
+
+use crate::asset::AssetReference;
+use crate::err::{StdResult, VaultFactoryError};
+use crate::state::{
+    read_paused_by_all, read_vaults, VaultToggle, ALL_PAUSED, PAUSED_BY_ALL, PAUSED_VAULTS, VAULTS,
+};
+
+/// Disables flash-loans and deposits on the given vault, or on a page of the vaults if none is
+/// given. When pausing all the vaults, the toggles of each one are kept so [unpause_vaults] can
+/// restore them, while vaults paused on their own or with both disabled already are skipped. The
+/// asset info reference of the last vault of the page is set as the response data.
+pub fn pause_vaults(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_addr: Option<String>,
+    reason: String,
+    start_after: Option<Vec<u8>>,
+    limit: Option<u32>,
+) -> StdResult<Response> {
+    let pause_info = PauseInfo::new(info.sender.clone(), reason.clone(), &env);
+
+    let mut data = None;
+    let vaults = match vault_addr {
+        Some(vault_addr) => {
+            let vault_addr = deps.api.addr_validate(&vault_addr)?;
+            ensure_vault_exists(deps.as_ref(), &vault_addr)?;
+
+            PAUSED_VAULTS.save(deps.storage, &vault_addr, &pause_info)?;
+            vec![vault_addr]
+        }
+        None => {
+            // pausing all the vaults takes several pages, the first one sets the pause info
+            if !ALL_PAUSED.exists(deps.storage) {
+                ALL_PAUSED.save(deps.storage, &pause_info)?;
+            }
+
+            let page = read_vaults(deps.storage, deps.api, start_after, limit)?;
+            let last_vault = page.last().map(|vault| vault.asset_info_reference.clone());
+            data = Some(to_json_binary(&last_vault)?);
+
+            let mut vaults = vec![];
+            for vault in page {
+                let vault_addr = deps.api.addr_validate(&vault.vault)?;
+                if PAUSED_VAULTS.has(deps.storage, &vault_addr)
+                    || PAUSED_BY_ALL.has(deps.storage, &vault_addr)
+                {
+                    continue;
+                }
+
+                let toggle = query_vault_config(deps.as_ref(), &vault_addr)?.toggle();
+                if !toggle.flash_loan_enabled && !toggle.deposit_enabled {
+                    continue;
+                }
+
+                PAUSED_BY_ALL.save(deps.storage, &vault_addr, &toggle)?;
+                vaults.push(vault_addr);
+            }
+            vaults
+        }
+    };
+
+    let mut res = Response::new().add_attributes(vec![
+        ("method", "pause_vaults".to_string()),
+        ("reason", reason),
+        ("paused_by", info.sender.to_string()),
+    ]);
+    for vault in vaults {
+        res = res
+            .add_attribute("vault", vault.to_string())
+            .add_message(toggle_vault_msg(
+                vault,
+                VaultToggle {
+                    flash_loan_enabled: false,
+                    deposit_enabled: false,
+                },
+            )?);
+    }
+
+    if let Some(data) = data {
+        res = res.set_data(data);
+    }
+
+    Ok(res)
+}
+
+/// Enables flash-loans and deposits on the given vault. If none is given, restores the toggles of
+/// a page of the vaults disabled when pausing all of them, except for the ones paused on their
+/// own. Once every vault is restored, the vaults are no longer paused.
+pub fn unpause_vaults(
+    deps: DepsMut,
+    vault_addr: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Response> {
+    let vaults = match vault_addr {
+        Some(vault_addr) => {
+            let vault_addr = deps.api.addr_validate(&vault_addr)?;
+            ensure_vault_exists(deps.as_ref(), &vault_addr)?;
+
+            PAUSED_VAULTS.remove(deps.storage, &vault_addr);
+            PAUSED_BY_ALL.remove(deps.storage, &vault_addr);
+            vec![(
+                vault_addr,
+                VaultToggle {
+                    flash_loan_enabled: true,
+                    deposit_enabled: true,
+                },
+            )]
+        }
+        None => {
+            let start_after = start_after
+                .map(|start_after| deps.api.addr_validate(&start_after))
+                .transpose()?;
+
+            let mut vaults = vec![];
+            for (vault_addr, toggle) in read_paused_by_all(deps.storage, start_after, limit)? {
+                PAUSED_BY_ALL.remove(deps.storage, &vault_addr);
+                if !PAUSED_VAULTS.has(deps.storage, &vault_addr) {
+                    vaults.push((vault_addr, toggle));
+                }
+            }
+
+            if PAUSED_BY_ALL
+                .keys(deps.storage, None, None, Order::Ascending)
+                .next()
+                .is_none()
+            {
+                ALL_PAUSED.remove(deps.storage);
+            }
+
+            vaults
+        }
+    };
+
+    let mut res = Response::new().add_attribute("method", "unpause_vaults");
+    for (vault, toggle) in vaults {
+        res = res
+            .add_attribute("vault", vault.to_string())
+            .add_message(toggle_vault_msg(vault, toggle)?);
+    }
+
+    Ok(res)
+}
+
+/// The part of the vault config needed to pause it. The rest is ignored.
+#[derive(Deserialize)]
+struct VaultConfig {
+    asset_info: AssetInfo,
+    flash_loan_enabled: bool,
+    deposit_enabled: bool,
+}
+
+impl VaultConfig {
+    fn toggle(&self) -> VaultToggle {
+        VaultToggle {
+            flash_loan_enabled: self.flash_loan_enabled,
+            deposit_enabled: self.deposit_enabled,
+        }
+    }
+}
+
+fn query_vault_config(deps: Deps, vault_addr: &Addr) -> StdResult<VaultConfig> {
+    Ok(deps
+        .querier
+        .query_wasm_smart(vault_addr, &QueryMsg::Config {})?)
+}
+
+/// Checks the given address is a vault created by the factory.
+fn ensure_vault_exists(deps: Deps, vault_addr: &Addr) -> StdResult<()> {
+    let config =
+        query_vault_config(deps, vault_addr).map_err(|_| VaultFactoryError::NonExistentVault {})?;
+
+    match VAULTS.may_load(deps.storage, config.asset_info.get_reference())? {
+        Some((addr, _)) if addr == *vault_addr => Ok(()),
+        _ => Err(VaultFactoryError::NonExistentVault {}),
+    }
+}
+
+/// Creates a message setting the flash-loan and deposit toggles of a vault.
+fn toggle_vault_msg(vault_addr: Addr, toggle: VaultToggle) -> StdResult<CosmosMsg> {
+    Ok(wasm_execute(
+        vault_addr,
+        &ExecuteMsg::UpdateConfig(UpdateConfigParams {
+            flash_loan_enabled: Some(toggle.flash_loan_enabled),
+            deposit_enabled: Some(toggle.deposit_enabled),
+            withdraw_enabled: None,
+            new_owner: None,
+            new_vault_fees: None,
+            new_fee_collector_addr: None,
+        }),
+        vec![],
+    )?
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, Addr};
+    use cw_multi_test::{App, Executor};
+
+    use white_whale_std::pause::PauseStatusResponse;
+    use white_whale_std::pool_network::asset::AssetInfo;
+    use white_whale_std::vault_network::vault::{
+        Config, QueryMsg as VaultQueryMsg, UpdateConfigParams,
+    };
+    use white_whale_std::vault_network::vault_factory::{ExecuteMsg, QueryMsg};
+
+    use crate::{
+        err::VaultFactoryError,
+        tests::{get_fees, mock_app, mock_creator, mock_instantiate::app_mock_instantiate},
+    };
+
+    fn create_vault(app: &mut App, factory_addr: &Addr, denom: &str) -> Addr {
+        let asset_info = AssetInfo::NativeToken {
+            denom: denom.to_string(),
+        };
+
+        app.execute_contract(
+            mock_creator().sender,
+            factory_addr.clone(),
+            &ExecuteMsg::CreateVault {
+                asset_info: asset_info.clone(),
+                fees: get_fees(),
+                token_factory_lp: false,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let vault_addr: Option<Addr> = app
+            .wrap()
+            .query_wasm_smart(factory_addr.clone(), &QueryMsg::Vault { asset_info })
+            .unwrap();
+        vault_addr.unwrap()
+    }
+
+    fn vault_config(app: &App, vault_addr: &Addr) -> Config {
+        app.wrap()
+            .query_wasm_smart(vault_addr.clone(), &VaultQueryMsg::Config {})
+            .unwrap()
+    }
+
+    #[test]
+    fn guardian_can_pause_vaults() {
+        let mut app = mock_app();
+        let factory_addr = app_mock_instantiate(&mut app);
+        let luna_vault = create_vault(&mut app, &factory_addr, "uluna");
+        let whale_vault = create_vault(&mut app, &factory_addr, "uwhale");
+        let atom_vault = create_vault(&mut app, &factory_addr, "uatom");
+
+        // the owner disabled deposits on the atom vault
+        app.execute_contract(
+            mock_creator().sender,
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateVaultConfig {
+                vault_addr: atom_vault.to_string(),
+                params: UpdateConfigParams {
+                    flash_loan_enabled: None,
+                    deposit_enabled: Some(false),
+                    withdraw_enabled: None,
+                    new_owner: None,
+                    new_vault_fees: None,
+                    new_fee_collector_addr: None,
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+        // the guardian can't pause before being set by the owner
+        let err = app
+            .execute_contract(
+                Addr::unchecked("guardian"),
+                factory_addr.clone(),
+                &ExecuteMsg::PauseVaults {
+                    vault_addr: None,
+                    reason: "exploit".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause()
+                .downcast_ref::<VaultFactoryError>()
+                .unwrap(),
+            &VaultFactoryError::Unauthorized {}
+        );
+
+        app.execute_contract(
+            mock_creator().sender,
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateGuardian {
+                guardian: Some("guardian".to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // only the vaults created by the factory can be paused
+        for vault_addr in [factory_addr.to_string(), "vault0000".to_string()] {
+            let err = app
+                .execute_contract(
+                    Addr::unchecked("guardian"),
+                    factory_addr.clone(),
+                    &ExecuteMsg::PauseVaults {
+                        vault_addr: Some(vault_addr),
+                        reason: "exploit".to_string(),
+                        start_after: None,
+                        limit: None,
+                    },
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.root_cause()
+                    .downcast_ref::<VaultFactoryError>()
+                    .unwrap(),
+                &VaultFactoryError::NonExistentVault {}
+            );
+        }
+
+        // pause a single vault
+        app.execute_contract(
+            Addr::unchecked("guardian"),
+            factory_addr.clone(),
+            &ExecuteMsg::PauseVaults {
+                vault_addr: Some(luna_vault.to_string()),
+                reason: "flash-loan exploit".to_string(),
+                start_after: None,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let config = vault_config(&app, &luna_vault);
+        assert!(!config.flash_loan_enabled);
+        assert!(!config.deposit_enabled);
+        assert!(config.withdraw_enabled);
+        assert!(vault_config(&app, &whale_vault).flash_loan_enabled);
+
+        let pause_status: PauseStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr.clone(),
+                &QueryMsg::PauseStatus {
+                    contract: Some(luna_vault.to_string()),
+                },
+            )
+            .unwrap();
+        assert_eq!(pause_status.guardian, Some(Addr::unchecked("guardian")));
+        assert_eq!(pause_status.all_paused, None);
+        assert_eq!(
+            pause_status.contract_paused.unwrap().reason,
+            "flash-loan exploit".to_string()
+        );
+
+        // pause all the vaults, two at a time
+        let res = app
+            .execute_contract(
+                Addr::unchecked("guardian"),
+                factory_addr.clone(),
+                &ExecuteMsg::PauseVaults {
+                    vault_addr: None,
+                    reason: "exploit".to_string(),
+                    start_after: None,
+                    limit: Some(2),
+                },
+                &[],
+            )
+            .unwrap();
+        let last_vault: Option<Vec<u8>> = from_json(res.data.unwrap()).unwrap();
+        assert!(last_vault.is_some());
+
+        let pause_status: PauseStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr.clone(),
+                &QueryMsg::PauseStatus { contract: None },
+            )
+            .unwrap();
+        assert_eq!(
+            pause_status.all_paused.unwrap().reason,
+            "exploit".to_string()
+        );
+
+        let res = app
+            .execute_contract(
+                Addr::unchecked("guardian"),
+                factory_addr.clone(),
+                &ExecuteMsg::PauseVaults {
+                    vault_addr: None,
+                    reason: "exploit".to_string(),
+                    start_after: last_vault,
+                    limit: Some(2),
+                },
+                &[],
+            )
+            .unwrap();
+        let last_vault: Option<Vec<u8>> = from_json(res.data.unwrap()).unwrap();
+        assert!(last_vault.is_some());
+
+        for vault in [&whale_vault, &atom_vault] {
+            let config = vault_config(&app, vault);
+            assert!(!config.flash_loan_enabled);
+            assert!(!config.deposit_enabled);
+        }
+
+        // the page after the last vault is empty
+        let res = app
+            .execute_contract(
+                Addr::unchecked("guardian"),
+                factory_addr.clone(),
+                &ExecuteMsg::PauseVaults {
+                    vault_addr: None,
+                    reason: "exploit".to_string(),
+                    start_after: last_vault,
+                    limit: Some(2),
+                },
+                &[],
+            )
+            .unwrap();
+        let last_vault: Option<Vec<u8>> = from_json(res.data.unwrap()).unwrap();
+        assert_eq!(last_vault, None);
+
+        // the guardian can't unpause
+        let err = app
+            .execute_contract(
+                Addr::unchecked("guardian"),
+                factory_addr.clone(),
+                &ExecuteMsg::UnpauseVaults {
+                    vault_addr: None,
+                    start_after: None,
+                    limit: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.root_cause()
+                .downcast_ref::<VaultFactoryError>()
+                .unwrap(),
+            &VaultFactoryError::Unauthorized {}
+        );
+
+        // the owner can, one vault at a time. The vaults stay paused until all of them are
+        // restored
+        app.execute_contract(
+            mock_creator().sender,
+            factory_addr.clone(),
+            &ExecuteMsg::UnpauseVaults {
+                vault_addr: None,
+                start_after: None,
+                limit: Some(1),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let pause_status: PauseStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr.clone(),
+                &QueryMsg::PauseStatus { contract: None },
+            )
+            .unwrap();
+        assert!(pause_status.all_paused.is_some());
+
+        app.execute_contract(
+            mock_creator().sender,
+            factory_addr.clone(),
+            &ExecuteMsg::UnpauseVaults {
+                vault_addr: None,
+                start_after: None,
+                limit: Some(1),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // only what pausing all the vaults disabled is restored, the luna vault stays paused on
+        // its own and deposits stay disabled on the atom vault
+        let config = vault_config(&app, &whale_vault);
+        assert!(config.flash_loan_enabled);
+        assert!(config.deposit_enabled);
+        let config = vault_config(&app, &atom_vault);
+        assert!(config.flash_loan_enabled);
+        assert!(!config.deposit_enabled);
+        let config = vault_config(&app, &luna_vault);
+        assert!(!config.flash_loan_enabled);
+        assert!(!config.deposit_enabled);
+
+        let pause_status: PauseStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr.clone(),
+                &QueryMsg::PauseStatus {
+                    contract: Some(luna_vault.to_string()),
+                },
+            )
+            .unwrap();
+        assert_eq!(pause_status.all_paused, None);
+        assert!(pause_status.is_paused());
+
+        // the owner unpauses the luna vault on its own
+        app.execute_contract(
+            mock_creator().sender,
+            factory_addr.clone(),
+            &ExecuteMsg::UnpauseVaults {
+                vault_addr: Some(luna_vault.to_string()),
+                start_after: None,
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let config = vault_config(&app, &luna_vault);
+        assert!(config.flash_loan_enabled);
+        assert!(config.deposit_enabled);
+
+        let pause_status: PauseStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr,
+                &QueryMsg::PauseStatus {
+                    contract: Some(luna_vault.to_string()),
+                },
+            )
+            .unwrap();
+        assert!(!pause_status.is_paused());
+    }
+}
@@ -1,7 +1,9 @@
 use cosmwasm_std::{DepsMut, Response};
+use white_whale_std::vault_network::vault_factory::{VaultCreationFee, VaultFeeBounds};
 
 use crate::{
     err::{StdResult, VaultFactoryError},
+    fee::validate_fee_bounds,
     state::CONFIG,
 };
 
@@ -11,6 +13,8 @@ pub fn update_config(
     new_fee_collector_addr: Option<String>,
     new_vault_id: Option<u64>,
     new_token_id: Option<u64>,
+    new_vault_creation_fee: Option<VaultCreationFee>,
+    new_fee_bounds: Option<VaultFeeBounds>,
 ) -> StdResult<Response> {
     let new_config = CONFIG.update::<_, VaultFactoryError>(deps.storage, |mut config| {
         if let Some(new_owner) = new_owner {
@@ -29,6 +33,15 @@ pub fn update_config(
             config.token_id = new_token_id;
         }
 
+        if let Some(new_vault_creation_fee) = new_vault_creation_fee {
+            config.vault_creation_fee = Some(new_vault_creation_fee);
+        }
+
+        if let Some(new_fee_bounds) = new_fee_bounds {
+            validate_fee_bounds(&new_fee_bounds)?;
+            config.fee_bounds = Some(new_fee_bounds);
+        }
+
         Ok(config)
     })?;
 
@@ -46,8 +59,11 @@ pub fn update_config(
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{from_json, testing::mock_info, Addr, Response};
-    use white_whale_std::vault_network::vault_factory::{Config, ExecuteMsg, QueryMsg};
+    use cosmwasm_std::{from_json, testing::mock_info, Addr, Decimal, Response};
+    use white_whale_std::fee::{Fee, VaultFee};
+    use white_whale_std::vault_network::vault_factory::{
+        Config, ExecuteMsg, QueryMsg, VaultFeeBounds,
+    };
 
     use crate::{
         contract::{execute, query},
@@ -66,6 +82,8 @@ mod tests {
                 fee_collector_addr: None,
                 vault_id: None,
                 token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: None,
             },
         );
 
@@ -101,6 +119,8 @@ mod tests {
                 fee_collector_addr: Some("other_acc".to_string()),
                 vault_id: None,
                 token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: None,
             },
         );
 
@@ -136,6 +156,8 @@ mod tests {
                 fee_collector_addr: None,
                 vault_id: Some(3u64),
                 token_id: Some(4u64),
+                vault_creation_fee: None,
+                fee_bounds: None,
             },
         );
 
@@ -157,6 +179,8 @@ mod tests {
             owner: mock_creator().sender,
             vault_id: 3,
             token_id: 4,
+            vault_creation_fee: None,
+            fee_bounds: None,
         };
 
         let config: Config =
@@ -178,6 +202,8 @@ mod tests {
                 fee_collector_addr: None,
                 vault_id: None,
                 token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: None,
             },
         );
 
@@ -199,6 +225,8 @@ mod tests {
             owner: mock_creator().sender,
             vault_id: 1,
             token_id: 2,
+            vault_creation_fee: None,
+            fee_bounds: None,
         };
 
         let config: Config =
@@ -225,9 +253,70 @@ mod tests {
                 fee_collector_addr: Some(unauthorized_sender.sender.into_string()),
                 vault_id: None,
                 token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: None,
             },
         )
         .unwrap_err();
         assert_eq!(res, VaultFactoryError::Unauthorized {});
     }
+
+    #[test]
+    fn does_error_if_invalid_fee_bounds() {
+        let fee = |percent: u64| Fee {
+            share: Decimal::percent(percent),
+        };
+
+        // the min flash loan fee exceeds the max
+        let (res, ..) = mock_execute(
+            1,
+            2,
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                vault_id: None,
+                token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: Some(VaultFeeBounds {
+                    min: VaultFee {
+                        flash_loan_fee: fee(10),
+                        protocol_fee: fee(0),
+                        burn_fee: fee(0),
+                    },
+                    max: VaultFee {
+                        flash_loan_fee: fee(5),
+                        protocol_fee: fee(5),
+                        burn_fee: fee(5),
+                    },
+                }),
+            },
+        );
+        assert_eq!(res.unwrap_err(), VaultFactoryError::InvalidFeeBounds {});
+
+        // the max fees exceed 100% together
+        let (res, ..) = mock_execute(
+            1,
+            2,
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                vault_id: None,
+                token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: Some(VaultFeeBounds {
+                    min: VaultFee {
+                        flash_loan_fee: fee(0),
+                        protocol_fee: fee(0),
+                        burn_fee: fee(0),
+                    },
+                    max: VaultFee {
+                        flash_loan_fee: fee(50),
+                        protocol_fee: fee(50),
+                        burn_fee: fee(0),
+                    },
+                }),
+            },
+        );
+        assert_eq!(res.unwrap_err(), VaultFactoryError::InvalidFeeBounds {});
+    }
 }
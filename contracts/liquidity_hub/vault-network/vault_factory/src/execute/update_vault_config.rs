@@ -3,15 +3,28 @@ use cosmwasm_std::{wasm_execute, DepsMut, Response};
 use white_whale_std::vault_network::vault::UpdateConfigParams;
 
 use crate::err::StdResult;
+use crate::fee::assert_fees_within_bounds;
+use crate::state::{CONFIG, PAUSED_VAULTS};
 
 pub fn update_vault_config(
     deps: DepsMut,
     vault_addr: String,
     params: UpdateConfigParams,
 ) -> StdResult<Response> {
+    let vault_addr = deps.api.addr_validate(vault_addr.as_str())?;
+
+    if let Some(new_vault_fees) = &params.new_vault_fees {
+        assert_fees_within_bounds(new_vault_fees, &CONFIG.load(deps.storage)?.fee_bounds)?;
+    }
+
+    // re-enabling flash-loans or deposits unpauses the vault
+    if params.flash_loan_enabled == Some(true) || params.deposit_enabled == Some(true) {
+        PAUSED_VAULTS.remove(deps.storage, &vault_addr);
+    }
+
     Ok(Response::new()
         .add_message(wasm_execute(
-            deps.api.addr_validate(vault_addr.as_str())?.to_string(),
+            vault_addr.to_string(),
             &white_whale_std::vault_network::vault::ExecuteMsg::UpdateConfig(params),
             vec![],
         )?)
@@ -20,12 +33,18 @@ pub fn update_vault_config(
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::Addr;
+    use cosmwasm_std::{Addr, Decimal};
     use cw_multi_test::Executor;
+    use white_whale_std::fee::{Fee, VaultFee};
+    use white_whale_std::vault_network::vault_factory::VaultFeeBounds;
 
     use crate::{
+        contract::execute,
         err::VaultFactoryError,
-        tests::{get_fees, mock_app, mock_creator, mock_instantiate::app_mock_instantiate},
+        tests::{
+            get_fees, mock_app, mock_creator,
+            mock_instantiate::{app_mock_instantiate, mock_instantiate},
+        },
     };
 
     #[test]
@@ -171,4 +190,74 @@ mod tests {
             &VaultFactoryError::Unauthorized {}
         );
     }
+
+    #[test]
+    fn cannot_update_vault_fees_out_of_bounds() {
+        let (mut deps, env) = mock_instantiate(1, 2);
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            white_whale_std::vault_network::vault_factory::ExecuteMsg::UpdateConfig {
+                owner: None,
+                fee_collector_addr: None,
+                vault_id: None,
+                token_id: None,
+                vault_creation_fee: None,
+                fee_bounds: Some(VaultFeeBounds {
+                    min: VaultFee {
+                        flash_loan_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                        protocol_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                        burn_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                    },
+                    max: VaultFee {
+                        flash_loan_fee: Fee {
+                            share: Decimal::percent(5),
+                        },
+                        protocol_fee: Fee {
+                            share: Decimal::percent(1),
+                        },
+                        burn_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                    },
+                }),
+            },
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_creator(),
+            white_whale_std::vault_network::vault_factory::ExecuteMsg::UpdateVaultConfig {
+                vault_addr: "vault".to_string(),
+                params: white_whale_std::vault_network::vault::UpdateConfigParams {
+                    flash_loan_enabled: None,
+                    deposit_enabled: None,
+                    withdraw_enabled: None,
+                    new_owner: None,
+                    new_vault_fees: Some(get_fees()),
+                    new_fee_collector_addr: None,
+                },
+            },
+        );
+
+        assert_eq!(
+            res.unwrap_err(),
+            VaultFactoryError::FeeOutOfBounds {
+                fee: "protocol_fee".to_string(),
+                share: get_fees().protocol_fee.share,
+                min: Decimal::zero(),
+                max: Decimal::percent(1),
+            }
+        );
+    }
 }
@@ -0,0 +1,159 @@
+use cosmwasm_std::{wasm_execute, Coin, CosmosMsg, MessageInfo};
+use white_whale_std::fee::{Fee, VaultFee};
+use white_whale_std::pool_network::asset::AssetInfo;
+use white_whale_std::vault_network::vault_factory::{Config, VaultCreationFee, VaultFeeBounds};
+
+use crate::err::{StdResult, VaultFactoryError};
+
+/// Checks that the given vault fees are valid and, if the factory has fee bounds set, that each of
+/// them is within its bounds.
+pub fn assert_fees_within_bounds(
+    fees: &VaultFee,
+    fee_bounds: &Option<VaultFeeBounds>,
+) -> StdResult<()> {
+    fees.is_valid()?;
+
+    let Some(fee_bounds) = fee_bounds else {
+        return Ok(());
+    };
+
+    for (name, fee, min, max) in fee_components(fees, fee_bounds) {
+        if fee.share < min.share || fee.share > max.share {
+            return Err(VaultFactoryError::FeeOutOfBounds {
+                fee: name.to_string(),
+                share: fee.share,
+                min: min.share,
+                max: max.share,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the max fees of the given bounds are valid and that no min fee exceeds its max.
+pub fn validate_fee_bounds(fee_bounds: &VaultFeeBounds) -> StdResult<()> {
+    fee_bounds
+        .max
+        .is_valid()
+        .map_err(|_| VaultFactoryError::InvalidFeeBounds {})?;
+
+    if fee_components(&fee_bounds.min, fee_bounds)
+        .iter()
+        .any(|(_, _, min, max)| min.share > max.share)
+    {
+        return Err(VaultFactoryError::InvalidFeeBounds {});
+    }
+
+    Ok(())
+}
+
+/// Pairs each component of the given fees with its bounds.
+fn fee_components<'a>(
+    fees: &'a VaultFee,
+    fee_bounds: &'a VaultFeeBounds,
+) -> [(&'static str, &'a Fee, &'a Fee, &'a Fee); 3] {
+    [
+        (
+            "protocol_fee",
+            &fees.protocol_fee,
+            &fee_bounds.min.protocol_fee,
+            &fee_bounds.max.protocol_fee,
+        ),
+        (
+            "flash_loan_fee",
+            &fees.flash_loan_fee,
+            &fee_bounds.min.flash_loan_fee,
+            &fee_bounds.max.flash_loan_fee,
+        ),
+        (
+            "burn_fee",
+            &fees.burn_fee,
+            &fee_bounds.min.burn_fee,
+            &fee_bounds.max.burn_fee,
+        ),
+    ]
+}
+
+/// Collects the vault creation fee from the sender, if any. The owner is exempt from it, and is the
+/// only one allowed to create vaults until the vault creation fee and the fee bounds are set.
+///
+/// Returns the messages forwarding the fee to the fee collector (or burning it), and the funds left
+/// to be sent along with the vault instantiation.
+pub fn charge_vault_creation_fee(
+    info: &MessageInfo,
+    config: &Config,
+) -> StdResult<(Vec<CosmosMsg>, Vec<Coin>)> {
+    let mut funds = info.funds.clone();
+
+    if info.sender == config.owner {
+        return Ok((vec![], funds));
+    }
+
+    // permissionless vault creation stays disabled until the owner sets both the fee and the bounds
+    let Some(VaultCreationFee { fee, burn }) = config.vault_creation_fee.clone() else {
+        return Err(VaultFactoryError::PermissionlessVaultCreationDisabled {});
+    };
+
+    if config.fee_bounds.is_none() {
+        return Err(VaultFactoryError::PermissionlessVaultCreationDisabled {});
+    }
+
+    if fee.amount.is_zero() {
+        return Ok((vec![], funds));
+    }
+
+    let message = match &fee.info {
+        AssetInfo::NativeToken { denom } => {
+            // the fee is taken out of the funds sent along, the rest goes to the vault instantiation
+            let paid_amount = funds
+                .iter()
+                .find(|coin| &coin.denom == denom)
+                .map(|coin| coin.amount)
+                .unwrap_or_default();
+
+            if paid_amount < fee.amount {
+                return Err(VaultFactoryError::VaultCreationFeeNotPaid {
+                    paid_amount,
+                    required_amount: fee.amount,
+                });
+            }
+
+            funds = funds
+                .into_iter()
+                .map(|mut coin| {
+                    if &coin.denom == denom {
+                        coin.amount -= fee.amount;
+                    }
+                    coin
+                })
+                .filter(|coin| !coin.amount.is_zero())
+                .collect();
+
+            if burn {
+                fee.into_burn_msg()?
+            } else {
+                fee.into_msg(config.fee_collector_addr.clone())?
+            }
+        }
+        AssetInfo::Token { contract_addr } => {
+            // the factory must have been given an allowance to spend the fee
+            let msg = if burn {
+                cw20::Cw20ExecuteMsg::BurnFrom {
+                    owner: info.sender.to_string(),
+                    amount: fee.amount,
+                }
+            } else {
+                cw20::Cw20ExecuteMsg::TransferFrom {
+                    owner: info.sender.to_string(),
+                    recipient: config.fee_collector_addr.to_string(),
+                    amount: fee.amount,
+                }
+            };
+
+            wasm_execute(contract_addr, &msg, vec![])?.into()
+        }
+    };
+
+    Ok((vec![message], funds))
+}
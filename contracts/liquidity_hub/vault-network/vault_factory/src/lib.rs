@@ -4,6 +4,7 @@ pub mod queries;
 pub mod reply;
 
 pub mod asset;
+pub mod fee;
 mod migrations;
 pub mod response;
 pub mod state;
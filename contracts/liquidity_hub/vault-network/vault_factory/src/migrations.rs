@@ -2,8 +2,11 @@ use cosmwasm_std::{Addr, DepsMut, Order, StdError};
 use cw_storage_plus::Map;
 
 use white_whale_std::pool_network::asset::AssetInfo;
+use white_whale_std::vault_network::vault_factory::{VaultCreationFee, VaultFeeBounds};
 
-use crate::state::{TMP_VAULT_ASSET, VAULTS};
+use crate::err::StdResult;
+use crate::fee::validate_fee_bounds;
+use crate::state::{CONFIG, TMP_VAULT_ASSET, VAULTS};
 
 /// Migrates the VAULTS state from v1.0.9 and lower to v1.1.0, which includes the asset info.
 pub fn migrate_to_v110(deps: DepsMut) -> Result<(), StdError> {
@@ -33,3 +36,26 @@ pub fn migrate_to_v110(deps: DepsMut) -> Result<(), StdError> {
 
     Ok(())
 }
+
+/// Sets the vault creation fee and the fee bounds, if given. Factories predating them have neither
+/// set, which keeps vault creation owner-only until both are.
+pub fn set_vault_creation_config(
+    deps: DepsMut,
+    vault_creation_fee: Option<VaultCreationFee>,
+    fee_bounds: Option<VaultFeeBounds>,
+) -> StdResult<()> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if let Some(vault_creation_fee) = vault_creation_fee {
+        config.vault_creation_fee = Some(vault_creation_fee);
+    }
+
+    if let Some(fee_bounds) = fee_bounds {
+        validate_fee_bounds(&fee_bounds)?;
+        config.fee_bounds = Some(fee_bounds);
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(())
+}
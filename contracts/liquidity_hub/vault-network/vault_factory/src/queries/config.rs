@@ -25,7 +25,9 @@ mod test {
                 owner: mock_creator().sender,
                 vault_id: 5,
                 token_id: 6,
-                fee_collector_addr: Addr::unchecked("fee_collector")
+                fee_collector_addr: Addr::unchecked("fee_collector"),
+                vault_creation_fee: None,
+                fee_bounds: None,
             }
         )
     }
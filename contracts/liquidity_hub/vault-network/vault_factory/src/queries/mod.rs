@@ -1,6 +1,8 @@
 mod config;
+mod pause_status;
 mod vault;
 
 pub use self::vault::get_vault;
 pub use self::vault::get_vaults;
 pub use config::get_config;
+pub use pause_status::get_pause_status;
@@ -0,0 +1,22 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+
+use white_whale_std::pause::PauseStatusResponse;
+
+use crate::err::StdResult;
+use crate::state::{ALL_PAUSED, GUARDIAN, PAUSED_VAULTS};
+
+/// Retrieves the guardian and whether vaults were paused through the factory.
+pub fn get_pause_status(deps: Deps, contract: Option<String>) -> StdResult<Binary> {
+    let contract_paused = match contract {
+        Some(contract) => {
+            PAUSED_VAULTS.may_load(deps.storage, &deps.api.addr_validate(&contract)?)?
+        }
+        None => None,
+    };
+
+    Ok(to_json_binary(&PauseStatusResponse {
+        guardian: GUARDIAN.may_load(deps.storage)?,
+        all_paused: ALL_PAUSED.may_load(deps.storage)?,
+        contract_paused,
+    })?)
+}
@@ -1,5 +1,7 @@
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Api, Order, StdResult, Storage};
 use cw_storage_plus::{Bound, Item, Map};
+use white_whale_std::pause::PauseInfo;
 use white_whale_std::pool_network::asset::AssetInfo;
 
 use white_whale_std::vault_network::vault_factory::{Config, VaultInfo};
@@ -8,6 +10,23 @@ pub const CONFIG: Item<Config> = Item::new("config");
 
 pub const VAULTS: Map<&[u8], (Addr, AssetInfo)> = Map::new("vaults");
 
+/// The guardian of the factory, which can pause vaults but never unpause them.
+pub const GUARDIAN: Item<Addr> = Item::new("guardian");
+/// Set while all the vaults are paused, see [PauseInfo].
+pub const ALL_PAUSED: Item<PauseInfo> = Item::new("all_paused");
+/// The vaults paused on their own.
+pub const PAUSED_VAULTS: Map<&Addr, PauseInfo> = Map::new("paused_vaults");
+/// The vaults disabled when pausing all of them, with the toggles they had before so they can be
+/// restored when unpausing.
+pub const PAUSED_BY_ALL: Map<&Addr, VaultToggle> = Map::new("paused_by_all");
+
+/// The flash-loan and deposit toggles of a vault.
+#[cw_serde]
+pub struct VaultToggle {
+    pub flash_loan_enabled: bool,
+    pub deposit_enabled: bool,
+}
+
 /// Used to temporarily store the asset being instantiated between `create_vault` and `reply` callback
 pub const TMP_VAULT_ASSET: Item<(Vec<u8>, AssetInfo)> = Item::new("tmp_vault_asset");
 
@@ -48,3 +67,18 @@ fn calc_range_start(start_after: Option<Vec<u8>>) -> Option<Vec<u8>> {
         v
     })
 }
+
+/// Reads the vaults disabled by [ALL_PAUSED], paginated.
+pub fn read_paused_by_all(
+    storage: &dyn Storage,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, VaultToggle)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    PAUSED_BY_ALL
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
@@ -7,7 +7,7 @@ use white_whale_std::vault_network::vault_router::{
 
 use crate::err::{StdResult, VaultRouterError};
 use crate::execute::{complete_loan, flash_loan, next_loan, update_config};
-use crate::queries::get_config;
+use crate::queries::{get_config, get_flash_loan_quote};
 use crate::state::CONFIG;
 
 const CONTRACT_NAME: &str = "white_whale-vault_router";
@@ -89,5 +89,6 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => get_config(deps),
+        QueryMsg::FlashLoanQuote { assets } => get_flash_loan_quote(deps, assets),
     }
 }
@@ -32,8 +32,8 @@ pub enum VaultRouterError {
         required_amount: Uint128,
     },
 
-    #[error("Nested flash-loans are disabled")]
-    NestedFlashLoansDisabled {},
+    #[error("Attempt to flash-loan asset \"{asset}\" more than once in the same flash-loan")]
+    DuplicateAsset { asset: Asset },
 }
 
 impl From<semver::Error> for VaultRouterError {
@@ -10,7 +10,12 @@ use crate::{
 
 /// Performs a flash-loan by finding the vault addresses, loaning the assets,
 /// running the messages the user wants, and finally returning the assets to the
-/// vault.
+/// vaults.
+///
+/// Multiple assets are loaned by chaining the loans through `NextLoan`, each
+/// asset being loaned from its own vault. An asset can only be loaned once per
+/// flash-loan, as the repayment of each vault is verified against the balance
+/// the router holds of its asset.
 pub fn flash_loan(
     deps: DepsMut,
     info: MessageInfo,
@@ -19,8 +24,13 @@ pub fn flash_loan(
 ) -> StdResult<Response> {
     let config = CONFIG.load(deps.storage)?;
 
-    if assets.len() > 1 {
-        return Err(VaultRouterError::NestedFlashLoansDisabled {});
+    // each asset can only be loaned once, from its own vault
+    for (i, asset) in assets.iter().enumerate() {
+        if assets[..i].iter().any(|other| other.info == asset.info) {
+            return Err(VaultRouterError::DuplicateAsset {
+                asset: asset.clone(),
+            });
+        }
     }
 
     // get the vaults to perform loans for
@@ -239,15 +249,95 @@ mod tests {
     }
 
     #[test]
-    fn does_not_allow_nested_flashloans() {
+    fn does_not_allow_duplicate_assets() {
         let mut app = mock_app_with_balance(vec![(mock_admin(), coins(10_000, "uluna"))]);
         let AppInstantiateResponse { router_addr, .. } = app_mock_instantiate(&mut app);
 
-        // try borrowing multiple assets, i.e. taking out nested flashloans
+        let borrow_asset = Asset {
+            amount: Uint128::new(1_000),
+            info: AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        };
+
+        // try borrowing the same asset twice
         let err = app
             .execute_contract(
                 mock_creator().sender,
                 router_addr,
+                &ExecuteMsg::FlashLoan {
+                    assets: vec![
+                        borrow_asset.clone(),
+                        Asset {
+                            amount: Uint128::new(500),
+                            ..borrow_asset.clone()
+                        },
+                    ],
+                    msgs: vec![],
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast::<VaultRouterError>().unwrap(),
+            VaultRouterError::DuplicateAsset {
+                asset: Asset {
+                    amount: Uint128::new(500),
+                    ..borrow_asset
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn does_succeed_with_multiple_assets() {
+        let mut app = mock_app_with_balance(vec![(mock_admin(), coins(10_066, "uluna"))]);
+        let AppInstantiateResponse {
+            router_addr,
+            token_addr,
+            native_vault_addr,
+            token_vault_addr,
+            ..
+        } = app_mock_instantiate(&mut app);
+
+        let dummy_contract_addr = create_dummy_contract(&mut app);
+        let transfer_amount = 66u128;
+
+        // give the dummy contract the fees for the native loan, and the router the ones for the
+        // token loan
+        app.send_tokens(
+            mock_admin(),
+            dummy_contract_addr.clone(),
+            &coins(transfer_amount, "uluna"),
+        )
+        .unwrap();
+        app.execute_contract(
+            mock_admin(),
+            token_addr.clone(),
+            &cw20::Cw20ExecuteMsg::Transfer {
+                recipient: router_addr.to_string(),
+                amount: Uint128::new(transfer_amount),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let payload = vec![WasmMsg::Execute {
+            contract_addr: dummy_contract_addr.into_string(),
+            msg: to_json_binary(&crate::tests::ExecuteMsg::Send {
+                to_address: router_addr.clone(),
+                amount: coins(transfer_amount, "uluna"),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()];
+
+        let res = app
+            .execute_contract(
+                mock_creator().sender,
+                router_addr.clone(),
                 &ExecuteMsg::FlashLoan {
                     assets: vec![
                         Asset {
@@ -256,22 +346,149 @@ mod tests {
                                 denom: "uluna".to_string(),
                             },
                         },
+                        Asset {
+                            amount: Uint128::new(1_000),
+                            info: AssetInfo::Token {
+                                contract_addr: token_addr.to_string(),
+                            },
+                        },
+                    ],
+                    msgs: payload,
+                },
+                &[],
+            )
+            .unwrap();
+
+        // the loans are chained, and completed once the payload is executed
+        let methods = res
+            .events
+            .iter()
+            .filter(|event| event.ty == "wasm")
+            .filter_map(|event| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attribute| attribute.key == "method")
+                    .map(|attribute| attribute.value.as_str())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            methods,
+            vec![
+                "flash_loan",
+                "flash_loan",
+                "next_loan",
+                "flash_loan",
+                "next_loan",
+                "complete_loan",
+                "after_trade",
+                "after_trade",
+            ]
+        );
+
+        // each vault is paid back with its fees
+        let native_vault_balance = app
+            .wrap()
+            .query_balance(native_vault_addr, "uluna")
+            .unwrap();
+        assert_eq!(native_vault_balance.amount, Uint128::new(10_066));
+
+        let token_vault_balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                token_addr.clone(),
+                &cw20::Cw20QueryMsg::Balance {
+                    address: token_vault_addr.into_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(token_vault_balance.balance, Uint128::new(10_066));
+
+        // nothing is left in the router
+        let router_balance = app
+            .wrap()
+            .query_balance(router_addr.clone(), "uluna")
+            .unwrap();
+        assert!(router_balance.amount.is_zero());
+
+        let router_token_balance: cw20::BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                token_addr,
+                &cw20::Cw20QueryMsg::Balance {
+                    address: router_addr.into_string(),
+                },
+            )
+            .unwrap();
+        assert!(router_token_balance.balance.is_zero());
+    }
+
+    #[test]
+    fn does_error_when_any_vault_is_not_paid_back() {
+        let mut app = mock_app_with_balance(vec![(mock_admin(), coins(10_066, "uluna"))]);
+        let AppInstantiateResponse {
+            router_addr,
+            token_addr,
+            ..
+        } = app_mock_instantiate(&mut app);
+
+        let dummy_contract_addr = create_dummy_contract(&mut app);
+        let transfer_amount = 66u128;
+
+        // only the fees for the native loan are paid
+        app.send_tokens(
+            mock_admin(),
+            dummy_contract_addr.clone(),
+            &coins(transfer_amount, "uluna"),
+        )
+        .unwrap();
+
+        let payload = vec![WasmMsg::Execute {
+            contract_addr: dummy_contract_addr.into_string(),
+            msg: to_json_binary(&crate::tests::ExecuteMsg::Send {
+                to_address: router_addr.clone(),
+                amount: coins(transfer_amount, "uluna"),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into()];
+
+        let token_asset = Asset {
+            amount: Uint128::new(1_000),
+            info: AssetInfo::Token {
+                contract_addr: token_addr.to_string(),
+            },
+        };
+
+        let err = app
+            .execute_contract(
+                mock_creator().sender,
+                router_addr,
+                &ExecuteMsg::FlashLoan {
+                    assets: vec![
                         Asset {
                             amount: Uint128::new(1_000),
                             info: AssetInfo::NativeToken {
                                 denom: "uluna".to_string(),
                             },
                         },
+                        token_asset.clone(),
                     ],
-                    msgs: vec![],
+                    msgs: payload,
                 },
                 &[],
             )
             .unwrap_err();
 
         assert_eq!(
-            err.downcast::<VaultRouterError>().unwrap(),
-            VaultRouterError::NestedFlashLoansDisabled {}
+            err.root_cause().to_string(),
+            VaultRouterError::NegativeProfit {
+                input: token_asset,
+                output_amount: Uint128::new(1_000),
+                required_amount: Uint128::new(1_066),
+            }
+            .to_string()
         );
     }
 
@@ -0,0 +1,162 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+
+use white_whale_std::pool_network::asset::Asset;
+use white_whale_std::vault_network::vault::{AvailableLiquidityResponse, PaybackAmountResponse};
+use white_whale_std::vault_network::vault_router::{FlashLoanQuote, FlashLoanQuoteResponse};
+
+use crate::err::{StdResult, VaultRouterError};
+use crate::state::CONFIG;
+
+/// Simulates a flash-loan of the given assets, resolving the vault of each asset through the vault
+/// factory and quoting the amount to pay back along with the liquidity available.
+pub fn get_flash_loan_quote(deps: Deps, assets: Vec<Asset>) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let quotes = assets
+        .into_iter()
+        .map(|asset| {
+            // query factory for address
+            let vault: Option<String> = deps.querier.query_wasm_smart(
+                config.vault_factory.clone(),
+                &white_whale_std::vault_network::vault_factory::QueryMsg::Vault {
+                    asset_info: asset.info.clone(),
+                },
+            )?;
+
+            // return InvalidAsset if address doesn't exist
+            let vault =
+                deps.api
+                    .addr_validate(&vault.ok_or(VaultRouterError::InvalidAsset {
+                        asset: asset.clone(),
+                    })?)?;
+
+            let payback_amount: PaybackAmountResponse = deps.querier.query_wasm_smart(
+                vault.clone(),
+                &white_whale_std::vault_network::vault::QueryMsg::GetPaybackAmount {
+                    amount: asset.amount,
+                },
+            )?;
+
+            let available_liquidity: AvailableLiquidityResponse = deps.querier.query_wasm_smart(
+                vault.clone(),
+                &white_whale_std::vault_network::vault::QueryMsg::AvailableLiquidity {},
+            )?;
+
+            Ok(FlashLoanQuote {
+                vault,
+                asset,
+                payback_amount: payback_amount.payback_amount,
+                protocol_fee: payback_amount.protocol_fee,
+                flash_loan_fee: payback_amount.flash_loan_fee,
+                burn_fee: payback_amount.burn_fee,
+                available_liquidity: available_liquidity.available_liquidity.amount,
+                flash_loan_enabled: available_liquidity.flash_loan_enabled,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(to_json_binary(&FlashLoanQuoteResponse { quotes })?)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{coins, Uint128};
+
+    use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+    use white_whale_std::vault_network::vault_router::{
+        FlashLoanQuote, FlashLoanQuoteResponse, QueryMsg,
+    };
+
+    use crate::tests::{
+        mock_admin, mock_app_with_balance,
+        mock_instantiate::{app_mock_instantiate, AppInstantiateResponse},
+    };
+
+    #[test]
+    fn does_quote_flash_loans() {
+        let mut app = mock_app_with_balance(vec![(mock_admin(), coins(10_000, "uluna"))]);
+        let AppInstantiateResponse {
+            router_addr,
+            token_addr,
+            native_vault_addr,
+            token_vault_addr,
+            ..
+        } = app_mock_instantiate(&mut app);
+
+        let native_asset = Asset {
+            amount: Uint128::new(1_000),
+            info: AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+        };
+        let token_asset = Asset {
+            amount: Uint128::new(3_000),
+            info: AssetInfo::Token {
+                contract_addr: token_addr.into_string(),
+            },
+        };
+
+        let res: FlashLoanQuoteResponse = app
+            .wrap()
+            .query_wasm_smart(
+                router_addr,
+                &QueryMsg::FlashLoanQuote {
+                    assets: vec![native_asset.clone(), token_asset.clone()],
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            res,
+            FlashLoanQuoteResponse {
+                quotes: vec![
+                    FlashLoanQuote {
+                        vault: native_vault_addr,
+                        asset: native_asset,
+                        payback_amount: Uint128::new(1_066),
+                        protocol_fee: Uint128::new(33),
+                        flash_loan_fee: Uint128::new(33),
+                        burn_fee: Uint128::zero(),
+                        available_liquidity: Uint128::new(10_000),
+                        flash_loan_enabled: true,
+                    },
+                    FlashLoanQuote {
+                        vault: token_vault_addr,
+                        asset: token_asset,
+                        payback_amount: Uint128::new(3_198),
+                        protocol_fee: Uint128::new(99),
+                        flash_loan_fee: Uint128::new(99),
+                        burn_fee: Uint128::zero(),
+                        available_liquidity: Uint128::new(10_000),
+                        flash_loan_enabled: true,
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn does_reject_invalid_asset() {
+        let mut app = mock_app_with_balance(vec![(mock_admin(), coins(10_000, "uluna"))]);
+        let AppInstantiateResponse { router_addr, .. } = app_mock_instantiate(&mut app);
+
+        let err = app
+            .wrap()
+            .query_wasm_smart::<FlashLoanQuoteResponse>(
+                router_addr,
+                &QueryMsg::FlashLoanQuote {
+                    assets: vec![Asset {
+                        amount: Uint128::new(1_000),
+                        info: AssetInfo::NativeToken {
+                            denom: "ukrw".to_string(),
+                        },
+                    }],
+                },
+            )
+            .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("Attempt to flash-loan asset \"1000ukrw\" that does not have a vault"));
+    }
+}
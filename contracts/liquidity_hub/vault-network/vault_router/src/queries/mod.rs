@@ -1,3 +1,5 @@
 mod config;
+mod flash_loan_quote;
 
 pub use config::get_config;
+pub use flash_loan_quote::get_flash_loan_quote;
@@ -1,37 +1,104 @@
 use cosmwasm_std::{
-    Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Order, Response, StdResult,
-    Timestamp, Uint128, Uint64,
+    from_json, Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response,
+    StdResult, Storage, Timestamp, Uint128, Uint64,
 };
+use cw20::Cw20ReceiveMsg;
 use white_whale_std::pool_network::asset;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 
-use white_whale_std::whale_lair::Bond;
+use white_whale_std::whale_lair::{Bond, Cw20HookMsg, WeightGrowth, WeightMultiplier};
 
-use crate::helpers::validate_growth_rate;
+use crate::helpers::{
+    validate_growth_rate, validate_instant_unbond_penalty, validate_weight_multipliers,
+};
 use crate::queries::MAX_PAGE_LIMIT;
-use crate::state::{update_global_weight, update_local_weight, BOND, CONFIG, GLOBAL, UNBOND};
+use crate::state::{
+    checkpoint_bond, checkpoint_global, get_weight_multiplier, get_weighted_amount,
+    snapshot_voting_power, update_global_weight, update_local_weight, BOND, CONFIG, GLOBAL, LOCKS,
+    PENALTIES, RECEIPT_DENOMS, TOTAL_PENALTIES, UNBOND,
+};
 use crate::{helpers, ContractError};
 
-/// Bonds the provided asset.
+/// Bonds the provided native asset, crediting the bond to the receiver if provided or to the sender
+/// otherwise.
 pub(crate) fn bond(
-    mut deps: DepsMut,
+    deps: DepsMut,
     timestamp: Timestamp,
     info: MessageInfo,
     env: Env,
     asset: Asset,
+    receiver: Option<String>,
 ) -> Result<Response, ContractError> {
-    // validate the denom sent is the whitelisted one for bonding
+    // validate the denom sent is the whitelisted one for bonding. cw20 tokens are bonded through
+    // the Receive hook.
     let denom = match asset.info.clone() {
         AssetInfo::NativeToken { denom } => denom,
         AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
     };
 
-    helpers::validate_funds(&deps, &info, &asset, denom.clone())?;
-    helpers::validate_claimed(&deps, &info)?;
+    helpers::validate_funds(&deps, &info, &asset, denom)?;
+
+    bond_asset(deps, timestamp, env, info.sender, asset, receiver)
+}
+
+/// Bonds the cw20 tokens received, crediting the bond to the receiver if provided or to the sender
+/// otherwise.
+pub(crate) fn receive_cw20(
+    deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    env: Env,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    // the cw20 token contract is the sender of the message
+    let asset = Asset {
+        info: AssetInfo::Token {
+            contract_addr: info.sender.to_string(),
+        },
+        amount: cw20_msg.amount,
+    };
+
+    helpers::validate_bonding_asset(deps.storage, &asset.info)?;
+
+    if asset.amount.is_zero() {
+        return Err(ContractError::AssetMismatch {});
+    }
+
+    match from_json(&cw20_msg.msg)? {
+        Cw20HookMsg::Bond { receiver } => {
+            let sender = deps.api.addr_validate(&cw20_msg.sender)?;
+            bond_asset(deps, timestamp, env, sender, asset, receiver)
+        }
+    }
+}
+
+/// Bonds the provided asset on behalf of the sender, once the asset has been received.
+fn bond_asset(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    env: Env,
+    sender: Addr,
+    asset: Asset,
+    receiver: Option<String>,
+) -> Result<Response, ContractError> {
+    let denom = helpers::get_denom(&asset.info);
+    let config = CONFIG.load(deps.storage)?;
+
+    let receiver = receiver
+        .map(|receiver| deps.api.addr_validate(&receiver))
+        .transpose()?
+        .unwrap_or_else(|| sender.clone());
+
+    helpers::validate_claimed(&deps, &receiver)?;
     helpers::validate_bonding_for_current_epoch(&deps, &env)?;
 
+    // snapshot the bond and the global index before modifying them
+    let epoch_id = helpers::get_epoch(deps.as_ref(), None)?.id;
+    checkpoint_bond(deps.storage, &receiver, &denom, epoch_id)?;
+    checkpoint_global(deps.storage, epoch_id)?;
+
     let mut bond = BOND
-        .key((&info.sender, &denom))
+        .key((&receiver, &denom))
         .may_load(deps.storage)?
         .unwrap_or(Bond {
             asset: Asset {
@@ -41,20 +108,35 @@ pub(crate) fn bond(
             ..Bond::default()
         });
 
+    // the weight of the bond starts at its weighted amount
+    let weighted_amount = get_weighted_amount(&config, &asset);
+
     // update local values
     bond.asset.amount = bond.asset.amount.checked_add(asset.amount)?;
     // let new_bond_weight = get_weight(timestamp, bond.weight, asset.amount, config.growth_rate, bond.timestamp)?;
-    bond.weight = bond.weight.checked_add(asset.amount)?;
-    bond = update_local_weight(&mut deps, info.sender.clone(), timestamp, bond)?;
+    bond.weight = bond.weight.checked_add(weighted_amount)?;
+    bond = update_local_weight(&mut deps, receiver.clone(), timestamp, bond)?;
+
+    BOND.save(deps.storage, (&receiver, &denom), &bond)?;
+
+    // increasing a bond locks it again
+    if let WeightGrowth::Lockup { duration, .. } = config.weight_growth {
+        LOCKS.save(
+            deps.storage,
+            (&receiver, &denom),
+            &timestamp.plus_nanos(duration.u64()),
+        )?;
+    }
 
-    BOND.save(deps.storage, (&info.sender, &denom), &bond)?;
+    let receipt_messages =
+        helpers::mint_receipt_msgs(deps.storage, &env, &denom, &receiver, asset.amount)?;
 
     // update global values
     let mut global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
     // global_index = update_global_weight(&mut deps, timestamp, global_index)?;
 
     // include time term in the weight
-    global_index.weight = global_index.weight.checked_add(asset.amount)?;
+    global_index.weight = global_index.weight.checked_add(weighted_amount)?;
     global_index.bonded_amount = global_index.bonded_amount.checked_add(asset.amount)?;
     global_index.bonded_assets =
         asset::aggregate_assets(global_index.bonded_assets, vec![asset.clone()])?;
@@ -62,11 +144,22 @@ pub(crate) fn bond(
 
     GLOBAL.save(deps.storage, &global_index)?;
 
-    Ok(Response::default().add_attributes(vec![
-        ("action", "bond".to_string()),
-        ("address", info.sender.to_string()),
-        ("asset", asset.to_string()),
-    ]))
+    snapshot_voting_power(
+        deps.storage,
+        &[&receiver],
+        &denom,
+        env.block.height,
+        timestamp,
+    )?;
+
+    Ok(Response::default()
+        .add_messages(receipt_messages)
+        .add_attributes(vec![
+            ("action", "bond".to_string()),
+            ("address", sender.to_string()),
+            ("receiver", receiver.to_string()),
+            ("asset", asset.to_string()),
+        ]))
 }
 
 /// Unbonds the provided amount of tokens
@@ -81,63 +174,352 @@ pub(crate) fn unbond(
         return Err(ContractError::InvalidUnbondingAmount {});
     }
 
-    let denom = match asset.info.clone() {
-        AssetInfo::NativeToken { denom } => denom,
-        AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
-    };
+    let denom = helpers::get_denom(&asset.info);
+
+    // the receipt tokens for the bond being unbonded are burned
+    let receipt_messages =
+        helpers::burn_receipt_msgs(deps.storage, &env, &info, &denom, asset.amount)?;
 
-    helpers::validate_claimed(&deps, &info)?;
+    helpers::validate_claimed(&deps, &info.sender)?;
     helpers::validate_bonding_for_current_epoch(&deps, &env)?;
 
-    if let Some(mut unbond) = BOND.key((&info.sender, &denom)).may_load(deps.storage)? {
-        // check if the address has enough bond
-        if unbond.asset.amount < asset.amount {
-            return Err(ContractError::InsufficientBond {});
-        }
-        // update local values, decrease the bond
-        unbond = update_local_weight(&mut deps, info.sender.clone(), timestamp, unbond.clone())?;
-        let weight_slash = unbond.weight * Decimal::from_ratio(asset.amount, unbond.asset.amount);
-        unbond.weight = unbond.weight.checked_sub(weight_slash)?;
-        unbond.asset.amount = unbond.asset.amount.checked_sub(asset.amount)?;
-
-        if unbond.asset.amount.is_zero() {
-            BOND.remove(deps.storage, (&info.sender, &denom));
-        } else {
-            BOND.save(deps.storage, (&info.sender, &denom), &unbond)?;
-        }
+    remove_bond(
+        &mut deps,
+        timestamp,
+        env.block.height,
+        &info.sender,
+        &denom,
+        &asset,
+    )?;
+
+    // record the unbonding
+    UNBOND.save(
+        deps.storage,
+        (&info.sender, &denom, timestamp.nanos()),
+        &Bond {
+            asset: asset.clone(),
+            weight: Uint128::zero(),
+            timestamp,
+        },
+    )?;
 
-        // record the unbonding
-        UNBOND.save(
+    Ok(Response::default()
+        .add_messages(receipt_messages)
+        .add_attributes(vec![
+            ("action", "unbond".to_string()),
+            ("address", info.sender.to_string()),
+            ("asset", asset.to_string()),
+        ]))
+}
+
+/// Unbonds the provided amount of tokens and sends them to the sender right away, minus the
+/// instant unbond penalty which is sent to the fee collector.
+pub(crate) fn instant_unbond(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    env: Env,
+    asset: Asset,
+) -> Result<Response, ContractError> {
+    if asset.amount.is_zero() {
+        return Err(ContractError::InvalidUnbondingAmount {});
+    }
+
+    let denom = helpers::get_denom(&asset.info);
+
+    let penalty_rate = CONFIG
+        .load(deps.storage)?
+        .instant_unbond_penalty
+        .ok_or(ContractError::InstantUnbondDisabled {})?;
+
+    // the receipt tokens for the bond being unbonded are burned
+    let mut messages = helpers::burn_receipt_msgs(deps.storage, &env, &info, &denom, asset.amount)?;
+
+    helpers::validate_claimed(&deps, &info.sender)?;
+    helpers::validate_bonding_for_current_epoch(&deps, &env)?;
+
+    remove_bond(
+        &mut deps,
+        timestamp,
+        env.block.height,
+        &info.sender,
+        &denom,
+        &asset,
+    )?;
+
+    let penalty = asset.amount * penalty_rate;
+    let refund_amount = asset.amount.checked_sub(penalty)?;
+
+    if !penalty.is_zero() {
+        PENALTIES.update(
             deps.storage,
-            (&info.sender, &denom, timestamp.nanos()),
-            &Bond {
-                asset: asset.clone(),
-                weight: Uint128::zero(),
-                timestamp,
-            },
+            (&info.sender, &denom),
+            |penalties| -> StdResult<_> { Ok(penalties.unwrap_or_default().checked_add(penalty)?) },
         )?;
+        TOTAL_PENALTIES.update(deps.storage, &denom, |penalties| -> StdResult<_> {
+            Ok(penalties.unwrap_or_default().checked_add(penalty)?)
+        })?;
+
+        messages.push(
+            Asset {
+                info: asset.info.clone(),
+                amount: penalty,
+            }
+            .into_msg(helpers::get_fee_collector_addr(deps.as_ref())?)?,
+        );
+    }
 
-        // update global values
-        let mut global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
-        global_index = update_global_weight(&mut deps, timestamp, global_index)?;
-        global_index.bonded_amount = global_index.bonded_amount.checked_sub(asset.amount)?;
-        global_index.bonded_assets =
-            asset::deduct_assets(global_index.bonded_assets, vec![asset.clone()])?;
-        global_index.weight = global_index.weight.checked_sub(weight_slash)?;
-
-        GLOBAL.save(deps.storage, &global_index)?;
+    if !refund_amount.is_zero() {
+        messages.push(
+            Asset {
+                info: asset.info.clone(),
+                amount: refund_amount,
+            }
+            .into_msg(info.sender.clone())?,
+        );
+    }
 
-        Ok(Response::default().add_attributes(vec![
-            ("action", "unbond".to_string()),
+    Ok(Response::default()
+        .add_messages(messages)
+        .add_attributes(vec![
+            ("action", "instant_unbond".to_string()),
             ("address", info.sender.to_string()),
             ("asset", asset.to_string()),
+            ("penalty", penalty.to_string()),
+            ("refund_amount", refund_amount.to_string()),
         ]))
+}
+
+/// Removes the provided amount of tokens from the bond of the given address, along with its
+/// share of the weight, and updates the global index accordingly.
+fn remove_bond(
+    deps: &mut DepsMut,
+    timestamp: Timestamp,
+    height: u64,
+    address: &Addr,
+    denom: &str,
+    asset: &Asset,
+) -> Result<(), ContractError> {
+    // snapshot the bond and the global index before modifying them
+    let epoch_id = helpers::get_epoch(deps.as_ref(), None)?.id;
+    checkpoint_bond(deps.storage, address, denom, epoch_id)?;
+    checkpoint_global(deps.storage, epoch_id)?;
+
+    let mut unbond = BOND
+        .key((address, denom))
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NothingToUnbond {})?;
+
+    // check if the address has enough bond
+    if unbond.asset.amount < asset.amount {
+        return Err(ContractError::InsufficientBond {});
+    }
+
+    // locks are only enforced while the weight growth uses lockups
+    if let WeightGrowth::Lockup { .. } = CONFIG.load(deps.storage)?.weight_growth {
+        if let Some(unlock_time) = LOCKS.may_load(deps.storage, (address, denom))? {
+            if unlock_time > timestamp {
+                return Err(ContractError::BondLocked { unlock_time });
+            }
+        }
+    }
+
+    // update local values, decrease the bond
+    unbond = update_local_weight(deps, address.clone(), timestamp, unbond.clone())?;
+    let weight_slash = unbond.weight * Decimal::from_ratio(asset.amount, unbond.asset.amount);
+    unbond.weight = unbond.weight.checked_sub(weight_slash)?;
+    unbond.asset.amount = unbond.asset.amount.checked_sub(asset.amount)?;
+
+    if unbond.asset.amount.is_zero() {
+        BOND.remove(deps.storage, (address, denom));
+        LOCKS.remove(deps.storage, (address, denom));
+    } else {
+        BOND.save(deps.storage, (address, denom), &unbond)?;
+    }
+
+    // update global values
+    let mut global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
+    global_index = update_global_weight(deps, timestamp, global_index)?;
+    global_index.bonded_amount = global_index.bonded_amount.checked_sub(asset.amount)?;
+    global_index.bonded_assets =
+        asset::deduct_assets(global_index.bonded_assets, vec![asset.clone()])?;
+    global_index.weight = global_index.weight.checked_sub(weight_slash)?;
+
+    GLOBAL.save(deps.storage, &global_index)?;
+
+    snapshot_voting_power(deps.storage, &[address], denom, height, timestamp)?;
+
+    Ok(())
+}
+
+/// Transfers the provided amount of the sender's bond to the recipient. If the contract uses
+/// receipt tokens, the ones backing the bond must be sent along, and are forwarded to the recipient.
+pub(crate) fn transfer(
+    deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    env: Env,
+    asset: Asset,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    if asset.amount.is_zero() {
+        return Err(ContractError::InvalidTransferAmount {});
+    }
+
+    helpers::validate_bonding_asset(deps.storage, &asset.info)?;
+    let denom = helpers::get_denom(&asset.info);
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if recipient == info.sender {
+        return Err(ContractError::SelfTransfer {});
+    }
+
+    let receipt_messages =
+        match helpers::validate_receipt_funds(deps.storage, &info, &denom, asset.amount)? {
+            Some(receipt_denom) => vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![Coin {
+                    denom: receipt_denom,
+                    amount: asset.amount,
+                }],
+            })],
+            None => vec![],
+        };
+
+    helpers::validate_claimed(&deps, &info.sender)?;
+    helpers::validate_claimed(&deps, &recipient)?;
+
+    Ok(
+        transfer_bond(deps, timestamp, &env, info.sender, recipient, asset)?
+            .add_messages(receipt_messages),
+    )
+}
+
+/// Moves the bond backing the receipt tokens being transferred to the recipient. Transfers from and
+/// to the contract are mints, burns and unbondings, which are handled by bond and unbond.
+pub(crate) fn transfer_receipt(
+    deps: DepsMut,
+    env: Env,
+    from: String,
+    to: String,
+    amount: Coin,
+) -> Result<Response, ContractError> {
+    let Some(denom) = helpers::get_bonding_denom(deps.storage, &amount.denom)? else {
+        return Ok(Response::default());
+    };
+
+    if amount.amount.is_zero()
+        || from == to
+        || from == env.contract.address.as_str()
+        || to == env.contract.address.as_str()
+    {
+        return Ok(Response::default());
+    }
+
+    let from = deps.api.addr_validate(&from)?;
+    let to = deps.api.addr_validate(&to)?;
+
+    helpers::validate_claimed(&deps, &from)?;
+    helpers::validate_claimed(&deps, &to)?;
+
+    let info = helpers::get_bonding_asset_info(&CONFIG.load(deps.storage)?, &denom)
+        .ok_or(ContractError::InvalidBondingAsset {})?;
+
+    transfer_bond(
+        deps,
+        env.block.time,
+        &env,
+        from,
+        to,
+        Asset {
+            info,
+            amount: amount.amount,
+        },
+    )
+}
+
+/// Moves the given amount of bonded tokens from one address to another, along with the weight
+/// they have accrued.
+pub(crate) fn transfer_bond(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    env: &Env,
+    from: Addr,
+    to: Addr,
+    asset: Asset,
+) -> Result<Response, ContractError> {
+    let denom = helpers::get_denom(&asset.info);
+
+    helpers::validate_bonding_for_current_epoch(&deps, env)?;
+
+    // snapshot both bonds before modifying them. The global index doesn't change.
+    let epoch_id = helpers::get_epoch(deps.as_ref(), None)?.id;
+    checkpoint_bond(deps.storage, &from, &denom, epoch_id)?;
+    checkpoint_bond(deps.storage, &to, &denom, epoch_id)?;
+
+    let mut bond = BOND
+        .may_load(deps.storage, (&from, &denom))?
+        .ok_or(ContractError::InsufficientBond {})?;
+
+    if bond.asset.amount < asset.amount {
+        return Err(ContractError::InsufficientBond {});
+    }
+
+    // take the transferred share of the bond, including its weight
+    bond = update_local_weight(&mut deps, from.clone(), timestamp, bond)?;
+    let weight_transferred = bond.weight * Decimal::from_ratio(asset.amount, bond.asset.amount);
+    bond.weight = bond.weight.checked_sub(weight_transferred)?;
+    bond.asset.amount = bond.asset.amount.checked_sub(asset.amount)?;
+
+    if bond.asset.amount.is_zero() {
+        BOND.remove(deps.storage, (&from, &denom));
     } else {
-        Err(ContractError::NothingToUnbond {})
+        BOND.save(deps.storage, (&from, &denom), &bond)?;
+    }
+
+    let mut recipient_bond = BOND
+        .key((&to, &denom))
+        .may_load(deps.storage)?
+        .unwrap_or(Bond {
+            asset: Asset {
+                amount: Uint128::zero(),
+                ..asset.clone()
+            },
+            ..Bond::default()
+        });
+
+    recipient_bond = update_local_weight(&mut deps, to.clone(), timestamp, recipient_bond)?;
+    recipient_bond.weight = recipient_bond.weight.checked_add(weight_transferred)?;
+    recipient_bond.asset.amount = recipient_bond.asset.amount.checked_add(asset.amount)?;
+
+    BOND.save(deps.storage, (&to, &denom), &recipient_bond)?;
+
+    snapshot_voting_power(
+        deps.storage,
+        &[&from, &to],
+        &denom,
+        env.block.height,
+        timestamp,
+    )?;
+
+    // the recipient's bond stays locked for as long as the transferred one was
+    if let Some(unlock_time) = LOCKS.may_load(deps.storage, (&from, &denom))? {
+        LOCKS.update(deps.storage, (&to, &denom), |lock| -> StdResult<_> {
+            Ok(lock.map_or(unlock_time, |lock| lock.max(unlock_time)))
+        })?;
     }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "transfer_bond".to_string()),
+        ("from", from.to_string()),
+        ("to", to.to_string()),
+        ("asset", asset.to_string()),
+        ("weight", weight_transferred.to_string()),
+    ]))
 }
 
-/// Withdraws the rewards for the provided address
+/// Withdraws the matured unbondings of the given denom, skipping the ones still in the unbonding
+/// period.
 pub(crate) fn withdraw(
     deps: DepsMut,
     timestamp: Timestamp,
@@ -146,38 +528,21 @@ pub(crate) fn withdraw(
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    let unbondings: Vec<(u64, Bond)> = UNBOND
-        .prefix((&address, &denom))
-        .range(deps.storage, None, None, Order::Ascending)
-        .take(MAX_PAGE_LIMIT as usize)
-        .collect::<StdResult<Vec<(u64, Bond)>>>()?;
-
-    let mut refund_amount = Uint128::zero();
+    let unbondings = helpers::get_matured_unbondings(
+        deps.storage,
+        &address,
+        &denom,
+        timestamp,
+        config.unbonding_period,
+        MAX_PAGE_LIMIT as usize,
+    )?;
 
     if unbondings.is_empty() {
         return Err(ContractError::NothingToWithdraw {});
     }
 
-    for unbonding in unbondings {
-        let (ts, bond) = unbonding;
-        if timestamp.minus_nanos(config.unbonding_period.u64()) >= bond.timestamp {
-            let denom = match bond.asset.info {
-                AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
-                AssetInfo::NativeToken { denom } => denom,
-            };
-
-            refund_amount = refund_amount.checked_add(bond.asset.amount)?;
-            UNBOND.remove(deps.storage, (&address, &denom, ts));
-        }
-    }
-
-    let refund_msg = CosmosMsg::Bank(BankMsg::Send {
-        to_address: address.to_string(),
-        amount: vec![Coin {
-            denom: denom.clone(),
-            amount: refund_amount,
-        }],
-    });
+    let (refund_msg, refund_amount) =
+        refund_unbondings(deps.storage, &address, &denom, unbondings)?;
 
     Ok(Response::default()
         .add_message(refund_msg)
@@ -189,7 +554,84 @@ pub(crate) fn withdraw(
         ]))
 }
 
+/// Withdraws the matured unbondings of all the bonding assets, processing up to `limit` unbonding
+/// entries in total.
+pub(crate) fn withdraw_all(
+    deps: DepsMut,
+    timestamp: Timestamp,
+    address: Addr,
+    limit: Option<u8>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut remaining = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+
+    let mut messages = vec![];
+    let mut refunds = vec![];
+    for asset_info in config.bonding_assets.iter() {
+        if remaining == 0 {
+            break;
+        }
+
+        let denom = helpers::get_denom(asset_info);
+        let unbondings = helpers::get_matured_unbondings(
+            deps.storage,
+            &address,
+            &denom,
+            timestamp,
+            config.unbonding_period,
+            remaining,
+        )?;
+        if unbondings.is_empty() {
+            continue;
+        }
+        remaining = remaining.saturating_sub(unbondings.len());
+
+        let (refund_msg, refund_amount) =
+            refund_unbondings(deps.storage, &address, &denom, unbondings)?;
+        messages.push(refund_msg);
+        refunds.push(format!("{refund_amount}{denom}"));
+    }
+
+    if messages.is_empty() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+
+    Ok(Response::default()
+        .add_messages(messages)
+        .add_attributes(vec![
+            ("action", "withdraw_all".to_string()),
+            ("address", address.to_string()),
+            ("refunds", refunds.join(", ")),
+        ]))
+}
+
+/// Removes the given unbondings and returns the message refunding them along with the refunded
+/// amount.
+fn refund_unbondings(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    denom: &str,
+    unbondings: Vec<(u64, Bond)>,
+) -> Result<(CosmosMsg, Uint128), ContractError> {
+    let asset_info = unbondings[0].1.asset.info.clone();
+
+    let mut refund_amount = Uint128::zero();
+    for (ts, bond) in unbondings {
+        refund_amount = refund_amount.checked_add(bond.asset.amount)?;
+        UNBOND.remove(storage, (address, denom, ts));
+    }
+
+    let refund_msg = Asset {
+        info: asset_info,
+        amount: refund_amount,
+    }
+    .into_msg(address.clone())?;
+
+    Ok((refund_msg, refund_amount))
+}
+
 /// Updates the configuration of the contract
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn update_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -197,6 +639,9 @@ pub(crate) fn update_config(
     unbonding_period: Option<Uint64>,
     growth_rate: Option<Decimal>,
     fee_distributor_addr: Option<String>,
+    epoch_manager_addr: Option<String>,
+    instant_unbond_penalty: Option<Decimal>,
+    bonding_assets_limit: Option<u32>,
 ) -> Result<Response, ContractError> {
     // check the owner is the one who sent the message
     let mut config = CONFIG.load(deps.storage)?;
@@ -221,6 +666,26 @@ pub(crate) fn update_config(
         config.fee_distributor_addr = deps.api.addr_validate(&fee_distributor_addr)?;
     }
 
+    if let Some(epoch_manager_addr) = epoch_manager_addr {
+        config.epoch_manager_addr = deps.api.addr_validate(&epoch_manager_addr)?;
+    }
+
+    if let Some(instant_unbond_penalty) = instant_unbond_penalty {
+        validate_instant_unbond_penalty(Some(instant_unbond_penalty))?;
+        config.instant_unbond_penalty = Some(instant_unbond_penalty);
+    }
+
+    if let Some(bonding_assets_limit) = bonding_assets_limit {
+        // the limit can't be lower than the amount of bonding assets, which can't be removed
+        if (bonding_assets_limit as usize) < config.bonding_assets.len() {
+            return Err(ContractError::InvalidBondingAssetsLimit(
+                bonding_assets_limit as usize,
+                config.bonding_assets.len(),
+            ));
+        }
+        config.bonding_assets_limit = bonding_assets_limit;
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::default().add_attributes(vec![
@@ -228,5 +693,65 @@ pub(crate) fn update_config(
         ("owner", config.owner.to_string()),
         ("unbonding_period", config.unbonding_period.to_string()),
         ("growth_rate", config.growth_rate.to_string()),
+        (
+            "bonding_assets_limit",
+            config.bonding_assets_limit.to_string(),
+        ),
     ]))
 }
+
+/// Adds a new bonding asset. If the contract uses receipt tokens, a receipt token is created for it.
+pub(crate) fn add_bonding_asset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_info: AssetInfo,
+    weight_multiplier: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if config.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let asset_info = asset_info.validate(&deps.as_ref())?;
+
+    if config.bonding_assets.contains(&asset_info) {
+        return Err(ContractError::DuplicateBondingAsset {});
+    }
+
+    if config.bonding_assets.len() >= config.bonding_assets_limit as usize {
+        return Err(ContractError::InvalidBondingAssetsLimit(
+            config.bonding_assets_limit as usize,
+            config.bonding_assets.len() + 1,
+        ));
+    }
+
+    let receipt_messages = if RECEIPT_DENOMS.is_empty(deps.storage) {
+        vec![]
+    } else {
+        helpers::create_receipt_token(deps.storage, &env, config.bonding_assets.len(), &asset_info)?
+    };
+
+    config.bonding_assets.push(asset_info.clone());
+
+    if let Some(multiplier) = weight_multiplier {
+        config.weight_multipliers.push(WeightMultiplier {
+            asset_info: asset_info.clone(),
+            multiplier,
+        });
+        validate_weight_multipliers(&config.bonding_assets, &config.weight_multipliers)?;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default()
+        .add_messages(receipt_messages)
+        .add_attributes(vec![
+            ("action", "add_bonding_asset".to_string()),
+            ("asset_info", asset_info.to_string()),
+            (
+                "weight_multiplier",
+                get_weight_multiplier(&config, &asset_info).to_string(),
+            ),
+        ]))
+}
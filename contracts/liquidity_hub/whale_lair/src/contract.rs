@@ -2,13 +2,17 @@ use cosmwasm_std::{entry_point, Addr};
 use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
-use white_whale_std::pool_network::asset::AssetInfo;
 
-use white_whale_std::whale_lair::{Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use white_whale_std::whale_lair::{
+    Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg,
+};
 
 use crate::error::ContractError;
-use crate::helpers::validate_growth_rate;
-use crate::state::{BONDING_ASSETS_LIMIT, CONFIG};
+use crate::helpers::{
+    create_receipt_tokens, validate_growth_rate, validate_instant_unbond_penalty,
+    validate_weight_growth, validate_weight_multipliers,
+};
+use crate::state::CONFIG;
 use crate::{commands, migrations, queries};
 
 // version info for migration info
@@ -18,26 +22,27 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
-    if msg.bonding_assets.len() > BONDING_ASSETS_LIMIT {
+    if msg.bonding_assets.len() > msg.bonding_assets_limit as usize {
         return Err(ContractError::InvalidBondingAssetsLimit(
-            BONDING_ASSETS_LIMIT,
+            msg.bonding_assets_limit as usize,
             msg.bonding_assets.len(),
         ));
     }
 
     validate_growth_rate(msg.growth_rate)?;
+    validate_instant_unbond_penalty(msg.instant_unbond_penalty)?;
+    validate_weight_multipliers(&msg.bonding_assets, &msg.weight_multipliers)?;
+    validate_weight_growth(&msg.weight_growth)?;
 
-    //todo since this should only accept native tokens, we could omit the asset type and pass the denom directly
-    for asset in &msg.bonding_assets {
-        match asset {
-            AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
-            AssetInfo::NativeToken { .. } => {}
-        };
-    }
+    let bonding_assets = msg
+        .bonding_assets
+        .into_iter()
+        .map(|asset_info| asset_info.validate(&deps.as_ref()))
+        .collect::<StdResult<Vec<_>>>()?;
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
@@ -45,12 +50,23 @@ pub fn instantiate(
         owner: deps.api.addr_validate(info.sender.as_str())?,
         unbonding_period: msg.unbonding_period,
         growth_rate: msg.growth_rate,
-        bonding_assets: msg.bonding_assets,
+        bonding_assets,
         fee_distributor_addr: Addr::unchecked(""),
+        epoch_manager_addr: Addr::unchecked(""),
+        instant_unbond_penalty: msg.instant_unbond_penalty,
+        bonding_assets_limit: msg.bonding_assets_limit,
+        weight_multipliers: msg.weight_multipliers,
+        weight_growth: msg.weight_growth,
     };
 
     CONFIG.save(deps.storage, &config)?;
 
+    let receipt_messages = if msg.receipt_token {
+        create_receipt_tokens(deps.storage, &env, &config.bonding_assets)?
+    } else {
+        vec![]
+    };
+
     let bonding_assets = config
         .bonding_assets
         .iter()
@@ -58,13 +74,20 @@ pub fn instantiate(
         .collect::<Vec<_>>()
         .join(", ");
 
-    Ok(Response::default().add_attributes(vec![
-        ("action", "instantiate".to_string()),
-        ("owner", config.owner.to_string()),
-        ("unbonding_period", config.unbonding_period.to_string()),
-        ("growth_rate", config.growth_rate.to_string()),
-        ("bonding_assets", bonding_assets),
-    ]))
+    Ok(Response::default()
+        .add_messages(receipt_messages)
+        .add_attributes(vec![
+            ("action", "instantiate".to_string()),
+            ("owner", config.owner.to_string()),
+            ("unbonding_period", config.unbonding_period.to_string()),
+            ("growth_rate", config.growth_rate.to_string()),
+            ("bonding_assets", bonding_assets),
+            (
+                "bonding_assets_limit",
+                config.bonding_assets_limit.to_string(),
+            ),
+            ("receipt_token", msg.receipt_token.to_string()),
+        ]))
 }
 
 #[entry_point]
@@ -75,16 +98,31 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Bond { asset } => commands::bond(deps, env.block.time, info, env, asset),
+        ExecuteMsg::Receive(msg) => commands::receive_cw20(deps, env.block.time, info, env, msg),
+        ExecuteMsg::Bond { asset, receiver } => {
+            commands::bond(deps, env.block.time, info, env, asset, receiver)
+        }
         ExecuteMsg::Unbond { asset } => commands::unbond(deps, env.block.time, info, env, asset),
+        ExecuteMsg::InstantUnbond { asset } => {
+            commands::instant_unbond(deps, env.block.time, info, env, asset)
+        }
+        ExecuteMsg::TransferBond { asset, recipient } => {
+            commands::transfer(deps, env.block.time, info, env, asset, recipient)
+        }
         ExecuteMsg::Withdraw { denom } => {
             commands::withdraw(deps, env.block.time, info.sender, denom)
         }
+        ExecuteMsg::WithdrawAll { limit } => {
+            commands::withdraw_all(deps, env.block.time, info.sender, limit)
+        }
         ExecuteMsg::UpdateConfig {
             owner,
             unbonding_period,
             growth_rate,
             fee_distributor_addr,
+            epoch_manager_addr,
+            instant_unbond_penalty,
+            bonding_assets_limit,
         } => commands::update_config(
             deps,
             info,
@@ -92,7 +130,14 @@ pub fn execute(
             unbonding_period,
             growth_rate,
             fee_distributor_addr,
+            epoch_manager_addr,
+            instant_unbond_penalty,
+            bonding_assets_limit,
         ),
+        ExecuteMsg::AddBondingAsset {
+            asset_info,
+            weight_multiplier,
+        } => commands::add_bonding_asset(deps, env, info, asset_info, weight_multiplier),
     }
 }
 
@@ -119,6 +164,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             address,
             denom,
         )?),
+        QueryMsg::UnbondingSchedule { address } => to_json_binary(
+            &queries::query_unbonding_schedule(deps, env.block.time, address)?,
+        ),
         QueryMsg::Weight {
             address,
             timestamp,
@@ -135,14 +183,40 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 global_index,
             )?)
         }
+        QueryMsg::EpochWeight { address, epoch_id } => {
+            to_json_binary(&queries::query_epoch_weight(deps, address, epoch_id)?)
+        }
         QueryMsg::TotalBonded {} => to_json_binary(&queries::query_total_bonded(deps)?),
         QueryMsg::GlobalIndex {} => to_json_binary(&queries::query_global_index(deps)?),
+        QueryMsg::Penalties { address } => {
+            to_json_binary(&queries::query_penalties(deps, address)?)
+        }
+        QueryMsg::ReceiptDenoms {} => to_json_binary(&queries::query_receipt_denoms(deps)?),
+        QueryMsg::VotingPowerAtHeight { address, height } => to_json_binary(
+            &queries::query_voting_power_at_height(deps, &env, address, height)?,
+        ),
+        QueryMsg::TotalPowerAtHeight { height } => {
+            to_json_binary(&queries::query_total_power_at_height(deps, &env, height)?)
+        }
+        QueryMsg::Info {} => to_json_binary(&queries::query_info(deps)?),
+        QueryMsg::Dao {} => to_json_binary(&queries::query_dao(deps)?),
+    }
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::BlockBeforeSend { from, to, amount } => {
+            commands::transfer_receipt(deps, env, from, to, amount)
+        }
+        // bonds are moved when the transfer can still be blocked
+        SudoMsg::TrackBeforeSend { .. } => Ok(Response::default()),
     }
 }
 
 #[cfg(not(tarpaulin_include))]
 #[entry_point]
-pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     use white_whale_std::migrate_guards::check_contract_name;
 
     check_contract_name(deps.storage, CONTRACT_NAME.to_string())?;
@@ -161,6 +235,10 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
         migrations::migrate_to_v090(deps.branch())?;
     }
 
+    if storage_version < Version::parse("0.11.0")? {
+        migrations::migrate_to_v0110(deps.branch(), &env, msg.epoch_manager_addr)?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
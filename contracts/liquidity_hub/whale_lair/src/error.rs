@@ -1,4 +1,4 @@
-use cosmwasm_std::{DivideByZeroError, OverflowError, StdError};
+use cosmwasm_std::{DivideByZeroError, OverflowError, StdError, Timestamp};
 use semver::Version;
 use thiserror::Error;
 
@@ -36,9 +36,21 @@ pub enum ContractError {
     )]
     InvalidBondingAssetsLimit(usize, usize),
 
-    #[error("Can only bond native assets.")]
+    #[error("The asset is not whitelisted for bonding. cw20 tokens must be bonded through the Receive hook.")]
     InvalidBondingAsset {},
 
+    #[error("The asset is already a bonding asset.")]
+    DuplicateBondingAsset {},
+
+    #[error("Weight multipliers must be greater than zero and set once for bonding assets only.")]
+    InvalidWeightMultiplier {},
+
+    #[error("Invalid weight growth. Caps must be at least one, time scales and lockup multipliers greater than zero.")]
+    InvalidWeightGrowth {},
+
+    #[error("The bond is locked until {unlock_time}.")]
+    BondLocked { unlock_time: Timestamp },
+
     #[error("Nothing to unbond.")]
     NothingToUnbond {},
 
@@ -51,11 +63,32 @@ pub enum ContractError {
         current_version: Version,
     },
 
+    #[error("The epoch manager address is required to migrate to this version.")]
+    MissingEpochManager {},
+
     #[error("There are unclaimed rewards available. Claim them before attempting to bond/unbond")]
     UnclaimedRewards {},
 
     #[error("Trying to bond/unbond at a late time before the new/latest epoch has been created")]
     NewEpochNotCreatedYet {},
+
+    #[error("The instant unbond penalty must be between 0 and 1. i.e. 0.1 for 10%")]
+    InvalidInstantUnbondPenalty {},
+
+    #[error("Instant unbonding is disabled.")]
+    InstantUnbondDisabled {},
+
+    #[error("The amount of tokens to transfer must be greater than zero.")]
+    InvalidTransferAmount {},
+
+    #[error("Can't transfer a bond to the same address.")]
+    SelfTransfer {},
+
+    #[error("Receipt tokens require the osmosis_token_factory feature to be enabled")]
+    TokenFactoryNotEnabled {},
+
+    #[error("The receipt tokens sent don't match the amount to unbond")]
+    ReceiptMismatch {},
 }
 
 impl From<semver::Error> for ContractError {
@@ -1,10 +1,25 @@
-use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, StdResult, Timestamp, Uint64};
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
-use white_whale_std::fee_distributor::{ClaimableEpochsResponse, EpochResponse};
+#[cfg(feature = "osmosis_token_factory")]
+use cosmwasm_std::{coins, BankMsg};
+use cosmwasm_std::{
+    Addr, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order, StdResult, Storage,
+    Timestamp, Uint128, Uint64,
+};
+use white_whale_std::epoch_manager::epoch_manager::{
+    ConfigResponse, EpochConfig, EpochResponse, EpochV2, QueryMsg as EpochManagerQueryMsg,
+};
+use white_whale_std::fee_distributor::ClaimableEpochsResponse;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+#[cfg(feature = "osmosis_token_factory")]
+use white_whale_std::pool_network::denom_osmosis::{
+    Coin, MsgBurn, MsgCreateDenom, MsgMint, MsgSetBeforeSendHook,
+};
+use white_whale_std::whale_lair::{Bond, Config, WeightGrowth, WeightMultiplier};
 
 use crate::error::ContractError;
-use crate::state::CONFIG;
+use crate::state::{CONFIG, RECEIPT_DENOMS, UNBOND};
+
+/// Prefix of the receipt tokens subdenom, followed by the index of the bonding asset.
+pub const RECEIPT_SUBDENOM_PREFIX: &str = "receipt";
 
 /// Validates that the growth rate is between 0 and 1.
 pub fn validate_growth_rate(growth_rate: Decimal) -> Result<(), ContractError> {
@@ -14,6 +29,79 @@ pub fn validate_growth_rate(growth_rate: Decimal) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Validates that the instant unbond penalty is between 0 and 1.
+pub fn validate_instant_unbond_penalty(penalty: Option<Decimal>) -> Result<(), ContractError> {
+    if let Some(penalty) = penalty {
+        if penalty > Decimal::percent(100) {
+            return Err(ContractError::InvalidInstantUnbondPenalty {});
+        }
+    }
+    Ok(())
+}
+
+/// Validates the parameters of the weight growth model.
+pub fn validate_weight_growth(weight_growth: &WeightGrowth) -> Result<(), ContractError> {
+    let is_valid = match weight_growth {
+        WeightGrowth::Linear {} => true,
+        WeightGrowth::Capped { max_multiplier } => *max_multiplier >= Decimal::one(),
+        WeightGrowth::Logarithmic { time_scale, .. } => !time_scale.is_zero(),
+        WeightGrowth::Lockup { multiplier, .. } => !multiplier.is_zero(),
+    };
+
+    if !is_valid {
+        return Err(ContractError::InvalidWeightGrowth {});
+    }
+    Ok(())
+}
+
+/// Validates the weight multipliers are greater than zero and refer to bonding assets, once each.
+pub fn validate_weight_multipliers(
+    bonding_assets: &[AssetInfo],
+    weight_multipliers: &[WeightMultiplier],
+) -> Result<(), ContractError> {
+    for (index, weight_multiplier) in weight_multipliers.iter().enumerate() {
+        if weight_multiplier.multiplier.is_zero()
+            || !bonding_assets.contains(&weight_multiplier.asset_info)
+            || weight_multipliers[..index]
+                .iter()
+                .any(|other| other.asset_info == weight_multiplier.asset_info)
+        {
+            return Err(ContractError::InvalidWeightMultiplier {});
+        }
+    }
+    Ok(())
+}
+
+/// Returns the denom bonds of the given asset are stored under, i.e. the denom of native tokens and
+/// the contract address of cw20 tokens.
+pub fn get_denom(asset_info: &AssetInfo) -> String {
+    match asset_info {
+        AssetInfo::NativeToken { denom } => denom.clone(),
+        AssetInfo::Token { contract_addr } => contract_addr.clone(),
+    }
+}
+
+/// Returns the bonding asset stored under the given denom, if any.
+pub fn get_bonding_asset_info(config: &Config, denom: &str) -> Option<AssetInfo> {
+    config
+        .bonding_assets
+        .iter()
+        .find(|asset_info| get_denom(asset_info) == denom)
+        .cloned()
+}
+
+/// Validates that the given asset is whitelisted for bonding.
+pub fn validate_bonding_asset(
+    storage: &dyn Storage,
+    asset_info: &AssetInfo,
+) -> Result<(), ContractError> {
+    if !CONFIG.load(storage)?.bonding_assets.contains(asset_info) {
+        return Err(ContractError::InvalidBondingAsset {});
+    }
+
+    Ok(())
+}
+
 /// Validates that the asset sent on the message matches the asset provided and is whitelisted for bonding.
 pub fn validate_funds(
     deps: &DepsMut,
@@ -27,13 +115,7 @@ pub fn validate_funds(
         || info.funds[0].amount.is_zero()
         || info.funds[0].amount != asset.amount
         || info.funds[0].denom != denom
-        || !bonding_assets.iter().any(|asset_info| {
-            let d = match asset_info {
-                AssetInfo::NativeToken { denom } => denom.clone(),
-                AssetInfo::Token { .. } => String::new(),
-            };
-            d == denom
-        })
+        || !bonding_assets.contains(&asset.info)
     {
         return Err(ContractError::AssetMismatch {});
     }
@@ -41,8 +123,30 @@ pub fn validate_funds(
     Ok(())
 }
 
+/// Returns up to `limit` unbonding entries of the given address and denom that have passed the
+/// unbonding period. Entries that haven't matured yet are skipped, so the matured entries stored
+/// after them are still found.
+pub fn get_matured_unbondings(
+    storage: &dyn Storage,
+    address: &Addr,
+    denom: &str,
+    timestamp: Timestamp,
+    unbonding_period: Uint64,
+    limit: usize,
+) -> StdResult<Vec<(u64, Bond)>> {
+    UNBOND
+        .prefix((address, denom))
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, bond)) => timestamp.minus_nanos(unbonding_period.u64()) >= bond.timestamp,
+            Err(_) => true,
+        })
+        .take(limit)
+        .collect()
+}
+
 /// if user has unclaimed rewards, fail with an exception prompting them to claim
-pub fn validate_claimed(deps: &DepsMut, info: &MessageInfo) -> Result<(), ContractError> {
+pub fn validate_claimed(deps: &DepsMut, address: &Addr) -> Result<(), ContractError> {
     // Query fee distributor
     // if user has unclaimed rewards, fail with an exception prompting them to claim
     let config = CONFIG.load(deps.storage)?;
@@ -52,7 +156,7 @@ pub fn validate_claimed(deps: &DepsMut, info: &MessageInfo) -> Result<(), Contra
     let claimable_rewards: ClaimableEpochsResponse = deps.querier.query_wasm_smart(
         fee_distributor,
         &white_whale_std::fee_distributor::QueryMsg::Claimable {
-            address: info.sender.to_string(),
+            address: address.to_string(),
         },
     )?;
 
@@ -64,26 +168,45 @@ pub fn validate_claimed(deps: &DepsMut, info: &MessageInfo) -> Result<(), Contra
     Ok(())
 }
 
-/// Validates that the current time is not more than a day after the epoch start time. Helps preventing
-/// global_index timestamp issues when querying the weight.
-pub fn validate_bonding_for_current_epoch(deps: &DepsMut, env: &Env) -> Result<(), ContractError> {
-    // Query current epoch on fee distributor
-    let config = CONFIG.load(deps.storage)?;
-    let fee_distributor = config.fee_distributor_addr;
+/// Queries the fee collector address from the fee distributor.
+pub fn get_fee_collector_addr(deps: Deps) -> StdResult<Addr> {
+    let fee_distributor = CONFIG.load(deps.storage)?.fee_distributor_addr;
 
-    let epoch_response: EpochResponse = deps.querier.query_wasm_smart(
+    let config: white_whale_std::fee_distributor::Config = deps.querier.query_wasm_smart(
         fee_distributor,
-        &white_whale_std::fee_distributor::QueryMsg::CurrentEpoch {},
+        &white_whale_std::fee_distributor::QueryMsg::Config {},
+    )?;
+
+    Ok(config.fee_collector_addr)
+}
+
+/// Queries the given epoch from the epoch manager. If no id is provided, the current epoch is returned.
+pub fn get_epoch(deps: Deps, epoch_id: Option<u64>) -> StdResult<EpochV2> {
+    let epoch_manager = CONFIG.load(deps.storage)?.epoch_manager_addr;
+
+    let epoch_response: EpochResponse = deps.querier.query_wasm_smart(
+        epoch_manager,
+        &match epoch_id {
+            Some(id) => EpochManagerQueryMsg::Epoch { id },
+            None => EpochManagerQueryMsg::CurrentEpoch {},
+        },
     )?;
 
-    let current_epoch = epoch_response.epoch;
+    Ok(epoch_response.epoch)
+}
+
+/// Validates that the current time is not more than a day after the epoch start time. Helps preventing
+/// global_index timestamp issues when querying the weight.
+pub fn validate_bonding_for_current_epoch(deps: &DepsMut, env: &Env) -> Result<(), ContractError> {
+    // Query current epoch on the epoch manager
+    let current_epoch = get_epoch(deps.as_ref(), None)?;
     let current_time = env.block.time.seconds();
     pub const DAY_IN_SECONDS: u64 = 86_400u64;
 
     // if the current time is more than a day after the epoch start time, then it means the latest
     // epoch has not been created and thus, prevent users from bonding/unbonding to avoid global_index
     // timestamp issues when querying the weight.
-    if current_epoch.id != Uint64::zero()
+    if current_epoch.id != 0u64
         && current_time - current_epoch.start_time.seconds() > DAY_IN_SECONDS
     {
         return Err(ContractError::NewEpochNotCreatedYet {});
@@ -92,25 +215,182 @@ pub fn validate_bonding_for_current_epoch(deps: &DepsMut, env: &Env) -> Result<(
     Ok(())
 }
 
-/// Calculates the epoch id for any given timestamp based on the genesis epoch configuration.
+/// Queries the epoch configuration from the epoch manager.
+pub fn get_epoch_config(deps: Deps) -> StdResult<EpochConfig> {
+    let epoch_manager = CONFIG.load(deps.storage)?.epoch_manager_addr;
+
+    let config: ConfigResponse = deps
+        .querier
+        .query_wasm_smart(epoch_manager, &EpochManagerQueryMsg::Config {})?;
+
+    Ok(config.epoch_config)
+}
+
+/// Calculates the epoch id for any given timestamp, relative to the current epoch of the epoch manager.
 pub fn calculate_epoch(
-    genesis_epoch_config: EpochConfig,
+    current_epoch: &EpochV2,
+    epoch_duration: Uint64,
     timestamp: Timestamp,
 ) -> StdResult<Uint64> {
-    let epoch_duration: Uint64 = genesis_epoch_config.duration;
+    let current_epoch_id = Uint64::new(current_epoch.id);
+    let current_epoch_start = Uint64::new(current_epoch.start_time.nanos());
+    let timestamp = Uint64::new(timestamp.nanos());
+
+    if timestamp >= current_epoch_start {
+        let elapsed_time = timestamp.checked_sub(current_epoch_start)?;
+        return Ok(current_epoch_id.checked_add(elapsed_time.checked_div(epoch_duration)?)?);
+    }
+
+    // the timestamp belongs to a past epoch. If it's before the first epoch, return Epoch 0.
+    let elapsed_time = current_epoch_start.checked_sub(timestamp)?;
+    let elapsed_epochs = elapsed_time
+        .checked_add(epoch_duration)?
+        .checked_sub(Uint64::one())?
+        .checked_div(epoch_duration)?;
+
+    Ok(current_epoch_id.saturating_sub(elapsed_epochs))
+}
+
+/// Creates a receipt token for each of the bonding assets. The contract is set as the before send
+/// hook of the receipt tokens, so it can move the bonds along with them.
+pub fn create_receipt_tokens(
+    storage: &mut dyn Storage,
+    env: &Env,
+    bonding_assets: &[AssetInfo],
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let mut messages = vec![];
+
+    for (index, asset_info) in bonding_assets.iter().enumerate() {
+        messages.extend(create_receipt_token(storage, env, index, asset_info)?);
+    }
+
+    Ok(messages)
+}
 
-    // if this is true, it means the epoch is before the genesis epoch. In that case return Epoch 0.
-    if Uint64::new(timestamp.nanos()) < genesis_epoch_config.genesis_epoch {
-        return Ok(Uint64::zero());
+/// Creates the receipt token of the bonding asset at the given index of the bonding assets.
+#[allow(unused_variables)]
+pub fn create_receipt_token(
+    storage: &mut dyn Storage,
+    env: &Env,
+    index: usize,
+    asset_info: &AssetInfo,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    #[cfg(feature = "osmosis_token_factory")]
+    {
+        let subdenom = format!("{RECEIPT_SUBDENOM_PREFIX}{index}");
+        let receipt_denom = format!("{}/{}/{}", "factory", env.contract.address, subdenom);
+        RECEIPT_DENOMS.save(storage, &get_denom(asset_info), &receipt_denom)?;
+
+        return Ok(vec![
+            <MsgCreateDenom as Into<CosmosMsg>>::into(MsgCreateDenom {
+                sender: env.contract.address.to_string(),
+                subdenom,
+            }),
+            <MsgSetBeforeSendHook as Into<CosmosMsg>>::into(MsgSetBeforeSendHook {
+                sender: env.contract.address.to_string(),
+                denom: receipt_denom,
+                cosmwasm_address: env.contract.address.to_string(),
+            }),
+        ]);
     }
 
-    let elapsed_time =
-        Uint64::new(timestamp.nanos()).checked_sub(genesis_epoch_config.genesis_epoch)?;
-    let epoch = elapsed_time
-        .checked_div(epoch_duration)?
-        .checked_add(Uint64::one())?;
+    #[allow(unreachable_code)]
+    Err(ContractError::TokenFactoryNotEnabled {})
+}
+
+/// Returns the bonding denom the given receipt denom represents, if any.
+pub fn get_bonding_denom(storage: &dyn Storage, receipt_denom: &str) -> StdResult<Option<String>> {
+    RECEIPT_DENOMS
+        .range(storage, None, None, Order::Ascending)
+        .find_map(|item| match item {
+            Ok((bonding_denom, denom)) if denom == receipt_denom => Some(Ok(bonding_denom)),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .transpose()
+}
+
+/// Creates the messages minting receipt tokens for the given bond to the recipient. Returns no
+/// messages if the contract doesn't use receipt tokens.
+#[allow(unused_variables)]
+pub fn mint_receipt_msgs(
+    storage: &dyn Storage,
+    env: &Env,
+    bonding_denom: &str,
+    recipient: &Addr,
+    amount: Uint128,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let Some(receipt_denom) = RECEIPT_DENOMS.may_load(storage, bonding_denom)? else {
+        return Ok(vec![]);
+    };
+
+    // the receipt tokens are minted to the contract, then sent to the recipient
+    #[cfg(feature = "osmosis_token_factory")]
+    return Ok(vec![
+        <MsgMint as Into<CosmosMsg>>::into(MsgMint {
+            sender: env.contract.address.to_string(),
+            amount: Some(Coin {
+                denom: receipt_denom.clone(),
+                amount: amount.to_string(),
+            }),
+        }),
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount.u128(), receipt_denom),
+        }),
+    ]);
+
+    #[allow(unreachable_code)]
+    Err(ContractError::TokenFactoryNotEnabled {})
+}
+
+/// Validates the receipt tokens for the given bond were sent. Returns the receipt denom, or none if
+/// the contract doesn't use receipt tokens.
+pub fn validate_receipt_funds(
+    storage: &dyn Storage,
+    info: &MessageInfo,
+    bonding_denom: &str,
+    amount: Uint128,
+) -> Result<Option<String>, ContractError> {
+    let Some(receipt_denom) = RECEIPT_DENOMS.may_load(storage, bonding_denom)? else {
+        return Ok(None);
+    };
+
+    if info.funds.len() != 1
+        || info.funds[0].denom != receipt_denom
+        || info.funds[0].amount != amount
+    {
+        return Err(ContractError::ReceiptMismatch {});
+    }
+
+    Ok(Some(receipt_denom))
+}
+
+/// Validates the receipt tokens for the given bond were sent, and creates the message burning
+/// them. Returns no messages if the contract doesn't use receipt tokens.
+#[allow(unused_variables)]
+pub fn burn_receipt_msgs(
+    storage: &dyn Storage,
+    env: &Env,
+    info: &MessageInfo,
+    bonding_denom: &str,
+    amount: Uint128,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let Some(receipt_denom) = validate_receipt_funds(storage, info, bonding_denom, amount)? else {
+        return Ok(vec![]);
+    };
+
+    #[cfg(feature = "osmosis_token_factory")]
+    return Ok(vec![<MsgBurn as Into<CosmosMsg>>::into(MsgBurn {
+        sender: env.contract.address.to_string(),
+        amount: Some(Coin {
+            denom: receipt_denom,
+            amount: amount.to_string(),
+        }),
+    })]);
 
-    Ok(epoch)
+    #[allow(unreachable_code)]
+    Err(ContractError::TokenFactoryNotEnabled {})
 }
 
 #[cfg(test)]
@@ -119,49 +399,50 @@ mod tests {
 
     #[test]
     fn test_calculate_epoch() {
-        let genesis_epoch = EpochConfig {
-            duration: Uint64::from(86400000000000u64), // 1 day in nanoseconds
-            genesis_epoch: Uint64::from(1683212400000000000u64), // May 4th 2023 15:00:00
+        let epoch_duration = Uint64::from(86400000000000u64); // 1 day in nanoseconds
+        let current_epoch = EpochV2 {
+            id: 1u64,
+            start_time: Timestamp::from_nanos(1683212400000000000u64), // May 4th 2023 15:00:00
         };
 
-        // First bond timestamp equals genesis epoch
+        // First bond timestamp equals the current epoch start time
         let first_bond_timestamp = Timestamp::from_nanos(1683212400000000000u64);
-        let epoch = calculate_epoch(genesis_epoch.clone(), first_bond_timestamp).unwrap();
+        let epoch = calculate_epoch(&current_epoch, epoch_duration, first_bond_timestamp).unwrap();
         assert_eq!(epoch, Uint64::from(1u64));
 
         // First bond timestamp is one day after genesis epoch
         let first_bond_timestamp = Timestamp::from_nanos(1683309600000000000u64);
-        let epoch = calculate_epoch(genesis_epoch.clone(), first_bond_timestamp).unwrap();
+        let epoch = calculate_epoch(&current_epoch, epoch_duration, first_bond_timestamp).unwrap();
         assert_eq!(epoch, Uint64::from(2u64));
 
         // First bond timestamp is three days after genesis epoch
         let first_bond_timestamp = Timestamp::from_nanos(1683471600000000000u64);
-        let epoch = calculate_epoch(genesis_epoch.clone(), first_bond_timestamp).unwrap();
+        let epoch = calculate_epoch(&current_epoch, epoch_duration, first_bond_timestamp).unwrap();
         assert_eq!(epoch, Uint64::from(4u64));
 
         // First bond timestamp is before genesis epoch
         let first_bond_timestamp = Timestamp::from_nanos(1683212300000000000u64);
-        let epoch = calculate_epoch(genesis_epoch.clone(), first_bond_timestamp).unwrap();
+        let epoch = calculate_epoch(&current_epoch, epoch_duration, first_bond_timestamp).unwrap();
         assert_eq!(epoch, Uint64::zero());
 
         // First bond timestamp is within the same epoch as genesis epoch
         let first_bond_timestamp = Timestamp::from_nanos(1683223200000000000u64);
-        let epoch = calculate_epoch(genesis_epoch.clone(), first_bond_timestamp).unwrap();
+        let epoch = calculate_epoch(&current_epoch, epoch_duration, first_bond_timestamp).unwrap();
         assert_eq!(epoch, Uint64::from(1u64));
 
         // First bond timestamp is at the end of the genesis epoch, but not exactly (so it's still not epoch 2)
         let first_bond_timestamp = Timestamp::from_nanos(1683298799999999999u64);
-        let epoch = calculate_epoch(genesis_epoch.clone(), first_bond_timestamp).unwrap();
+        let epoch = calculate_epoch(&current_epoch, epoch_duration, first_bond_timestamp).unwrap();
         assert_eq!(epoch, Uint64::from(1u64));
 
         // First bond timestamp is exactly one nanosecond after the end of an epoch
         let first_bond_timestamp = Timestamp::from_nanos(1683298800000000001u64);
-        let epoch = calculate_epoch(genesis_epoch.clone(), first_bond_timestamp).unwrap();
+        let epoch = calculate_epoch(&current_epoch, epoch_duration, first_bond_timestamp).unwrap();
         assert_eq!(epoch, Uint64::from(2u64));
 
         // First bond timestamp is June 13th 2023 10:56:53
         let first_bond_timestamp = Timestamp::from_nanos(1686653813000000000u64);
-        let epoch = calculate_epoch(genesis_epoch, first_bond_timestamp).unwrap();
+        let epoch = calculate_epoch(&current_epoch, epoch_duration, first_bond_timestamp).unwrap();
         assert_eq!(epoch, Uint64::from(40u64));
     }
 }
@@ -1,10 +1,33 @@
 #![cfg(not(tarpaulin_include))]
-use crate::state::CONFIG;
+use crate::error::ContractError;
+use crate::state::{BOND, BOND_SNAPSHOTS, CONFIG, GLOBAL, GLOBAL_SNAPSHOTS, SNAPSHOT_TIME};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, DepsMut, StdError, Uint64};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, Order, StdError, StdResult, Uint64};
 use cw_storage_plus::Item;
 use white_whale_std::pool_network::asset::AssetInfo;
-use white_whale_std::whale_lair::Config;
+use white_whale_std::whale_lair::{Config, WeightGrowth};
+
+/// The amount of bonding assets allowed before the limit was moved into the config.
+const LEGACY_BONDING_ASSETS_LIMIT: u32 = 2;
+
+/// The config of v0.9.x and v0.10.x. The epoch manager was added in v0.10.0.
+#[cw_serde]
+struct ConfigV0100 {
+    /// Owner of the contract.
+    pub owner: Addr,
+    /// Unbonding period in nanoseconds.
+    pub unbonding_period: Uint64,
+    /// A fraction that controls the effect of time on the weight of a bond. If the growth rate is set
+    /// to zero, time will have no impact on the weight.
+    pub growth_rate: Decimal,
+    /// Denom of the asset to be bonded. Can't only be set at instantiation.
+    pub bonding_assets: Vec<AssetInfo>,
+    /// Address of the fee distributor contract.
+    pub fee_distributor_addr: Addr,
+    /// Address of the epoch manager contract.
+    pub epoch_manager_addr: Option<Addr>,
+}
+const CONFIGV0100: Item<ConfigV0100> = Item::new("config");
 
 pub fn migrate_to_v090(deps: DepsMut) -> Result<(), StdError> {
     #[cw_serde]
@@ -22,15 +45,66 @@ pub fn migrate_to_v090(deps: DepsMut) -> Result<(), StdError> {
     const CONFIGV080: Item<ConfigV080> = Item::new("config");
     let config_v080 = CONFIGV080.load(deps.storage)?;
 
-    let config = Config {
+    let config = ConfigV0100 {
         owner: config_v080.owner,
         unbonding_period: config_v080.unbonding_period,
         growth_rate: config_v080.growth_rate,
         bonding_assets: config_v080.bonding_assets,
         fee_distributor_addr: Addr::unchecked(""), // set it empty, then update with the new value
+        epoch_manager_addr: None,
+    };
+
+    CONFIGV0100.save(deps.storage, &config)?;
+
+    Ok(())
+}
+
+/// Migrates the state from v0.9.x or v0.10.x to v0.11.0.
+///
+/// The epoch manager is taken from the migrate message, falling back to the one stored since
+/// v0.10.0. Receipt tokens, instant unbonding and weight multipliers are left disabled, and the
+/// weight keeps growing linearly, so the weights don't change. The current bonds and global index
+/// are snapshotted, so the voting power is known from the migration onwards.
+pub fn migrate_to_v0110(
+    deps: DepsMut,
+    env: &Env,
+    epoch_manager_addr: Option<String>,
+) -> Result<(), ContractError> {
+    let config_v0100 = CONFIGV0100.load(deps.storage)?;
+
+    let epoch_manager_addr = match epoch_manager_addr {
+        Some(epoch_manager_addr) => deps.api.addr_validate(&epoch_manager_addr)?,
+        None => config_v0100
+            .epoch_manager_addr
+            .ok_or(ContractError::MissingEpochManager {})?,
+    };
+
+    let config = Config {
+        owner: config_v0100.owner,
+        unbonding_period: config_v0100.unbonding_period,
+        growth_rate: config_v0100.growth_rate,
+        bonding_assets: config_v0100.bonding_assets,
+        fee_distributor_addr: config_v0100.fee_distributor_addr,
+        epoch_manager_addr,
+        instant_unbond_penalty: None,
+        bonding_assets_limit: LEGACY_BONDING_ASSETS_LIMIT,
+        weight_multipliers: vec![],
+        weight_growth: WeightGrowth::Linear {},
     };
 
     CONFIG.save(deps.storage, &config)?;
 
+    let bonds = BOND
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for ((address, denom), bond) in bonds {
+        BOND_SNAPSHOTS.save(deps.storage, (&address, &denom), &bond, env.block.height)?;
+    }
+
+    let global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
+    GLOBAL_SNAPSHOTS.save(deps.storage, &global_index, env.block.height)?;
+    SNAPSHOT_TIME.save(deps.storage, &env.block.time, env.block.height)?;
+
     Ok(())
 }
@@ -1,22 +1,26 @@
 use std::collections::HashSet;
 
 use cosmwasm_std::{
-    to_json_binary, Decimal, Deps, Order, QueryRequest, StdError, StdResult, Timestamp, Uint128,
-    Uint64, WasmQuery,
+    Addr, Decimal, Deps, Env, Order, StdError, StdResult, Storage, Timestamp, Uint128, Uint64,
 };
 use cw_storage_plus::Bound;
 
-use white_whale_std::fee_distributor::QueryMsg;
 use white_whale_std::{
-    pool_network::asset::AssetInfo,
+    pool_network::asset::{Asset, AssetInfo},
     whale_lair::{
-        Bond, BondedResponse, BondingWeightResponse, Config, GlobalIndex, UnbondingResponse,
-        WithdrawableResponse,
+        Bond, BondedResponse, BondingWeightResponse, Config, GlobalIndex, InfoResponse,
+        PenaltiesResponse, ReceiptDenom, ReceiptDenomsResponse, TotalPowerAtHeightResponse,
+        UnbondingResponse, UnbondingSchedule, UnbondingScheduleResponse,
+        VotingPowerAtHeightResponse, WithdrawableResponse,
     },
 };
 
 use crate::helpers;
-use crate::state::{get_weight, BOND, BONDING_ASSETS_LIMIT, CONFIG, GLOBAL, UNBOND};
+use crate::state::{
+    get_bond_at_epoch, get_global_index_at_epoch, get_total_weighted_amount, get_weight,
+    get_weight_at_checkpoint, get_weighted_amount, BOND, BOND_SNAPSHOTS, CONFIG, GLOBAL,
+    GLOBAL_SNAPSHOTS, PENALTIES, RECEIPT_DENOMS, SNAPSHOT_TIME, TOTAL_PENALTIES, UNBOND,
+};
 
 /// Queries the current configuration of the contract.
 pub(crate) fn query_config(deps: Deps) -> StdResult<Config> {
@@ -26,11 +30,12 @@ pub(crate) fn query_config(deps: Deps) -> StdResult<Config> {
 /// Queries the current bonded amount of the given address.
 pub(crate) fn query_bonded(deps: Deps, address: String) -> StdResult<BondedResponse> {
     let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
 
     let bonds: Vec<Bond> = BOND
         .prefix(&address)
         .range(deps.storage, None, None, Order::Ascending)
-        .take(BONDING_ASSETS_LIMIT)
+        .take(config.bonding_assets_limit as usize)
         .map(|item| {
             let (_, bond) = item?;
             Ok(bond)
@@ -60,14 +65,10 @@ pub(crate) fn query_bonded(deps: Deps, address: String) -> StdResult<BondedRespo
         bonded_assets.push(bond.asset);
     }
 
-    let fee_distributor_addr = CONFIG.load(deps.storage)?.fee_distributor_addr;
-    let config: white_whale_std::fee_distributor::Config =
-        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-            contract_addr: fee_distributor_addr.to_string(),
-            msg: to_json_binary(&QueryMsg::Config {})?,
-        }))?;
-    let epoch_config = config.epoch_config;
-    let first_bonded_epoch_id = helpers::calculate_epoch(epoch_config, first_bond_timestamp)?;
+    let current_epoch = helpers::get_epoch(deps, None)?;
+    let epoch_config = helpers::get_epoch_config(deps)?;
+    let first_bonded_epoch_id =
+        helpers::calculate_epoch(&current_epoch, epoch_config.duration, first_bond_timestamp)?;
 
     Ok(BondedResponse {
         total_bonded,
@@ -147,6 +148,64 @@ pub(crate) fn query_withdrawable(
     })
 }
 
+/// Queries a summary of the unbondings of the given address for each of the bonding assets.
+pub(crate) fn query_unbonding_schedule(
+    deps: Deps,
+    timestamp: Timestamp,
+    address: String,
+) -> StdResult<UnbondingScheduleResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut schedule = vec![];
+    for asset_info in config.bonding_assets.iter() {
+        let denom = helpers::get_denom(asset_info);
+        let mut unbonding_schedule = UnbondingSchedule {
+            denom: denom.clone(),
+            withdrawable_amount: Uint128::zero(),
+            unbonding_amount: Uint128::zero(),
+            pending_unbondings: 0u32,
+            next_maturity: None,
+            final_maturity: None,
+        };
+
+        for item in
+            UNBOND
+                .prefix((&address, &denom))
+                .range(deps.storage, None, None, Order::Ascending)
+        {
+            let (_, bond) = item?;
+            let maturity = bond.timestamp.plus_nanos(config.unbonding_period.u64());
+
+            if maturity <= timestamp {
+                unbonding_schedule.withdrawable_amount = unbonding_schedule
+                    .withdrawable_amount
+                    .checked_add(bond.asset.amount)?;
+            } else {
+                unbonding_schedule.unbonding_amount = unbonding_schedule
+                    .unbonding_amount
+                    .checked_add(bond.asset.amount)?;
+                unbonding_schedule.pending_unbondings += 1;
+                unbonding_schedule.next_maturity = Some(
+                    unbonding_schedule
+                        .next_maturity
+                        .map_or(maturity, |next| next.min(maturity)),
+                );
+            }
+
+            unbonding_schedule.final_maturity = Some(
+                unbonding_schedule
+                    .final_maturity
+                    .map_or(maturity, |last| last.max(maturity)),
+            );
+        }
+
+        schedule.push(unbonding_schedule);
+    }
+
+    Ok(UnbondingScheduleResponse { schedule })
+}
+
 /// Queries the current weight of the given address.
 pub(crate) fn query_weight(
     deps: Deps,
@@ -173,8 +232,9 @@ pub(crate) fn query_weight(
         bond.weight = get_weight(
             timestamp,
             bond.weight,
-            bond.asset.amount,
+            get_weighted_amount(&config, &bond.asset),
             config.growth_rate,
+            &config.weight_growth,
             bond.timestamp,
         )?;
 
@@ -184,7 +244,7 @@ pub(crate) fn query_weight(
                 if !unique_denoms.contains(&denom) {
                     unique_denoms.insert(denom.clone());
                 }
-            } //todo the bonding assets can't be Tokens, so this could be removed
+            }
             AssetInfo::Token { contract_addr } => {
                 // If the contract_addr is not in the set of unique denoms, add it
                 if !unique_denoms.contains(&contract_addr) {
@@ -210,8 +270,9 @@ pub(crate) fn query_weight(
     global_index.weight = get_weight(
         timestamp,
         global_index.weight,
-        global_index.bonded_amount,
+        get_total_weighted_amount(&config, &global_index.bonded_assets)?,
         config.growth_rate,
+        &config.weight_growth,
         global_index.timestamp,
     )?;
 
@@ -227,6 +288,60 @@ pub(crate) fn query_weight(
     })
 }
 
+/// Queries the weight the given address had when the given epoch was created, based on the bond and
+/// global index checkpoints. The weight is calculated at the epoch's start time.
+pub(crate) fn query_epoch_weight(
+    deps: Deps,
+    address: String,
+    epoch_id: Uint64,
+) -> StdResult<BondingWeightResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let timestamp = helpers::get_epoch(deps, Some(epoch_id.u64()))?.start_time;
+
+    let mut total_bond_weight = Uint128::zero();
+    for bonding_asset in config.bonding_assets.iter() {
+        let denom = helpers::get_denom(bonding_asset);
+
+        if let Some(bond) = get_bond_at_epoch(deps.storage, &address, &denom, epoch_id.u64())? {
+            let weight = get_weight_at_checkpoint(
+                timestamp,
+                bond.weight,
+                get_weighted_amount(&config, &bond.asset),
+                config.growth_rate,
+                &config.weight_growth,
+                bond.timestamp,
+            )?;
+            total_bond_weight = total_bond_weight.checked_add(weight)?;
+        }
+    }
+
+    let global_index = get_global_index_at_epoch(deps.storage, epoch_id.u64())?;
+    let global_weight = get_weight_at_checkpoint(
+        timestamp,
+        global_index.weight,
+        get_total_weighted_amount(&config, &global_index.bonded_assets)?,
+        config.growth_rate,
+        &config.weight_growth,
+        global_index.timestamp,
+    )?;
+
+    let share = if global_weight.is_zero() {
+        Decimal::zero()
+    } else {
+        Decimal::from_ratio(total_bond_weight, global_weight)
+    };
+
+    Ok(BondingWeightResponse {
+        address: address.to_string(),
+        weight: total_bond_weight,
+        global_weight,
+        share,
+        timestamp,
+    })
+}
+
 /// Queries the total amount of assets that have been bonded to the contract.
 pub fn query_total_bonded(deps: Deps) -> StdResult<BondedResponse> {
     let global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
@@ -242,3 +357,137 @@ pub fn query_global_index(deps: Deps) -> StdResult<GlobalIndex> {
     let global_index = GLOBAL.may_load(deps.storage)?.unwrap_or_default();
     Ok(global_index)
 }
+
+/// Queries the penalties charged for unbonding instantly by the given address, or by everyone if
+/// no address is provided.
+pub fn query_penalties(deps: Deps, address: Option<String>) -> StdResult<PenaltiesResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let penalties = match address {
+        Some(address) => {
+            let address = deps.api.addr_validate(&address)?;
+            PENALTIES
+                .prefix(&address)
+                .range(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<(String, Uint128)>>>()?
+        }
+        None => TOTAL_PENALTIES
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(String, Uint128)>>>()?,
+    };
+
+    Ok(PenaltiesResponse {
+        penalties: penalties
+            .into_iter()
+            .map(|(denom, amount)| Asset {
+                info: helpers::get_bonding_asset_info(&config, &denom)
+                    .unwrap_or(AssetInfo::NativeToken { denom }),
+                amount,
+            })
+            .collect(),
+    })
+}
+
+/// Queries the receipt token denoms of the bonding assets.
+pub fn query_receipt_denoms(deps: Deps) -> StdResult<ReceiptDenomsResponse> {
+    let receipt_denoms = RECEIPT_DENOMS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (bonding_denom, receipt_denom) = item?;
+            Ok(ReceiptDenom {
+                bonding_denom,
+                receipt_denom,
+            })
+        })
+        .collect::<StdResult<Vec<ReceiptDenom>>>()?;
+
+    Ok(ReceiptDenomsResponse { receipt_denoms })
+}
+
+/// Queries the voting power of the given address at the given block height, i.e. the weight of the
+/// bonds it had at that height. Defaults to the current height.
+pub(crate) fn query_voting_power_at_height(
+    deps: Deps,
+    env: &Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<VotingPowerAtHeightResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+    let (height, timestamp) = get_snapshot_height_and_time(deps.storage, env, height)?;
+
+    let mut power = Uint128::zero();
+    for asset_info in config.bonding_assets.iter() {
+        let denom = helpers::get_denom(asset_info);
+        if let Some(bond) =
+            BOND_SNAPSHOTS.may_load_at_height(deps.storage, (&address, &denom), height)?
+        {
+            power = power.checked_add(get_weight(
+                timestamp,
+                bond.weight,
+                get_weighted_amount(&config, &bond.asset),
+                config.growth_rate,
+                &config.weight_growth,
+                bond.timestamp,
+            )?)?;
+        }
+    }
+
+    Ok(VotingPowerAtHeightResponse { power, height })
+}
+
+/// Queries the total voting power at the given block height, i.e. the global weight at that height.
+/// Defaults to the current height.
+pub(crate) fn query_total_power_at_height(
+    deps: Deps,
+    env: &Env,
+    height: Option<u64>,
+) -> StdResult<TotalPowerAtHeightResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (height, timestamp) = get_snapshot_height_and_time(deps.storage, env, height)?;
+
+    let global_index = GLOBAL_SNAPSHOTS
+        .may_load_at_height(deps.storage, height)?
+        .unwrap_or_default();
+
+    let power = get_weight(
+        timestamp,
+        global_index.weight,
+        get_total_weighted_amount(&config, &global_index.bonded_assets)?,
+        config.growth_rate,
+        &config.weight_growth,
+        global_index.timestamp,
+    )?;
+
+    Ok(TotalPowerAtHeightResponse { power, height })
+}
+
+/// Returns the height the snapshots are loaded at along with the time the weights are calculated at.
+/// Weights at past heights are calculated at the time of the last snapshot taken before them, as the
+/// block time of a past height is unknown. The current height uses the current block time.
+fn get_snapshot_height_and_time(
+    storage: &dyn Storage,
+    env: &Env,
+    height: Option<u64>,
+) -> StdResult<(u64, Timestamp)> {
+    match height {
+        Some(height) if height < env.block.height => Ok((
+            height,
+            SNAPSHOT_TIME
+                .may_load_at_height(storage, height)?
+                .unwrap_or_default(),
+        )),
+        _ => Ok((env.block.height, env.block.time)),
+    }
+}
+
+/// Queries the contract name and version.
+pub(crate) fn query_info(deps: Deps) -> StdResult<InfoResponse> {
+    Ok(InfoResponse {
+        info: cw2::get_contract_version(deps.storage)?,
+    })
+}
+
+/// Queries the DAO the contract is the voting module of, i.e. its owner.
+pub(crate) fn query_dao(deps: Deps) -> StdResult<Addr> {
+    Ok(CONFIG.load(deps.storage)?.owner)
+}
@@ -1,18 +1,68 @@
-use cosmwasm_std::{Addr, Decimal, DepsMut, StdError, StdResult, Timestamp, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{
+    Addr, Decimal, Decimal256, DepsMut, Order, StdError, StdResult, Storage, Timestamp, Uint128,
+    Uint256, Uint64,
+};
+use cw_storage_plus::{Bound, Item, Map, SnapshotItem, SnapshotMap, Strategy};
 
-use white_whale_std::pool_network::asset::AssetInfo;
-use white_whale_std::whale_lair::{Bond, Config, GlobalIndex};
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::whale_lair::{Bond, Config, GlobalIndex, WeightGrowth};
 
+use crate::helpers::get_denom;
 use crate::ContractError;
 
 type Denom = str;
 
-pub const BONDING_ASSETS_LIMIT: usize = 2;
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const BOND: Map<(&Addr, &Denom), Bond> = Map::new("bond");
 pub const UNBOND: Map<(&Addr, &Denom, u64), Bond> = Map::new("unbond");
 pub const GLOBAL: Item<GlobalIndex> = Item::new("global");
+/// The penalties charged for unbonding instantly, per address and denom.
+pub const PENALTIES: Map<(&Addr, &Denom), Uint128> = Map::new("penalties");
+/// The total penalties charged for unbonding instantly, per denom.
+pub const TOTAL_PENALTIES: Map<&Denom, Uint128> = Map::new("total_penalties");
+/// The time at which the bond of an address is unlocked, when the weight growth uses lockups. Key is
+/// (address, denom).
+pub const LOCKS: Map<(&Addr, &Denom), Timestamp> = Map::new("locks");
+/// The receipt token denom of each bonding asset, if the contract uses receipt tokens. Key is the
+/// bonding denom.
+pub const RECEIPT_DENOMS: Map<&Denom, String> = Map::new("receipt_denoms");
+
+// BOND_CHECKPOINTS and GLOBAL_CHECKPOINTS are used to deterministically calculate the weight of
+// an address when a given epoch was created, which is what the fee distributor uses to compute
+// rewards.
+
+/// The state of a bond right before it was modified for the first time after an epoch was created,
+/// i.e. the bond as it was when the epoch was created. Key is (address, denom, epoch_id).
+pub const BOND_CHECKPOINTS: Map<(&Addr, &Denom, u64), Bond> = Map::new("bond_checkpoints");
+/// The state of the global index right before it was modified for the first time after an epoch
+/// was created, i.e. the global index as it was when the epoch was created. Key is epoch_id.
+pub const GLOBAL_CHECKPOINTS: Map<u64, GlobalIndex> = Map::new("global_checkpoints");
+
+// BOND_SNAPSHOTS, GLOBAL_SNAPSHOTS and SNAPSHOT_TIME are used to calculate the voting power of an
+// address at a given block height, which is the weight it had at that height.
+
+/// The bonds indexed by block height. Key is (address, denom).
+pub const BOND_SNAPSHOTS: SnapshotMap<(&Addr, &Denom), Bond> = SnapshotMap::new(
+    "bond_snapshots",
+    "bond_snapshots__checkpoints",
+    "bond_snapshots__changelog",
+    Strategy::EveryBlock,
+);
+/// The global index indexed by block height.
+pub const GLOBAL_SNAPSHOTS: SnapshotItem<GlobalIndex> = SnapshotItem::new(
+    "global_snapshots",
+    "global_snapshots__checkpoints",
+    "global_snapshots__changelog",
+    Strategy::EveryBlock,
+);
+/// The block time of the last snapshot, indexed by block height. None of the snapshots at a given
+/// height were modified after this time, so it is when the weights at that height are calculated.
+pub const SNAPSHOT_TIME: SnapshotItem<Timestamp> = SnapshotItem::new(
+    "snapshot_time",
+    "snapshot_time__checkpoints",
+    "snapshot_time__changelog",
+    Strategy::EveryBlock,
+);
 
 /// Updates the local weight of the given address.
 pub fn update_local_weight(
@@ -26,19 +76,19 @@ pub fn update_local_weight(
     bond.weight = get_weight(
         timestamp,
         bond.weight,
-        bond.asset.amount,
+        get_weighted_amount(&config, &bond.asset),
         config.growth_rate,
+        &config.weight_growth,
         bond.timestamp,
     )?;
 
     bond.timestamp = timestamp;
 
-    let denom: &String = match &bond.asset.info {
-        AssetInfo::Token { .. } => return Err(ContractError::AssetMismatch {}),
-        AssetInfo::NativeToken { denom } => denom,
-    };
-
-    BOND.save(deps.storage, (&address, denom), &bond)?;
+    BOND.save(
+        deps.storage,
+        (&address, &get_denom(&bond.asset.info)),
+        &bond,
+    )?;
 
     Ok(bond)
 }
@@ -54,8 +104,9 @@ pub fn update_global_weight(
     global_index.weight = get_weight(
         timestamp,
         global_index.weight,
-        global_index.bonded_amount,
+        get_total_weighted_amount(&config, &global_index.bonded_assets)?,
         config.growth_rate,
+        &config.weight_growth,
         global_index.timestamp,
     )?;
 
@@ -66,12 +117,165 @@ pub fn update_global_weight(
     Ok(global_index)
 }
 
-/// Calculates the bonding weight of the given amount for the provided timestamps.
+/// Checkpoints the bond of the given address for the given epoch, if it hasn't been checkpointed yet.
+/// Must be called before the bond is modified.
+pub fn checkpoint_bond(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    denom: &Denom,
+    epoch_id: u64,
+) -> StdResult<()> {
+    if BOND_CHECKPOINTS.has(storage, (address, denom, epoch_id)) {
+        return Ok(());
+    }
+
+    // if there's no bond, checkpoint an empty one so it is known the address had nothing bonded
+    // when the epoch was created
+    let bond = BOND
+        .may_load(storage, (address, denom))?
+        .unwrap_or_default();
+    BOND_CHECKPOINTS.save(storage, (address, denom, epoch_id), &bond)
+}
+
+/// Checkpoints the global index for the given epoch, if it hasn't been checkpointed yet. Must be
+/// called before the global index is modified.
+pub fn checkpoint_global(storage: &mut dyn Storage, epoch_id: u64) -> StdResult<()> {
+    if GLOBAL_CHECKPOINTS.has(storage, epoch_id) {
+        return Ok(());
+    }
+
+    let global_index = GLOBAL.may_load(storage)?.unwrap_or_default();
+    GLOBAL_CHECKPOINTS.save(storage, epoch_id, &global_index)
+}
+
+/// Snapshots the bonds of the given addresses for the given denom, along with the global index, at
+/// the current block height. Must be called after the bonds are modified.
+pub fn snapshot_voting_power(
+    storage: &mut dyn Storage,
+    addresses: &[&Addr],
+    denom: &Denom,
+    height: u64,
+    timestamp: Timestamp,
+) -> StdResult<()> {
+    for address in addresses {
+        match BOND.may_load(storage, (address, denom))? {
+            Some(bond) => BOND_SNAPSHOTS.save(storage, (address, denom), &bond, height)?,
+            None => BOND_SNAPSHOTS.remove(storage, (address, denom), height)?,
+        }
+    }
+
+    let global_index = GLOBAL.may_load(storage)?.unwrap_or_default();
+    GLOBAL_SNAPSHOTS.save(storage, &global_index, height)?;
+    SNAPSHOT_TIME.save(storage, &timestamp, height)
+}
+
+/// Returns the bond of the given address as it was when the given epoch was created.
+pub fn get_bond_at_epoch(
+    storage: &dyn Storage,
+    address: &Addr,
+    denom: &Denom,
+    epoch_id: u64,
+) -> StdResult<Option<Bond>> {
+    // the first checkpoint taken at or after the given epoch holds the state the bond had when the
+    // epoch was created, as it wasn't modified in between. If there's none, the bond hasn't been
+    // modified since the epoch was created.
+    let checkpoint = BOND_CHECKPOINTS
+        .prefix((address, denom))
+        .range(
+            storage,
+            Some(Bound::inclusive(epoch_id)),
+            None,
+            Order::Ascending,
+        )
+        .next()
+        .transpose()?;
+
+    match checkpoint {
+        Some((_, bond)) => Ok(Some(bond)),
+        None => BOND.may_load(storage, (address, denom)),
+    }
+}
+
+/// Returns the global index as it was when the given epoch was created.
+pub fn get_global_index_at_epoch(storage: &dyn Storage, epoch_id: u64) -> StdResult<GlobalIndex> {
+    let checkpoint = GLOBAL_CHECKPOINTS
+        .range(
+            storage,
+            Some(Bound::inclusive(epoch_id)),
+            None,
+            Order::Ascending,
+        )
+        .next()
+        .transpose()?;
+
+    match checkpoint {
+        Some((_, global_index)) => Ok(global_index),
+        None => Ok(GLOBAL.may_load(storage)?.unwrap_or_default()),
+    }
+}
+
+/// Calculates the weight at the given timestamp out of a checkpointed state. If the state was
+/// modified after the timestamp, i.e. between the epoch start time and its creation, the recorded
+/// weight is used as is.
+pub fn get_weight_at_checkpoint(
+    timestamp: Timestamp,
+    weight: Uint128,
+    amount: Uint128,
+    growth_rate: Decimal,
+    weight_growth: &WeightGrowth,
+    checkpoint_timestamp: Timestamp,
+) -> StdResult<Uint128> {
+    if checkpoint_timestamp > timestamp {
+        return Ok(weight);
+    }
+
+    get_weight(
+        timestamp,
+        weight,
+        amount,
+        growth_rate,
+        weight_growth,
+        checkpoint_timestamp,
+    )
+}
+
+/// Returns the weight multiplier of the given bonding asset, which is one if it has none.
+pub fn get_weight_multiplier(config: &Config, asset_info: &AssetInfo) -> Decimal {
+    config
+        .weight_multipliers
+        .iter()
+        .find(|weight_multiplier| &weight_multiplier.asset_info == asset_info)
+        .map_or(Decimal::one(), |weight_multiplier| {
+            weight_multiplier.multiplier
+        })
+}
+
+/// Returns the amount the weight of the given asset grows with, i.e. its amount with the weight
+/// multiplier of the asset applied.
+pub fn get_weighted_amount(config: &Config, asset: &Asset) -> Uint128 {
+    asset.amount * get_weight_multiplier(config, &asset.info)
+}
+
+/// Returns the sum of the weighted amounts of the given assets. Used to calculate the global weight
+/// out of the assets bonded in the contract.
+pub fn get_total_weighted_amount(config: &Config, assets: &[Asset]) -> StdResult<Uint128> {
+    assets.iter().try_fold(Uint128::zero(), |total, asset| {
+        Ok(total.checked_add(get_weighted_amount(config, asset))?)
+    })
+}
+
+/// Calculates the bonding weight of the given amount for the provided timestamps, according to the
+/// weight growth model.
+///
+/// The global index grows with the same function as the bonds, out of the total bonded amount. All
+/// the models keep the global weight greater than or equal to the sum of the weights of the bonds,
+/// so the shares of the bonds never add up to more than one.
 pub fn get_weight(
     current_timestamp: Timestamp,
     weight: Uint128,
     amount: Uint128,
     growth_rate: Decimal,
+    weight_growth: &WeightGrowth,
     timestamp: Timestamp,
 ) -> StdResult<Uint128> {
     let time_factor = if timestamp == Timestamp::default() {
@@ -85,5 +289,241 @@ pub fn get_weight(
         )
     };
 
-    Ok(weight.checked_add(amount.checked_mul(time_factor)? * growth_rate)?)
+    match weight_growth {
+        WeightGrowth::Linear {} => {
+            Ok(weight.checked_add(amount.checked_mul(time_factor)? * growth_rate)?)
+        }
+        WeightGrowth::Capped { max_multiplier } => Ok(weight
+            .checked_add(amount.checked_mul(time_factor)? * growth_rate)?
+            .min(amount * *max_multiplier)),
+        WeightGrowth::Lockup { multiplier, .. } => {
+            Ok(weight.checked_add(amount.checked_mul(time_factor)? * growth_rate * *multiplier)?)
+        }
+        WeightGrowth::Logarithmic {
+            start_time,
+            time_scale,
+        } => {
+            if time_factor.is_zero() {
+                return Ok(weight);
+            }
+
+            // the growth speed decreases as 1 / (1 + t / time_scale), t being the time elapsed since
+            // the start time. Integrated between the two timestamps, the weight grows by
+            // amount * growth_rate * time_scale * ln((time_scale + t2) / (time_scale + t1)).
+            let elapsed_before = timestamp.seconds().saturating_sub(start_time.seconds());
+            let elapsed_after = current_timestamp
+                .seconds()
+                .saturating_sub(start_time.seconds());
+
+            let growth = Decimal256::from_ratio(amount, 1u128)
+                * Decimal256::from(growth_rate)
+                * Decimal256::from_ratio(time_scale.u64(), 1u128)
+                * ln(Decimal256::from_ratio(
+                    time_scale.checked_add(Uint64::new(elapsed_after))?,
+                    time_scale.checked_add(Uint64::new(elapsed_before))?,
+                ));
+
+            Ok(weight.checked_add(Uint128::try_from(growth.to_uint_floor())?)?)
+        }
+    }
+}
+
+/// Calculates the natural logarithm of a number greater than or equal to one, out of its binary
+/// logarithm.
+fn ln(x: Decimal256) -> Decimal256 {
+    // ln(2) with 18 decimals
+    const LN_2: Decimal256 = Decimal256::raw(693_147_180_559_945_309u128);
+    let two = Decimal256::percent(200);
+
+    // the integer part of the binary logarithm is the amount of times x can be halved
+    let mut y = x;
+    let mut integer_part = 0u64;
+    while y >= two {
+        y /= two;
+        integer_part += 1;
+    }
+
+    // the fractional part is calculated bit by bit, squaring y until it reaches two
+    let mut fractional_part = Uint256::zero();
+    let mut bit = Decimal256::one().atomics() >> 1;
+    while !bit.is_zero() {
+        y = y * y;
+        if y >= two {
+            y /= two;
+            fractional_part += bit;
+        }
+        bit >>= 1;
+    }
+
+    let log2 = Decimal256::new(
+        Uint256::from(integer_part) * Decimal256::one().atomics() + fractional_part,
+    );
+
+    log2 * LN_2
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+    use white_whale_std::pool_network::asset::Asset;
+
+    use super::*;
+
+    fn bond(amount: u128, weight: u128, seconds: u64) -> Bond {
+        Bond {
+            asset: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(amount),
+            },
+            timestamp: Timestamp::from_seconds(seconds),
+            weight: Uint128::new(weight),
+        }
+    }
+
+    #[test]
+    fn test_bond_checkpoints() {
+        let mut storage = MockStorage::new();
+        let address = Addr::unchecked("address");
+
+        // nothing bonded before the first checkpoint
+        checkpoint_bond(&mut storage, &address, "ampWHALE", 1u64).unwrap();
+        BOND.save(
+            &mut storage,
+            (&address, "ampWHALE"),
+            &bond(1_000, 1_000, 10),
+        )
+        .unwrap();
+        assert_eq!(
+            get_bond_at_epoch(&storage, &address, "ampWHALE", 1u64)
+                .unwrap()
+                .unwrap(),
+            Bond::default()
+        );
+        // the current bond is used for epochs created after the last modification
+        assert_eq!(
+            get_bond_at_epoch(&storage, &address, "ampWHALE", 2u64)
+                .unwrap()
+                .unwrap(),
+            bond(1_000, 1_000, 10)
+        );
+
+        // modify the bond on epoch 4, the checkpoint is kept for epochs 2, 3 and 4
+        checkpoint_bond(&mut storage, &address, "ampWHALE", 4u64).unwrap();
+        BOND.save(
+            &mut storage,
+            (&address, "ampWHALE"),
+            &bond(2_000, 5_000, 40),
+        )
+        .unwrap();
+        // a second modification within the same epoch doesn't override the checkpoint
+        checkpoint_bond(&mut storage, &address, "ampWHALE", 4u64).unwrap();
+        BOND.save(
+            &mut storage,
+            (&address, "ampWHALE"),
+            &bond(3_000, 6_000, 45),
+        )
+        .unwrap();
+
+        for epoch_id in 2u64..=4u64 {
+            assert_eq!(
+                get_bond_at_epoch(&storage, &address, "ampWHALE", epoch_id)
+                    .unwrap()
+                    .unwrap(),
+                bond(1_000, 1_000, 10)
+            );
+        }
+        assert_eq!(
+            get_bond_at_epoch(&storage, &address, "ampWHALE", 5u64)
+                .unwrap()
+                .unwrap(),
+            bond(3_000, 6_000, 45)
+        );
+        assert_eq!(
+            get_bond_at_epoch(&storage, &address, "bWHALE", 5u64).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_global_checkpoints() {
+        let mut storage = MockStorage::new();
+
+        checkpoint_global(&mut storage, 1u64).unwrap();
+        let global_index = GlobalIndex {
+            bonded_amount: Uint128::new(1_000),
+            bonded_assets: vec![],
+            timestamp: Timestamp::from_seconds(10),
+            weight: Uint128::new(1_000),
+        };
+        GLOBAL.save(&mut storage, &global_index).unwrap();
+
+        assert_eq!(
+            get_global_index_at_epoch(&storage, 1u64).unwrap(),
+            GlobalIndex::default()
+        );
+        assert_eq!(
+            get_global_index_at_epoch(&storage, 2u64).unwrap(),
+            global_index
+        );
+    }
+
+    #[test]
+    fn test_get_weight_at_checkpoint() {
+        // the checkpoint is older than the timestamp, so the weight grows
+        let weight = get_weight_at_checkpoint(
+            Timestamp::from_seconds(20),
+            Uint128::new(1_000),
+            Uint128::new(1_000),
+            Decimal::one(),
+            &WeightGrowth::Linear {},
+            Timestamp::from_seconds(10),
+        )
+        .unwrap();
+        assert_eq!(weight, Uint128::new(11_000));
+
+        // the checkpoint is newer than the timestamp, so the weight is used as is
+        let weight = get_weight_at_checkpoint(
+            Timestamp::from_seconds(20),
+            Uint128::new(1_000),
+            Uint128::new(1_000),
+            Decimal::one(),
+            &WeightGrowth::Linear {},
+            Timestamp::from_seconds(30),
+        )
+        .unwrap();
+        assert_eq!(weight, Uint128::new(1_000));
+    }
+
+    #[test]
+    fn test_logarithmic_weight_is_path_independent() {
+        let weight_growth = WeightGrowth::Logarithmic {
+            start_time: Timestamp::from_seconds(100),
+            time_scale: Uint64::new(1_000),
+        };
+        let get_log_weight = |weight: u128, from: u64, to: u64| {
+            get_weight(
+                Timestamp::from_seconds(to),
+                Uint128::new(weight),
+                Uint128::new(1_000_000),
+                Decimal::one(),
+                &weight_growth,
+                Timestamp::from_seconds(from),
+            )
+            .unwrap()
+        };
+
+        // updating the weight in between doesn't change the result, besides rounding
+        let weight = get_log_weight(1_000_000, 100, 7_100);
+        let intermediate_weight = get_log_weight(1_000_000, 100, 2_100);
+        let weight_in_two_steps = get_log_weight(intermediate_weight.u128(), 2_100, 7_100);
+
+        // 1_000_000 + 1_000_000 * 1_000 * ln(8)
+        assert_eq!(weight, Uint128::new(2_080_441_541u128));
+        assert!(weight.abs_diff(weight_in_two_steps) <= Uint128::one());
+
+        // no growth before the start time
+        assert_eq!(get_log_weight(1_000_000, 50, 100), Uint128::new(1_000_000));
+    }
 }
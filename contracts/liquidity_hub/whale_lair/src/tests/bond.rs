@@ -245,3 +245,48 @@ fn test_bond_wrong_asset() {
             },
         );
 }
+
+#[test]
+fn test_bond_on_behalf() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond_for(
+            sender.clone(),
+            another_sender.to_string(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::zero(),
+                bonded_assets: vec![],
+                first_bonded_epoch_id: Default::default(),
+            },
+        )
+        .assert_bonded_response(
+            another_sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(1_000u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(1_000u128),
+                }],
+                first_bonded_epoch_id: Default::default(),
+            },
+        );
+}
@@ -0,0 +1,222 @@
+use cosmwasm_std::{coins, Addr, Decimal, Timestamp, Uint128};
+
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::whale_lair::{BondingWeightResponse, WeightMultiplier};
+
+use crate::tests::robot::TestingRobot;
+use crate::ContractError;
+
+#[test]
+fn test_bond_cw20_with_weight_multiplier() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+    let mut token_addr = Addr::unchecked("");
+
+    robot
+        .instantiate_default()
+        .instantiate_cw20_token(&mut token_addr)
+        .update_bonding_assets_limit(sender.clone(), 3u32, |res| {
+            res.unwrap();
+        })
+        .add_bonding_asset(
+            sender.clone(),
+            AssetInfo::Token {
+                contract_addr: token_addr.to_string(),
+            },
+            Some(Decimal::percent(200)),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_config(|res| {
+            let config = res.unwrap().1;
+            assert_eq!(config.bonding_assets_limit, 3u32);
+            assert_eq!(
+                config.weight_multipliers,
+                vec![WeightMultiplier {
+                    asset_info: AssetInfo::Token {
+                        contract_addr: "contract4".to_string(),
+                    },
+                    multiplier: Decimal::percent(200),
+                }]
+            );
+        })
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond_cw20(
+            another_sender.clone(),
+            token_addr.clone(),
+            Uint128::new(1_000u128),
+            None,
+            |res| {
+                res.unwrap();
+            },
+        )
+        // the cw20 bond counts twice as much as the native one
+        .assert_bonding_weight_response(
+            another_sender.to_string(),
+            BondingWeightResponse {
+                address: another_sender.to_string(),
+                weight: Uint128::new(2_000u128),
+                global_weight: Uint128::new(3_000u128),
+                share: Decimal::from_ratio(2u128, 3u128),
+                timestamp: Timestamp::from_nanos(1571797419879305533u64),
+            },
+        )
+        .fast_forward(10u64)
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(11_000u128),
+                global_weight: Uint128::new(33_000u128),
+                share: Decimal::from_ratio(1u128, 3u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+            },
+        )
+        .assert_bonding_weight_response(
+            another_sender.to_string(),
+            BondingWeightResponse {
+                address: another_sender.to_string(),
+                weight: Uint128::new(22_000u128),
+                global_weight: Uint128::new(33_000u128),
+                share: Decimal::from_ratio(2u128, 3u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+            },
+        )
+        .unbond(
+            another_sender.clone(),
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: token_addr.to_string(),
+                },
+                amount: Uint128::new(400u128),
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(1_000u64)
+        .withdraw(another_sender.clone(), token_addr.to_string(), |res| {
+            res.unwrap();
+        })
+        .query_cw20_balance(another_sender.to_string(), token_addr, |balance| {
+            assert_eq!(balance, Uint128::new(999_999_400u128));
+        });
+}
+
+#[test]
+fn test_bonding_assets_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+    let mut token_addr = Addr::unchecked("");
+
+    robot
+        .instantiate_default()
+        .instantiate_cw20_token(&mut token_addr)
+        // the token is not a bonding asset yet
+        .bond_cw20(
+            sender.clone(),
+            token_addr.clone(),
+            Uint128::new(1_000u128),
+            None,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidBondingAsset {}.to_string()
+                );
+            },
+        )
+        // cw20 tokens can't be bonded with the native bond message
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: token_addr.to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &[],
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidBondingAsset {}.to_string()
+                );
+            },
+        )
+        .add_bonding_asset(
+            another_sender,
+            AssetInfo::Token {
+                contract_addr: token_addr.to_string(),
+            },
+            None,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+            },
+        )
+        .add_bonding_asset(
+            sender.clone(),
+            AssetInfo::NativeToken {
+                denom: "ampWHALE".to_string(),
+            },
+            None,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::DuplicateBondingAsset {}.to_string()
+                );
+            },
+        )
+        .add_bonding_asset(
+            sender.clone(),
+            AssetInfo::Token {
+                contract_addr: token_addr.to_string(),
+            },
+            None,
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidBondingAssetsLimit(2, 3).to_string()
+                );
+            },
+        )
+        // the limit can't be lower than the current amount of bonding assets
+        .update_bonding_assets_limit(sender.clone(), 1u32, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::InvalidBondingAssetsLimit(1, 2).to_string()
+            );
+        })
+        .update_bonding_assets_limit(sender.clone(), 3u32, |res| {
+            res.unwrap();
+        })
+        .add_bonding_asset(
+            sender,
+            AssetInfo::Token {
+                contract_addr: token_addr.to_string(),
+            },
+            Some(Decimal::zero()),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidWeightMultiplier {}.to_string()
+                );
+            },
+        );
+}
@@ -2,7 +2,7 @@ use cosmwasm_std::{Addr, Decimal, Uint64};
 
 use crate::tests::robot::TestingRobot;
 use white_whale_std::pool_network::asset::AssetInfo;
-use white_whale_std::whale_lair::Config;
+use white_whale_std::whale_lair::{Config, WeightGrowth};
 
 #[test]
 fn test_instantiate_successfully() {
@@ -35,6 +35,11 @@ fn test_instantiate_successfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            epoch_manager_addr: Addr::unchecked("contract3"),
+            instant_unbond_penalty: Some(Decimal::percent(10)),
+            bonding_assets_limit: 2,
+            weight_multipliers: vec![],
+            weight_growth: WeightGrowth::Linear {},
         });
 }
 
@@ -68,12 +73,12 @@ fn test_instantiate_unsuccessfully() {
         },
     );
 
-    // invalid tokens
+    // invalid cw20 token address
     robot.instantiate_err(
         Uint64::new(1_000u64),
         Decimal::one(),
         vec![AssetInfo::Token {
-            contract_addr: "contract123".to_string(),
+            contract_addr: "Contract123".to_string(),
         }],
         &vec![],
         |error| {
@@ -1,6 +1,11 @@
 mod bond;
+mod bonding_assets;
 mod instantiate;
+mod receipt;
 mod robot;
+mod transfer;
 mod unbond;
 mod update_config;
+mod voting_power;
+mod weight_growth;
 mod withdraw;
@@ -0,0 +1,388 @@
+use cosmwasm_std::{coin, coins, Uint128};
+
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::whale_lair::BondedResponse;
+
+use crate::tests::robot::TestingRobot;
+#[cfg(not(feature = "osmosis_token_factory"))]
+use crate::ContractError;
+
+#[cfg(not(feature = "osmosis_token_factory"))]
+#[test]
+fn test_receipt_token_requires_token_factory() {
+    let mut robot = TestingRobot::default();
+
+    robot.instantiate_with_receipt_token_err(
+        vec![AssetInfo::NativeToken {
+            denom: "ampWHALE".to_string(),
+        }],
+        |error| {
+            assert_eq!(
+                error.root_cause().to_string(),
+                ContractError::TokenFactoryNotEnabled {}.to_string()
+            );
+        },
+    );
+}
+
+#[test]
+fn test_transfer_hook_ignores_other_denoms() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    let bonded_response = BondedResponse {
+        total_bonded: Uint128::new(1_000u128),
+        bonded_assets: vec![Asset {
+            info: AssetInfo::NativeToken {
+                denom: "ampWHALE".to_string(),
+            },
+            amount: Uint128::new(1_000u128),
+        }],
+        first_bonded_epoch_id: Default::default(),
+    };
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // the contract doesn't use receipt tokens, so sending the bonded asset doesn't move the bond
+        .block_before_send(
+            sender.clone(),
+            another_sender.clone(),
+            coin(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_bonded_response(sender.to_string(), bonded_response)
+        .assert_bonded_response(
+            another_sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::zero(),
+                bonded_assets: vec![],
+                first_bonded_epoch_id: Default::default(),
+            },
+        );
+}
+
+#[cfg(feature = "osmosis_token_factory")]
+mod token_factory {
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+        MOCK_CONTRACT_ADDR,
+    };
+    use cosmwasm_std::{
+        coin, coins, from_json, to_json_binary, Addr, BankMsg, ContractResult, CosmosMsg, Decimal,
+        OwnedDeps, SystemResult, Timestamp, Uint128, Uint64, WasmQuery,
+    };
+
+    use white_whale_std::epoch_manager::epoch_manager::{
+        ConfigResponse, EpochConfig, EpochResponse, EpochV2, QueryMsg as EpochManagerQueryMsg,
+    };
+    use white_whale_std::fee_distributor::ClaimableEpochsResponse;
+    use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+    use white_whale_std::pool_network::denom_osmosis::{
+        Coin, MsgBurn, MsgCreateDenom, MsgMint, MsgSetBeforeSendHook,
+    };
+    use white_whale_std::whale_lair::{
+        BondedResponse, ExecuteMsg, InstantiateMsg, QueryMsg, ReceiptDenom, ReceiptDenomsResponse,
+        SudoMsg, WeightGrowth,
+    };
+
+    use crate::contract::{execute, instantiate, query, sudo};
+    use crate::ContractError;
+
+    const RECEIPT_DENOM: &str = "factory/cosmos2contract/receipt0";
+
+    fn mock_lair() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == "epoch_manager" => {
+                let response = match from_json(msg).unwrap() {
+                    EpochManagerQueryMsg::CurrentEpoch {} => to_json_binary(&EpochResponse {
+                        epoch: EpochV2 {
+                            id: 0u64,
+                            start_time: Timestamp::from_seconds(1_000u64),
+                        },
+                    }),
+                    EpochManagerQueryMsg::Config {} => to_json_binary(&ConfigResponse {
+                        owner: Addr::unchecked("owner"),
+                        epoch_config: EpochConfig {
+                            duration: Uint64::new(86_400_000_000_000u64),
+                            genesis_epoch: Uint64::new(1_000_000_000_000u64),
+                        },
+                    }),
+                    _ => panic!("unexpected epoch manager query"),
+                };
+                SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+            }
+            // no rewards to claim
+            _ => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&ClaimableEpochsResponse { epochs: vec![] }).unwrap(),
+            )),
+        });
+
+        let res = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            InstantiateMsg {
+                unbonding_period: Uint64::new(1_000_000_000_000u64),
+                growth_rate: Decimal::one(),
+                bonding_assets: vec![AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                }],
+                receipt_token: true,
+                instant_unbond_penalty: None,
+                bonding_assets_limit: 2,
+                weight_multipliers: vec![],
+                weight_growth: WeightGrowth::Linear {},
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|sub_msg| sub_msg.msg)
+                .collect::<Vec<CosmosMsg>>(),
+            vec![
+                MsgCreateDenom {
+                    sender: MOCK_CONTRACT_ADDR.to_string(),
+                    subdenom: "receipt0".to_string(),
+                }
+                .into(),
+                MsgSetBeforeSendHook {
+                    sender: MOCK_CONTRACT_ADDR.to_string(),
+                    denom: RECEIPT_DENOM.to_string(),
+                    cosmwasm_address: MOCK_CONTRACT_ADDR.to_string(),
+                }
+                .into(),
+            ]
+        );
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            ExecuteMsg::UpdateConfig {
+                owner: None,
+                unbonding_period: None,
+                growth_rate: None,
+                fee_distributor_addr: Some("fee_distributor".to_string()),
+                epoch_manager_addr: Some("epoch_manager".to_string()),
+                instant_unbond_penalty: None,
+                bonding_assets_limit: None,
+            },
+        )
+        .unwrap();
+
+        deps
+    }
+
+    fn ampwhale(amount: u128) -> Asset {
+        Asset {
+            info: AssetInfo::NativeToken {
+                denom: "ampWHALE".to_string(),
+            },
+            amount: Uint128::new(amount),
+        }
+    }
+
+    fn query_bonded(deps: &OwnedDeps<MockStorage, MockApi, MockQuerier>, address: &str) -> Uint128 {
+        let bonded: BondedResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::Bonded {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        bonded.total_bonded
+    }
+
+    #[test]
+    fn test_receipt_token_moves_bond() {
+        let mut deps = mock_lair();
+
+        let receipt_denoms: ReceiptDenomsResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::ReceiptDenoms {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            receipt_denoms.receipt_denoms,
+            vec![ReceiptDenom {
+                bonding_denom: "ampWHALE".to_string(),
+                receipt_denom: RECEIPT_DENOM.to_string(),
+            }]
+        );
+
+        // bonding mints the receipt tokens to the bonder
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &coins(1_000u128, "ampWHALE")),
+            ExecuteMsg::Bond {
+                asset: ampwhale(1_000u128),
+                receiver: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|sub_msg| sub_msg.msg)
+                .collect::<Vec<CosmosMsg>>(),
+            vec![
+                MsgMint {
+                    sender: MOCK_CONTRACT_ADDR.to_string(),
+                    amount: Some(Coin {
+                        denom: RECEIPT_DENOM.to_string(),
+                        amount: "1000".to_string(),
+                    }),
+                }
+                .into(),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "alice".to_string(),
+                    amount: coins(1_000u128, RECEIPT_DENOM),
+                }),
+            ]
+        );
+
+        // transferring the receipt tokens moves the bond
+        sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::BlockBeforeSend {
+                from: "alice".to_string(),
+                to: "bob".to_string(),
+                amount: coin(400u128, RECEIPT_DENOM),
+            },
+        )
+        .unwrap();
+        assert_eq!(query_bonded(&deps, "alice"), Uint128::new(600u128));
+        assert_eq!(query_bonded(&deps, "bob"), Uint128::new(400u128));
+
+        // transferring the bond through the contract forwards the receipt tokens
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &coins(100u128, RECEIPT_DENOM)),
+            ExecuteMsg::TransferBond {
+                asset: ampwhale(100u128),
+                recipient: "carol".to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "carol".to_string(),
+                amount: coins(100u128, RECEIPT_DENOM),
+            })
+        );
+        assert_eq!(query_bonded(&deps, "alice"), Uint128::new(500u128));
+        assert_eq!(query_bonded(&deps, "carol"), Uint128::new(100u128));
+
+        // can't transfer more receipts than the bond backing them
+        let err = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::BlockBeforeSend {
+                from: "bob".to_string(),
+                to: "carol".to_string(),
+                amount: coin(500u128, RECEIPT_DENOM),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::InsufficientBond {});
+
+        // unbonding requires the receipt tokens, which are burned
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &[]),
+            ExecuteMsg::Unbond {
+                asset: ampwhale(400u128),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::ReceiptMismatch {});
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bob", &coins(400u128, RECEIPT_DENOM)),
+            ExecuteMsg::Unbond {
+                asset: ampwhale(400u128),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            MsgBurn {
+                sender: MOCK_CONTRACT_ADDR.to_string(),
+                amount: Some(Coin {
+                    denom: RECEIPT_DENOM.to_string(),
+                    amount: "400".to_string(),
+                }),
+            }
+            .into()
+        );
+        assert_eq!(query_bonded(&deps, "bob"), Uint128::zero());
+    }
+
+    #[test]
+    fn test_receipt_transfer_requires_claimed_rewards() {
+        let mut deps = mock_lair();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &coins(1_000u128, "ampWHALE")),
+            ExecuteMsg::Bond {
+                asset: ampwhale(1_000u128),
+                receiver: None,
+            },
+        )
+        .unwrap();
+
+        // alice has rewards pending to be claimed
+        deps.querier.update_wasm(|_| {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&ClaimableEpochsResponse {
+                    epochs: vec![Default::default()],
+                })
+                .unwrap(),
+            ))
+        });
+
+        let err = sudo(
+            deps.as_mut(),
+            mock_env(),
+            SudoMsg::BlockBeforeSend {
+                from: "alice".to_string(),
+                to: "bob".to_string(),
+                amount: coin(400u128, RECEIPT_DENOM),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::UnclaimedRewards {});
+    }
+}
@@ -1,14 +1,17 @@
-use cosmwasm_std::{coin, Addr, Coin, Decimal, StdResult, Uint64};
-use cw_multi_test::{App, AppResponse, Executor};
+use cosmwasm_std::{coin, to_json_binary, Addr, Coin, Decimal, StdResult, Uint128, Uint64};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_multi_test::{App, AppResponse, ContractWrapper, Executor};
 
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::whale_lair::{
-    BondedResponse, BondingWeightResponse, Config, ExecuteMsg, InstantiateMsg, QueryMsg,
-    UnbondingResponse, WithdrawableResponse,
+    BondedResponse, BondingWeightResponse, Config, Cw20HookMsg, ExecuteMsg, GlobalIndex,
+    InfoResponse, InstantiateMsg, PenaltiesResponse, QueryMsg, SudoMsg, TotalPowerAtHeightResponse,
+    UnbondingResponse, UnbondingScheduleResponse, VotingPowerAtHeightResponse, WeightGrowth,
+    WithdrawableResponse,
 };
 use white_whale_testing::integration::contracts::{
-    store_fee_collector_code, store_fee_distributor_code, whale_lair_contract,
+    instantiate_epoch_manager, store_fee_collector_code, store_fee_distributor_code,
+    whale_lair_contract,
 };
 use white_whale_testing::integration::integration_mocks::mock_app_with_balance;
 
@@ -17,6 +20,7 @@ pub struct TestingRobot {
     pub sender: Addr,
     pub another_sender: Addr,
     whale_lair_addr: Addr,
+    weight_growth: WeightGrowth,
 }
 
 /// instantiate / execute messages
@@ -51,9 +55,17 @@ impl TestingRobot {
             sender,
             another_sender,
             whale_lair_addr: Addr::unchecked(""),
+            weight_growth: WeightGrowth::Linear {},
         }
     }
 
+    /// Sets the weight growth the contract is instantiated with.
+    pub(crate) fn with_weight_growth(&mut self, weight_growth: WeightGrowth) -> &mut Self {
+        self.weight_growth = weight_growth;
+
+        self
+    }
+
     pub(crate) fn fast_forward(&mut self, seconds: u64) -> &mut Self {
         let mut block_info = self.app.block_info();
         block_info.time = block_info.time.plus_nanos(seconds * 1_000_000_000);
@@ -62,6 +74,15 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn next_block(&mut self, seconds: u64) -> &mut Self {
+        let mut block_info = self.app.block_info();
+        block_info.height += 1;
+        block_info.time = block_info.time.plus_nanos(seconds * 1_000_000_000);
+        self.app.set_block(block_info);
+
+        self
+    }
+
     pub(crate) fn instantiate_default(&mut self) -> &mut Self {
         self.instantiate(
             Uint64::new(1_000_000_000_000u64),
@@ -101,9 +122,15 @@ impl TestingRobot {
             .unwrap();
         println!("fee_collector_address: {}", fee_collector_address);
 
-        let whale_lair_addr =
-            instantiate_contract(self, unbonding_period, growth_rate, bonding_assets, funds)
-                .unwrap();
+        let whale_lair_addr = instantiate_contract(
+            self,
+            unbonding_period,
+            growth_rate,
+            bonding_assets,
+            false,
+            funds,
+        )
+        .unwrap();
 
         let fee_distributor_address = self
             .app
@@ -113,11 +140,8 @@ impl TestingRobot {
                 &white_whale_std::fee_distributor::InstantiateMsg {
                     bonding_contract_addr: whale_lair_addr.clone().to_string(),
                     fee_collector_addr: fee_collector_address.clone().to_string(),
+                    epoch_manager_addr: "contract3".to_string(),
                     grace_period: Uint64::new(1),
-                    epoch_config: EpochConfig {
-                        duration: Uint64::new(86_400_000_000_000u64), // a day
-                        genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
-                    },
                     distribution_asset: AssetInfo::NativeToken {
                         denom: "uwhale".to_string(),
                     },
@@ -127,13 +151,31 @@ impl TestingRobot {
                 None,
             )
             .unwrap();
+
+        let epoch_manager_address = instantiate_epoch_manager(&mut self.app, self.sender.clone());
+
+        self.app
+            .execute_contract(
+                self.sender.clone(),
+                epoch_manager_address.clone(),
+                &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+                    contract_addr: fee_distributor_address.to_string(),
+                    gas_limit: None,
+                },
+                &[],
+            )
+            .unwrap();
+
         // Now set the fee distributor on the config of the whale lair
         // So that we can check claims before letting them bond/unbond
         let msg = ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.clone().to_string()),
+            epoch_manager_addr: Some(epoch_manager_address.to_string()),
             owner: None,
             unbonding_period: None,
             growth_rate: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
         };
         self.app
             .execute_contract(self.sender.clone(), whale_lair_addr.clone(), &msg, &[])
@@ -152,13 +194,59 @@ impl TestingRobot {
         error: impl Fn(anyhow::Error),
     ) -> &mut Self {
         error(
-            instantiate_contract(self, unbonding_period, growth_rate, bonding_assets, funds)
-                .unwrap_err(),
+            instantiate_contract(
+                self,
+                unbonding_period,
+                growth_rate,
+                bonding_assets,
+                false,
+                funds,
+            )
+            .unwrap_err(),
+        );
+
+        self
+    }
+
+    #[cfg(not(feature = "osmosis_token_factory"))]
+    pub(crate) fn instantiate_with_receipt_token_err(
+        &mut self,
+        bonding_assets: Vec<AssetInfo>,
+        error: impl Fn(anyhow::Error),
+    ) -> &mut Self {
+        error(
+            instantiate_contract(
+                self,
+                Uint64::new(1_000_000_000_000u64),
+                Decimal::one(),
+                bonding_assets,
+                true,
+                &vec![],
+            )
+            .unwrap_err(),
         );
 
         self
     }
 
+    pub(crate) fn block_before_send(
+        &mut self,
+        from: Addr,
+        to: Addr,
+        amount: Coin,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = SudoMsg::BlockBeforeSend {
+            from: from.to_string(),
+            to: to.to_string(),
+            amount,
+        };
+
+        response(self.app.wasm_sudo(self.whale_lair_addr.clone(), &msg));
+
+        self
+    }
+
     pub(crate) fn bond(
         &mut self,
         sender: Addr,
@@ -166,7 +254,10 @@ impl TestingRobot {
         funds: &[Coin],
         response: impl Fn(Result<AppResponse, anyhow::Error>),
     ) -> &mut Self {
-        let msg = ExecuteMsg::Bond { asset };
+        let msg = ExecuteMsg::Bond {
+            asset,
+            receiver: None,
+        };
 
         response(
             self.app
@@ -176,6 +267,44 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn bond_for(
+        &mut self,
+        sender: Addr,
+        receiver: String,
+        asset: Asset,
+        funds: &[Coin],
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::Bond {
+            asset,
+            receiver: Some(receiver),
+        };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, funds),
+        );
+
+        self
+    }
+
+    pub(crate) fn transfer_bond(
+        &mut self,
+        sender: Addr,
+        recipient: String,
+        asset: Asset,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::TransferBond { asset, recipient };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
     pub(crate) fn unbond(
         &mut self,
         sender: Addr,
@@ -192,6 +321,22 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn instant_unbond(
+        &mut self,
+        sender: Addr,
+        asset: Asset,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::InstantUnbond { asset };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
     pub(crate) fn withdraw(
         &mut self,
         sender: Addr,
@@ -208,6 +353,22 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn withdraw_all(
+        &mut self,
+        sender: Addr,
+        limit: Option<u8>,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::WithdrawAll { limit };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
     pub(crate) fn update_config(
         &mut self,
         sender: Addr,
@@ -221,6 +382,53 @@ impl TestingRobot {
             unbonding_period,
             growth_rate,
             fee_distributor_addr: None,
+            epoch_manager_addr: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: None,
+        };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn update_bonding_assets_limit(
+        &mut self,
+        sender: Addr,
+        bonding_assets_limit: u32,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::UpdateConfig {
+            owner: None,
+            unbonding_period: None,
+            growth_rate: None,
+            fee_distributor_addr: None,
+            epoch_manager_addr: None,
+            instant_unbond_penalty: None,
+            bonding_assets_limit: Some(bonding_assets_limit),
+        };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
+    pub(crate) fn add_bonding_asset(
+        &mut self,
+        sender: Addr,
+        asset_info: AssetInfo,
+        weight_multiplier: Option<Decimal>,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::AddBondingAsset {
+            asset_info,
+            weight_multiplier,
         };
 
         response(
@@ -230,6 +438,64 @@ impl TestingRobot {
 
         self
     }
+
+    /// Instantiates a cw20 token with a balance for both senders.
+    pub(crate) fn instantiate_cw20_token(&mut self, token_addr: &mut Addr) -> &mut Self {
+        let cw20_token_id = self.app.store_code(Box::new(ContractWrapper::new(
+            cw20_base::contract::execute,
+            cw20_base::contract::instantiate,
+            cw20_base::contract::query,
+        )));
+
+        *token_addr = self
+            .app
+            .instantiate_contract(
+                cw20_token_id,
+                self.sender.clone(),
+                &cw20_base::msg::InstantiateMsg {
+                    name: "Governance Token".to_string(),
+                    symbol: "GOV".to_string(),
+                    decimals: 6,
+                    initial_balances: vec![
+                        Cw20Coin {
+                            address: self.sender.to_string(),
+                            amount: Uint128::new(1_000_000_000u128),
+                        },
+                        Cw20Coin {
+                            address: self.another_sender.to_string(),
+                            amount: Uint128::new(1_000_000_000u128),
+                        },
+                    ],
+                    mint: None,
+                    marketing: None,
+                },
+                &[],
+                "cw20_token",
+                None,
+            )
+            .unwrap();
+
+        self
+    }
+
+    pub(crate) fn bond_cw20(
+        &mut self,
+        sender: Addr,
+        token_addr: Addr,
+        amount: Uint128,
+        receiver: Option<String>,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = Cw20ExecuteMsg::Send {
+            contract: self.whale_lair_addr.to_string(),
+            amount,
+            msg: to_json_binary(&Cw20HookMsg::Bond { receiver }).unwrap(),
+        };
+
+        response(self.app.execute_contract(sender, token_addr, &msg, &[]));
+
+        self
+    }
 }
 
 fn instantiate_contract(
@@ -237,12 +503,18 @@ fn instantiate_contract(
     unbonding_period: Uint64,
     growth_rate: Decimal,
     bonding_assets: Vec<AssetInfo>,
+    receipt_token: bool,
     funds: &Vec<Coin>,
 ) -> anyhow::Result<Addr> {
     let msg = InstantiateMsg {
         unbonding_period,
         growth_rate,
         bonding_assets,
+        receipt_token,
+        instant_unbond_penalty: Some(Decimal::percent(10)),
+        bonding_assets_limit: 2,
+        weight_multipliers: vec![],
+        weight_growth: robot.weight_growth.clone(),
     };
 
     let whale_lair_id = robot.app.store_code(whale_lair_contract());
@@ -360,6 +632,155 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn query_unbonding_schedule(
+        &mut self,
+        address: String,
+        response: impl Fn(StdResult<(&mut Self, UnbondingScheduleResponse)>),
+    ) -> &mut Self {
+        let unbonding_schedule_response: UnbondingScheduleResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::UnbondingSchedule { address },
+            )
+            .unwrap();
+
+        response(Ok((self, unbonding_schedule_response)));
+
+        self
+    }
+
+    pub(crate) fn query_voting_power_at_height(
+        &mut self,
+        address: String,
+        height: Option<u64>,
+        response: impl Fn(StdResult<(&mut Self, VotingPowerAtHeightResponse)>),
+    ) -> &mut Self {
+        let voting_power_response: VotingPowerAtHeightResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::VotingPowerAtHeight { address, height },
+            )
+            .unwrap();
+
+        response(Ok((self, voting_power_response)));
+
+        self
+    }
+
+    pub(crate) fn query_info(
+        &mut self,
+        response: impl Fn(StdResult<(&mut Self, InfoResponse)>),
+    ) -> &mut Self {
+        let info_response: InfoResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::Info {})
+            .unwrap();
+
+        response(Ok((self, info_response)));
+
+        self
+    }
+
+    pub(crate) fn query_dao(
+        &mut self,
+        response: impl Fn(StdResult<(&mut Self, Addr)>),
+    ) -> &mut Self {
+        let dao: Addr = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::Dao {})
+            .unwrap();
+
+        response(Ok((self, dao)));
+
+        self
+    }
+
+    pub(crate) fn query_total_power_at_height(
+        &mut self,
+        height: Option<u64>,
+        response: impl Fn(StdResult<(&mut Self, TotalPowerAtHeightResponse)>),
+    ) -> &mut Self {
+        let total_power_response: TotalPowerAtHeightResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.whale_lair_addr,
+                &QueryMsg::TotalPowerAtHeight { height },
+            )
+            .unwrap();
+
+        response(Ok((self, total_power_response)));
+
+        self
+    }
+
+    pub(crate) fn query_global_index(
+        &mut self,
+        response: impl Fn(StdResult<(&mut Self, GlobalIndex)>),
+    ) -> &mut Self {
+        let global_index: GlobalIndex = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::GlobalIndex {})
+            .unwrap();
+
+        response(Ok((self, global_index)));
+
+        self
+    }
+
+    pub(crate) fn query_penalties(
+        &mut self,
+        address: Option<String>,
+        response: impl Fn(StdResult<(&mut Self, PenaltiesResponse)>),
+    ) -> &mut Self {
+        let penalties_response: PenaltiesResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::Penalties { address })
+            .unwrap();
+
+        response(Ok((self, penalties_response)));
+
+        self
+    }
+
+    pub(crate) fn query_balance(
+        &mut self,
+        address: String,
+        denom: String,
+        response: impl Fn(Uint128),
+    ) -> &mut Self {
+        let balance = self.app.wrap().query_balance(address, denom).unwrap();
+
+        response(balance.amount);
+
+        self
+    }
+
+    pub(crate) fn query_cw20_balance(
+        &mut self,
+        address: String,
+        token_addr: Addr,
+        response: impl Fn(Uint128),
+    ) -> &mut Self {
+        let balance: BalanceResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(token_addr, &Cw20QueryMsg::Balance { address })
+            .unwrap();
+
+        response(balance.balance);
+
+        self
+    }
+
     pub(crate) fn query_total_bonded(
         &mut self,
         response: impl Fn(StdResult<(&mut Self, BondedResponse)>),
@@ -0,0 +1,162 @@
+use cosmwasm_std::{coins, Decimal, Uint128};
+
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+
+use crate::tests::robot::TestingRobot;
+use crate::ContractError;
+
+fn ampwhale(amount: u128) -> Asset {
+    Asset {
+        info: AssetInfo::NativeToken {
+            denom: "ampWHALE".to_string(),
+        },
+        amount: Uint128::new(amount),
+    }
+}
+
+#[test]
+fn test_transfer_bond_successfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            ampwhale(1_000u128),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        .transfer_bond(
+            sender.clone(),
+            another_sender.to_string(),
+            ampwhale(400u128),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // the accrued weight is moved along with the bond
+        .query_weight(sender.to_string(), |res| {
+            let weight = res.unwrap().1;
+            assert_eq!(weight.weight, Uint128::new(6_600u128));
+            assert_eq!(weight.global_weight, Uint128::new(11_000u128));
+            assert_eq!(weight.share, Decimal::from_ratio(6u128, 10u128));
+        })
+        .query_weight(another_sender.to_string(), |res| {
+            let weight = res.unwrap().1;
+            assert_eq!(weight.weight, Uint128::new(4_400u128));
+            assert_eq!(weight.share, Decimal::from_ratio(4u128, 10u128));
+        })
+        .query_bonded(another_sender.to_string(), |res| {
+            let bonded = res.unwrap().1;
+            assert_eq!(bonded.total_bonded, Uint128::new(400u128));
+        })
+        // the global index doesn't change
+        .query_global_index(|res| {
+            let global_index = res.unwrap().1;
+            assert_eq!(global_index.bonded_amount, Uint128::new(1_000u128));
+            assert_eq!(global_index.bonded_assets, vec![ampwhale(1_000u128)]);
+        })
+        .fast_forward(10u64)
+        // transferring the whole bond removes it
+        .transfer_bond(
+            sender.clone(),
+            another_sender.to_string(),
+            ampwhale(600u128),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_bonded(sender.to_string(), |res| {
+            let bonded = res.unwrap().1;
+            assert_eq!(bonded.total_bonded, Uint128::zero());
+        })
+        .query_weight(another_sender.to_string(), |res| {
+            let weight = res.unwrap().1;
+            assert_eq!(weight.weight, Uint128::new(21_000u128));
+            assert_eq!(weight.global_weight, Uint128::new(21_000u128));
+            assert_eq!(weight.share, Decimal::one());
+        });
+}
+
+#[test]
+fn test_transfer_bond_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .transfer_bond(
+            sender.clone(),
+            another_sender.to_string(),
+            ampwhale(1_000u128),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InsufficientBond {}.to_string()
+                );
+            },
+        )
+        .bond(
+            sender.clone(),
+            ampwhale(1_000u128),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .transfer_bond(
+            sender.clone(),
+            another_sender.to_string(),
+            ampwhale(0u128),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidTransferAmount {}.to_string()
+                );
+            },
+        )
+        .transfer_bond(
+            sender.clone(),
+            sender.to_string(),
+            ampwhale(1_000u128),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::SelfTransfer {}.to_string()
+                );
+            },
+        )
+        .transfer_bond(
+            sender.clone(),
+            another_sender.to_string(),
+            ampwhale(1_001u128),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InsufficientBond {}.to_string()
+                );
+            },
+        )
+        .transfer_bond(
+            sender,
+            another_sender.to_string(),
+            Asset {
+                info: AssetInfo::Token {
+                    contract_addr: "contract123".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidBondingAsset {}.to_string()
+                );
+            },
+        );
+}
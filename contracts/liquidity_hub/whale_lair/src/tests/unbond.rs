@@ -5,6 +5,7 @@ use white_whale_std::whale_lair::{Bond, BondedResponse, BondingWeightResponse, U
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 
 use crate::tests::robot::TestingRobot;
+use crate::ContractError;
 
 #[test]
 fn test_unbond_successfully() {
@@ -480,3 +481,140 @@ fn test_unbond_unsuccessfully() {
             },
         );
 }
+
+#[test]
+fn test_instant_unbond_successfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    let penalties = vec![Asset {
+        info: AssetInfo::NativeToken {
+            denom: "ampWHALE".to_string(),
+        },
+        amount: Uint128::new(40u128),
+    }];
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        .instant_unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(400u128),
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        // the 10% penalty goes to the fee collector, the rest is sent right away
+        .query_balance(sender.to_string(), "ampWHALE".to_string(), |balance| {
+            assert_eq!(balance, Uint128::new(999_999_360u128));
+        })
+        .query_balance("contract0".to_string(), "ampWHALE".to_string(), |balance| {
+            assert_eq!(balance, Uint128::new(40u128));
+        })
+        .assert_unbonding_response(
+            sender.to_string(),
+            "ampWHALE".to_string(),
+            UnbondingResponse {
+                total_amount: Uint128::zero(),
+                unbonding_requests: vec![],
+            },
+        )
+        .query_bonded(sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.total_bonded, Uint128::new(600u128));
+        })
+        .query_penalties(Some(sender.to_string()), |res| {
+            assert_eq!(res.unwrap().1.penalties, penalties);
+        })
+        .query_penalties(Some(another_sender.to_string()), |res| {
+            assert!(res.unwrap().1.penalties.is_empty());
+        })
+        .query_penalties(None, |res| {
+            assert_eq!(res.unwrap().1.penalties, penalties);
+        });
+}
+
+#[test]
+fn test_instant_unbond_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        .instant_unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::NothingToUnbond {}.to_string()
+                );
+            },
+        )
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .instant_unbond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::zero(),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InvalidUnbondingAmount {}.to_string()
+                );
+            },
+        )
+        .instant_unbond(
+            sender,
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(2_000u128),
+            },
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::InsufficientBond {}.to_string()
+                );
+            },
+        );
+}
@@ -1,7 +1,7 @@
 use cosmwasm_std::{Addr, Decimal, Uint128, Uint64};
 
 use white_whale_std::pool_network::asset::AssetInfo;
-use white_whale_std::whale_lair::Config;
+use white_whale_std::whale_lair::{Config, WeightGrowth};
 
 use crate::tests::robot::TestingRobot;
 
@@ -25,6 +25,11 @@ fn test_update_config_successfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            epoch_manager_addr: Addr::unchecked("contract3"),
+            instant_unbond_penalty: Some(Decimal::percent(10)),
+            bonding_assets_limit: 2,
+            weight_multipliers: vec![],
+            weight_growth: WeightGrowth::Linear {},
         })
         .update_config(
             owner.clone(),
@@ -49,6 +54,11 @@ fn test_update_config_successfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            epoch_manager_addr: Addr::unchecked("contract3"),
+            instant_unbond_penalty: Some(Decimal::percent(10)),
+            bonding_assets_limit: 2,
+            weight_multipliers: vec![],
+            weight_growth: WeightGrowth::Linear {},
         })
         .update_config(
             owner,
@@ -70,6 +80,11 @@ fn test_update_config_successfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            epoch_manager_addr: Addr::unchecked("contract3"),
+            instant_unbond_penalty: Some(Decimal::percent(10)),
+            bonding_assets_limit: 2,
+            weight_multipliers: vec![],
+            weight_growth: WeightGrowth::Linear {},
         });
 }
 
@@ -92,6 +107,11 @@ fn test_update_config_unsuccessfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            epoch_manager_addr: Addr::unchecked("contract3"),
+            instant_unbond_penalty: Some(Decimal::percent(10)),
+            bonding_assets_limit: 2,
+            weight_multipliers: vec![],
+            weight_growth: WeightGrowth::Linear {},
         })
         .update_config(
             Addr::unchecked("unauthorized"),
@@ -122,6 +142,11 @@ fn test_update_config_unsuccessfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            epoch_manager_addr: Addr::unchecked("contract3"),
+            instant_unbond_penalty: Some(Decimal::percent(10)),
+            bonding_assets_limit: 2,
+            weight_multipliers: vec![],
+            weight_growth: WeightGrowth::Linear {},
         })
         .update_config(
             Addr::unchecked("owner"),
@@ -152,5 +177,10 @@ fn test_update_config_unsuccessfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            epoch_manager_addr: Addr::unchecked("contract3"),
+            instant_unbond_penalty: Some(Decimal::percent(10)),
+            bonding_assets_limit: 2,
+            weight_multipliers: vec![],
+            weight_growth: WeightGrowth::Linear {},
         });
 }
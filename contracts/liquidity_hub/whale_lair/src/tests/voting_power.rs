@@ -0,0 +1,100 @@
+use cosmwasm_std::{coins, Uint128};
+
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+
+use crate::tests::robot::TestingRobot;
+
+fn asset(denom: &str, amount: u128) -> Asset {
+    Asset {
+        info: AssetInfo::NativeToken {
+            denom: denom.to_string(),
+        },
+        amount: Uint128::new(amount),
+    }
+}
+
+#[test]
+fn test_voting_power_at_height() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            asset("ampWHALE", 1_000u128),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // bonds count from the block after they were made
+        .query_voting_power_at_height(sender.to_string(), None, |res| {
+            let voting_power = res.unwrap().1;
+            assert_eq!(voting_power.power, Uint128::zero());
+            assert_eq!(voting_power.height, 12_345u64);
+        })
+        .next_block(10u64)
+        .bond(
+            another_sender.clone(),
+            asset("bWHALE", 1_000u128),
+            &coins(1_000u128, "bWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .next_block(10u64)
+        .unbond(sender.clone(), asset("ampWHALE", 400u128), |res| {
+            res.unwrap();
+        })
+        .next_block(10u64)
+        .query_voting_power_at_height(sender.to_string(), Some(12_345u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::zero());
+        })
+        .query_voting_power_at_height(sender.to_string(), Some(12_346u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(1_000u128));
+        })
+        // the weights at a past height are calculated when the last snapshot before it was taken
+        .query_voting_power_at_height(sender.to_string(), Some(12_347u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(11_000u128));
+        })
+        .query_voting_power_at_height(another_sender.to_string(), Some(12_347u64), |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(1_000u128));
+        })
+        // the total power is the global weight, which is never lower than the sum of the weights
+        .query_total_power_at_height(Some(12_347u64), |res| {
+            let total_power = res.unwrap().1;
+            assert_eq!(total_power.power, Uint128::new(22_000u128));
+            assert_eq!(total_power.height, 12_347u64);
+        })
+        // the current voting power keeps growing with time
+        .query_voting_power_at_height(sender.to_string(), None, |res| {
+            let voting_power = res.unwrap().1;
+            assert_eq!(voting_power.power, Uint128::new(18_600u128));
+            assert_eq!(voting_power.height, 12_348u64);
+        })
+        .query_voting_power_at_height(another_sender.to_string(), None, |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(21_000u128));
+        })
+        .query_total_power_at_height(None, |res| {
+            assert_eq!(res.unwrap().1.power, Uint128::new(49_600u128));
+        });
+}
+
+#[test]
+fn test_voting_module_info() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        .query_info(|res| {
+            let info = res.unwrap().1.info;
+            assert_eq!(info.contract, "white_whale-whale_lair");
+            assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        })
+        .query_dao(|res| {
+            assert_eq!(res.unwrap().1, sender);
+        });
+}
@@ -0,0 +1,159 @@
+use cosmwasm_std::{coins, Decimal, Timestamp, Uint128, Uint64};
+
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::whale_lair::WeightGrowth;
+
+use crate::tests::robot::TestingRobot;
+use crate::ContractError;
+
+fn ampwhale(amount: u128) -> Asset {
+    Asset {
+        info: AssetInfo::NativeToken {
+            denom: "ampWHALE".to_string(),
+        },
+        amount: Uint128::new(amount),
+    }
+}
+
+#[test]
+fn test_capped_weight_growth() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .with_weight_growth(WeightGrowth::Capped {
+            max_multiplier: Decimal::percent(300),
+        })
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            ampwhale(1_000u128),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(1u64)
+        .query_weight(sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.weight, Uint128::new(2_000u128));
+        })
+        // the weight doesn't grow beyond 3 times the bonded amount
+        .fast_forward(10u64)
+        .query_weight(sender.to_string(), |res| {
+            let weight = res.unwrap().1;
+            assert_eq!(weight.weight, Uint128::new(3_000u128));
+            assert_eq!(weight.global_weight, Uint128::new(3_000u128));
+        });
+}
+
+#[test]
+fn test_logarithmic_weight_growth() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .with_weight_growth(WeightGrowth::Logarithmic {
+            start_time: Timestamp::from_nanos(1571797419879305533u64),
+            time_scale: Uint64::new(10u64),
+        })
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            ampwhale(1_000u128),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        // 1_000 + 1_000 * 10 * ln(2)
+        .fast_forward(10u64)
+        .query_weight(sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.weight, Uint128::new(7_931u128));
+        })
+        // 1_000 + 1_000 * 10 * ln(4), growing half as fast as with a linear growth
+        .fast_forward(20u64)
+        .query_weight(sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.weight, Uint128::new(14_862u128));
+        });
+}
+
+#[test]
+fn test_lockup_weight_growth() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    robot
+        .with_weight_growth(WeightGrowth::Lockup {
+            duration: Uint64::new(100_000_000_000u64),
+            multiplier: Decimal::percent(200),
+        })
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            ampwhale(1_000u128),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        .query_weight(sender.to_string(), |res| {
+            assert_eq!(res.unwrap().1.weight, Uint128::new(21_000u128));
+        })
+        .unbond(sender.clone(), ampwhale(1_000u128), |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::BondLocked {
+                    unlock_time: Timestamp::from_nanos(1571797519879305533u64)
+                }
+                .to_string()
+            );
+        })
+        .instant_unbond(sender.clone(), ampwhale(1_000u128), |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::BondLocked {
+                    unlock_time: Timestamp::from_nanos(1571797519879305533u64)
+                }
+                .to_string()
+            );
+        })
+        .fast_forward(90u64)
+        .unbond(sender, ampwhale(1_000u128), |res| {
+            res.unwrap();
+        });
+}
+
+#[test]
+fn test_invalid_weight_growth() {
+    for weight_growth in [
+        WeightGrowth::Capped {
+            max_multiplier: Decimal::percent(50),
+        },
+        WeightGrowth::Logarithmic {
+            start_time: Timestamp::from_nanos(1571797419879305533u64),
+            time_scale: Uint64::zero(),
+        },
+        WeightGrowth::Lockup {
+            duration: Uint64::new(100_000_000_000u64),
+            multiplier: Decimal::zero(),
+        },
+    ] {
+        TestingRobot::default()
+            .with_weight_growth(weight_growth)
+            .instantiate_err(
+                Uint64::new(1_000_000_000_000u64),
+                Decimal::one(),
+                vec![AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                }],
+                &vec![],
+                |err| {
+                    assert_eq!(
+                        err.root_cause().to_string(),
+                        ContractError::InvalidWeightGrowth {}.to_string()
+                    );
+                },
+            );
+    }
+}
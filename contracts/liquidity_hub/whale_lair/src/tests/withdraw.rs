@@ -1,9 +1,10 @@
-use cosmwasm_std::{coins, Event, Uint128};
+use cosmwasm_std::{coins, Event, Timestamp, Uint128};
 
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
-use white_whale_std::whale_lair::WithdrawableResponse;
+use white_whale_std::whale_lair::{UnbondingSchedule, WithdrawableResponse};
 
 use crate::tests::robot::TestingRobot;
+use crate::ContractError;
 
 #[test]
 fn test_withdraw_successfully() {
@@ -117,3 +118,126 @@ fn test_withdraw_unsuccessfully() {
             //assert error is NothingToWithdraw
         });
 }
+
+#[test]
+fn test_withdraw_all_successfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+
+    let asset = |denom: &str, amount: u128| Asset {
+        info: AssetInfo::NativeToken {
+            denom: denom.to_string(),
+        },
+        amount: Uint128::new(amount),
+    };
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            asset("ampWHALE", 1_000u128),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .bond(
+            sender.clone(),
+            asset("bWHALE", 1_000u128),
+            &coins(1_000u128, "bWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        .unbond(sender.clone(), asset("ampWHALE", 300u128), |res| {
+            res.unwrap();
+        })
+        .fast_forward(10u64)
+        .unbond(sender.clone(), asset("bWHALE", 200u128), |res| {
+            res.unwrap();
+        })
+        .fast_forward(500u64)
+        .unbond(sender.clone(), asset("ampWHALE", 100u128), |res| {
+            res.unwrap();
+        })
+        .query_unbonding_schedule(sender.to_string(), |res| {
+            assert_eq!(
+                res.unwrap().1.schedule,
+                vec![
+                    UnbondingSchedule {
+                        denom: "ampWHALE".to_string(),
+                        withdrawable_amount: Uint128::zero(),
+                        unbonding_amount: Uint128::new(400u128),
+                        pending_unbondings: 2u32,
+                        next_maturity: Some(Timestamp::from_nanos(1571798429879305533u64)),
+                        final_maturity: Some(Timestamp::from_nanos(1571798939879305533u64)),
+                    },
+                    UnbondingSchedule {
+                        denom: "bWHALE".to_string(),
+                        withdrawable_amount: Uint128::zero(),
+                        unbonding_amount: Uint128::new(200u128),
+                        pending_unbondings: 1u32,
+                        next_maturity: Some(Timestamp::from_nanos(1571798439879305533u64)),
+                        final_maturity: Some(Timestamp::from_nanos(1571798439879305533u64)),
+                    },
+                ]
+            );
+        })
+        .withdraw_all(sender.clone(), None, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::NothingToWithdraw {}.to_string()
+            );
+        })
+        .fast_forward(600u64)
+        // only one unbonding entry is processed
+        .withdraw_all(sender.clone(), Some(1u8), |res| {
+            let events = res.unwrap().events;
+            let transfer_event = events.last().unwrap().clone();
+            assert_eq!(
+                transfer_event,
+                Event::new("transfer").add_attributes(vec![
+                    ("recipient", sender.to_string()),
+                    ("sender", "contract1".to_string()),
+                    ("amount", "300ampWHALE".to_string()),
+                ])
+            );
+        })
+        .query_unbonding_schedule(sender.to_string(), |res| {
+            let schedule = res.unwrap().1.schedule;
+            assert_eq!(schedule[0].withdrawable_amount, Uint128::zero());
+            assert_eq!(schedule[0].unbonding_amount, Uint128::new(100u128));
+            assert_eq!(schedule[1].withdrawable_amount, Uint128::new(200u128));
+            assert_eq!(schedule[1].unbonding_amount, Uint128::zero());
+            assert_eq!(schedule[1].next_maturity, None);
+        })
+        .withdraw_all(sender.clone(), None, |res| {
+            let events = res.unwrap().events;
+            let transfer_event = events.last().unwrap().clone();
+            assert_eq!(
+                transfer_event,
+                Event::new("transfer").add_attributes(vec![
+                    ("recipient", sender.to_string()),
+                    ("sender", "contract1".to_string()),
+                    ("amount", "200bWHALE".to_string()),
+                ])
+            );
+        })
+        .fast_forward(500u64)
+        .withdraw_all(sender.clone(), None, |res| {
+            res.unwrap();
+        })
+        .withdraw_all(sender.clone(), None, |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::NothingToWithdraw {}.to_string()
+            );
+        })
+        .query_unbonding_schedule(sender.to_string(), |res| {
+            let schedule = res.unwrap().1.schedule;
+            assert!(schedule
+                .iter()
+                .all(|s| s.withdrawable_amount.is_zero() && s.final_maturity.is_none()));
+        });
+}
@@ -14,12 +14,32 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     CreateEpoch {},
+    /// Creates all the epochs that were missed since the current one, up to `max` of them, which
+    /// can't be more than 10. The hooks are notified about each created epoch, in order.
+    CreateEpochs {
+        max: u32,
+    },
+    /// Adds a hook that is notified whenever a new epoch is created. If a gas limit is provided,
+    /// the hook can't consume more gas than that when processing an epoch.
     AddHook {
         contract_addr: String,
+        gas_limit: Option<u64>,
     },
     RemoveHook {
         contract_addr: String,
     },
+    /// Updates the gas limit of the given hook. If no gas limit is provided, the hook is called
+    /// without one.
+    UpdateHookGasLimit {
+        contract_addr: String,
+        gas_limit: Option<u64>,
+    },
+    /// Delivers the given epoch again to a hook that failed to process it. Can only be called by
+    /// the owner or the hook itself.
+    RetryHook {
+        contract_addr: String,
+        epoch_id: u64,
+    },
     UpdateConfig {
         owner: Option<String>,
         epoch_config: Option<EpochConfig>,
@@ -40,6 +60,15 @@ pub enum QueryMsg {
     /// Returns the epoch with the given id.
     #[returns(EpochResponse)]
     Epoch { id: u64 },
+
+    /// Returns the epoch the given timestamp falls in, computed from the epoch config. The epoch
+    /// might not have been created yet if the timestamp is in the future.
+    #[returns(EpochResponse)]
+    EpochAt { timestamp: Timestamp },
+
+    /// Returns the gas limit of the given hook and the epochs it failed to process.
+    #[returns(HookStatusResponse)]
+    HookStatus { contract_addr: String },
 }
 
 #[cw_serde]
@@ -70,6 +99,22 @@ pub struct EpochResponse {
     pub epoch: EpochV2,
 }
 
+#[cw_serde]
+pub struct HookStatusResponse {
+    pub contract_addr: Addr,
+    /// The gas limit the hook is called with, if any.
+    pub gas_limit: Option<u64>,
+    /// The epochs the hook failed to process, which can be retried.
+    pub failed_epochs: Vec<FailedHookDelivery>,
+}
+
+#[cw_serde]
+pub struct FailedHookDelivery {
+    pub epoch_id: u64,
+    /// The error returned by the hook.
+    pub error: String,
+}
+
 #[cw_serde]
 pub struct ClaimableEpochsResponse {
     pub epochs: Vec<EpochV2>,
@@ -63,9 +63,11 @@ pub enum FactoryType {
         start_after: Option<Vec<u8>>,
         limit: Option<u32>,
     },
-    /// Pool Factory
+    /// Pool Factory. Pagination works as in the pool factory's pairs query, with
+    /// `start_after_pool_identifier` telling apart the pools of the `start_after` asset couple.
     Pool {
         start_after: Option<[AssetInfo; 2]>,
+        start_after_pool_identifier: Option<String>,
         limit: Option<u32>,
     },
 }
@@ -1,4 +1,4 @@
-use crate::epoch_manager::epoch_manager::EpochConfig;
+use crate::epoch_manager::hooks::EpochChangedHookMsg;
 use crate::pool_network::asset::{Asset, AssetInfo};
 use crate::whale_lair::GlobalIndex;
 use cosmwasm_schema::{cw_serde, QueryResponses};
@@ -11,8 +11,8 @@ pub struct Config {
     pub owner: Addr,
     pub bonding_contract_addr: Addr,
     pub fee_collector_addr: Addr,
+    pub epoch_manager_addr: Addr,
     pub grace_period: Uint64,
-    pub epoch_config: EpochConfig,
     pub distribution_asset: AssetInfo,
 }
 
@@ -49,11 +49,11 @@ pub struct InstantiateMsg {
     pub bonding_contract_addr: String,
     /// Fee collector address.
     pub fee_collector_addr: String,
+    /// Address of the epoch manager contract, which triggers the creation of new epochs.
+    pub epoch_manager_addr: String,
     /// The duration of the grace period in epochs, i.e. how many expired epochs can be claimed
     /// back in time after new epochs have been created.
     pub grace_period: Uint64,
-    /// Configuration for the epoch.
-    pub epoch_config: EpochConfig,
     /// The asset that is going to be distributed by the contracdt.
     pub distribution_asset: AssetInfo,
 }
@@ -61,21 +61,25 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a new epoch, forwarding available tokens from epochs that are past the grace period.
-    /// Can only be executed by the fee collector.
-    NewEpoch {},
+    /// Can only be executed by the epoch manager.
+    EpochChangedHook(EpochChangedHookMsg),
 
     /// Claims tokens from the current epoch and all epochs that are in the grace period.
-    /// Sends all tokens to the sender.
-    Claim {},
+    /// If epoch_ids are provided, only those epochs are claimed. Sends all tokens to the receiver,
+    /// which defaults to the sender.
+    Claim {
+        epoch_ids: Option<Vec<Uint64>>,
+        receiver: Option<String>,
+    },
 
     /// Updates the [Config] of the contract.
     UpdateConfig {
         owner: Option<String>,
         bonding_contract_addr: Option<String>,
         fee_collector_addr: Option<String>,
+        epoch_manager_addr: Option<String>,
         grace_period: Option<Uint64>,
         distribution_asset: Option<AssetInfo>,
-        epoch_config: Option<EpochConfig>,
     },
 }
 
@@ -101,6 +105,10 @@ pub enum QueryMsg {
     /// Returns the [Epoch]s that can be claimed by an address.
     #[returns(ClaimableEpochsResponse)]
     Claimable { address: String },
+
+    /// Returns the ids of the epochs within the grace period that have been claimed by an address.
+    #[returns(ClaimedEpochsResponse)]
+    ClaimedEpochs { address: String },
 }
 
 #[cw_serde]
@@ -114,4 +122,13 @@ pub struct ClaimableEpochsResponse {
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct ClaimedEpochsResponse {
+    pub epoch_ids: Vec<Uint64>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    /// The epoch manager driving the epochs. Required when migrating from a version prior to
+    /// v0.11.0, as epochs were created by the contract itself until then.
+    pub epoch_manager_addr: Option<String>,
+}
@@ -3,6 +3,7 @@ pub mod fee;
 pub mod fee_collector;
 pub mod fee_distributor;
 pub mod migrate_guards;
+pub mod pause;
 pub mod pool_network;
 pub mod traits;
 pub mod vault_network;
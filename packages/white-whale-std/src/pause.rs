@@ -0,0 +1,40 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Env, Timestamp};
+
+/// Why, when and by whom contracts were paused through their factory.
+#[cw_serde]
+pub struct PauseInfo {
+    /// The address that paused the contracts, i.e. the owner or the guardian of the factory.
+    pub paused_by: Addr,
+    pub reason: String,
+    pub paused_at: Timestamp,
+}
+
+impl PauseInfo {
+    pub fn new(paused_by: Addr, reason: String, env: &Env) -> Self {
+        Self {
+            paused_by,
+            reason,
+            paused_at: env.block.time,
+        }
+    }
+}
+
+/// Response for the `PauseStatus` query of the factories.
+#[cw_serde]
+pub struct PauseStatusResponse {
+    /// The guardian of the factory, which can pause the contracts but never unpause them.
+    pub guardian: Option<Addr>,
+    /// Set if all the contracts of the factory were paused at once.
+    pub all_paused: Option<PauseInfo>,
+    /// Set if the contract given in the query was paused on its own.
+    pub contract_paused: Option<PauseInfo>,
+}
+
+impl PauseStatusResponse {
+    /// Whether the contract given in the query is paused, either on its own or along with all the
+    /// contracts of the factory.
+    pub fn is_paused(&self) -> bool {
+        self.all_paused.is_some() || self.contract_paused.is_some()
+    }
+}
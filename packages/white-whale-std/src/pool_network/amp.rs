@@ -0,0 +1,112 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{StdError, StdResult};
+
+/// Minimum amplification coefficient.
+pub const MIN_AMP: u64 = 1;
+/// Maximum amplification coefficient.
+pub const MAX_AMP: u64 = 1_000_000;
+/// Minimum number of blocks an amplification coefficient change must take place over.
+pub const MIN_RAMP_BLOCKS: u64 = 10000;
+/// Maximum factor the amplification coefficient can be changed by in a single command.
+pub const MAX_AMP_CHANGE: u64 = 10;
+
+/// Ramps the amplification coefficient of a stableswap pool linearly from its current value to
+/// `future_a`, reached at `future_block`.
+#[cw_serde]
+pub struct RampAmp {
+    pub future_a: u64,
+    pub future_block: u64,
+}
+
+/// Updates the amplification coefficient of a stableswap pair.
+#[cw_serde]
+pub enum AmpFactorUpdate {
+    /// Starts ramping the amplification coefficient, see [RampAmp].
+    Ramp(RampAmp),
+    /// Stops an ongoing ramp, fixing the amplification coefficient at its current value.
+    StopRamp {},
+}
+
+/// An ongoing (or finished) ramp of the amplification coefficient of a stableswap pool.
+#[cw_serde]
+pub struct AmpRamp {
+    pub initial_amp: u64,
+    pub future_amp: u64,
+    pub initial_amp_block: u64,
+    pub future_amp_block: u64,
+}
+
+impl AmpRamp {
+    /// Computes the amplification coefficient at the given block height.
+    pub fn amp_at(&self, block_height: u64) -> Option<u64> {
+        compute_amp_factor(
+            self.initial_amp,
+            self.future_amp,
+            block_height,
+            self.initial_amp_block,
+            self.future_amp_block,
+        )
+    }
+}
+
+/// Computes the amplification coefficient (A).
+///
+/// The amplification coefficient linearly moves from `initial_amp_factor` to `target_amp_factor`
+/// between `start_ramp` and `stop_ramp`. Once `stop_ramp` is reached (or when it is zero), the
+/// target amplification coefficient is returned.
+pub fn compute_amp_factor(
+    initial_amp_factor: u64,
+    target_amp_factor: u64,
+    current: u64,
+    start_ramp: u64,
+    stop_ramp: u64,
+) -> Option<u64> {
+    if current < stop_ramp {
+        let time_range = u128::from(stop_ramp.checked_sub(start_ramp)?);
+        let time_delta = u128::from(current.checked_sub(start_ramp)?);
+
+        // Compute amp factor based on ramp time
+        if target_amp_factor >= initial_amp_factor {
+            // Ramp up
+            let amp_range = target_amp_factor.checked_sub(initial_amp_factor)?;
+            let amp_delta = u128::from(amp_range)
+                .checked_mul(time_delta)?
+                .checked_div(time_range)?;
+            initial_amp_factor.checked_add(u64::try_from(amp_delta).ok()?)
+        } else {
+            // Ramp down
+            let amp_range = initial_amp_factor.checked_sub(target_amp_factor)?;
+            let amp_delta = u128::from(amp_range)
+                .checked_mul(time_delta)?
+                .checked_div(time_range)?;
+            initial_amp_factor.checked_sub(u64::try_from(amp_delta).ok()?)
+        }
+    } else {
+        // when stop_ramp == 0 or current >= stop_ramp
+        Some(target_amp_factor)
+    }
+}
+
+/// Validates a ramp of the amplification coefficient from `current_amp`, starting at `block_height`.
+pub fn validate_amp_ramp(current_amp: u64, ramp: &RampAmp, block_height: u64) -> StdResult<()> {
+    if ramp.future_a < MIN_AMP {
+        return Err(StdError::generic_err(format!(
+            "New amp must be over {MIN_AMP}"
+        )));
+    }
+    if ramp.future_a > MAX_AMP {
+        return Err(StdError::generic_err(format!(
+            "New amp must be under {MAX_AMP}"
+        )));
+    }
+    if (ramp.future_a > current_amp) && (ramp.future_a > current_amp * MAX_AMP_CHANGE)
+        || (ramp.future_a < current_amp) && (ramp.future_a * MAX_AMP_CHANGE < current_amp)
+    {
+        return Err(StdError::generic_err("Amp change over max"));
+    }
+    if ramp.future_block < block_height + MIN_RAMP_BLOCKS {
+        return Err(StdError::generic_err("Amp change ramp time under minimum"));
+    }
+
+    Ok(())
+}
@@ -1,7 +1,12 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 
-use crate::pool_network::asset::{AssetInfo, PairInfo, PairType, TrioInfo};
-use crate::pool_network::pair::{FeatureToggle, PoolFee};
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
+use crate::pause::PauseStatusResponse;
+use crate::pool_network::amp::AmpFactorUpdate;
+use crate::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType, TrioInfo};
+use crate::pool_network::pair::{DynamicFeeUpdate, FeatureToggle, PoolFee};
+use crate::pool_network::pool_stats::PoolStats;
 use crate::pool_network::trio::{
     FeatureToggle as TrioFeatureToggle, PoolFee as TrioPoolFee, RampAmp,
 };
@@ -14,17 +19,85 @@ pub struct InstantiateMsg {
     pub trio_code_id: u64,
     pub token_code_id: u64,
     pub fee_collector_addr: String,
+    /// The fee charged to non-owner accounts for creating a pair or trio. Only the owner can create
+    /// pools while it is not set.
+    pub pool_creation_fee: Option<PoolCreationFee>,
+    /// The pool fees non-owner accounts are allowed to create pools with. Only the owner can create
+    /// pools while it is not set or empty.
+    pub allowed_fee_tiers: Option<Vec<PoolFee>>,
+}
+
+/// The fee charged for creating a pool, and what happens to it once collected.
+#[cw_serde]
+pub struct PoolCreationFee {
+    /// The asset and amount to be paid.
+    pub fee: Asset,
+    /// If true, the fee is burned. Otherwise, it is sent to the fee collector.
+    pub burn: bool,
+}
+
+/// Swap fee discounts granted to some traders by the pairs created by the factory. A discount
+/// reduces both the swap and protocol fees, e.g. a discount of 0.5 halves them.
+#[cw_serde]
+pub struct FeeDiscounts {
+    /// The whale lair contract, used to look up the bonding weight of the traders.
+    pub whale_lair_addr: String,
+    /// The discounts granted by bonding weight. Traders get the discount of the highest tier
+    /// their weight reaches.
+    pub weight_tiers: Vec<FeeDiscountTier>,
+    /// The discount granted to the addresses in the fee discount whitelist.
+    pub whitelist_discount: Decimal,
+    /// The routers allowed to swap on behalf of traders. Swaps routed through them get the
+    /// discount of the trader they pass along instead of their own.
+    pub trusted_routers: Vec<String>,
+}
+
+/// A fee discount granted to the traders with at least `min_weight` bonded in the whale lair.
+#[cw_serde]
+pub struct FeeDiscountTier {
+    pub min_weight: Uint128,
+    pub discount: Decimal,
+}
+
+/// A pair or trio created by the factory, used to paginate through both of them at once.
+#[cw_serde]
+pub enum PoolCursor {
+    Pair {
+        asset_infos: [AssetInfo; 2],
+        pool_identifier: String,
+    },
+    Trio {
+        asset_infos: [AssetInfo; 3],
+    },
+}
+
+/// Metadata of an asset, as registered in the factory's asset registry.
+#[cw_serde]
+pub struct AssetMetadata {
+    /// The ticker of the asset, e.g. `WHALE`. Used to label the pools created by the factory.
+    pub symbol: String,
+    /// The human readable name of the asset, e.g. `White Whale`.
+    pub display_name: String,
+    pub decimals: u8,
+    pub logo_uri: Option<String>,
+    /// The IBC path the asset was transferred through, e.g. `transfer/channel-0`, if it is an IBC
+    /// asset.
+    pub ibc_origin_path: Option<String>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Updates contract's config, i.e. relevant code_ids, fee_collector address and owner
+    /// Updates contract's config, i.e. relevant code_ids, fee_collector address, owner, the pool
+    /// creation fee and the allowed fee tiers. Passing an empty list of fee tiers makes pool
+    /// creation owner-only.
     UpdateConfig {
         owner: Option<String>,
         fee_collector_addr: Option<String>,
         token_code_id: Option<u64>,
         pair_code_id: Option<u64>,
         trio_code_id: Option<u64>,
+        pool_creation_fee: Option<PoolCreationFee>,
+        allowed_fee_tiers: Option<Vec<PoolFee>>,
     },
     #[cfg(not(feature = "osmosis"))]
     /// Updates a pair config
@@ -34,6 +107,8 @@ pub enum ExecuteMsg {
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
+        amp_factor: Option<AmpFactorUpdate>,
+        dynamic_fee: Option<DynamicFeeUpdate>,
     },
     #[cfg(feature = "osmosis")]
     /// Updates a pair config
@@ -43,6 +118,8 @@ pub enum ExecuteMsg {
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
+        amp_factor: Option<AmpFactorUpdate>,
+        dynamic_fee: Option<DynamicFeeUpdate>,
         cosmwasm_pool_interface: Option<String>,
     },
     /// Updates a trio config
@@ -54,7 +131,10 @@ pub enum ExecuteMsg {
         feature_toggle: Option<TrioFeatureToggle>,
         amp_factor: Option<RampAmp>,
     },
-    /// Instantiates pair contract
+    /// Instantiates pair contract. Can be called by anyone, as long as the pool creation fee is
+    /// paid and the pool fees match one of the allowed fee tiers. The owner is exempt from both.
+    /// The pair gets a new pool identifier, returned in the `pool_identifier` attribute. There
+    /// can't be two pools with the same type and fees for the same assets.
     CreatePair {
         /// Asset infos
         asset_infos: [AssetInfo; 2],
@@ -65,7 +145,8 @@ pub enum ExecuteMsg {
         /// use a cw20 token instead.
         token_factory_lp: bool,
     },
-    /// Instantiates pair contract
+    /// Instantiates trio contract. Can be called by anyone, as long as the pool creation fee is
+    /// paid and the pool fees match one of the allowed fee tiers. The owner is exempt from both.
     CreateTrio {
         /// Asset infos
         asset_infos: [AssetInfo; 3],
@@ -87,10 +168,96 @@ pub enum ExecuteMsg {
         contract: String,
         code_id: Option<u64>,
     },
-    /// Removes pair contract given asset infos
-    RemovePair { asset_infos: [AssetInfo; 2] },
+    /// Removes pair contract given asset infos. If no pool identifier is given, the default pool of
+    /// the asset couple is removed.
+    RemovePair {
+        asset_infos: [AssetInfo; 2],
+        pool_identifier: Option<String>,
+    },
     /// Removes trio contract given asset infos
     RemoveTrio { asset_infos: [AssetInfo; 3] },
+    /// Sets the swap fee discounts granted by the pairs, or removes them if none is given.
+    UpdateFeeDiscounts { fee_discounts: Option<FeeDiscounts> },
+    /// Adds and removes addresses from the fee discount whitelist, e.g. the fee collector or
+    /// aggregator partners.
+    UpdateFeeDiscountWhitelist {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Migrates a page of the pairs created by the factory to a given code_id, or to the pair
+    /// code_id in the config if not specified. Pagination works as in [QueryMsg::Pairs].
+    MigratePairs {
+        code_id: Option<u64>,
+        start_after: Option<[AssetInfo; 2]>,
+        start_after_pool_identifier: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Updates the feature toggle of the pairs matching the given filter.
+    UpdatePairsFeatureToggle {
+        feature_toggle: FeatureToggle,
+        filter: PairsFilter,
+    },
+    /// Emergency switch, disables swaps, deposits and withdrawals on a page of the pairs and trios
+    /// created by the factory, pairs first. Can be executed by the guardian as well. The last pool
+    /// of the page is set as the response data, to resume from with `start_after`. Ten pools are
+    /// paused by default if no limit is specified, and at most 30 at once.
+    PauseAll {
+        reason: String,
+        start_after: Option<PoolCursor>,
+        limit: Option<u32>,
+    },
+    /// Restores the feature toggle of a page of the pairs and trios disabled by
+    /// [ExecuteMsg::PauseAll], sorted by address, with the same limits. Pools paused on their own
+    /// or disabled before stay as they are. Everything is unpaused once all of them are restored.
+    UnpauseAll {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Disables swaps, deposits and withdrawals on the given pair. Can be executed by the guardian
+    /// as well. The pair is unpaused by updating its feature toggle.
+    PausePair { pair_addr: String, reason: String },
+    /// Disables swaps, deposits and withdrawals on the given trio. Can be executed by the guardian
+    /// as well. The trio is unpaused by updating its feature toggle.
+    PauseTrio { trio_addr: String, reason: String },
+    /// Sets the guardian, which can pause pairs and trios but never unpause them nor change their
+    /// config. If none is given, the guardian is removed.
+    UpdateGuardian { guardian: Option<String> },
+    /// Registers the metadata of the given asset, overwriting the existing entry if any. The
+    /// decimals of native tokens are registered as with [ExecuteMsg::AddNativeTokenDecimals].
+    RegisterAssetMetadata {
+        asset_info: AssetInfo,
+        metadata: AssetMetadata,
+    },
+    /// Removes the metadata of the given asset from the asset registry.
+    RemoveAssetMetadata { asset_info: AssetInfo },
+    /// Sets the epoch manager the pairs and trios bucket their swap statistics by. If none is
+    /// given, the epoch manager is removed and the pools only keep their all time statistics.
+    UpdateEpochManager { epoch_manager_addr: Option<String> },
+}
+
+/// Selects the pairs a batch operation is applied to. The pair type and asset filters are applied
+/// on top of a page of the pairs created by the factory, paginated as in [QueryMsg::Pairs].
+#[cw_serde]
+#[derive(Default)]
+pub struct PairsFilter {
+    pub start_after: Option<[AssetInfo; 2]>,
+    pub start_after_pool_identifier: Option<String>,
+    pub limit: Option<u32>,
+    /// If set, only pairs of the given type are selected. The amp of stableswap pairs is ignored.
+    pub pair_type: Option<PairType>,
+    /// If set, only pairs containing the given asset are selected.
+    pub asset_info: Option<AssetInfo>,
+}
+
+impl PairsFilter {
+    /// Whether the given pair matches the pair type and asset filters.
+    pub fn matches(&self, pair_info: &PairInfo) -> bool {
+        self.pair_type.as_ref().map_or(true, |pair_type| {
+            std::mem::discriminant(pair_type) == std::mem::discriminant(&pair_info.pair_type)
+        }) && self.asset_info.as_ref().map_or(true, |asset_info| {
+            pair_info.asset_infos.contains(asset_info)
+        })
+    }
 }
 
 #[cw_serde]
@@ -99,17 +266,30 @@ pub enum QueryMsg {
     /// Retrieves the configuration of the factory.
     #[returns(ConfigResponse)]
     Config {},
-    /// Retrieves the info for the pair with the given asset_infos.
+    /// Retrieves the info for the pair with the given asset_infos. As there can be multiple pools
+    /// for the same assets, a pool identifier can be given to pick one of them. Pool identifiers are
+    /// assigned by the factory when the pool is created and never change. If not specified, the
+    /// default pool of the asset couple is returned, i.e. the first one created for it.
     #[returns(PairInfo)]
-    Pair { asset_infos: [AssetInfo; 2] },
+    Pair {
+        asset_infos: [AssetInfo; 2],
+        pool_identifier: Option<String>,
+    },
     /// Retrieves the pairs created by the factory. This query has pagination enabled, querying ten
     /// items by default if not specified otherwise. The max amount of items that can be queried at
-    /// once is 30. `start_after` is the last asset_info of a page.
+    /// once is 30. `start_after` is the last asset_info of a page and `start_after_pool_identifier`
+    /// its pool identifier, see [PairsResponse]. If only `start_after` is given, the page starts
+    /// after all the pools of that asset couple.
     #[returns(PairsResponse)]
     Pairs {
         start_after: Option<[AssetInfo; 2]>,
+        start_after_pool_identifier: Option<String>,
         limit: Option<u32>,
     },
+    /// Retrieves all the pools created by the factory for the given asset couple, along with their
+    /// pool identifiers.
+    #[returns(PairsByAssetsResponse)]
+    PairsByAssets { asset_infos: [AssetInfo; 2] },
     /// Retrieves the info for the trio with the given asset_infos.
     #[returns(TrioInfo)]
     Trio { asset_infos: [AssetInfo; 3] },
@@ -124,6 +304,63 @@ pub enum QueryMsg {
     /// Retrieves the decimals for the given native or ibc denom.
     #[returns(NativeTokenDecimalsResponse)]
     NativeTokenDecimals { denom: String },
+    /// Retrieves the swap fee discounts granted by the pairs, if any.
+    #[returns(Option<FeeDiscounts>)]
+    FeeDiscounts {},
+    /// Retrieves the addresses in the fee discount whitelist. This query has pagination enabled,
+    /// querying ten items by default if not specified otherwise. The max amount of items that can
+    /// be queried at once is 30.
+    #[returns(FeeDiscountWhitelistResponse)]
+    FeeDiscountWhitelist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the swap fee discount granted to the given address. Queried by the pairs on every
+    /// swap. If the address is a trusted router and a trader is given, the trader's discount is
+    /// retrieved instead.
+    #[returns(FeeDiscountResponse)]
+    FeeDiscount {
+        address: String,
+        trader: Option<String>,
+    },
+    /// Retrieves the guardian and whether pairs and trios were paused through the factory. If a
+    /// contract is given, whether it was paused on its own is retrieved as well.
+    #[returns(PauseStatusResponse)]
+    PauseStatus { contract: Option<String> },
+    /// Retrieves the metadata registered for the given asset, if any.
+    #[returns(Option<AssetMetadata>)]
+    AssetMetadata { asset_info: AssetInfo },
+    /// Retrieves the metadata of the assets in the asset registry. This query has pagination
+    /// enabled, querying ten items by default if not specified otherwise. The max amount of items
+    /// that can be queried at once is 30.
+    #[returns(AssetsMetadataResponse)]
+    AssetsMetadata {
+        start_after: Option<AssetInfo>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the current epoch of the epoch manager, if any is set. Queried by the pairs and
+    /// trios to bucket their swap statistics, which cache the epoch until it ends.
+    #[returns(CurrentEpochResponse)]
+    CurrentEpoch {},
+    /// Retrieves the reserves, total share and swap statistics of the pairs created by the
+    /// factory, since their inception or for the given epoch. Pagination works as in
+    /// [QueryMsg::Pairs].
+    #[returns(PairsStatsResponse)]
+    PairsStats {
+        start_after: Option<[AssetInfo; 2]>,
+        start_after_pool_identifier: Option<String>,
+        limit: Option<u32>,
+        epoch_id: Option<u64>,
+    },
+    /// Retrieves the reserves, total share and swap statistics of the trios created by the
+    /// factory, since their inception or for the given epoch. Pagination works as in
+    /// [QueryMsg::Trios].
+    #[returns(TriosStatsResponse)]
+    TriosStats {
+        start_after: Option<[AssetInfo; 3]>,
+        limit: Option<u32>,
+        epoch_id: Option<u64>,
+    },
 }
 
 // We define a custom struct for each query response
@@ -134,6 +371,8 @@ pub struct ConfigResponse {
     pub pair_code_id: u64,
     pub trio_code_id: u64,
     pub token_code_id: u64,
+    pub pool_creation_fee: Option<PoolCreationFee>,
+    pub allowed_fee_tiers: Vec<PoolFee>,
 }
 
 /// We currently take no arguments for migrations
@@ -144,6 +383,25 @@ pub struct MigrateMsg {}
 #[cw_serde]
 pub struct PairsResponse {
     pub pairs: Vec<PairInfo>,
+    /// The pool identifier of the last pair of the page, to resume the pagination from.
+    pub last_pool_identifier: Option<String>,
+}
+
+#[cw_serde]
+pub struct PairsByAssetsResponse {
+    pub pairs: Vec<PoolInfo>,
+    /// The identifier of the pool used when none is given, if any.
+    pub default_pool_identifier: Option<String>,
+}
+
+/// A pair pool along with the identifier that tells it apart from other pools for the same assets.
+#[cw_serde]
+pub struct PoolInfo {
+    pub pool_identifier: String,
+    pub pair_info: PairInfo,
+    /// The fees of the pool. Pools created before multiple pools per asset couple were supported
+    /// have none until their fees are updated through the factory.
+    pub pool_fees: Option<PoolFee>,
 }
 
 #[cw_serde]
@@ -155,3 +413,64 @@ pub struct TriosResponse {
 pub struct NativeTokenDecimalsResponse {
     pub decimals: u8,
 }
+
+#[cw_serde]
+pub struct FeeDiscountWhitelistResponse {
+    pub addresses: Vec<String>,
+}
+
+#[cw_serde]
+pub struct FeeDiscountResponse {
+    pub discount: Decimal,
+}
+
+/// An asset along with the metadata registered for it.
+#[cw_serde]
+pub struct AssetMetadataInfo {
+    pub asset_info: AssetInfo,
+    pub metadata: AssetMetadata,
+}
+
+#[cw_serde]
+pub struct AssetsMetadataResponse {
+    pub assets: Vec<AssetMetadataInfo>,
+}
+
+#[cw_serde]
+pub struct CurrentEpochResponse {
+    pub epoch_id: Option<u64>,
+    /// When the current epoch ends, if any.
+    pub end_time: Option<Timestamp>,
+}
+
+/// The reserves, total share and swap statistics of a pair. If the pair fails to answer, it
+/// has no assets, a zero total share and empty statistics.
+#[cw_serde]
+pub struct PairStatsInfo {
+    pub pair_info: PairInfo,
+    pub assets: Vec<Asset>,
+    pub total_share: Uint128,
+    pub stats: PoolStats,
+}
+
+#[cw_serde]
+pub struct PairsStatsResponse {
+    pub pairs: Vec<PairStatsInfo>,
+    /// The pool identifier of the last pair of the page, to resume the pagination from.
+    pub last_pool_identifier: Option<String>,
+}
+
+/// The reserves, total share and swap statistics of a trio. If the trio fails to answer, it
+/// has no assets, a zero total share and empty statistics.
+#[cw_serde]
+pub struct TrioStatsInfo {
+    pub trio_info: TrioInfo,
+    pub assets: Vec<Asset>,
+    pub total_share: Uint128,
+    pub stats: PoolStats,
+}
+
+#[cw_serde]
+pub struct TriosStatsResponse {
+    pub trios: Vec<TrioStatsInfo>,
+}
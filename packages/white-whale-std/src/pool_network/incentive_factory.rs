@@ -1,6 +1,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
 
+use crate::pause::PauseStatusResponse;
 use crate::pool_network::asset::{Asset, AssetInfo};
 
 #[cw_serde]
@@ -77,6 +78,26 @@ pub enum ExecuteMsg {
         /// The new code ID to migrate the incentive contract to.
         code_id: u64,
     },
+    /// Pauses incentive contracts, preventing flows and positions from being opened or expanded.
+    /// Closing positions, withdrawing and claiming remain possible. Can be executed by the guardian
+    /// as well.
+    PauseIncentives {
+        /// The address of the incentive contract. If unspecified, will pause all incentive contracts.
+        incentive_address: Option<String>,
+        /// Why the incentive contracts are paused.
+        reason: String,
+    },
+    /// Unpauses incentive contracts.
+    UnpauseIncentives {
+        /// The address of the incentive contract. If unspecified, will unpause all incentive
+        /// contracts.
+        incentive_address: Option<String>,
+    },
+    /// Sets the guardian, which can pause incentive contracts but never unpause them nor change the
+    /// config.
+    ///
+    /// If unspecified, the guardian is removed.
+    UpdateGuardian { guardian: Option<String> },
 }
 
 #[cw_serde]
@@ -101,6 +122,12 @@ pub enum QueryMsg {
         /// If unspecified, will default to a value specified by the contract.
         limit: Option<u32>,
     },
+    /// Retrieves the guardian and whether incentive contracts are paused.
+    #[returns(PauseStatusResponse)]
+    PauseStatus {
+        /// An optional incentive contract to check whether it was paused on its own.
+        contract: Option<String>,
+    },
 }
 
 #[cw_serde]
@@ -1,19 +1,28 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::panic;
 
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    from_json, to_json_binary, Coin, ContractInfoResponse, ContractResult, Empty, OwnedDeps,
-    Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, WasmQuery,
+    from_json, to_json_binary, Addr, Coin, ContractInfoResponse, ContractResult, Decimal, Empty,
+    OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Timestamp, Uint128,
+    WasmQuery,
 };
 use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
+use crate::fee::Fee;
 use crate::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType, TrioInfo};
-use crate::pool_network::factory::{NativeTokenDecimalsResponse, QueryMsg as FactoryQueryMsg};
-use crate::pool_network::pair::{PoolResponse as PairPoolResponse, QueryMsg as PairQueryMsg};
+use crate::pool_network::factory::{
+    CurrentEpochResponse, FeeDiscountResponse, NativeTokenDecimalsResponse,
+    QueryMsg as FactoryQueryMsg,
+};
+use crate::pool_network::pair::{
+    Config as PairConfig, FeatureToggle, PoolFee, PoolResponse as PairPoolResponse,
+    QueryMsg as PairQueryMsg,
+};
 use crate::pool_network::pair::{ReverseSimulationResponse, SimulationResponse};
+use crate::pool_network::pool_stats::PoolStats;
 use crate::pool_network::trio;
 use crate::pool_network::trio::{PoolResponse as TrioPoolResponse, QueryMsg as TrioQueryMsg};
 
@@ -92,6 +101,11 @@ pub fn balances_to_map(
 pub struct PoolFactoryQuerier {
     pairs: HashMap<String, PairInfo>,
     native_token_decimals: HashMap<String, u8>,
+    fee_discounts: HashMap<String, Decimal>,
+    current_epoch: Option<(u64, Timestamp)>,
+    feature_toggles: HashMap<String, FeatureToggle>,
+    trios: HashMap<String, TrioInfo>,
+    unresponsive_pools: HashSet<String>,
 }
 
 impl PoolFactoryQuerier {
@@ -99,6 +113,11 @@ impl PoolFactoryQuerier {
         PoolFactoryQuerier {
             pairs: pairs_to_map(pairs),
             native_token_decimals: native_token_decimals_to_map(native_token_decimals),
+            fee_discounts: HashMap::new(),
+            current_epoch: None,
+            feature_toggles: HashMap::new(),
+            trios: HashMap::new(),
+            unresponsive_pools: HashSet::new(),
         }
     }
 }
@@ -157,8 +176,18 @@ impl Querier for WasmMockTrioQuerier {
 impl WasmMockQuerier {
     pub fn handle_query(&self, request: &QueryRequest<Empty>) -> QuerierResult {
         match &request {
+            QueryRequest::Wasm(WasmQuery::Smart { contract_addr, .. })
+                if self
+                    .pool_factory_querier
+                    .unresponsive_pools
+                    .contains(contract_addr) =>
+            {
+                SystemResult::Err(SystemError::NoSuchContract {
+                    addr: contract_addr.to_string(),
+                })
+            }
             QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => match from_json(msg) {
-                Ok(FactoryQueryMsg::Pair { asset_infos }) => {
+                Ok(FactoryQueryMsg::Pair { asset_infos, .. }) => {
                     let key = [asset_infos[0].to_string(), asset_infos[1].to_string()].join("");
                     let mut sort_key: Vec<char> = key.chars().collect();
                     sort_key.sort_by(|a, b| b.cmp(a));
@@ -174,6 +203,32 @@ impl WasmMockQuerier {
                         }),
                     }
                 }
+                Ok(FactoryQueryMsg::FeeDiscount { address, .. }) => {
+                    SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&FeeDiscountResponse {
+                            discount: self
+                                .pool_factory_querier
+                                .fee_discounts
+                                .get(&address)
+                                .cloned()
+                                .unwrap_or_default(),
+                        })
+                        .unwrap(),
+                    ))
+                }
+                Ok(FactoryQueryMsg::CurrentEpoch {}) => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&CurrentEpochResponse {
+                        epoch_id: self
+                            .pool_factory_querier
+                            .current_epoch
+                            .map(|(epoch_id, _)| epoch_id),
+                        end_time: self
+                            .pool_factory_querier
+                            .current_epoch
+                            .map(|(_, end_time)| end_time),
+                    })
+                    .unwrap(),
+                )),
                 Ok(FactoryQueryMsg::NativeTokenDecimals { denom }) => {
                     match self.pool_factory_querier.native_token_decimals.get(&denom) {
                         Some(decimals) => SystemResult::Ok(ContractResult::Ok(
@@ -188,90 +243,171 @@ impl WasmMockQuerier {
                         }),
                     }
                 }
-                _ => {
-                    match from_json(msg) {
-                        Ok(PairQueryMsg::Pool {}) => SystemResult::Ok(ContractResult::from(
-                            to_json_binary(&PairPoolResponse {
-                                assets: vec![
-                                    Asset {
-                                        info: AssetInfo::NativeToken {
-                                            denom: "uluna".to_string(),
-                                        },
-                                        amount: Uint128::new(1_000_000_000u128),
-                                    },
-                                    Asset {
-                                        info: AssetInfo::NativeToken {
-                                            denom: "ujuno".to_string(),
-                                        },
-                                        amount: Uint128::new(1_000_000_000u128),
-                                    },
-                                ],
-                                total_share: Uint128::new(2_000_000_000u128),
-                            }),
-                        )),
-                        Ok(PairQueryMsg::Pair {}) => {
-                            SystemResult::Ok(ContractResult::from(to_json_binary(&PairInfo {
-                                asset_infos: [
-                                    AssetInfo::NativeToken {
+                _ => match from_json(msg) {
+                    Ok(PairQueryMsg::Pool {}) => {
+                        SystemResult::Ok(ContractResult::from(to_json_binary(&PairPoolResponse {
+                            assets: vec![
+                                Asset {
+                                    info: AssetInfo::NativeToken {
                                         denom: "uluna".to_string(),
                                     },
-                                    AssetInfo::NativeToken {
-                                        denom: "uluna".to_string(),
+                                    amount: Uint128::new(1_000_000_000u128),
+                                },
+                                Asset {
+                                    info: AssetInfo::NativeToken {
+                                        denom: "ujuno".to_string(),
                                     },
-                                ],
-                                asset_decimals: [6u8, 6u8],
-                                contract_addr: "pair0000".to_string(),
-                                liquidity_token: AssetInfo::Token {
-                                    contract_addr: "liquidity0000".to_string(),
+                                    amount: Uint128::new(1_000_000_000u128),
                                 },
-                                pair_type: PairType::ConstantProduct,
-                            })))
+                            ],
+                            total_share: Uint128::new(2_000_000_000u128),
+                        })))
+                    }
+                    Ok(PairQueryMsg::Pair {}) => {
+                        if let Some(pair) = self
+                            .pool_factory_querier
+                            .pairs
+                            .values()
+                            .find(|pair| &pair.contract_addr == contract_addr)
+                        {
+                            return SystemResult::Ok(ContractResult::from(to_json_binary(pair)));
                         }
-                        Ok(PairQueryMsg::Simulation { offer_asset }) => {
-                            #[cfg(not(feature = "osmosis"))]
-                            let data = SimulationResponse {
-                                return_amount: offer_asset.amount,
-                                swap_fee_amount: Uint128::zero(),
-                                spread_amount: Uint128::zero(),
-                                protocol_fee_amount: Uint128::zero(),
-                                burn_fee_amount: Uint128::zero(),
-                            };
 
-                            #[cfg(feature = "osmosis")]
-                            let data = SimulationResponse {
-                                return_amount: offer_asset.amount,
-                                swap_fee_amount: Uint128::zero(),
-                                spread_amount: Uint128::zero(),
-                                protocol_fee_amount: Uint128::zero(),
-                                burn_fee_amount: Uint128::zero(),
-                                osmosis_fee_amount: Uint128::zero(),
-                            };
-
-                            SystemResult::Ok(ContractResult::from(to_json_binary(&data)))
-                        }
-                        Ok(PairQueryMsg::ReverseSimulation { ask_asset }) => {
-                            #[cfg(not(feature = "osmosis"))]
-                            let data = ReverseSimulationResponse {
-                                offer_amount: ask_asset.amount,
-                                swap_fee_amount: Uint128::zero(),
-                                spread_amount: Uint128::zero(),
-                                protocol_fee_amount: Uint128::zero(),
-                                burn_fee_amount: Uint128::zero(),
-                            };
-
-                            #[cfg(feature = "osmosis")]
-                            let data = ReverseSimulationResponse {
-                                offer_amount: ask_asset.amount,
-                                swap_fee_amount: Uint128::zero(),
-                                spread_amount: Uint128::zero(),
-                                protocol_fee_amount: Uint128::zero(),
-                                burn_fee_amount: Uint128::zero(),
-                                osmosis_fee_amount: Uint128::zero(),
-                            };
+                        SystemResult::Ok(ContractResult::from(to_json_binary(&PairInfo {
+                            asset_infos: [
+                                AssetInfo::NativeToken {
+                                    denom: "uluna".to_string(),
+                                },
+                                AssetInfo::NativeToken {
+                                    denom: "uluna".to_string(),
+                                },
+                            ],
+                            asset_decimals: [6u8, 6u8],
+                            contract_addr: "pair0000".to_string(),
+                            liquidity_token: AssetInfo::Token {
+                                contract_addr: "liquidity0000".to_string(),
+                            },
+                            pair_type: PairType::ConstantProduct,
+                        })))
+                    }
+                    Ok(PairQueryMsg::Simulation { offer_asset, .. }) => {
+                        #[cfg(not(feature = "osmosis"))]
+                        let data = SimulationResponse {
+                            return_amount: offer_asset.amount,
+                            swap_fee: Decimal::zero(),
+                            swap_fee_amount: Uint128::zero(),
+                            spread_amount: Uint128::zero(),
+                            protocol_fee_amount: Uint128::zero(),
+                            burn_fee_amount: Uint128::zero(),
+                        };
+
+                        #[cfg(feature = "osmosis")]
+                        let data = SimulationResponse {
+                            return_amount: offer_asset.amount,
+                            swap_fee: Decimal::zero(),
+                            swap_fee_amount: Uint128::zero(),
+                            spread_amount: Uint128::zero(),
+                            protocol_fee_amount: Uint128::zero(),
+                            burn_fee_amount: Uint128::zero(),
+                            osmosis_fee_amount: Uint128::zero(),
+                        };
+
+                        SystemResult::Ok(ContractResult::from(to_json_binary(&data)))
+                    }
+                    Ok(PairQueryMsg::PoolStats { .. }) => SystemResult::Ok(ContractResult::from(
+                        to_json_binary(&PoolStats::default()),
+                    )),
+                    Ok(PairQueryMsg::Config {}) => {
+                        let feature_toggle = self
+                            .pool_factory_querier
+                            .feature_toggles
+                            .get(contract_addr)
+                            .cloned()
+                            .unwrap_or(FeatureToggle {
+                                withdrawals_enabled: true,
+                                deposits_enabled: true,
+                                swaps_enabled: true,
+                            });
+
+                        #[cfg(not(feature = "osmosis"))]
+                        let data = PairConfig {
+                            owner: Addr::unchecked("factory"),
+                            fee_collector_addr: Addr::unchecked("collector"),
+                            pool_fees: PoolFee {
+                                protocol_fee: Fee {
+                                    share: Decimal::zero(),
+                                },
+                                swap_fee: Fee {
+                                    share: Decimal::zero(),
+                                },
+                                burn_fee: Fee {
+                                    share: Decimal::zero(),
+                                },
+                            },
+                            feature_toggle,
+                            dynamic_fee: None,
+                        };
+
+                        #[cfg(feature = "osmosis")]
+                        let data = PairConfig {
+                            owner: Addr::unchecked("factory"),
+                            fee_collector_addr: Addr::unchecked("collector"),
+                            pool_fees: PoolFee {
+                                protocol_fee: Fee {
+                                    share: Decimal::zero(),
+                                },
+                                swap_fee: Fee {
+                                    share: Decimal::zero(),
+                                },
+                                burn_fee: Fee {
+                                    share: Decimal::zero(),
+                                },
+                                osmosis_fee: Fee {
+                                    share: Decimal::zero(),
+                                },
+                            },
+                            feature_toggle,
+                            dynamic_fee: None,
+                            cosmwasm_pool_interface: Addr::unchecked("cosmwasm_pool_interface"),
+                        };
 
-                            SystemResult::Ok(ContractResult::from(to_json_binary(&data)))
+                        SystemResult::Ok(ContractResult::from(to_json_binary(&data)))
+                    }
+                    Ok(PairQueryMsg::ReverseSimulation { ask_asset }) => {
+                        #[cfg(not(feature = "osmosis"))]
+                        let data = ReverseSimulationResponse {
+                            offer_amount: ask_asset.amount,
+                            swap_fee_amount: Uint128::zero(),
+                            spread_amount: Uint128::zero(),
+                            protocol_fee_amount: Uint128::zero(),
+                            burn_fee_amount: Uint128::zero(),
+                        };
+
+                        #[cfg(feature = "osmosis")]
+                        let data = ReverseSimulationResponse {
+                            offer_amount: ask_asset.amount,
+                            swap_fee_amount: Uint128::zero(),
+                            spread_amount: Uint128::zero(),
+                            protocol_fee_amount: Uint128::zero(),
+                            burn_fee_amount: Uint128::zero(),
+                            osmosis_fee_amount: Uint128::zero(),
+                        };
+
+                        SystemResult::Ok(ContractResult::from(to_json_binary(&data)))
+                    }
+                    _ if matches!(from_json(msg), Ok(TrioQueryMsg::Trio {})) => {
+                        match self.pool_factory_querier.trios.get(contract_addr) {
+                            Some(trio) => {
+                                SystemResult::Ok(ContractResult::from(to_json_binary(trio)))
+                            }
+                            None => SystemResult::Err(SystemError::InvalidRequest {
+                                error: "No trio info exists".to_string(),
+                                request: msg.as_slice().into(),
+                            }),
                         }
-                        _ => match from_json(msg).unwrap() {
+                    }
+                    _ => {
+                        match from_json(msg).unwrap() {
                             Cw20QueryMsg::TokenInfo {} => {
                                 let balances: &HashMap<String, Uint128> =
                                 match self.token_querier.balances.get(contract_addr) {
@@ -334,9 +470,9 @@ impl WasmMockQuerier {
                             }
 
                             _ => panic!("DO NOT ENTER HERE"),
-                        },
+                        }
                     }
-                }
+                },
             },
             QueryRequest::Wasm(WasmQuery::ContractInfo { .. }) => {
                 let mut contract_info_response = ContractInfoResponse::default();
@@ -378,6 +514,19 @@ impl WasmMockTrioQuerier {
                         }),
                     }
                 }
+                Ok(FactoryQueryMsg::CurrentEpoch {}) => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&CurrentEpochResponse {
+                        epoch_id: self
+                            .pool_factory_querier
+                            .current_epoch
+                            .map(|(epoch_id, _)| epoch_id),
+                        end_time: self
+                            .pool_factory_querier
+                            .current_epoch
+                            .map(|(_, end_time)| end_time),
+                    })
+                    .unwrap(),
+                )),
                 Ok(FactoryQueryMsg::NativeTokenDecimals { denom }) => {
                     match self.pool_factory_querier.native_token_decimals.get(&denom) {
                         Some(decimals) => SystemResult::Ok(ContractResult::Ok(
@@ -461,6 +610,9 @@ impl WasmMockTrioQuerier {
 
                             SystemResult::Ok(ContractResult::from(to_json_binary(&data)))
                         }
+                        Ok(TrioQueryMsg::PoolStats { .. }) => SystemResult::Ok(
+                            ContractResult::from(to_json_binary(&PoolStats::default())),
+                        ),
                         Ok(TrioQueryMsg::ReverseSimulation { ask_asset, .. }) => {
                             #[cfg(not(feature = "osmosis"))]
                             let data = trio::ReverseSimulationResponse {
@@ -588,6 +740,47 @@ impl WasmMockQuerier {
         self.pool_factory_querier = PoolFactoryQuerier::new(pairs, native_token_decimals);
     }
 
+    // configure the fee discounts returned by the pool factory
+    pub fn with_fee_discounts(&mut self, fee_discounts: &[(&String, Decimal)]) {
+        for (address, discount) in fee_discounts {
+            self.pool_factory_querier
+                .fee_discounts
+                .insert(address.to_string(), *discount);
+        }
+    }
+
+    // configure the current epoch returned by the pool factory, with the time it ends
+    pub fn with_current_epoch(&mut self, current_epoch: Option<(u64, Timestamp)>) {
+        self.pool_factory_querier.current_epoch = current_epoch;
+    }
+
+    // configure the trios answering the trio info query, by contract address
+    pub fn with_trios(&mut self, trios: &[&TrioInfo]) {
+        for trio in trios {
+            self.pool_factory_querier
+                .trios
+                .insert(trio.contract_addr.clone(), (*trio).clone());
+        }
+    }
+
+    // configure the pools failing to answer any query
+    pub fn with_unresponsive_pools(&mut self, pools: &[&str]) {
+        for pool in pools {
+            self.pool_factory_querier
+                .unresponsive_pools
+                .insert(pool.to_string());
+        }
+    }
+
+    // configure the feature toggles returned by the pools, which have everything enabled otherwise
+    pub fn with_feature_toggles(&mut self, feature_toggles: &[(&String, FeatureToggle)]) {
+        for (pool_addr, feature_toggle) in feature_toggles {
+            self.pool_factory_querier
+                .feature_toggles
+                .insert(pool_addr.to_string(), feature_toggle.clone());
+        }
+    }
+
     pub fn with_balance(&mut self, balances: &[(&String, Vec<Coin>)]) {
         for (addr, balance) in balances {
             self.base.update_balance(addr.to_string(), balance.clone());
@@ -618,6 +811,11 @@ impl WasmMockTrioQuerier {
         self.pool_factory_querier = PoolFactoryQuerier::new(pairs, native_token_decimals);
     }
 
+    // configure the current epoch returned by the pool factory, with the time it ends
+    pub fn with_current_epoch(&mut self, current_epoch: Option<(u64, Timestamp)>) {
+        self.pool_factory_querier.current_epoch = current_epoch;
+    }
+
     pub fn with_balance(&mut self, balances: &[(&String, Vec<Coin>)]) {
         for (addr, balance) in balances {
             self.base.update_balance(addr.to_string(), balance.clone());
@@ -656,6 +854,7 @@ mod mock_exception {
                     denom: "ulunc".to_string(),
                 },
             ],
+            pool_identifier: None,
         })
         .unwrap();
         assert_eq!(
@@ -1,3 +1,4 @@
+pub mod amp;
 pub mod asset;
 #[cfg(feature = "injective")]
 pub mod denom_injective;
@@ -8,6 +9,7 @@ pub mod frontend_helper;
 pub mod incentive;
 pub mod incentive_factory;
 pub mod pair;
+pub mod pool_stats;
 pub mod querier;
 pub mod router;
 pub mod swap;
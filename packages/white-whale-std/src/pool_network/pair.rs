@@ -3,7 +3,9 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Decimal, StdError, StdResult, Uint128};
 use cw20::Cw20ReceiveMsg;
 
+use crate::pool_network::amp::AmpFactorUpdate;
 use crate::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
+use crate::pool_network::pool_stats::PoolStats;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -39,6 +41,9 @@ pub enum ExecuteMsg {
         belief_price: Option<Decimal>,
         max_spread: Option<Decimal>,
         to: Option<String>,
+        /// The trader on whose behalf the swap is performed, used to look up the fee discount.
+        /// Only honored when the sender is a router trusted by the factory.
+        trader: Option<String>,
     },
     #[cfg(feature = "osmosis")]
     /// Updates the pair pool config
@@ -47,6 +52,10 @@ pub enum ExecuteMsg {
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
+        /// Ramps or stops ramping the amp factor. Only valid for StableSwap pairs.
+        amp_factor: Option<AmpFactorUpdate>,
+        /// Enables or disables the dynamic swap fee.
+        dynamic_fee: Option<DynamicFeeUpdate>,
         cosmwasm_pool_interface: Option<String>,
     },
     #[cfg(not(feature = "osmosis"))]
@@ -56,6 +65,10 @@ pub enum ExecuteMsg {
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
+        /// Ramps or stops ramping the amp factor. Only valid for StableSwap pairs.
+        amp_factor: Option<AmpFactorUpdate>,
+        /// Enables or disables the dynamic swap fee.
+        dynamic_fee: Option<DynamicFeeUpdate>,
     },
     /// Collects the Protocol fees accrued by the pool
     CollectProtocolFees {},
@@ -68,6 +81,9 @@ pub enum Cw20HookMsg {
         belief_price: Option<Decimal>,
         max_spread: Option<Decimal>,
         to: Option<String>,
+        /// The trader on whose behalf the swap is performed, used to look up the fee discount.
+        /// Only honored when the sender is a router trusted by the factory.
+        trader: Option<String>,
     },
     /// Withdraws liquidity
     WithdrawLiquidity {},
@@ -104,6 +120,16 @@ pub enum QueryMsg {
     /// perform the swap.
     #[returns(ReverseSimulationResponse)]
     ReverseSimulation { ask_asset: Asset },
+    /// Simulates a swap performed by the given trader, applying the trader's fee discount.
+    #[returns(SimulationResponse)]
+    DiscountedSimulation { offer_asset: Asset, trader: String },
+    /// Simulates a reverse swap performed by the given trader, applying the trader's fee discount.
+    #[returns(ReverseSimulationResponse)]
+    DiscountedReverseSimulation { ask_asset: Asset, trader: String },
+    /// Retrieves the swap statistics of the pool since its inception, or for the given epoch of
+    /// the epoch manager if any.
+    #[returns(PoolStats)]
+    PoolStats { epoch_id: Option<u64> },
 }
 
 /// Pool feature toggle
@@ -166,12 +192,108 @@ impl PoolFee {
     }
 }
 
+/// Dynamic swap fee of a pair. When set, the swap fee is scaled between `min_fee` and `max_fee`
+/// instead of using the swap fee share of the [PoolFee].
+#[cw_serde]
+pub struct DynamicFee {
+    pub min_fee: Fee,
+    pub max_fee: Fee,
+    pub mode: DynamicFeeMode,
+}
+
+/// The max amount of price observations kept by a pool to compute its volatility, see
+/// [DynamicFeeMode::Volatility].
+pub const MAX_PRICE_OBSERVATIONS: u64 = 100;
+
+/// What the dynamic swap fee is scaled by.
+#[cw_serde]
+pub enum DynamicFeeMode {
+    /// Scales the fee with the price volatility of the pool over the last `window` seconds, i.e.
+    /// the relative difference between the highest and lowest price observed. The max fee is
+    /// charged once the volatility reaches `max_volatility`.
+    ///
+    /// The pool keeps a price observation per block it is swapped in, up to the latest
+    /// [MAX_PRICE_OBSERVATIONS]. On busy pools, the oldest observations within the window may be
+    /// overwritten already, so the volatility is computed over a shorter period than `window`.
+    Volatility {
+        window: u64,
+        max_volatility: Decimal,
+    },
+    /// Scales the fee with the imbalance of the pool after the swap, i.e. the difference between
+    /// both pools relative to their sum. The max fee is charged once the imbalance reaches
+    /// `max_imbalance`. Only valid for StableSwap pairs.
+    Imbalance { max_imbalance: Decimal },
+}
+
+impl DynamicFee {
+    /// Checks that the given [DynamicFee] is valid, i.e. the bounds are valid fees in the right
+    /// order, the max fee doesn't exceed 100% together with the rest of the [PoolFee] and the
+    /// mode parameters are not zero.
+    pub fn is_valid(&self, pool_fees: &PoolFee) -> StdResult<()> {
+        self.min_fee.is_valid()?;
+        self.max_fee.is_valid()?;
+
+        if self.min_fee.share > self.max_fee.share {
+            return Err(StdError::generic_err(
+                "Dynamic fee min_fee can't be higher than max_fee",
+            ));
+        }
+
+        PoolFee {
+            swap_fee: self.max_fee.clone(),
+            ..pool_fees.clone()
+        }
+        .is_valid()?;
+
+        match &self.mode {
+            DynamicFeeMode::Volatility {
+                window,
+                max_volatility,
+            } => {
+                if *window == 0 || max_volatility.is_zero() {
+                    return Err(StdError::generic_err("Invalid dynamic fee mode"));
+                }
+            }
+            DynamicFeeMode::Imbalance { max_imbalance } => {
+                if max_imbalance.is_zero() || *max_imbalance > Decimal::one() {
+                    return Err(StdError::generic_err("Invalid dynamic fee mode"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Scales the fee linearly between `min_fee` and `max_fee`, according to how close `value`
+    /// is to `max_value`.
+    pub fn scale(&self, value: Decimal, max_value: Decimal) -> Fee {
+        let ratio = if value >= max_value {
+            Decimal::one()
+        } else {
+            value / max_value
+        };
+
+        Fee {
+            share: self.min_fee.share + (self.max_fee.share - self.min_fee.share) * ratio,
+        }
+    }
+}
+
+/// Enables or disables the dynamic swap fee of a pair.
+#[cw_serde]
+pub enum DynamicFeeUpdate {
+    Enable(DynamicFee),
+    Disable {},
+}
+
 #[cw_serde]
 pub struct Config {
     pub owner: Addr,
     pub fee_collector_addr: Addr,
     pub pool_fees: PoolFee,
     pub feature_toggle: FeatureToggle,
+    /// The dynamic swap fee of the pair, if enabled
+    pub dynamic_fee: Option<DynamicFee>,
     #[cfg(feature = "osmosis")]
     pub cosmwasm_pool_interface: Addr,
 }
@@ -190,6 +312,10 @@ pub struct PoolResponse {
 pub struct SimulationResponse {
     pub return_amount: Uint128,
     pub spread_amount: Uint128,
+    /// The swap fee share charged on the swap, which varies if the pair has a dynamic fee. Defaults
+    /// to zero when deserializing responses of pairs that predate it.
+    #[serde(default)]
+    pub swap_fee: Decimal,
     pub swap_fee_amount: Uint128,
     pub protocol_fee_amount: Uint128,
     pub burn_fee_amount: Uint128,
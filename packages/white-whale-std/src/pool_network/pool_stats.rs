@@ -0,0 +1,125 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Env, QuerierWrapper, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+use crate::pool_network::asset::Asset;
+use crate::pool_network::factory::{CurrentEpochResponse, QueryMsg as FactoryQueryMsg};
+
+/// The all time swap statistics of a pool.
+pub const POOL_STATS: Item<PoolStats> = Item::new("pool_stats");
+/// The swap statistics of a pool per epoch of the epoch manager, keyed by epoch id.
+pub const EPOCH_POOL_STATS: Map<u64, PoolStats> = Map::new("epoch_pool_stats");
+
+/// The epoch the swap statistics are bucketed by, as last queried from the factory.
+#[cw_serde]
+pub struct CachedEpoch {
+    pub epoch_id: Option<u64>,
+    /// Until when the epoch is used without querying the factory again.
+    pub expires_at: Timestamp,
+}
+
+/// The current epoch, cached so the factory is only queried once per epoch.
+pub const CURRENT_EPOCH: Item<CachedEpoch> = Item::new("current_epoch");
+
+/// How long a pool waits before querying the factory again when there is no epoch to bucket the
+/// swap statistics by.
+const NO_EPOCH_RETRY_SECONDS: u64 = 3_600;
+
+/// Swap statistics of a pool, either since its inception or for a single epoch of the epoch
+/// manager.
+#[cw_serde]
+#[derive(Default)]
+pub struct PoolStats {
+    /// The amounts swapped into the pool, per offer asset.
+    pub volume: Vec<Asset>,
+    /// The swap fees earned by the liquidity providers, per ask asset.
+    pub lp_fees: Vec<Asset>,
+    /// The number of swaps performed on the pool.
+    pub swap_count: u64,
+}
+
+impl PoolStats {
+    /// Adds a swap to the statistics, given the asset offered and the swap fee paid to the
+    /// liquidity providers.
+    pub fn record_swap(&mut self, offer_asset: &Asset, lp_fee: &Asset) -> StdResult<()> {
+        add_to_assets(&mut self.volume, offer_asset)?;
+        if !lp_fee.amount.is_zero() {
+            add_to_assets(&mut self.lp_fees, lp_fee)?;
+        }
+        self.swap_count += 1;
+
+        Ok(())
+    }
+}
+
+/// Gets the current epoch to bucket the swap statistics of a pool by. The epoch is queried from the
+/// given factory and cached until it ends. If the factory has no epoch manager set or can't be
+/// queried, only the all time statistics are kept until the factory is queried again.
+pub fn current_epoch(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper,
+    env: &Env,
+    factory: &Addr,
+) -> StdResult<Option<u64>> {
+    if let Some(cached_epoch) = CURRENT_EPOCH.may_load(storage)? {
+        if env.block.time < cached_epoch.expires_at {
+            return Ok(cached_epoch.epoch_id);
+        }
+    }
+
+    let cached_epoch = match querier
+        .query_wasm_smart::<CurrentEpochResponse>(factory, &FactoryQueryMsg::CurrentEpoch {})
+    {
+        Ok(CurrentEpochResponse {
+            epoch_id: Some(epoch_id),
+            end_time: Some(end_time),
+        }) => CachedEpoch {
+            epoch_id: Some(epoch_id),
+            expires_at: end_time,
+        },
+        _ => CachedEpoch {
+            epoch_id: None,
+            expires_at: env.block.time.plus_seconds(NO_EPOCH_RETRY_SECONDS),
+        },
+    };
+    CURRENT_EPOCH.save(storage, &cached_epoch)?;
+
+    Ok(cached_epoch.epoch_id)
+}
+
+/// Records a swap in the all time statistics of a pool and, if an epoch is given, in the
+/// statistics of that epoch.
+pub fn record_swap_stats(
+    storage: &mut dyn Storage,
+    epoch_id: Option<u64>,
+    offer_asset: &Asset,
+    lp_fee: &Asset,
+) -> StdResult<()> {
+    let mut pool_stats = POOL_STATS.may_load(storage)?.unwrap_or_default();
+    pool_stats.record_swap(offer_asset, lp_fee)?;
+    POOL_STATS.save(storage, &pool_stats)?;
+
+    if let Some(epoch_id) = epoch_id {
+        let mut epoch_stats = EPOCH_POOL_STATS
+            .may_load(storage, epoch_id)?
+            .unwrap_or_default();
+        epoch_stats.record_swap(offer_asset, lp_fee)?;
+        EPOCH_POOL_STATS.save(storage, epoch_id, &epoch_stats)?;
+    }
+
+    Ok(())
+}
+
+/// Adds the given asset to the amount of the matching asset in the list, or appends it if the list
+/// doesn't have it yet.
+fn add_to_assets(assets: &mut Vec<Asset>, asset: &Asset) -> StdResult<()> {
+    match assets
+        .iter_mut()
+        .find(|existing| existing.info.equal(&asset.info))
+    {
+        Some(existing) => existing.amount = existing.amount.checked_add(asset.amount)?,
+        None => assets.push(asset.clone()),
+    }
+
+    Ok(())
+}
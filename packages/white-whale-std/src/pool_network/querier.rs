@@ -79,11 +79,23 @@ pub fn query_pair_info(
     querier: &QuerierWrapper,
     factory_contract: Addr,
     asset_infos: &[AssetInfo; 2],
+) -> StdResult<PairInfo> {
+    query_pair_info_by_identifier(querier, factory_contract, asset_infos, None)
+}
+
+/// Queries the info of the pair pool with the given identifier for the given asset couple. If
+/// no identifier is given, the default pool of the asset couple is returned.
+pub fn query_pair_info_by_identifier(
+    querier: &QuerierWrapper,
+    factory_contract: Addr,
+    asset_infos: &[AssetInfo; 2],
+    pool_identifier: Option<String>,
 ) -> StdResult<PairInfo> {
     querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
         contract_addr: factory_contract.to_string(),
         msg: to_json_binary(&FactoryQueryMsg::Pair {
             asset_infos: asset_infos.clone(),
+            pool_identifier,
         })?,
     }))
 }
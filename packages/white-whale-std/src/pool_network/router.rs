@@ -16,6 +16,9 @@ pub enum SwapOperation {
     TerraSwap {
         offer_asset_info: AssetInfo,
         ask_asset_info: AssetInfo,
+        /// The identifier of the pool to swap through, as there can be multiple pools for the same
+        /// assets. If not specified, the default pool of the asset couple is used.
+        pool_identifier: Option<String>,
     },
 }
 
@@ -33,6 +36,7 @@ impl fmt::Display for SwapOperation {
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+                ..
             } => write!(
                 f,
                 "TerraSwap {{ offer_asset_info: {offer_asset_info}, ask_asset_info: {ask_asset_info} }}"
@@ -81,6 +85,8 @@ pub enum ExecuteMsg {
         operation: SwapOperation,
         to: Option<String>,
         max_spread: Option<Decimal>,
+        /// The trader who initiated the swap operations, passed to the pairs for the fee discount.
+        trader: Option<String>,
     },
     /// Checks if the swap amount exceeds the minimum_receive. This message can only be called
     /// internally by the router contract.
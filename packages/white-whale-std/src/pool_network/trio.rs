@@ -5,6 +5,7 @@ use cw20::Cw20ReceiveMsg;
 use crate::fee::Fee;
 
 use crate::pool_network::asset::{Asset, AssetInfo, TrioInfo};
+use crate::pool_network::pool_stats::PoolStats;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -53,11 +54,7 @@ pub enum ExecuteMsg {
     CollectProtocolFees {},
 }
 
-#[cw_serde]
-pub struct RampAmp {
-    pub future_a: u64,
-    pub future_block: u64,
-}
+pub use crate::pool_network::amp::RampAmp;
 
 #[cw_serde]
 pub enum Cw20HookMsg {
@@ -109,6 +106,10 @@ pub enum QueryMsg {
         ask_asset: Asset,
         offer_asset: Asset,
     },
+    /// Retrieves the swap statistics of the pool since its inception, or for the given epoch of
+    /// the epoch manager if any.
+    #[returns(PoolStats)]
+    PoolStats { epoch_id: Option<u64> },
 }
 
 /// Pool feature toggle
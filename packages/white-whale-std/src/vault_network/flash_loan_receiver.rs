@@ -0,0 +1,114 @@
+//! The interface contracts borrowing from the vaults implement to receive flash-loans, along with
+//! helpers to handle the loan and pay it back.
+//!
+//! When a flash-loan is taken with [crate::vault_network::vault::ExecuteMsg::FlashLoanToReceiver],
+//! the vault sends the loaned funds to the borrower and calls it with
+//! [FlashLoanReceiverMsg::OnFlashLoan]. The borrower must include the same variant in its execute
+//! message, and by the end of its execution it must have sent `amount + fee` back to the vault,
+//! which [repayment_msg] constructs. The loan is reverted otherwise.
+//!
+//! Loans taken with [crate::vault_network::vault::ExecuteMsg::FlashLoan] keep executing the
+//! borrower with the message it provided, as is.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, CosmosMsg, QuerierWrapper, StdError, StdResult, Uint128,
+    WasmMsg,
+};
+
+use crate::pool_network::asset::{Asset, AssetInfo};
+
+/// The message the vaults call the borrower with once the loaned funds are sent.
+#[cw_serde]
+pub enum FlashLoanReceiverMsg {
+    OnFlashLoan {
+        /// The asset loaned
+        asset: AssetInfo,
+        /// The amount loaned
+        amount: Uint128,
+        /// The fee that must be paid on top of the loaned amount
+        fee: Uint128,
+        /// The message provided by the borrower when requesting the flash-loan
+        data: Binary,
+    },
+}
+
+impl FlashLoanReceiverMsg {
+    /// Creates the message calling the receiver contract, sending the given funds along.
+    pub fn into_cosmos_msg<T: Into<String>>(
+        self,
+        contract_addr: T,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.into(),
+            msg: to_json_binary(&self)?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Creates the message paying back a flash-loan of `amount` plus `fee` of the given asset to the
+/// vault.
+pub fn repayment_msg(
+    vault: Addr,
+    asset: AssetInfo,
+    amount: Uint128,
+    fee: Uint128,
+) -> StdResult<CosmosMsg> {
+    Asset {
+        info: asset,
+        amount: amount.checked_add(fee)?,
+    }
+    .into_msg(vault)
+}
+
+/// Validates that the sender of [FlashLoanReceiverMsg::OnFlashLoan] is the vault the vault factory
+/// has registered for the loaned asset, as anyone could call the receiver otherwise.
+pub fn validate_vault(
+    querier: &QuerierWrapper,
+    vault_factory: &Addr,
+    sender: &Addr,
+    asset: &AssetInfo,
+) -> StdResult<()> {
+    let vault: Option<String> = querier.query_wasm_smart(
+        vault_factory,
+        &crate::vault_network::vault_factory::QueryMsg::Vault {
+            asset_info: asset.clone(),
+        },
+    )?;
+
+    match vault {
+        Some(vault) if vault == sender.as_str() => Ok(()),
+        _ => Err(StdError::generic_err(format!(
+            "{sender} is not the vault of {asset}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coins, BankMsg};
+
+    use super::*;
+
+    #[test]
+    fn repayment_includes_the_fee() {
+        assert_eq!(
+            repayment_msg(
+                Addr::unchecked("vault"),
+                AssetInfo::NativeToken {
+                    denom: "uluna".to_string(),
+                },
+                Uint128::new(1_000),
+                Uint128::new(10),
+            )
+            .unwrap(),
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "vault".to_string(),
+                amount: coins(1_010, "uluna"),
+            })
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod flash_loan_receiver;
 pub mod vault;
 pub mod vault_factory;
 pub mod vault_router;
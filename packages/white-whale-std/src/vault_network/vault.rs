@@ -66,11 +66,20 @@ pub enum ExecuteMsg {
     },
     /// Withdraws from the vault. Used when the LP token is a token factory token.
     Withdraw {},
-    /// Flash-loans a given amount from the vault.
+    /// Flash-loans a given amount from the vault. The sender is executed with `msg` along with the
+    /// loaned funds, and must pay back the loan along with its fee by the end of the call.
     FlashLoan {
         amount: Uint128,
         msg: Binary,
     },
+    /// Flash-loans a given amount from the vault to a contract implementing the flash-loan receiver
+    /// interface. The sender is called with
+    /// [crate::vault_network::flash_loan_receiver::FlashLoanReceiverMsg::OnFlashLoan], carrying
+    /// `data`, and must pay back the loan along with its fee by the end of the call.
+    FlashLoanToReceiver {
+        amount: Uint128,
+        data: Binary,
+    },
     /// Collects the Protocol fees
     CollectProtocolFees {},
     /// Updates the configuration of the contract.
@@ -98,6 +107,9 @@ pub enum QueryMsg {
     /// Retrieves the [`Uint128`] amount that must be sent back to the contract to pay off a loan taken out.
     #[returns(PaybackAmountResponse)]
     GetPaybackAmount { amount: Uint128 },
+    /// Retrieves the amount of the vault asset that can be flash-loaned.
+    #[returns(AvailableLiquidityResponse)]
+    AvailableLiquidity {},
 }
 
 #[cw_serde]
@@ -142,3 +154,11 @@ pub struct PaybackAmountResponse {
     /// The amount of fee to be burned
     pub burn_fee: Uint128,
 }
+
+#[cw_serde]
+pub struct AvailableLiquidityResponse {
+    /// The amount of the vault asset that can be flash-loaned, i.e. the balance of the vault.
+    pub available_liquidity: Asset,
+    /// If flash-loans are enabled
+    pub flash_loan_enabled: bool,
+}
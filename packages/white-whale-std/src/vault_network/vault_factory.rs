@@ -1,5 +1,6 @@
 use crate::fee::VaultFee;
-use crate::pool_network::asset::AssetInfo;
+use crate::pause::PauseStatusResponse;
+use crate::pool_network::asset::{Asset, AssetInfo};
 use crate::vault_network::vault;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
@@ -21,7 +22,10 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Creates a new vault given the asset info the vault should manage deposits and withdrawals
-    /// for and the fees
+    /// for and the fees. Can be called by anyone, as long as the vault creation fee is paid and the
+    /// fees are within the fee bounds. The owner is exempt from the vault creation fee, and is the
+    /// only one allowed to create vaults until both the vault creation fee and the fee bounds are
+    /// set.
     CreateVault {
         asset_info: AssetInfo,
         fees: VaultFee,
@@ -49,7 +53,32 @@ pub enum ExecuteMsg {
         fee_collector_addr: Option<String>,
         vault_id: Option<u64>,
         token_id: Option<u64>,
+        vault_creation_fee: Option<VaultCreationFee>,
+        fee_bounds: Option<VaultFeeBounds>,
     },
+    /// Disables flash-loans and deposits on vaults, leaving withdrawals enabled. If a [vault_addr]
+    /// is provided, then pauses only that vault. Otherwise a page of the vaults is paused, sorted
+    /// like [QueryMsg::Vaults], and the asset info reference of its last vault is set as the
+    /// response data, to resume from with `start_after`. Ten vaults are paused by default if no
+    /// limit is specified, and at most 30 at once. Can be executed by the guardian as well.
+    PauseVaults {
+        vault_addr: Option<String>,
+        reason: String,
+        start_after: Option<Vec<u8>>,
+        limit: Option<u32>,
+    },
+    /// Enables flash-loans and deposits on vaults. If a [vault_addr] is provided, then unpauses
+    /// only that vault. Otherwise a page of the vaults disabled by [ExecuteMsg::PauseVaults] is
+    /// restored, sorted by address, with the same limits. The vaults are unpaused once all of
+    /// them are restored.
+    UnpauseVaults {
+        vault_addr: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Sets the guardian, which can pause vaults but never unpause them nor change their config.
+    /// If none is given, the guardian is removed.
+    UpdateGuardian { guardian: Option<String> },
 }
 
 /// The query message
@@ -68,11 +97,20 @@ pub enum QueryMsg {
         start_after: Option<Vec<u8>>,
         limit: Option<u32>,
     },
+    /// Retrieves the guardian and whether vaults were paused through the factory. If a vault is
+    /// given, whether it was paused on its own is retrieved as well.
+    #[returns(PauseStatusResponse)]
+    PauseStatus { contract: Option<String> },
 }
 
 /// The migrate message
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// Sets the vault creation fee, if given.
+    pub vault_creation_fee: Option<VaultCreationFee>,
+    /// Sets the fee bounds, if given.
+    pub fee_bounds: Option<VaultFeeBounds>,
+}
 
 /// The `reply` code ID for the submessage after instantiating the vault.
 pub const INSTANTIATE_VAULT_REPLY_ID: u64 = 1;
@@ -83,6 +121,29 @@ pub struct Config {
     pub vault_id: u64,
     pub token_id: u64,
     pub fee_collector_addr: Addr,
+    /// The fee charged to non-owner accounts for creating a vault. Only the owner can create vaults
+    /// while it is not set.
+    pub vault_creation_fee: Option<VaultCreationFee>,
+    /// The bounds the fees of the vaults must be within, both when creating a vault and when
+    /// updating its fees. If not set, any valid fee is allowed, but only the owner can create
+    /// vaults.
+    pub fee_bounds: Option<VaultFeeBounds>,
+}
+
+/// The fee charged for creating a vault, and what happens to it once collected.
+#[cw_serde]
+pub struct VaultCreationFee {
+    /// The asset and amount to be paid.
+    pub fee: Asset,
+    /// If true, the fee is burned. Otherwise, it is sent to the fee collector.
+    pub burn: bool,
+}
+
+/// The inclusive bounds each component of a [VaultFee] must be within.
+#[cw_serde]
+pub struct VaultFeeBounds {
+    pub min: VaultFee,
+    pub max: VaultFee,
 }
 
 /// Response for the vaults query
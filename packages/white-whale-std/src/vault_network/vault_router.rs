@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, CosmosMsg};
+use cosmwasm_std::{Addr, CosmosMsg, Uint128};
 
 use crate::pool_network::asset::{Asset, AssetInfo};
 
@@ -16,7 +16,8 @@ pub struct InstantiateMsg {
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Retrieves the desired `assets` and runs the `msgs`, paying the required amount back the vaults
-    /// after running the messages, and returning the profit to the sender.
+    /// after running the messages, and returning the profit to the sender. Each asset is loaned from
+    /// its own vault, so an asset can only be provided once.
     FlashLoan {
         assets: Vec<Asset>,
         msgs: Vec<CosmosMsg>,
@@ -63,6 +64,10 @@ pub enum QueryMsg {
     /// Retrieves the configuration of the vault router.
     #[returns(Config)]
     Config {},
+    /// Simulates a flash-loan of the given `assets`, returning for each of them the vault it would
+    /// be loaned from, the amount to pay back along with its fees, and the liquidity available.
+    #[returns(FlashLoanQuoteResponse)]
+    FlashLoanQuote { assets: Vec<Asset> },
 }
 
 /// The migrate message
@@ -76,3 +81,29 @@ pub struct Config {
     /// The address of the vault factory
     pub vault_factory: Addr,
 }
+
+#[cw_serde]
+pub struct FlashLoanQuoteResponse {
+    /// The quotes of the assets, in the order they were requested.
+    pub quotes: Vec<FlashLoanQuote>,
+}
+
+#[cw_serde]
+pub struct FlashLoanQuote {
+    /// The vault the asset would be loaned from
+    pub vault: Addr,
+    /// The asset to loan
+    pub asset: Asset,
+    /// The total amount that must be returned. Equivalent to the loaned amount + `protocol_fee` + `flash_loan_fee` + `burn_fee`.
+    pub payback_amount: Uint128,
+    /// The amount of fee paid to the protocol
+    pub protocol_fee: Uint128,
+    /// The amount of fee paid to vault holders
+    pub flash_loan_fee: Uint128,
+    /// The amount of fee to be burned
+    pub burn_fee: Uint128,
+    /// The amount of the asset the vault can loan
+    pub available_liquidity: Uint128,
+    /// If flash-loans are enabled on the vault
+    pub flash_loan_enabled: bool,
+}
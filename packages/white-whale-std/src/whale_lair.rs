@@ -1,6 +1,8 @@
 use crate::pool_network::asset::{Asset, AssetInfo};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128, Uint64};
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp, Uint128, Uint64};
+use cw2::ContractVersion;
+use cw20::Cw20ReceiveMsg;
 
 #[cw_serde]
 pub struct Config {
@@ -11,10 +13,55 @@ pub struct Config {
     /// A fraction that controls the effect of time on the weight of a bond. If the growth rate is set
     /// to zero, time will have no impact on the weight.
     pub growth_rate: Decimal,
-    /// Denom of the asset to be bonded. Can't only be set at instantiation.
+    /// The assets that can be bonded, either native or cw20 tokens. Assets can be added but not
+    /// removed.
     pub bonding_assets: Vec<AssetInfo>,
     /// Address of the fee distributor contract.
     pub fee_distributor_addr: Addr,
+    /// Address of the epoch manager contract.
+    pub epoch_manager_addr: Addr,
+    /// The fraction of the unbonded amount charged for unbonding instantly, which is sent to the
+    /// fee collector. If not set, instant unbonding is disabled.
+    pub instant_unbond_penalty: Option<Decimal>,
+    /// The maximum amount of bonding assets the contract can have.
+    pub bonding_assets_limit: u32,
+    /// The weight multipliers of the bonding assets. Bonding assets without a multiplier count as
+    /// one.
+    pub weight_multipliers: Vec<WeightMultiplier>,
+    /// How the weight of the bonds grows with time.
+    pub weight_growth: WeightGrowth,
+}
+
+/// How the weight of the bonds grows with time, at the rate set by the growth rate. The weight of a
+/// bond starts at its (weighted) bonded amount.
+#[cw_serde]
+pub enum WeightGrowth {
+    /// The weight grows linearly, without limit.
+    Linear {},
+    /// The weight grows linearly until it reaches the given multiple of the bonded amount.
+    Capped { max_multiplier: Decimal },
+    /// The weight grows logarithmically since the start time, i.e. the growth slows down over time.
+    /// The time scale, in seconds, is how long it takes for the growth to slow down to half its
+    /// initial speed.
+    Logarithmic {
+        start_time: Timestamp,
+        time_scale: Uint64,
+    },
+    /// Bonds are locked for the given duration, in nanoseconds, every time they're increased. In
+    /// exchange, their weight grows linearly at the growth rate times the multiplier.
+    Lockup {
+        duration: Uint64,
+        multiplier: Decimal,
+    },
+}
+
+/// The multiplier applied to the weight of the bonds of a bonding asset, i.e. 2 makes a bond
+/// count twice as much as a bond of the same amount of a bonding asset with no multiplier. Can't
+/// be changed once set, so the weights already accrued stay consistent.
+#[cw_serde]
+pub struct WeightMultiplier {
+    pub asset_info: AssetInfo,
+    pub multiplier: Decimal,
 }
 
 #[cw_serde]
@@ -63,25 +110,70 @@ pub struct InstantiateMsg {
     pub growth_rate: Decimal,
     /// [AssetInfo] of the assets that can be bonded.
     pub bonding_assets: Vec<AssetInfo>,
+    /// The maximum amount of bonding assets the contract can have.
+    pub bonding_assets_limit: u32,
+    /// The weight multipliers of the bonding assets. Bonding assets without a multiplier count as
+    /// one.
+    pub weight_multipliers: Vec<WeightMultiplier>,
+    /// How the weight of the bonds grows with time. It can't be changed after instantiation, as
+    /// the weight accrued by the existing bonds depends on it.
+    pub weight_growth: WeightGrowth,
+    /// Whether bonds are represented by a transferable receipt token. If set, a token factory
+    /// denom is created for each bonding asset. Transferring the receipt moves the bond with it.
+    pub receipt_token: bool,
+    /// The fraction of the unbonded amount charged for unbonding instantly. Needs to be between 0
+    /// and 1. If not set, instant unbonding is disabled.
+    pub instant_unbond_penalty: Option<Decimal>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Bonds the specified [Asset].
-    Bond { asset: Asset },
+    /// Used to trigger the [Cw20HookMsg] messages, i.e. to bond cw20 tokens.
+    Receive(Cw20ReceiveMsg),
+    /// Bonds the specified native [Asset]. If a receiver is provided, the bond is credited to it
+    /// instead of the sender.
+    Bond {
+        asset: Asset,
+        receiver: Option<String>,
+    },
     /// Unbonds the specified [Asset].
     Unbond { asset: Asset },
-    /// Sends withdrawable unbonded tokens to the user.
+    /// Unbonds the specified [Asset] and sends it to the user right away, skipping the unbonding
+    /// period. The instant unbond penalty is deducted and sent to the fee collector.
+    InstantUnbond { asset: Asset },
+    /// Transfers the specified [Asset] of the sender's bond to the recipient, along with the weight
+    /// it has accrued.
+    TransferBond { asset: Asset, recipient: String },
+    /// Sends withdrawable unbonded tokens to the user. The denom of cw20 tokens is their contract
+    /// address.
     Withdraw { denom: String },
+    /// Sends the withdrawable unbonded tokens of all the bonding assets to the user, processing up
+    /// to `limit` unbonding entries.
+    WithdrawAll { limit: Option<u8> },
     /// Updates the [Config] of the contract.
     UpdateConfig {
         owner: Option<String>,
         unbonding_period: Option<Uint64>,
         growth_rate: Option<Decimal>,
         fee_distributor_addr: Option<String>,
+        epoch_manager_addr: Option<String>,
+        instant_unbond_penalty: Option<Decimal>,
+        bonding_assets_limit: Option<u32>,
+    },
+    /// Adds a new bonding asset, with an optional weight multiplier.
+    AddBondingAsset {
+        asset_info: AssetInfo,
+        weight_multiplier: Option<Decimal>,
     },
 }
 
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Bonds the cw20 tokens sent. If a receiver is provided, the bond is credited to it instead of
+    /// the sender.
+    Bond { receiver: Option<String> },
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -94,7 +186,8 @@ pub enum QueryMsg {
     Bonded { address: String },
 
     /// Returns the amount of tokens of the given denom that are been unbonded by the specified address.
-    /// Allows pagination with start_after and limit.
+    /// Allows pagination with start_after and limit. The denom of cw20 tokens is their contract
+    /// address.
     #[returns(UnbondingResponse)]
     Unbonding {
         address: String,
@@ -108,6 +201,11 @@ pub enum QueryMsg {
     #[returns(WithdrawableResponse)]
     Withdrawable { address: String, denom: String },
 
+    /// Returns a summary of the unbondings of the specified address per bonding asset, i.e. how
+    /// much can be withdrawn already, how much is still unbonding and when it matures.
+    #[returns(UnbondingScheduleResponse)]
+    UnbondingSchedule { address: String },
+
     /// Returns the weight of the address.
     #[returns(BondingWeightResponse)]
     Weight {
@@ -116,6 +214,11 @@ pub enum QueryMsg {
         global_index: Option<GlobalIndex>,
     },
 
+    /// Returns the weight the address had when the given epoch was created. Used by the fee
+    /// distributor to calculate the rewards of an address for a given epoch.
+    #[returns(BondingWeightResponse)]
+    EpochWeight { address: String, epoch_id: Uint64 },
+
     /// Returns the total amount of assets that have been bonded to the contract.
     #[returns(BondedResponse)]
     TotalBonded {},
@@ -123,10 +226,66 @@ pub enum QueryMsg {
     /// Returns the global index of the contract.
     #[returns(GlobalIndex)]
     GlobalIndex {},
+
+    /// Returns the penalties charged for unbonding instantly by the given address, or by everyone if
+    /// no address is provided.
+    #[returns(PenaltiesResponse)]
+    Penalties { address: Option<String> },
+
+    /// Returns the receipt token denoms of the bonding assets, if the contract was instantiated
+    /// with receipt tokens.
+    #[returns(ReceiptDenomsResponse)]
+    ReceiptDenoms {},
+
+    /// Returns the voting power of the address at the given block height, or at the current one if
+    /// none is provided. The voting power is the weight of the bonds the address had at that height.
+    /// Compatible with the voting module interface of DAO DAO.
+    #[returns(VotingPowerAtHeightResponse)]
+    VotingPowerAtHeight {
+        address: String,
+        height: Option<u64>,
+    },
+
+    /// Returns the total voting power at the given block height, or at the current one if none is
+    /// provided, i.e. the global weight at that height.
+    #[returns(TotalPowerAtHeightResponse)]
+    TotalPowerAtHeight { height: Option<u64> },
+
+    /// Returns the contract name and version, as required by the voting module interface of
+    /// DAO DAO.
+    #[returns(InfoResponse)]
+    Info {},
+
+    /// Returns the address of the DAO the contract is the voting module of, which is its owner.
+    #[returns(Addr)]
+    Dao {},
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// The epoch manager the epochs are read from. Required when migrating from a version prior to
+    /// v0.10.0, as the epochs were read from the fee distributor until then. Otherwise it replaces
+    /// the stored epoch manager if set.
+    pub epoch_manager_addr: Option<String>,
+}
+
+#[cw_serde]
+pub enum SudoMsg {
+    /// Called by the token factory before receipt tokens are sent. Moves the bond backing the
+    /// receipt tokens from the sender to the recipient, or blocks the transfer if it can't.
+    BlockBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+    /// Called by the token factory before receipt tokens are sent. Can't block the transfer, so
+    /// it's ignored.
+    TrackBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+}
 
 /// Response for the Bonded query
 #[cw_serde]
@@ -149,6 +308,28 @@ pub struct WithdrawableResponse {
     pub withdrawable_amount: Uint128,
 }
 
+/// Response for the UnbondingSchedule query.
+#[cw_serde]
+pub struct UnbondingScheduleResponse {
+    pub schedule: Vec<UnbondingSchedule>,
+}
+
+#[cw_serde]
+pub struct UnbondingSchedule {
+    /// The denom of the bonding asset. The denom of cw20 tokens is their contract address.
+    pub denom: String,
+    /// The amount that has passed the unbonding period and can be withdrawn.
+    pub withdrawable_amount: Uint128,
+    /// The amount that is still in the unbonding period.
+    pub unbonding_amount: Uint128,
+    /// The amount of unbonding entries that are still in the unbonding period.
+    pub pending_unbondings: u32,
+    /// When the earliest unbonding still in the unbonding period matures.
+    pub next_maturity: Option<Timestamp>,
+    /// When the latest unbonding matures.
+    pub final_maturity: Option<Timestamp>,
+}
+
 /// Response for the Weight query.
 #[cw_serde]
 pub struct BondingWeightResponse {
@@ -158,3 +339,44 @@ pub struct BondingWeightResponse {
     pub share: Decimal,
     pub timestamp: Timestamp,
 }
+
+/// Response for the Penalties query.
+#[cw_serde]
+pub struct PenaltiesResponse {
+    /// The penalties charged, per denom.
+    pub penalties: Vec<Asset>,
+}
+
+/// Response for the VotingPowerAtHeight query.
+#[cw_serde]
+pub struct VotingPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
+/// Response for the TotalPowerAtHeight query.
+#[cw_serde]
+pub struct TotalPowerAtHeightResponse {
+    pub power: Uint128,
+    pub height: u64,
+}
+
+/// Response for the Info query.
+#[cw_serde]
+pub struct InfoResponse {
+    pub info: ContractVersion,
+}
+
+/// Response for the ReceiptDenoms query.
+#[cw_serde]
+pub struct ReceiptDenomsResponse {
+    pub receipt_denoms: Vec<ReceiptDenom>,
+}
+
+#[cw_serde]
+pub struct ReceiptDenom {
+    /// The denom of the bonding asset.
+    pub bonding_denom: String,
+    /// The denom of the receipt token representing bonds of the bonding asset.
+    pub receipt_denom: String,
+}
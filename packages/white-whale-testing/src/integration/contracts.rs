@@ -1,5 +1,6 @@
-use cosmwasm_std::Empty;
-use cw_multi_test::{App, Contract, ContractWrapper};
+use cosmwasm_std::{Addr, Empty, Timestamp, Uint64};
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use white_whale_std::epoch_manager::epoch_manager::{EpochConfig, EpochV2, InstantiateMsg};
 
 pub fn whale_lair_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -7,7 +8,8 @@ pub fn whale_lair_contract() -> Box<dyn Contract<Empty>> {
         whale_lair::contract::instantiate,
         whale_lair::contract::query,
     )
-    .with_migrate(whale_lair::contract::migrate);
+    .with_migrate(whale_lair::contract::migrate)
+    .with_sudo(whale_lair::contract::sudo);
 
     Box::new(contract)
 }
@@ -41,3 +43,43 @@ pub fn store_fee_collector_code(app: &mut App) -> u64 {
 
     app.store_code(contract)
 }
+
+/// Stores the epoch manager contract to the app
+pub fn store_epoch_manager_code(app: &mut App) -> u64 {
+    let contract = Box::new(
+        ContractWrapper::new_with_empty(
+            epoch_manager::contract::execute,
+            epoch_manager::contract::instantiate,
+            epoch_manager::contract::query,
+        )
+        .with_reply(epoch_manager::contract::reply)
+        .with_migrate(epoch_manager::contract::migrate),
+    );
+
+    app.store_code(contract)
+}
+
+/// Instantiates an epoch manager with daily epochs. It starts a day before the genesis epoch, so the
+/// first epoch it creates starts on March 14, 2023 2:00:00 PM.
+pub fn instantiate_epoch_manager(app: &mut App, creator: Addr) -> Addr {
+    let epoch_manager_id = store_epoch_manager_code(app);
+
+    app.instantiate_contract(
+        epoch_manager_id,
+        creator,
+        &InstantiateMsg {
+            start_epoch: EpochV2 {
+                id: 0,
+                start_time: Timestamp::from_nanos(1_678_716_000_000_000_000u64),
+            },
+            epoch_config: EpochConfig {
+                duration: Uint64::new(86_400_000_000_000u64), // a day
+                genesis_epoch: Uint64::new(1_678_716_000_000_000_000u64), // March 13, 2023 2:00:00 PM
+            },
+        },
+        &[],
+        "epoch_manager",
+        None,
+    )
+    .unwrap()
+}